@@ -90,6 +90,85 @@ export interface CentyClient {
     request: SearchItemsRequest,
     callback: (error: grpc.ServiceError | null, response: SearchItemsResponse) => void
   ): void;
+  aggregateItems(
+    request: AggregateItemsRequest,
+    callback: (error: grpc.ServiceError | null, response: AggregateItemsResponse) => void
+  ): void;
+  explainQuery(
+    request: ExplainQueryRequest,
+    callback: (error: grpc.ServiceError | null, response: ExplainQueryResponse) => void
+  ): void;
+
+  // Change notifications (server streaming)
+  watch(request: WatchRequest): grpc.ClientReadableStream<WatchEvent>;
+
+  // Mutation journal
+  listJournalEntries(
+    request: ListJournalEntriesRequest,
+    callback: (error: grpc.ServiceError | null, response: ListJournalEntriesResponse) => void
+  ): void;
+
+  // Hook run history
+  listHookRuns(
+    request: ListHookRunsRequest,
+    callback: (error: grpc.ServiceError | null, response: ListHookRunsResponse) => void
+  ): void;
+
+  // Async hook queue
+  listHookDeliveries(
+    request: ListHookDeliveriesRequest,
+    callback: (error: grpc.ServiceError | null, response: ListHookDeliveriesResponse) => void
+  ): void;
+  retryHookDelivery(
+    request: HookDeliveryRequest,
+    callback: (error: grpc.ServiceError | null, response: RetryHookDeliveryResponse) => void
+  ): void;
+  discardHookDelivery(
+    request: HookDeliveryRequest,
+    callback: (error: grpc.ServiceError | null, response: DiscardHookDeliveryResponse) => void
+  ): void;
+  testHook(
+    request: TestHookRequest,
+    callback: (error: grpc.ServiceError | null, response: TestHookResponse) => void
+  ): void;
+
+  // Item revision history
+  getItemHistory(
+    request: GetItemHistoryRequest,
+    callback: (error: grpc.ServiceError | null, response: GetItemHistoryResponse) => void
+  ): void;
+  getItemAtRevision(
+    request: GetItemAtRevisionRequest,
+    callback: (error: grpc.ServiceError | null, response: GetItemAtRevisionResponse) => void
+  ): void;
+
+  // Undo
+  undo(
+    request: UndoRequest,
+    callback: (error: grpc.ServiceError | null, response: UndoResponse) => void
+  ): void;
+
+  // Saved views
+  listViews(
+    request: ListViewsRequest,
+    callback: (error: grpc.ServiceError | null, response: ListViewsResponse) => void
+  ): void;
+  getView(
+    request: GetViewRequest,
+    callback: (error: grpc.ServiceError | null, response: GetViewResponse) => void
+  ): void;
+  createView(
+    request: CreateViewRequest,
+    callback: (error: grpc.ServiceError | null, response: CreateViewResponse) => void
+  ): void;
+  updateView(
+    request: UpdateViewRequest,
+    callback: (error: grpc.ServiceError | null, response: UpdateViewResponse) => void
+  ): void;
+  deleteView(
+    request: DeleteViewRequest,
+    callback: (error: grpc.ServiceError | null, response: DeleteViewResponse) => void
+  ): void;
 
   // Assets
   addAsset(
@@ -412,6 +491,9 @@ export interface ListItemsRequest {
   limit?: number;
   offset?: number;
   filter?: string;
+  sort?: string;
+  cursor?: string;
+  view?: string;
 }
 
 export interface ListItemsResponse {
@@ -419,6 +501,334 @@ export interface ListItemsResponse {
   error: string;
   items: GenericItem[];
   totalCount: number;
+  nextCursor: string;
+}
+
+export interface AggregateItemsRequest {
+  projectPath?: string;
+  organizationSlug?: string;
+  itemType: string;
+  filter?: string;
+  groupBy: string;
+}
+
+export interface ItemGroup {
+  key: string;
+  count: number;
+}
+
+export interface AggregateItemsResponse {
+  success: boolean;
+  error: string;
+  groups: ItemGroup[];
+  totalCount: number;
+  errors: string[];
+}
+
+export interface ExplainQueryRequest {
+  projectPath?: string;
+  filter: string;
+}
+
+export interface ExplainQueryResponse {
+  success: boolean;
+  error: string;
+  normalized: string;
+  pushedDown: string;
+  residual: string;
+  complete: boolean;
+}
+
+export interface WatchRequest {
+  projectPath: string;
+  itemType?: string;
+}
+
+export interface WatchEvent {
+  kind: 'created' | 'updated' | 'deleted' | 'moved';
+  entity: 'item' | 'link' | 'user' | 'config';
+  projectPath: string;
+  itemType: string;
+  id: string;
+  previousId: string;
+  movedToProjectPath: string;
+}
+
+export interface ListJournalEntriesRequest {
+  projectPath: string;
+  itemType?: string;
+  itemId?: string;
+  actor?: string;
+  since?: string;
+  until?: string;
+  limit?: number;
+}
+
+export interface JournalFieldChange {
+  field: string;
+  before: string;
+  after: string;
+}
+
+export interface JournalEntry {
+  timestamp: string;
+  actor: string;
+  operation: string;
+  entity: 'item' | 'link' | 'user';
+  itemType: string;
+  itemId: string;
+  changes: JournalFieldChange[];
+}
+
+export interface ListJournalEntriesResponse {
+  success: boolean;
+  error: string;
+  entries: JournalEntry[];
+  totalCount: number;
+}
+
+export interface ListHookRunsRequest {
+  projectPath: string;
+  itemType?: string;
+  itemId?: string;
+  pattern?: string;
+  phase?: 'pre' | 'post' | '';
+  outcome?: string;
+  limit?: number;
+}
+
+export interface HookRun {
+  timestamp: string;
+  pattern: string;
+  target: string;
+  phase: 'pre' | 'post';
+  itemType: string;
+  operation: string;
+  itemId: string;
+  isAsync: boolean;
+  outcome: 'succeeded' | 'failed' | 'timed_out' | 'errored' | 'skipped';
+  exitCode?: number;
+  durationMs: string;
+  timedOut: boolean;
+  stdout: string;
+  stderr: string;
+  error: string;
+}
+
+export interface ListHookRunsResponse {
+  success: boolean;
+  error: string;
+  runs: HookRun[];
+  totalCount: number;
+}
+
+export interface ListHookDeliveriesRequest {
+  projectPath: string;
+  status?: 'pending' | 'dead' | '';
+}
+
+export interface HookDelivery {
+  id: string;
+  pattern: string;
+  target: string;
+  itemType: string;
+  operation: string;
+  itemId: string;
+  status: 'pending' | 'dead';
+  attempts: number;
+  maxAttempts: number;
+  createdAt: string;
+  nextAttemptAt: string;
+  lastError: string;
+}
+
+export interface ListHookDeliveriesResponse {
+  success: boolean;
+  error: string;
+  deliveries: HookDelivery[];
+}
+
+export interface HookDeliveryRequest {
+  projectPath: string;
+  deliveryId: string;
+}
+
+export interface RetryHookDeliveryResponse {
+  success: boolean;
+  error: string;
+  delivery?: HookDelivery;
+}
+
+export interface DiscardHookDeliveryResponse {
+  success: boolean;
+  error: string;
+}
+
+export interface TestHookRequest {
+  projectPath: string;
+  itemType: string;
+  event: string;
+  requestData?: string;
+  itemId?: string;
+  execute?: boolean;
+}
+
+export interface TestedHook {
+  pattern: string;
+  target: string;
+  specificity: number;
+  isAsync: boolean;
+  whenMatches: boolean;
+  run?: HookRun;
+}
+
+export interface TestHookResponse {
+  success: boolean;
+  error: string;
+  phase: 'pre' | 'post' | '';
+  operation: string;
+  itemType: string;
+  hooks: TestedHook[];
+  requestData: string;
+  blockedBy: string;
+}
+
+export interface GetItemHistoryRequest {
+  projectPath: string;
+  itemType: string;
+  itemId: string;
+  limit?: number;
+}
+
+export interface ItemRevision {
+  commit: string;
+  authorName: string;
+  authorEmail: string;
+  date: string;
+  message: string;
+  path: string;
+  previousPath: string;
+  changeKind: string;
+  changes: JournalFieldChange[];
+  bodyDiff: string;
+}
+
+export interface GetItemHistoryResponse {
+  success: boolean;
+  error: string;
+  revisions: ItemRevision[];
+}
+
+export interface GetItemAtRevisionRequest {
+  projectPath: string;
+  itemType: string;
+  itemId: string;
+  revision: string;
+}
+
+export interface GetItemAtRevisionResponse {
+  success: boolean;
+  error: string;
+  item?: GenericItem;
+  commit: string;
+  path: string;
+  content: string;
+}
+
+export interface UndoRequest {
+  projectPath: string;
+  count?: number;
+}
+
+export interface UndoneOperation {
+  id: string;
+  timestamp: string;
+  operation: string;
+  itemType: string;
+  itemId: string;
+}
+
+export interface UndoResponse {
+  success: boolean;
+  error: string;
+  undone: UndoneOperation[];
+}
+
+export interface SavedView {
+  id: string;
+  name: string;
+  description: string;
+  itemType: string;
+  filter: string;
+  sort: string;
+  fields: string[];
+}
+
+export interface ListViewsRequest {
+  projectPath: string;
+  itemType?: string;
+}
+
+export interface ListViewsResponse {
+  success: boolean;
+  error: string;
+  views: SavedView[];
+  totalCount: number;
+}
+
+export interface GetViewRequest {
+  projectPath: string;
+  view: string;
+}
+
+export interface GetViewResponse {
+  success: boolean;
+  error: string;
+  view?: SavedView;
+}
+
+export interface CreateViewRequest {
+  projectPath: string;
+  id?: string;
+  name: string;
+  description?: string;
+  itemType: string;
+  filter?: string;
+  sort?: string;
+  fields?: string[];
+}
+
+export interface CreateViewResponse {
+  success: boolean;
+  error: string;
+  view?: SavedView;
+}
+
+export interface UpdateViewRequest {
+  projectPath: string;
+  viewId: string;
+  name: string;
+  description?: string;
+  itemType: string;
+  filter?: string;
+  sort?: string;
+  fields?: string[];
+}
+
+export interface UpdateViewResponse {
+  success: boolean;
+  error: string;
+  view?: SavedView;
+}
+
+export interface DeleteViewRequest {
+  projectPath: string;
+  viewId: string;
+}
+
+export interface DeleteViewResponse {
+  success: boolean;
+  error: string;
 }
 
 export interface UpdateItemRequest {
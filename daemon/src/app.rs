@@ -1,4 +1,5 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("centy_descriptor");
 pub const DEFAULT_ADDR: &str = "127.0.0.1:50051";
 /// Centy Daemon - Local-first issue and documentation tracker service
@@ -27,6 +28,71 @@ pub struct Args {
     /// Custom log directory (default: ~/.centy/logs)
     #[arg(long, env = "CENTY_LOG_DIR")]
     pub log_dir: Option<String>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+/// Commands run instead of the server
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Git merge driver for `.centy` markdown files.
+    /// Register it with:
+    /// git config merge.centy.driver "centy-daemon merge-driver %O %A %B %P"
+    MergeDriver(MergeDriverArgs),
+    /// Git post-merge hook: renumber issues whose display numbers collide
+    /// after a merge. Reconciliation installs it in the repository's
+    /// post-merge hook
+    PostMerge(PostMergeArgs),
+    /// Work with the project's hooks.yaml
+    Hooks(HooksArgs),
+}
+/// The files git passes to a merge driver
+#[derive(clap::Args, Debug)]
+pub struct MergeDriverArgs {
+    /// Common ancestor's version (%O)
+    pub base: PathBuf,
+    /// Current version (%A); the result is written here
+    pub ours: PathBuf,
+    /// Other branch's version (%B)
+    pub theirs: PathBuf,
+    /// Path of the file in the repository (%P)
+    pub path: String,
+}
+/// The project a post-merge hook renumbers
+#[derive(clap::Args, Debug)]
+pub struct PostMergeArgs {
+    /// Folder holding `.centy`, relative to the work tree root
+    #[arg(default_value = ".")]
+    pub project: PathBuf,
+}
+/// `centy-daemon hooks` subcommands
+#[derive(clap::Args, Debug)]
+pub struct HooksArgs {
+    #[command(subcommand)]
+    pub command: HooksCommand,
+}
+#[derive(Subcommand, Debug)]
+pub enum HooksCommand {
+    /// Show which hooks an event would run, most specific first.
+    /// With --run, run them against sample data; no item is touched.
+    Test(HookTestArgs),
+}
+/// The event to test and the sample to test it with
+#[derive(clap::Args, Debug)]
+pub struct HookTestArgs {
+    /// Event as `<item_type>.<event>`, e.g. issue.creating
+    pub event: String,
+    /// Project directory
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+    /// Sample request data, as a JSON object
+    #[arg(long)]
+    pub data: Option<String>,
+    /// Sample item id
+    #[arg(long)]
+    pub item_id: Option<String>,
+    /// Run the matching hooks instead of only listing them
+    #[arg(long)]
+    pub run: bool,
 }
 pub fn report_server_error(
     addr: std::net::SocketAddr,
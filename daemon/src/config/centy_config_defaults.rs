@@ -16,6 +16,7 @@ fn test_centy_config_default() {
     assert!(config.priority_colors.is_empty());
     assert!(config.custom_link_types.is_empty());
     assert!(config.workspace.update_status_on_open.is_none());
+    assert!(!config.auto_commit.enabled);
 }
 
 #[test]
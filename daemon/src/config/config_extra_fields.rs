@@ -42,6 +42,7 @@ fn test_is_system_key_exact() {
     assert!(is_system_key("priorityLevels"));
     assert!(is_system_key("workspace"));
     assert!(is_system_key("cleanup"));
+    assert!(is_system_key("autoCommit"));
     assert!(!is_system_key("team"));
     assert!(!is_system_key("myCustomKey"));
 }
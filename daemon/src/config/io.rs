@@ -1,7 +1,8 @@
 use super::migrate;
 use super::project_metadata::ProjectMetadata;
-use super::types::CentyConfig;
+use super::types::{CentyConfig, SyncConfig};
 use crate::utils::get_centy_path;
+use crate::watch::{publish, ChangeEvent, ChangeKind, EntityKind};
 use std::path::Path;
 use tokio::fs;
 use tracing::warn;
@@ -32,6 +33,22 @@ pub async fn read_config(project_path: &Path) -> Result<Option<CentyConfig>, mds
     }
     Ok(Some(config))
 }
+/// Read only the `sync` section, without the async runtime, for git work
+/// that already runs on a blocking thread. A missing file or section gives
+/// the defaults.
+pub fn read_sync_config(project_path: &Path) -> Result<SyncConfig, mdstore::ConfigError> {
+    let config_path = get_centy_path(project_path).join("config.json");
+    let content = match std::fs::read_to_string(&config_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(SyncConfig::default()),
+        Err(e) => return Err(e.into()),
+    };
+    let raw: serde_json::Value = serde_json::from_str(&content)?;
+    match raw.get("sync") {
+        Some(section) => Ok(serde_json::from_value(section.clone())?),
+        None => Ok(SyncConfig::default()),
+    }
+}
 /// Write the configuration file in flat dot-separated format.
 pub async fn write_config(
     project_path: &Path,
@@ -42,6 +59,13 @@ pub async fn write_config(
     let flat_value = migrate::flatten_config(nested_value);
     let content = serde_json::to_string_pretty(&flat_value)?;
     fs::write(&config_path, content).await?;
+    publish(ChangeEvent::new(
+        ChangeKind::Updated,
+        EntityKind::Config,
+        project_path,
+        "",
+        "",
+    ));
     Ok(())
 }
 /// Read the project metadata file (.centy/project.json)
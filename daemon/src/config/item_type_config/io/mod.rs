@@ -6,6 +6,7 @@ pub use legacy::read_legacy_allowed_states;
 
 use super::types::ItemTypeConfig;
 use crate::utils::{get_centy_path, with_yaml_header};
+use crate::watch::{publish, ChangeEvent, ChangeKind, EntityKind};
 use std::path::Path;
 use tokio::fs;
 
@@ -40,5 +41,12 @@ pub async fn write_item_type_config(
     let config_path = type_dir.join("config.yaml");
     let content = with_yaml_header(&serde_yaml::to_string(config)?);
     fs::write(&config_path, content).await?;
+    publish(ChangeEvent::new(
+        ChangeKind::Updated,
+        EntityKind::Config,
+        project_path,
+        folder,
+        "",
+    ));
     Ok(())
 }
@@ -5,12 +5,15 @@ mod project_metadata;
 mod system_keys;
 mod types;
 pub use io::{
-    get_project_title, read_config, read_project_metadata, set_project_title, write_config,
-    write_project_metadata,
+    get_project_title, read_config, read_project_metadata, read_sync_config, set_project_title,
+    write_config, write_project_metadata,
 };
 pub use project_metadata::ProjectMetadata;
 pub use system_keys::is_system_key;
-pub use types::{default_priority_levels, CentyConfig, CleanupConfig, WorkspaceConfig};
+pub use types::{
+    default_priority_levels, AutoCommitConfig, CentyConfig, CleanupConfig, SyncConfig,
+    WorkspaceConfig,
+};
 #[cfg(test)]
 #[path = "centy_config_defaults.rs"]
 mod centy_config_defaults;
@@ -10,6 +10,8 @@ const SYSTEM_KEYS: &[&str] = &[
     "defaultEditor",
     "workspace",
     "cleanup",
+    "autoCommit",
+    "sync",
 ];
 
 /// Key prefixes that belong to system-managed sections.
@@ -18,6 +18,47 @@ pub struct CleanupConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retention_period: Option<String>,
 }
+/// Auto-commit configuration section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCommitConfig {
+    /// Commit every change to `.centy/` to the checked-out branch.
+    #[serde(default)]
+    pub enabled: bool,
+    /// How long to wait for further changes before committing, so a burst
+    /// of changes becomes one commit.
+    ///
+    /// In milliseconds. Omit to use the default (2000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debounce_ms: Option<u64>,
+}
+/// Sync configuration section: where the `.centy` sync branch is exchanged
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConfig {
+    /// Git remote the sync branch is fetched from and pushed to.
+    #[serde(default = "default_sync_remote")]
+    pub remote: String,
+    /// Branch holding the synced `.centy` directories, apart from the code.
+    #[serde(default = "default_sync_branch")]
+    pub branch: String,
+}
+#[must_use]
+pub fn default_sync_remote() -> String {
+    "origin".to_string()
+}
+#[must_use]
+pub fn default_sync_branch() -> String {
+    "centy".to_string()
+}
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            remote: default_sync_remote(),
+            branch: default_sync_branch(),
+        }
+    }
+}
 /// Workspace configuration section
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -59,6 +100,12 @@ pub struct CentyConfig {
     /// Cleanup / retention settings for soft-deleted artifacts
     #[serde(default)]
     pub cleanup: CleanupConfig,
+    /// Auto-commit settings for changes to `.centy/`
+    #[serde(default)]
+    pub auto_commit: AutoCommitConfig,
+    /// Remote and branch used by sync pulls and pushes
+    #[serde(default)]
+    pub sync: SyncConfig,
     /// User-defined free-form key-value pairs (preserved through read/write cycles)
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
@@ -85,6 +132,8 @@ impl Default for CentyConfig {
             default_editor: None,
             workspace: WorkspaceConfig::default(),
             cleanup: CleanupConfig::default(),
+            auto_commit: AutoCommitConfig::default(),
+            sync: SyncConfig::default(),
             extra: HashMap::new(),
         }
     }
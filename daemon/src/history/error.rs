@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Errors raised while reading an item's history from git.
+#[derive(Error, Debug)]
+pub enum HistoryError {
+    #[error("Project is not inside a git repository")]
+    NotGitRepository,
+
+    #[error("Project directory {0} is outside the repository work tree")]
+    OutsideWorkTree(String),
+
+    #[error("Revision not found: {0}")]
+    RevisionNotFound(String),
+
+    #[error("Item {item_id} does not exist at revision {revision}")]
+    NotInRevision { item_id: String, revision: String },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Git error: {0}")]
+    Git(#[from] git2::Error),
+}
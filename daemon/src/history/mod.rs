@@ -0,0 +1,17 @@
+//! Item revision history from git.
+//!
+//! Items are plain markdown files under `.centy/`, so a project committed to
+//! git already holds every earlier version of them. This module walks the
+//! commits that touched an item's file, following moves between item types
+//! and slug renames, and turns each into a frontmatter-aware diff. It backs
+//! the `GetItemHistory` and `GetItemAtRevision` RPCs.
+mod error;
+mod revisions;
+mod types;
+mod walk;
+pub use error::HistoryError;
+pub use revisions::{item_at_revision, item_history};
+pub use types::{ChangeKind, ItemAtRevision, Revision};
+#[cfg(test)]
+#[path = "revisions_tests.rs"]
+mod revisions_tests;
@@ -0,0 +1,161 @@
+use super::error::HistoryError;
+use super::types::{ChangeKind, ItemAtRevision, Revision};
+use super::walk::{blob_at, file_changes, item_path, open_repo, walk_file_changes, FileChange};
+use crate::journal::{diff_fields, item_fields};
+use crate::utils::strip_centy_md_header;
+use chrono::DateTime;
+use git2::{Oid, Patch, Repository};
+use std::ops::ControlFlow;
+use std::path::Path;
+
+/// The commits that touched an item's file, newest first, each with its
+/// author, date, message, field changes and body diff.
+///
+/// History follows the item through moves between types and slug renames.
+/// `limit` caps the number of revisions (`0` for no limit).
+pub fn item_history(
+    project_path: &Path,
+    folder: &str,
+    id: &str,
+    limit: usize,
+) -> Result<Vec<Revision>, HistoryError> {
+    let repo = open_repo(project_path)?;
+    let path = item_path(&repo, project_path, folder, id)?;
+    file_changes(&repo, &path, limit)?
+        .iter()
+        .map(|change| to_revision(&repo, change))
+        .collect()
+}
+
+/// An item as stored at `revision` (anything `git rev-parse` accepts).
+///
+/// The item is looked up under its current path first and then under the
+/// paths it had before moves and renames, walking back only until one of
+/// them is in the revision.
+pub fn item_at_revision(
+    project_path: &Path,
+    folder: &str,
+    id: &str,
+    revision: &str,
+) -> Result<ItemAtRevision, HistoryError> {
+    let repo = open_repo(project_path)?;
+    let commit = repo
+        .revparse_single(revision)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|_e| HistoryError::RevisionNotFound(revision.to_string()))?;
+    let tree = commit.tree()?;
+    let path = item_path(&repo, project_path, folder, id)?;
+    let not_found = || HistoryError::NotInRevision {
+        item_id: id.to_string(),
+        revision: revision.to_string(),
+    };
+    let located = match blob_at(&tree, &path) {
+        Some(blob) => Some((path, blob)),
+        None => walk_file_changes(&repo, &path, |change| {
+            change
+                .previous_path
+                .and_then(|previous| blob_at(&tree, &previous).map(|blob| (previous, blob)))
+                .map_or(ControlFlow::Continue(()), ControlFlow::Break)
+        })?,
+    };
+    let (found, blob) = located.ok_or_else(not_found)?;
+    let content = blob_text(&repo, Some(blob))?;
+    let stem = file_name(found.file_stem());
+    let item_type = file_name(found.parent().and_then(Path::file_name));
+    let item = parse_item(&stem, &content).ok_or_else(not_found)?;
+    Ok(ItemAtRevision {
+        item,
+        item_type,
+        commit: commit.id().to_string(),
+        path: found.to_string_lossy().to_string(),
+        content,
+    })
+}
+
+fn to_revision(repo: &Repository, change: &FileChange) -> Result<Revision, HistoryError> {
+    let commit = repo.find_commit(change.commit)?;
+    let author = commit.author();
+    let old_text = blob_text(repo, change.old_blob)?;
+    let new_text = blob_text(repo, change.new_blob)?;
+    let id = file_name(change.path.file_stem());
+    let old_item = change.old_blob.and_then(|_| parse_item(&id, &old_text));
+    let new_item = change.new_blob.and_then(|_| parse_item(&id, &new_text));
+    let (changes, body_diff) = if old_item.is_none() && new_item.is_none() {
+        // Unparseable content is diffed as plain text.
+        (Vec::new(), text_diff(&old_text, &new_text)?)
+    } else {
+        let old_fields = old_item.as_ref().map(item_fields);
+        let new_fields = new_item.as_ref().map(item_fields);
+        let changes = diff_fields(old_fields.as_ref(), new_fields.as_ref())
+            .into_iter()
+            .filter(|c| c.field != "body")
+            .collect();
+        let body = |item: Option<&mdstore::Item>| item.map(|i| i.body.clone()).unwrap_or_default();
+        let diff = text_diff(&body(old_item.as_ref()), &body(new_item.as_ref()))?;
+        (changes, diff)
+    };
+    let kind = match (&change.previous_path, change.old_blob, change.new_blob) {
+        (Some(_), _, _) => ChangeKind::Renamed,
+        (None, None, _) => ChangeKind::Added,
+        (None, Some(_), None) => ChangeKind::Deleted,
+        (None, Some(_), Some(_)) => ChangeKind::Modified,
+    };
+    Ok(Revision {
+        commit: commit.id().to_string(),
+        author_name: author.name().unwrap_or_default().to_string(),
+        author_email: author.email().unwrap_or_default().to_string(),
+        date: DateTime::from_timestamp(commit.time().seconds(), 0)
+            .map(|at| at.to_rfc3339())
+            .unwrap_or_default(),
+        message: commit.message().unwrap_or_default().trim().to_string(),
+        path: change.path.to_string_lossy().to_string(),
+        previous_path: change
+            .previous_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string()),
+        kind,
+        changes,
+        body_diff,
+    })
+}
+
+fn blob_text(repo: &Repository, blob: Option<Oid>) -> Result<String, HistoryError> {
+    match blob {
+        Some(oid) => Ok(String::from_utf8_lossy(repo.find_blob(oid)?.content()).to_string()),
+        None => Ok(String::new()),
+    }
+}
+
+fn file_name(name: Option<&std::ffi::OsStr>) -> String {
+    name.map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Parse an item file the way the item store writes it.
+fn parse_item(id: &str, content: &str) -> Option<mdstore::Item> {
+    let (frontmatter, title, body): (mdstore::Frontmatter, String, String) =
+        mdstore::parse_frontmatter(strip_centy_md_header(content)).ok()?;
+    Some(mdstore::Item {
+        id: id.to_string(),
+        title,
+        body,
+        frontmatter,
+        comment: None,
+    })
+}
+
+/// Unified diff between two texts, without file headers.
+fn text_diff(old: &str, new: &str) -> Result<String, HistoryError> {
+    if old == new {
+        return Ok(String::new());
+    }
+    let mut patch = Patch::from_buffers(old.as_bytes(), None, new.as_bytes(), None, None)?;
+    let buf = patch.to_buf()?;
+    let text = String::from_utf8_lossy(&buf);
+    // Drop the file headers that precede the first hunk.
+    let hunks = text
+        .find("@@")
+        .and_then(|start| text.get(start..))
+        .unwrap_or_default();
+    Ok(hunks.to_string())
+}
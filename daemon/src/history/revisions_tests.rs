@@ -0,0 +1,296 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::*;
+use git2::{Commit, IndexAddOption, Repository, Signature};
+use std::path::Path;
+
+fn item_file(title: &str, status: &str, body: &str) -> String {
+    format!(
+        "---\nstatus: {status}\npriority: 2\ncreatedAt: 2024-01-01T00:00:00Z\nupdatedAt: 2024-01-01T00:00:00Z\n---\n# {title}\n\n{body}\n"
+    )
+}
+
+fn write(project: &Path, folder: &str, id: &str, content: &str) {
+    let dir = project.join(".centy").join(folder);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(format!("{id}.md")), content).unwrap();
+}
+
+fn commit_all(repo: &Repository, message: &str) {
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"], IndexAddOption::DEFAULT, None).unwrap();
+    index.update_all(["*"], None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Alice", "alice@example.com").unwrap();
+    let parents: Vec<Commit<'_>> = repo
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok())
+        .into_iter()
+        .collect();
+    let parent_refs: Vec<&Commit<'_>> = parents.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parent_refs,
+    )
+    .unwrap();
+}
+
+fn setup() -> (tempfile::TempDir, Repository) {
+    let temp = tempfile::tempdir().unwrap();
+    let repo = Repository::init(temp.path()).unwrap();
+    (temp, repo)
+}
+
+#[test]
+fn test_history_lists_edits_newest_first() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "old text"),
+    );
+    commit_all(&repo, "Add login issue");
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "closed", "new text"),
+    );
+    commit_all(&repo, "Close login issue");
+
+    let revisions = item_history(project, "issues", "login", 0).unwrap();
+
+    assert_eq!(revisions.len(), 2);
+    let latest = &revisions[0];
+    assert_eq!(latest.kind, ChangeKind::Modified);
+    assert_eq!(latest.message, "Close login issue");
+    assert_eq!(latest.author_name, "Alice");
+    assert_eq!(latest.author_email, "alice@example.com");
+    assert_eq!(latest.path, ".centy/issues/login.md");
+    assert_eq!(latest.changes.len(), 1);
+    assert_eq!(latest.changes[0].field, "status");
+    assert_eq!(latest.changes[0].before, Some(serde_json::json!("open")));
+    assert_eq!(latest.changes[0].after, Some(serde_json::json!("closed")));
+    assert!(latest.body_diff.contains("-old text"));
+    assert!(latest.body_diff.contains("+new text"));
+    assert_eq!(revisions[1].kind, ChangeKind::Added);
+    assert!(revisions[1].changes.iter().any(|c| c.field == "title"));
+}
+
+#[test]
+fn test_history_skips_commits_that_do_not_touch_the_item() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Add login issue");
+    write(
+        project,
+        "issues",
+        "other",
+        &item_file("Other", "open", "text"),
+    );
+    commit_all(&repo, "Add other issue");
+
+    let revisions = item_history(project, "issues", "login", 0).unwrap();
+
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].message, "Add login issue");
+}
+
+#[test]
+fn test_history_follows_moves_and_renames() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Add login issue");
+    std::fs::remove_file(project.join(".centy/issues/login.md")).unwrap();
+    write(
+        project,
+        "archived",
+        "login-bug",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Archive login issue");
+
+    let revisions = item_history(project, "archived", "login-bug", 0).unwrap();
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].kind, ChangeKind::Renamed);
+    assert_eq!(
+        revisions[0].previous_path.as_deref(),
+        Some(".centy/issues/login.md")
+    );
+    assert!(revisions[0].changes.is_empty());
+    assert_eq!(revisions[1].kind, ChangeKind::Added);
+    assert_eq!(revisions[1].path, ".centy/issues/login.md");
+}
+
+#[test]
+fn test_history_keeps_deleted_items() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Add login issue");
+    std::fs::remove_file(project.join(".centy/issues/login.md")).unwrap();
+    write(
+        project,
+        "issues",
+        "other",
+        &item_file("Other", "open", "text"),
+    );
+    commit_all(&repo, "Drop login issue");
+
+    let revisions = item_history(project, "issues", "login", 0).unwrap();
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].kind, ChangeKind::Deleted);
+    assert!(revisions[0].body_diff.contains("-text"));
+}
+
+#[test]
+fn test_history_limit() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    for status in ["open", "in-progress", "closed"] {
+        write(
+            project,
+            "issues",
+            "login",
+            &item_file("Login", status, "text"),
+        );
+        commit_all(&repo, status);
+    }
+
+    let revisions = item_history(project, "issues", "login", 2).unwrap();
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].message, "closed");
+}
+
+#[test]
+fn test_history_of_empty_repository_is_empty() {
+    let (temp, _repo) = setup();
+    std::fs::create_dir_all(temp.path().join(".centy")).unwrap();
+
+    assert!(item_history(temp.path(), "issues", "login", 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_history_outside_git_fails() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp.path().join(".centy")).unwrap();
+
+    let result = item_history(temp.path(), "issues", "login", 0);
+
+    assert!(matches!(result, Err(HistoryError::NotGitRepository)));
+}
+
+#[test]
+fn test_item_at_revision_returns_old_version() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "old text"),
+    );
+    commit_all(&repo, "Add login issue");
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login page", "closed", "new"),
+    );
+    commit_all(&repo, "Close login issue");
+
+    let old = item_at_revision(project, "issues", "login", "HEAD~1").unwrap();
+
+    assert_eq!(old.item.id, "login");
+    assert_eq!(old.item.title, "Login");
+    assert_eq!(old.item.frontmatter.status.as_deref(), Some("open"));
+    assert_eq!(old.item_type, "issues");
+    assert_eq!(old.path, ".centy/issues/login.md");
+    assert!(old.content.contains("old text"));
+}
+
+#[test]
+fn test_item_at_revision_before_rename_uses_old_path() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Add login issue");
+    let first = repo.head().unwrap().target().unwrap().to_string();
+    std::fs::remove_file(project.join(".centy/issues/login.md")).unwrap();
+    write(
+        project,
+        "issues",
+        "login-bug",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Rename login issue");
+
+    let old = item_at_revision(project, "issues", "login-bug", first.get(..8).unwrap()).unwrap();
+
+    assert_eq!(old.item.id, "login");
+    assert_eq!(old.commit, first);
+    assert_eq!(old.path, ".centy/issues/login.md");
+}
+
+#[test]
+fn test_item_at_revision_errors() {
+    let (temp, repo) = setup();
+    let project = temp.path();
+    write(
+        project,
+        "issues",
+        "login",
+        &item_file("Login", "open", "text"),
+    );
+    commit_all(&repo, "Add login issue");
+    write(
+        project,
+        "issues",
+        "other",
+        &item_file("Other", "open", "text"),
+    );
+    commit_all(&repo, "Add other issue");
+
+    assert!(matches!(
+        item_at_revision(project, "issues", "login", "no-such-branch"),
+        Err(HistoryError::RevisionNotFound(_))
+    ));
+    assert!(matches!(
+        item_at_revision(project, "issues", "other", "HEAD~1"),
+        Err(HistoryError::NotInRevision { .. })
+    ));
+}
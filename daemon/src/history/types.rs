@@ -0,0 +1,57 @@
+use crate::journal::FieldChange;
+
+/// How a commit changed an item's file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    /// Moved to another folder or renamed, possibly with edits.
+    Renamed,
+    Deleted,
+}
+
+impl ChangeKind {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Modified => "modified",
+            Self::Renamed => "renamed",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// One commit that touched an item's file.
+#[derive(Debug, Clone)]
+pub struct Revision {
+    pub commit: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// Commit time, RFC 3339 in UTC.
+    pub date: String,
+    pub message: String,
+    /// Repository-relative path of the file in this commit.
+    pub path: String,
+    /// Path in the parent commit, when this commit renamed the file.
+    pub previous_path: Option<String>,
+    pub kind: ChangeKind,
+    /// Frontmatter and title changes; the body is in `body_diff`.
+    pub changes: Vec<FieldChange>,
+    /// Unified diff of the body, hunks only.
+    pub body_diff: String,
+}
+
+/// An item as stored at a given revision.
+#[derive(Debug, Clone)]
+pub struct ItemAtRevision {
+    pub item: mdstore::Item,
+    /// Folder the item lived in at that revision.
+    pub item_type: String,
+    /// Full id of the resolved commit.
+    pub commit: String,
+    /// Repository-relative path of the file at that revision.
+    pub path: String,
+    /// Raw file content.
+    pub content: String,
+}
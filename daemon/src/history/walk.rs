@@ -0,0 +1,151 @@
+use super::error::HistoryError;
+use crate::utils::get_centy_path;
+use git2::{Delta, DiffFindOptions, DiffOptions, ErrorCode, ObjectType, Oid, Repository, Tree};
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
+/// A commit on the first-parent history of HEAD that changed an item's file.
+#[derive(Debug)]
+pub(super) struct FileChange {
+    pub commit: Oid,
+    /// Path of the file in the commit.
+    pub path: PathBuf,
+    /// Path in the parent, when the commit renamed the file.
+    pub previous_path: Option<PathBuf>,
+    pub old_blob: Option<Oid>,
+    pub new_blob: Option<Oid>,
+}
+
+pub(super) fn open_repo(project_path: &Path) -> Result<Repository, HistoryError> {
+    Repository::discover(project_path).map_err(|e| {
+        if e.code() == ErrorCode::NotFound {
+            HistoryError::NotGitRepository
+        } else {
+            HistoryError::Git(e)
+        }
+    })
+}
+
+/// Path of an item's file relative to the repository work tree. The file
+/// does not need to exist, so deleted items keep their history.
+pub(super) fn item_path(
+    repo: &Repository,
+    project_path: &Path,
+    folder: &str,
+    id: &str,
+) -> Result<PathBuf, HistoryError> {
+    let workdir = repo
+        .workdir()
+        .ok_or(HistoryError::NotGitRepository)?
+        .canonicalize()?;
+    let centy = get_centy_path(project_path).canonicalize()?;
+    let relative = centy
+        .strip_prefix(&workdir)
+        .map_err(|_e| HistoryError::OutsideWorkTree(project_path.display().to_string()))?;
+    Ok(relative.join(folder).join(format!("{id}.md")))
+}
+
+/// The commits that changed the file at `path`, newest first, following
+/// renames back to the commit that added it. Only the first-parent history
+/// of HEAD is walked, so merged branches show up as their merge commit.
+/// `limit` caps the number of changes returned (`0` for no limit).
+pub(super) fn file_changes(
+    repo: &Repository,
+    path: &Path,
+    limit: usize,
+) -> Result<Vec<FileChange>, HistoryError> {
+    let mut changes = Vec::new();
+    walk_file_changes(repo, path, |change| {
+        changes.push(change);
+        if limit > 0 && changes.len() >= limit {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })?;
+    Ok(changes)
+}
+
+/// Hand the changes [`file_changes`] lists to `visit` one at a time, and
+/// stop walking as soon as it breaks with a value.
+pub(super) fn walk_file_changes<B>(
+    repo: &Repository,
+    path: &Path,
+    mut visit: impl FnMut(FileChange) -> ControlFlow<B>,
+) -> Result<Option<B>, HistoryError> {
+    if repo.is_empty()? {
+        return Ok(None);
+    }
+    let mut walk = repo.revwalk()?;
+    walk.push_head()?;
+    walk.simplify_first_parent()?;
+
+    let mut current = path.to_path_buf();
+    for oid in walk {
+        let commit = repo.find_commit(oid?)?;
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_e) => None,
+        };
+        let new_blob = blob_at(&tree, &current);
+        let parent_blob = parent_tree.as_ref().and_then(|t| blob_at(t, &current));
+        if new_blob == parent_blob {
+            continue;
+        }
+        let previous_path = match (&parent_tree, parent_blob, new_blob) {
+            (Some(parent), None, Some(_)) => renamed_from(repo, parent, &tree, &current)?,
+            (Some(_) | None, _, _) => None,
+        };
+        let old_blob = match (&previous_path, &parent_tree) {
+            (Some(previous), Some(parent)) => blob_at(parent, previous),
+            (Some(_) | None, _) => parent_blob,
+        };
+        let added = old_blob.is_none();
+        let change = FileChange {
+            commit: commit.id(),
+            path: current.clone(),
+            previous_path: previous_path.clone(),
+            old_blob,
+            new_blob,
+        };
+        if let ControlFlow::Break(value) = visit(change) {
+            return Ok(Some(value));
+        }
+        match previous_path {
+            Some(previous) => current = previous,
+            None if added => break,
+            None => {}
+        }
+    }
+    Ok(None)
+}
+
+/// The blob at `path` in `tree`, if there is one.
+pub(super) fn blob_at(tree: &Tree<'_>, path: &Path) -> Option<Oid> {
+    tree.get_path(path)
+        .ok()
+        .filter(|entry| entry.kind() == Some(ObjectType::Blob))
+        .map(|entry| entry.id())
+}
+
+/// Where a file added at `path` came from, when git sees the addition as a
+/// rename. Rename detection is limited to the `.centy` directory the file
+/// lives in, which covers moves between item types and slug renames.
+fn renamed_from(
+    repo: &Repository,
+    parent: &Tree<'_>,
+    tree: &Tree<'_>,
+    path: &Path,
+) -> Result<Option<PathBuf>, HistoryError> {
+    let mut options = DiffOptions::new();
+    if let Some(centy_dir) = path.parent().and_then(Path::parent) {
+        options.pathspec(centy_dir);
+    }
+    let mut diff = repo.diff_tree_to_tree(Some(parent), Some(tree), Some(&mut options))?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))?;
+    Ok(diff
+        .deltas()
+        .find(|delta| delta.status() == Delta::Renamed && delta.new_file().path() == Some(path))
+        .and_then(|delta| delta.old_file().path().map(Path::to_path_buf)))
+}
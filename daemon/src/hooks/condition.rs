@@ -0,0 +1,131 @@
+use super::context::HookContext;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A hook's `when:` clause: field name to condition. The hook runs only
+/// when every condition holds; an empty clause always holds.
+pub type WhenClause = BTreeMap<String, Condition>;
+
+/// A condition on one field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Condition {
+    /// `{ from: a, to: b }`: the field changed, optionally from and to the
+    /// given values. `{}` matches any change.
+    Changed(ChangeCondition),
+    /// `{ contains: x }`: a list field, such as `tags`, includes `x`.
+    Contains(ContainsCondition),
+    /// A plain value: the field equals it.
+    Equals(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChangeCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContainsCondition {
+    pub contains: Value,
+}
+
+/// Whether every condition in `when` holds for the operation in `context`.
+#[must_use]
+pub fn when_matches(when: &WhenClause, context: &HookContext) -> bool {
+    when.iter().all(|(field, condition)| {
+        let old = context
+            .before
+            .as_ref()
+            .and_then(|item| item_field(item, field));
+        let new = new_value(context, field);
+        match condition {
+            Condition::Equals(expected) => new.is_some_and(|value| loose_eq(value, expected)),
+            Condition::Contains(ContainsCondition { contains }) => new
+                .and_then(Value::as_array)
+                .is_some_and(|values| values.iter().any(|value| loose_eq(value, contains))),
+            Condition::Changed(ChangeCondition { from, to }) => {
+                !opt_eq(old, new)
+                    && from.as_ref().is_none_or(|f| opt_eq(old, Some(f)))
+                    && to.as_ref().is_none_or(|t| opt_eq(new, Some(t)))
+            }
+        }
+    })
+}
+
+/// The field's value once the operation is done: from the stored item when
+/// there is one, else as the request sets it, else unchanged from before.
+fn new_value<'ctx>(context: &'ctx HookContext, field: &str) -> Option<&'ctx Value> {
+    let before = || item_field(context.before.as_ref()?, field);
+    context.after.as_ref().map_or_else(
+        || {
+            request_field(context.request_data.as_ref()?, field)
+                .filter(|value| !is_unset(value))
+                .or_else(before)
+        },
+        |after| item_field(after, field),
+    )
+}
+
+/// A field of an item snapshot, where custom fields are `customFields.<name>`.
+fn item_field<'item>(item: &'item Value, field: &str) -> Option<&'item Value> {
+    custom_field_name(field).map_or_else(
+        || item.get(field),
+        |name| item.get(format!("customFields.{name}")),
+    )
+}
+
+/// A field of the request, where custom fields nest under `custom_fields`.
+fn request_field<'req>(request: &'req Value, field: &str) -> Option<&'req Value> {
+    custom_field_name(field).map_or_else(
+        || request.get(field),
+        |name| request.get("custom_fields")?.get(name),
+    )
+}
+
+fn custom_field_name(field: &str) -> Option<&str> {
+    field
+        .strip_prefix("customFields.")
+        .or_else(|| field.strip_prefix("custom_fields."))
+}
+
+/// Request values that mean "leave unchanged" in an update.
+fn is_unset(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Number(number) => number.as_u64() == Some(0),
+        Value::Array(items) => items.is_empty(),
+        Value::Bool(_) | Value::Object(_) => false,
+    }
+}
+
+fn opt_eq(left: Option<&Value>, right: Option<&Value>) -> bool {
+    match (left, right) {
+        (Some(l), Some(r)) => loose_eq(l, r),
+        (None, None) => true,
+        (Some(_), None) | (None, Some(_)) => false,
+    }
+}
+
+/// Equality that lets YAML `priority: "1"` match the number 1.
+fn loose_eq(left: &Value, right: &Value) -> bool {
+    left == right || scalar_text(left).is_some_and(|l| scalar_text(right) == Some(l))
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "condition_tests.rs"]
+mod condition_tests;
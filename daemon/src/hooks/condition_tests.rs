@@ -0,0 +1,154 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+use crate::hooks::{HookOperation, Phase};
+use serde_json::json;
+
+fn when(yaml: &str) -> WhenClause {
+    serde_yaml::from_str(yaml).expect("Should deserialize")
+}
+
+fn context(request: Value, before: Option<Value>, after: Option<Value>) -> HookContext {
+    HookContext::new(
+        Phase::Pre,
+        "issue",
+        HookOperation::Update,
+        "/tmp/project",
+        Some("abc"),
+        Some(request),
+        None,
+    )
+    .with_items(before, after)
+}
+
+#[test]
+fn test_when_parses_each_condition_kind() {
+    let clause = when(
+        "priority: 1\nstatus:\n  from: open\n  to: closed\ntags:\n  contains: urgent\ntitle: {}\n",
+    );
+
+    assert_eq!(clause["priority"], Condition::Equals(json!(1i32)));
+    assert_eq!(
+        clause["status"],
+        Condition::Changed(ChangeCondition {
+            from: Some(json!("open")),
+            to: Some(json!("closed")),
+        })
+    );
+    assert_eq!(
+        clause["tags"],
+        Condition::Contains(ContainsCondition {
+            contains: json!("urgent"),
+        })
+    );
+    assert_eq!(
+        clause["title"],
+        Condition::Changed(ChangeCondition {
+            from: None,
+            to: None
+        })
+    );
+}
+
+#[test]
+fn test_empty_when_always_matches() {
+    let ctx = context(json!({}), None, None);
+    assert!(when_matches(&WhenClause::new(), &ctx));
+}
+
+#[test]
+fn test_equality_uses_request_then_item() {
+    let before = json!({"status": "open", "priority": 2i32});
+    let ctx = context(json!({"status": "", "priority": 1i32}), Some(before), None);
+
+    assert!(when_matches(&when("priority: 1"), &ctx));
+    assert!(when_matches(&when("priority: \"1\""), &ctx));
+    assert!(!when_matches(&when("priority: 2"), &ctx));
+    // An empty request field leaves the item's value in place
+    assert!(when_matches(&when("status: open"), &ctx));
+    assert!(!when_matches(&when("assignee: me"), &ctx));
+}
+
+#[test]
+fn test_equality_prefers_item_after() {
+    let ctx = context(
+        json!({"status": "closed"}),
+        Some(json!({"status": "open"})),
+        Some(json!({"status": "done"})),
+    );
+    assert!(when_matches(&when("status: done"), &ctx));
+    assert!(!when_matches(&when("status: closed"), &ctx));
+}
+
+#[test]
+fn test_changed_from_to() {
+    let ctx = context(
+        json!({"status": "closed"}),
+        Some(json!({"status": "open"})),
+        None,
+    );
+
+    assert!(when_matches(&when("status: {to: closed}"), &ctx));
+    assert!(when_matches(
+        &when("status: {from: open, to: closed}"),
+        &ctx
+    ));
+    assert!(when_matches(&when("status: {}"), &ctx));
+    assert!(!when_matches(&when("status: {from: in-progress}"), &ctx));
+    assert!(!when_matches(&when("title: {}"), &ctx));
+}
+
+#[test]
+fn test_changed_requires_a_change() {
+    let ctx = context(
+        json!({"status": "closed"}),
+        Some(json!({"status": "closed"})),
+        None,
+    );
+    assert!(!when_matches(&when("status: {to: closed}"), &ctx));
+}
+
+#[test]
+fn test_changed_on_create_is_from_nothing() {
+    let ctx = context(json!({"status": "open"}), None, None);
+    assert!(when_matches(&when("status: {to: open}"), &ctx));
+}
+
+#[test]
+fn test_contains_checks_list_membership() {
+    let ctx = context(json!({"tags": ["bug", "urgent"]}), None, None);
+
+    assert!(when_matches(&when("tags: {contains: urgent}"), &ctx));
+    assert!(!when_matches(&when("tags: {contains: docs}"), &ctx));
+    assert!(!when_matches(&when("title: {contains: urgent}"), &ctx));
+}
+
+#[test]
+fn test_custom_fields_in_request_and_item() {
+    let request = context(json!({"custom_fields": {"team": "web"}}), None, None);
+    let item = context(json!({}), None, Some(json!({"customFields.team": "web"})));
+
+    for ctx in [&request, &item] {
+        assert!(when_matches(&when("customFields.team: web"), ctx));
+        assert!(when_matches(&when("custom_fields.team: web"), ctx));
+        assert!(!when_matches(&when("customFields.team: api"), ctx));
+    }
+}
+
+#[test]
+fn test_all_conditions_must_hold() {
+    let ctx = context(
+        json!({"status": "closed", "priority": 1i32}),
+        Some(json!({"status": "open", "priority": 1i32})),
+        None,
+    );
+
+    assert!(when_matches(
+        &when("status: {to: closed}\npriority: 1"),
+        &ctx
+    ));
+    assert!(!when_matches(
+        &when("status: {to: closed}\npriority: 2"),
+        &ctx
+    ));
+}
@@ -1,8 +1,8 @@
 mod pattern;
 mod types;
 pub use super::error::HookError;
-pub use pattern::ParsedPattern;
-pub use types::{HookDefinition, HookOperation, HooksFile, PatternSegment, Phase};
+pub use pattern::{parse_event, ParsedPattern};
+pub use types::{HookDefinition, HookOperation, HookTarget, HooksFile, PatternSegment, Phase};
 #[cfg(test)]
 #[path = "../hook_pattern_parsing_tests.rs"]
 mod hook_pattern_parsing_tests;
@@ -16,6 +16,14 @@ const VALID_EVENTS: &[&str] = &[
     "moved",
     "duplicating",
     "duplicated",
+    "archiving",
+    "archived",
+    "unarchiving",
+    "unarchived",
+    "adding",
+    "added",
+    "syncing",
+    "synced",
 ];
 /// Maps a (phase, operation) pair to its event name string.
 fn event_name(phase: Phase, operation: HookOperation) -> &'static str {
@@ -34,8 +42,38 @@ fn event_name(phase: Phase, operation: HookOperation) -> &'static str {
         (Phase::Post, HookOperation::Move) => "moved",
         (Phase::Pre, HookOperation::Duplicate) => "duplicating",
         (Phase::Post, HookOperation::Duplicate) => "duplicated",
+        (Phase::Pre, HookOperation::Archive) => "archiving",
+        (Phase::Post, HookOperation::Archive) => "archived",
+        (Phase::Pre, HookOperation::Unarchive) => "unarchiving",
+        (Phase::Post, HookOperation::Unarchive) => "unarchived",
+        (Phase::Pre, HookOperation::Add) => "adding",
+        (Phase::Post, HookOperation::Add) => "added",
+        (Phase::Pre, HookOperation::Sync) => "syncing",
+        (Phase::Post, HookOperation::Sync) => "synced",
     }
 }
+/// Every operation, for mapping event names back to operations.
+const OPERATIONS: [HookOperation; 11] = [
+    HookOperation::Create,
+    HookOperation::Update,
+    HookOperation::Delete,
+    HookOperation::SoftDelete,
+    HookOperation::Restore,
+    HookOperation::Move,
+    HookOperation::Duplicate,
+    HookOperation::Archive,
+    HookOperation::Unarchive,
+    HookOperation::Add,
+    HookOperation::Sync,
+];
+/// The phase and operation an event name such as `creating` stands for.
+#[must_use]
+pub fn parse_event(event: &str) -> Option<(Phase, HookOperation)> {
+    OPERATIONS
+        .iter()
+        .flat_map(|&operation| [(Phase::Pre, operation), (Phase::Post, operation)])
+        .find(|&(phase, operation)| event_name(phase, operation) == event)
+}
 /// A parsed hook pattern (e.g., "issue.creating" or "*.deleted")
 #[derive(Debug, Clone)]
 pub struct ParsedPattern {
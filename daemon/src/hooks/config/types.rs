@@ -1,21 +1,85 @@
+use super::super::condition::WhenClause;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 fn default_timeout() -> u64 {
     30
 }
 fn default_enabled() -> bool {
     true
 }
-/// Hook definition from hooks.yaml
+fn default_retries() -> u32 {
+    3
+}
+fn default_backoff() -> u64 {
+    1
+}
+/// Hook definition from hooks.yaml. A hook runs `command`, or posts the
+/// context to `url`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HookDefinition {
     pub pattern: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub command: String,
+    /// POST the context as JSON here instead of running a command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Extra request headers for `url` hooks. `${VAR}` expands from the
+    /// daemon's environment.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Key for the HMAC-SHA256 signature header of `url` hooks. `${VAR}`
+    /// expands from the daemon's environment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Extra attempts for `url` post-hooks and async hooks that fail.
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    /// Seconds before the first retry; each later retry waits twice as long.
+    #[serde(default = "default_backoff")]
+    pub backoff: u64,
     #[serde(default, rename = "async")]
     pub is_async: bool,
     #[serde(default = "default_timeout")]
     pub timeout: u64,
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+    /// Run only when these conditions on the request and item hold.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub when: WhenClause,
+}
+impl Default for HookDefinition {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            command: String::new(),
+            url: None,
+            headers: BTreeMap::new(),
+            secret: None,
+            retries: default_retries(),
+            backoff: default_backoff(),
+            is_async: false,
+            timeout: default_timeout(),
+            enabled: default_enabled(),
+            when: WhenClause::new(),
+        }
+    }
+}
+/// What a hook does when it fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookTarget<'hook> {
+    Command(&'hook str),
+    Url(&'hook str),
+}
+impl HookDefinition {
+    /// The command or URL to run; `None` when the hook sets both or neither.
+    #[must_use]
+    pub fn target(&self) -> Option<HookTarget<'_>> {
+        match (self.command.is_empty(), self.url.as_deref()) {
+            (false, None) => Some(HookTarget::Command(&self.command)),
+            (true, Some(url)) => Some(HookTarget::Url(url)),
+            (true, None) | (false, Some(_)) => None,
+        }
+    }
 }
 /// Top-level wrapper for hooks.yaml deserialization
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -48,6 +112,12 @@ pub enum HookOperation {
     Restore,
     Move,
     Duplicate,
+    Archive,
+    Unarchive,
+    /// Adding an asset to an item.
+    Add,
+    /// Syncing users from git history.
+    Sync,
 }
 impl HookOperation {
     #[must_use]
@@ -60,6 +130,31 @@ impl HookOperation {
             HookOperation::Restore => "restore",
             HookOperation::Move => "move",
             HookOperation::Duplicate => "duplicate",
+            HookOperation::Archive => "archive",
+            HookOperation::Unarchive => "unarchive",
+            HookOperation::Add => "add",
+            HookOperation::Sync => "sync",
+        }
+    }
+
+    /// The operation this one fired hooks as before it had its own events:
+    /// `move` for archive and unarchive, `create` for adding an asset.
+    /// Deprecated; hooks matching only the old events run until the next
+    /// release.
+    #[must_use]
+    pub fn legacy(self, item_type: &str) -> Option<Self> {
+        match self {
+            HookOperation::Archive | HookOperation::Unarchive => Some(HookOperation::Move),
+            HookOperation::Add if item_type == "asset" => Some(HookOperation::Create),
+            HookOperation::Create
+            | HookOperation::Update
+            | HookOperation::Delete
+            | HookOperation::SoftDelete
+            | HookOperation::Restore
+            | HookOperation::Move
+            | HookOperation::Duplicate
+            | HookOperation::Add
+            | HookOperation::Sync => None,
         }
     }
 }
@@ -1,9 +1,10 @@
 use super::config::{HookOperation, Phase};
-use serde::Serialize;
+use super::patch::HookModification;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Context passed to hook scripts via env vars and stdin JSON
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HookContext {
     pub phase: String,
     pub item_type: String,
@@ -15,6 +16,15 @@ pub struct HookContext {
     pub request_data: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub success: Option<bool>,
+    /// Changes pre-hooks made to `request_data` (post-hooks only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifications: Vec<HookModification>,
+    /// The item before the operation, where the caller loaded it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// The item after the operation (post-hooks only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
 }
 
 impl HookContext {
@@ -35,9 +45,31 @@ impl HookContext {
             item_id: item_id.map(String::from),
             request_data,
             success,
+            modifications: Vec::new(),
+            before: None,
+            after: None,
         }
     }
 
+    /// Record the changes pre-hooks made to the request.
+    #[must_use]
+    pub fn with_modifications(mut self, modifications: Vec<HookModification>) -> Self {
+        self.modifications = modifications;
+        self
+    }
+
+    /// Attach the item as it was before and after the operation.
+    #[must_use]
+    pub fn with_items(
+        mut self,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) -> Self {
+        self.before = before;
+        self.after = after;
+        self
+    }
+
     /// Convert to environment variables for the hook process
     #[must_use]
     pub fn to_env_vars(&self) -> HashMap<String, String> {
@@ -58,3 +58,54 @@ fn test_context_to_json() {
     assert_eq!(parsed["request_data"]["title"], "Test");
     assert_eq!(parsed["success"], true);
 }
+
+#[test]
+fn test_context_to_json_with_modifications() {
+    let plain = HookContext::new(
+        Phase::Post,
+        "issue",
+        HookOperation::Create,
+        "/tmp/project",
+        None,
+        None,
+        Some(true),
+    );
+    let patched = plain.clone().with_modifications(vec![HookModification {
+        pattern: "issue.creating".to_string(),
+        changes: vec![crate::hooks::PatchedField {
+            field: "title".to_string(),
+            before: Some(serde_json::json!("test")),
+            after: Some(serde_json::json!("Test")),
+        }],
+    }]);
+
+    let plain_json: serde_json::Value = serde_json::from_str(&plain.to_json().unwrap()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&patched.to_json().unwrap()).unwrap();
+    assert!(plain_json.get("modifications").is_none());
+    assert_eq!(parsed["modifications"][0]["pattern"], "issue.creating");
+    assert_eq!(parsed["modifications"][0]["changes"][0]["after"], "Test");
+}
+
+#[test]
+fn test_context_to_json_with_items() {
+    let plain = HookContext::new(
+        Phase::Post,
+        "issue",
+        HookOperation::Update,
+        "/tmp/project",
+        Some("issue-123"),
+        None,
+        Some(true),
+    );
+    let with_items = plain.clone().with_items(
+        Some(serde_json::json!({"status": "open"})),
+        Some(serde_json::json!({"status": "closed"})),
+    );
+
+    let bare: serde_json::Value = serde_json::from_str(&plain.to_json().unwrap()).unwrap();
+    let full: serde_json::Value = serde_json::from_str(&with_items.to_json().unwrap()).unwrap();
+    assert!(bare.get("before").is_none());
+    assert!(bare.get("after").is_none());
+    assert_eq!(full["before"]["status"], "open");
+    assert_eq!(full["after"]["status"], "closed");
+}
@@ -0,0 +1,152 @@
+//! Dry runs of `hooks.yaml`.
+//!
+//! A dry run shows which hooks an event would run, most specific first, and
+//! can execute them against sample data. No item is created or changed and
+//! nothing is recorded in the run history; the hooks themselves still run
+//! for real, so commands and webhooks see the sample as a live event.
+use super::condition::when_matches;
+use super::config::{parse_event, HookDefinition, ParsedPattern, Phase};
+use super::context::HookContext;
+use super::error::HookError;
+use super::patch::{apply_patch, parse_patch, HookModification};
+use super::runner::{find_matching_hooks, invoke_hook, load_hooks_config};
+use super::runs::{HookRun, HookRunOutcome};
+use serde_json::Value;
+use std::path::Path;
+use std::time::Instant;
+
+/// The event to dry-run and the sample data to run it with.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunInput {
+    /// Hook item type, e.g. `issue`.
+    pub item_type: String,
+    /// Event name, e.g. `creating` or `updated`.
+    pub event: String,
+    pub item_id: Option<String>,
+    pub request_data: Option<Value>,
+    /// Run the matching hooks instead of only listing them.
+    pub execute: bool,
+}
+
+/// A hook the event matches.
+#[derive(Debug, Clone)]
+pub struct DryRunHook {
+    pub hook: HookDefinition,
+    /// 2 for an exact pattern, 1 with one wildcard, 0 for `*.*`.
+    pub specificity: u8,
+    /// Whether the hook's `when` clause holds for the sample.
+    pub when_matches: bool,
+    /// How the hook ran; `None` when hooks were only listed, or an earlier
+    /// pre-hook would have aborted the operation.
+    pub run: Option<HookRun>,
+}
+
+/// What a dry run found.
+#[derive(Debug, Clone)]
+pub struct DryRunReport {
+    /// The synthetic context the hooks were given.
+    pub context: HookContext,
+    /// Matching hooks in the order they would run.
+    pub hooks: Vec<DryRunHook>,
+    /// The request as the pre-hooks left it.
+    pub request_data: Option<Value>,
+    pub modifications: Vec<HookModification>,
+    /// The pre-hook that would abort the operation.
+    pub blocked_by: Option<String>,
+}
+
+/// Find the hooks `input.event` would run for `input.item_type` and, if
+/// asked, run them with a synthetic context built from the sample.
+///
+/// Pre-hooks run as they would for a real request: patches carry over to
+/// later hooks and the first failure stops the rest. Post-hooks run inline
+/// once each, async ones included, without retries.
+pub async fn dry_run_hooks(
+    project_path: &Path,
+    input: DryRunInput,
+) -> Result<DryRunReport, HookError> {
+    let (phase, operation) = parse_event(&input.event).ok_or_else(|| {
+        HookError::InvalidPattern(format!("Unknown hook event '{}'", input.event))
+    })?;
+    let context = HookContext::new(
+        phase,
+        &input.item_type,
+        operation,
+        &project_path.to_string_lossy(),
+        input.item_id.as_deref(),
+        input.request_data,
+        (phase == Phase::Post).then_some(true),
+    );
+    let hooks = load_hooks_config(project_path).await;
+    let mut report = DryRunReport {
+        request_data: context.request_data.clone(),
+        context,
+        hooks: Vec::new(),
+        modifications: Vec::new(),
+        blocked_by: None,
+    };
+    for hook in find_matching_hooks(&hooks, phase, &input.item_type, operation) {
+        let mut current = report.context.clone();
+        current.request_data.clone_from(&report.request_data);
+        let run = if input.execute && report.blocked_by.is_none() {
+            Some(run_one(hook, &current, project_path, &mut report).await)
+        } else {
+            None
+        };
+        report.hooks.push(DryRunHook {
+            hook: hook.clone(),
+            specificity: ParsedPattern::parse(&hook.pattern).map_or(0, |p| p.specificity()),
+            when_matches: when_matches(&hook.when, &current),
+            run,
+        });
+    }
+    Ok(report)
+}
+
+async fn run_one(
+    hook: &HookDefinition,
+    context: &HookContext,
+    project_path: &Path,
+    report: &mut DryRunReport,
+) -> HookRun {
+    if !when_matches(&hook.when, context) {
+        return HookRun::skipped(hook, context);
+    }
+    let started = Instant::now();
+    let result = invoke_hook(hook, context, project_path, 0).await;
+    let mut run = HookRun::finished(hook, context, started.elapsed(), &result);
+    if context.phase != Phase::Pre.as_str() {
+        return run;
+    }
+    let patched = match &result {
+        Ok(exec) if exec.exit_code == 0i32 => patch_request(&hook.pattern, &exec.stdout, report),
+        Ok(_) | Err(_) => {
+            report.blocked_by = Some(hook.pattern.clone());
+            return run;
+        }
+    };
+    if let Err(e) = patched {
+        run.outcome = HookRunOutcome::Errored;
+        run.error = Some(e.to_string());
+        report.blocked_by = Some(hook.pattern.clone());
+    }
+    run
+}
+
+/// Apply a pre-hook's stdout to the request, as a real pre-hook run would.
+fn patch_request(pattern: &str, stdout: &str, report: &mut DryRunReport) -> Result<(), HookError> {
+    let Some(patch) = parse_patch(pattern, stdout)? else {
+        return Ok(());
+    };
+    let Some(Value::Object(request)) = report.request_data.as_mut() else {
+        return Ok(());
+    };
+    if let Some(modification) = apply_patch(pattern, request, patch)? {
+        report.modifications.push(modification);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "dry_run_tests.rs"]
+mod dry_run_tests;
@@ -0,0 +1,147 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+use crate::hooks::runs::read_runs;
+use serde_json::json;
+
+async fn project(hooks_yaml: &str) -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    let centy = temp.path().join(".centy");
+    tokio::fs::create_dir_all(&centy).await.unwrap();
+    tokio::fs::write(centy.join("hooks.yaml"), hooks_yaml)
+        .await
+        .unwrap();
+    temp
+}
+
+fn input(event: &str, execute: bool) -> DryRunInput {
+    DryRunInput {
+        item_type: "issue".to_string(),
+        event: event.to_string(),
+        item_id: None,
+        request_data: Some(json!({"title": "Login fails", "priority": 1i32})),
+        execute,
+    }
+}
+
+const HOOKS: &str = r#"hooks:
+  - pattern: "*.*"
+    command: echo any
+  - pattern: issue.creating
+    command: |
+      echo '{"title": "[bug] Login fails"}'
+  - pattern: "*.creating"
+    command: |
+      test "$(jq -r .request_data.title)" = "[bug] Login fails"
+  - pattern: issue.created
+    command: echo after
+  - pattern: issue.creating
+    command: echo urgent
+    when:
+      priority: 2
+"#;
+
+#[tokio::test]
+async fn test_lists_matching_hooks_most_specific_first() {
+    let temp = project(HOOKS).await;
+
+    let report = dry_run_hooks(temp.path(), input("creating", false))
+        .await
+        .unwrap();
+
+    let patterns: Vec<&str> = report
+        .hooks
+        .iter()
+        .map(|h| h.hook.pattern.as_str())
+        .collect();
+    assert_eq!(
+        patterns,
+        ["issue.creating", "issue.creating", "*.creating", "*.*"]
+    );
+    let specificity: Vec<u8> = report.hooks.iter().map(|h| h.specificity).collect();
+    assert_eq!(specificity, [2, 2, 1, 0]);
+    assert!(report.hooks[0].when_matches);
+    assert!(!report.hooks[1].when_matches);
+    assert!(report.hooks.iter().all(|h| h.run.is_none()));
+    assert_eq!(report.context.phase, "pre");
+    assert_eq!(report.context.operation, "create");
+}
+
+#[tokio::test]
+async fn test_executes_pre_hooks_with_patches_and_records_nothing() {
+    let temp = project(HOOKS).await;
+
+    let report = dry_run_hooks(temp.path(), input("creating", true))
+        .await
+        .unwrap();
+
+    let outcomes: Vec<HookRunOutcome> = report
+        .hooks
+        .iter()
+        .map(|h| h.run.as_ref().unwrap().outcome)
+        .collect();
+    assert_eq!(
+        outcomes,
+        [
+            HookRunOutcome::Succeeded,
+            HookRunOutcome::Skipped,
+            HookRunOutcome::Succeeded,
+            HookRunOutcome::Succeeded,
+        ]
+    );
+    assert_eq!(
+        report.request_data.unwrap()["title"],
+        json!("[bug] Login fails")
+    );
+    assert_eq!(report.modifications.len(), 1);
+    assert!(report.blocked_by.is_none());
+    assert!(read_runs(temp.path()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failing_pre_hook_blocks_later_hooks() {
+    let temp = project(
+        "hooks:
+  - pattern: issue.creating
+    command: echo no >&2; exit 2
+  - pattern: \"*.creating\"
+    command: echo later
+",
+    )
+    .await;
+
+    let report = dry_run_hooks(temp.path(), input("creating", true))
+        .await
+        .unwrap();
+
+    let first = report.hooks[0].run.as_ref().unwrap();
+    assert_eq!(first.outcome, HookRunOutcome::Failed);
+    assert_eq!(first.exit_code, Some(2i32));
+    assert_eq!(first.stderr.trim(), "no");
+    assert!(report.hooks[1].run.is_none());
+    assert_eq!(report.blocked_by.as_deref(), Some("issue.creating"));
+}
+
+#[tokio::test]
+async fn test_post_hooks_get_success_context() {
+    let temp = project(HOOKS).await;
+
+    let report = dry_run_hooks(temp.path(), input("created", true))
+        .await
+        .unwrap();
+
+    assert_eq!(report.context.success, Some(true));
+    assert_eq!(report.hooks.len(), 2);
+    assert_eq!(report.hooks[0].run.as_ref().unwrap().stdout.trim(), "after");
+}
+
+#[tokio::test]
+async fn test_unknown_event_is_rejected() {
+    let temp = project(HOOKS).await;
+
+    let err = dry_run_hooks(temp.path(), input("exploded", false))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, HookError::InvalidPattern(_)));
+}
@@ -9,6 +9,16 @@ pub enum HookError {
         stderr: String,
     },
 
+    #[error("Pre-hook '{pattern}' returned an invalid patch: {reason}")]
+    InvalidPatch { pattern: String, reason: String },
+
+    #[error("Webhook '{pattern}' responded with status {status}: {body}")]
+    WebhookFailed {
+        pattern: String,
+        status: u16,
+        body: String,
+    },
+
     #[error("Hook '{pattern}' timed out after {timeout_secs}s")]
     Timeout { pattern: String, timeout_secs: u64 },
 
@@ -18,6 +28,9 @@ pub enum HookError {
     #[error("Invalid hook pattern: {0}")]
     InvalidPattern(String),
 
+    #[error("No failed hook delivery with id {0}")]
+    DeliveryNotFound(String),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
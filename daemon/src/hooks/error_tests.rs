@@ -14,6 +14,32 @@ fn test_hook_error_pre_hook_failed() {
     assert!(display.contains("validation failed"));
 }
 
+#[test]
+fn test_hook_error_invalid_patch() {
+    let err = HookError::InvalidPatch {
+        pattern: "issue.creating".to_string(),
+        reason: "`item_id` cannot be changed".to_string(),
+    };
+    let display = format!("{err}");
+    assert!(display.contains("issue.creating"));
+    assert!(display.contains("invalid patch"));
+    assert!(display.contains("item_id"));
+}
+
+#[test]
+fn test_hook_error_webhook_failed() {
+    let err = HookError::WebhookFailed {
+        pattern: "issue.creating".to_string(),
+        status: 403,
+        body: "title is required".to_string(),
+    };
+    let display = format!("{err}");
+    assert!(display.contains("Webhook"));
+    assert!(display.contains("issue.creating"));
+    assert!(display.contains("status 403"));
+    assert!(display.contains("title is required"));
+}
+
 #[test]
 fn test_hook_error_timeout() {
     let err = HookError::Timeout {
@@ -41,6 +67,12 @@ fn test_hook_error_invalid_pattern() {
     assert!(display.contains("Invalid hook pattern"));
 }
 
+#[test]
+fn test_hook_error_delivery_not_found() {
+    let err = HookError::DeliveryNotFound("abc".to_string());
+    assert_eq!(format!("{err}"), "No failed hook delivery with id abc");
+}
+
 #[test]
 fn test_hook_error_from_io_error() {
     let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
@@ -9,6 +9,7 @@ use super::error::HookError;
 #[derive(Debug)]
 pub struct HookExecResult {
     pub exit_code: i32,
+    pub stdout: String,
     pub stderr: String,
 }
 
@@ -29,7 +30,7 @@ pub async fn execute_hook(
         .current_dir(project_path.join(".centy"))
         .envs(env_vars)
         .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .spawn()?;
 
@@ -40,8 +41,16 @@ pub async fn execute_hook(
         drop(stdin);
     }
 
-    // Take stderr handle before waiting
+    // Take the output handles before waiting, and drain stdout while the
+    // hook runs so a large patch cannot fill the pipe
     let mut stderr_handle = child.stderr.take();
+    let stdout_reader = child.stdout.take().map(|mut stdout| {
+        tokio::spawn(async move {
+            let mut buf = Vec::new();
+            drop(stdout.read_to_end(&mut buf).await);
+            buf
+        })
+    });
 
     // Wait with timeout
     let wait_result =
@@ -55,8 +64,14 @@ pub async fn execute_hook(
                 drop(stderr.read_to_end(&mut stderr_buf).await);
             }
 
+            let stdout_buf = match stdout_reader {
+                Some(reader) => reader.await.unwrap_or_default(),
+                None => Vec::new(),
+            };
+
             Ok(HookExecResult {
                 exit_code: status.code().unwrap_or(-1i32),
+                stdout: String::from_utf8_lossy(&stdout_buf).to_string(),
                 stderr: String::from_utf8_lossy(&stderr_buf).to_string(),
             })
         }
@@ -66,6 +81,9 @@ pub async fn execute_hook(
         Err(_) => {
             // Timeout - try to kill the process
             drop(child.kill().await);
+            if let Some(reader) = stdout_reader {
+                reader.abort();
+            }
             Err(HookError::Timeout {
                 pattern: pattern.to_string(),
                 timeout_secs,
@@ -15,6 +15,7 @@ fn test_find_matching_hooks_exact_match() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     }];
     let result = find_matching_hooks(&hooks, Phase::Pre, "issue", HookOperation::Create);
     assert_eq!(result.len(), 1);
@@ -28,6 +29,7 @@ fn test_find_matching_hooks_no_match() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     }];
     let result = find_matching_hooks(&hooks, Phase::Pre, "doc", HookOperation::Create);
     assert!(result.is_empty());
@@ -41,7 +43,39 @@ fn test_find_matching_hooks_disabled_skipped() {
         is_async: false,
         timeout: 30,
         enabled: false,
+        ..HookDefinition::default()
     }];
     let result = find_matching_hooks(&hooks, Phase::Pre, "issue", HookOperation::Create);
     assert!(result.is_empty());
 }
+
+#[test]
+fn test_hooks_for_event_keeps_legacy_only_hooks() {
+    let hook = |pattern: &str| HookDefinition {
+        pattern: pattern.to_string(),
+        command: "echo test".to_string(),
+        enabled: true,
+        ..HookDefinition::default()
+    };
+    let hooks = vec![hook("issue.archiving"), hook("issue.moving"), hook("*.*")];
+
+    let run = super::common::hooks_for_event(&hooks, Phase::Pre, "issue", HookOperation::Archive);
+
+    let fired: Vec<(&str, HookOperation)> = run
+        .iter()
+        .map(|(hook, operation)| (hook.pattern.as_str(), *operation))
+        .collect();
+    assert_eq!(
+        fired,
+        vec![
+            ("issue.archiving", HookOperation::Archive),
+            ("*.*", HookOperation::Archive),
+            ("issue.moving", HookOperation::Move),
+        ]
+    );
+    assert!(
+        super::common::hooks_for_event(&hooks, Phase::Pre, "issue", HookOperation::Move)
+            .iter()
+            .all(|(_, operation)| *operation == HookOperation::Move)
+    );
+}
@@ -72,3 +72,21 @@ fn test_matches_all_wildcards() {
     assert!(p.matches(Phase::Pre, "issue", HookOperation::Create));
     assert!(p.matches(Phase::Post, "doc", HookOperation::Delete));
 }
+
+#[test]
+fn test_parse_event() {
+    assert_eq!(
+        parse_event("creating"),
+        Some((Phase::Pre, HookOperation::Create))
+    );
+    assert_eq!(
+        parse_event("soft-deleted"),
+        Some((Phase::Post, HookOperation::SoftDelete))
+    );
+    assert_eq!(
+        parse_event("synced"),
+        Some((Phase::Post, HookOperation::Sync))
+    );
+    assert_eq!(parse_event("*"), None);
+    assert_eq!(parse_event("exploded"), None);
+}
@@ -23,6 +23,10 @@ fn test_all_pre_operations() {
         ("restoring", HookOperation::Restore),
         ("moving", HookOperation::Move),
         ("duplicating", HookOperation::Duplicate),
+        ("archiving", HookOperation::Archive),
+        ("unarchiving", HookOperation::Unarchive),
+        ("adding", HookOperation::Add),
+        ("syncing", HookOperation::Sync),
     ];
 
     for (event, op) in &ops {
@@ -50,6 +54,10 @@ fn test_all_post_operations() {
         ("restored", HookOperation::Restore),
         ("moved", HookOperation::Move),
         ("duplicated", HookOperation::Duplicate),
+        ("archived", HookOperation::Archive),
+        ("unarchived", HookOperation::Unarchive),
+        ("added", HookOperation::Add),
+        ("synced", HookOperation::Sync),
     ];
 
     for (event, op) in &ops {
@@ -26,6 +26,10 @@ fn test_hook_operation_as_str() {
     assert_eq!(HookOperation::Restore.as_str(), "restore");
     assert_eq!(HookOperation::Move.as_str(), "move");
     assert_eq!(HookOperation::Duplicate.as_str(), "duplicate");
+    assert_eq!(HookOperation::Archive.as_str(), "archive");
+    assert_eq!(HookOperation::Unarchive.as_str(), "unarchive");
+    assert_eq!(HookOperation::Add.as_str(), "add");
+    assert_eq!(HookOperation::Sync.as_str(), "sync");
 }
 
 // --- HookDefinition tests ---
@@ -38,6 +42,7 @@ fn test_hook_definition_yaml_serialization() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     };
 
     let yaml = serde_yaml::to_string(&hook).expect("Should serialize");
@@ -66,6 +71,7 @@ fn test_hook_definition_async_rename() {
         is_async: true,
         timeout: 60,
         enabled: false,
+        ..HookDefinition::default()
     };
 
     let yaml = serde_yaml::to_string(&hook).expect("Should serialize");
@@ -73,6 +79,52 @@ fn test_hook_definition_async_rename() {
     assert!(!yaml.contains("is_async"));
 }
 
+#[test]
+fn test_hook_definition_url_hook() {
+    let yaml = "pattern: issue.created\nurl: https://example.com/hook\nheaders:\n  Authorization: Bearer ${TOKEN}\nsecret: s3cret\nretries: 5\n";
+    let hook: HookDefinition = serde_yaml::from_str(yaml).expect("Should deserialize");
+    assert_eq!(
+        hook.target(),
+        Some(HookTarget::Url("https://example.com/hook"))
+    );
+    assert_eq!(hook.headers["Authorization"], "Bearer ${TOKEN}");
+    assert_eq!(hook.secret.as_deref(), Some("s3cret"));
+    assert_eq!(hook.retries, 5);
+
+    let yaml = serde_yaml::to_string(&hook).expect("Should serialize");
+    assert!(!yaml.contains("command"));
+}
+
+#[test]
+fn test_hook_definition_when_clause() {
+    let yaml = "pattern: issue.updated\ncommand: notify.sh\nwhen:\n  status:\n    to: closed\n";
+    let hook: HookDefinition = serde_yaml::from_str(yaml).expect("Should deserialize");
+    assert_eq!(hook.when.len(), 1);
+    assert!(hook.when.contains_key("status"));
+
+    let plain = HookDefinition::default();
+    assert!(plain.when.is_empty());
+    let yaml = serde_yaml::to_string(&plain).expect("Should serialize");
+    assert!(!yaml.contains("when"));
+}
+
+#[test]
+fn test_hook_definition_target() {
+    let command = HookDefinition {
+        command: "echo hi".to_string(),
+        ..HookDefinition::default()
+    };
+    let both = HookDefinition {
+        url: Some("https://example.com".to_string()),
+        ..command.clone()
+    };
+
+    assert_eq!(command.target(), Some(HookTarget::Command("echo hi")));
+    assert_eq!(command.retries, 3);
+    assert_eq!(both.target(), None);
+    assert_eq!(HookDefinition::default().target(), None);
+}
+
 #[test]
 fn test_hooks_file_deserialization() {
     let yaml = "hooks:\n  - pattern: \"issue.creating\"\n    command: \"echo pre\"\n  - pattern: \"*.deleted\"\n    command: \"notify.sh\"\n    async: true\n    timeout: 10\n";
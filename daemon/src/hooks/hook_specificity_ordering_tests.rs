@@ -9,6 +9,7 @@ fn test_find_matching_hooks_specificity_order() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         },
         HookDefinition {
             pattern: "issue.creating".to_string(),
@@ -16,6 +17,7 @@ fn test_find_matching_hooks_specificity_order() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         },
         HookDefinition {
             pattern: "*.creating".to_string(),
@@ -23,6 +25,7 @@ fn test_find_matching_hooks_specificity_order() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         },
     ];
     let result = find_matching_hooks(&hooks, Phase::Pre, "issue", HookOperation::Create);
@@ -40,6 +43,7 @@ fn test_find_matching_hooks_wildcard_matches_multiple() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     }];
     // Should match for any item type with post-delete event
     assert_eq!(
@@ -1,10 +1,22 @@
+pub mod condition;
 pub mod config;
 pub mod context;
+pub mod dry_run;
 pub mod error;
 pub mod executor;
+pub mod patch;
+pub mod queue;
 pub mod runner;
+pub mod runs;
+pub mod signature;
+pub mod webhook;
 
-pub use config::{HookDefinition, HookOperation, HooksFile, Phase};
+pub use condition::{when_matches, Condition, WhenClause};
+pub use config::{HookDefinition, HookOperation, HookTarget, HooksFile, Phase};
 pub use context::HookContext;
+pub use dry_run::{dry_run_hooks, DryRunHook, DryRunInput, DryRunReport};
 pub use error::HookError;
+pub use patch::{HookModification, PatchedField, PreHookOutcome};
+pub use queue::{DeliveryStatus, HookDelivery};
 pub use runner::{load_hooks_config, run_post_hooks, run_pre_hooks};
+pub use runs::{HookRun, HookRunFilter, HookRunOutcome};
@@ -0,0 +1,147 @@
+use super::error::HookError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Request fields that identify the target; pre-hooks may not rewrite them.
+const READ_ONLY_FIELDS: &[&str] = &["item_type", "item_id"];
+
+/// One request field a pre-hook changed. Nested keys are dotted, e.g.
+/// `custom_fields.team`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchedField {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// The changes one pre-hook made to the request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookModification {
+    pub pattern: String,
+    pub changes: Vec<PatchedField>,
+}
+
+/// The request as the pre-hooks left it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreHookOutcome {
+    pub request_data: Option<Value>,
+    pub modifications: Vec<HookModification>,
+}
+
+impl PreHookOutcome {
+    /// Whether any pre-hook changed the request.
+    #[must_use]
+    pub fn is_patched(&self) -> bool {
+        !self.modifications.is_empty()
+    }
+
+    /// The pattern of the last hook that changed `field` or a key under it.
+    #[must_use]
+    pub fn patched_by(&self, field: &str) -> Option<&str> {
+        let nested = format!("{field}.");
+        self.modifications
+            .iter()
+            .rev()
+            .find(|m| {
+                m.changes
+                    .iter()
+                    .any(|c| c.field == field || c.field.starts_with(&nested))
+            })
+            .map(|m| m.pattern.as_str())
+    }
+}
+
+/// Read a pre-hook's stdout as a patch. Output starting with `{` must be a
+/// JSON object; any other output is not a patch and is ignored.
+pub(crate) fn parse_patch(
+    pattern: &str,
+    stdout: &str,
+) -> Result<Option<Map<String, Value>>, HookError> {
+    let trimmed = stdout.trim();
+    if !trimmed.starts_with('{') {
+        return Ok(None);
+    }
+    serde_json::from_str(trimmed)
+        .map(Some)
+        .map_err(|e| HookError::InvalidPatch {
+            pattern: pattern.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Apply `patch` to the request as a JSON merge patch (RFC 7386): keys are
+/// set, `null` removes a key and objects merge key by key. Returns what
+/// changed, or `None` when nothing did.
+pub(crate) fn apply_patch(
+    pattern: &str,
+    request: &mut Map<String, Value>,
+    patch: Map<String, Value>,
+) -> Result<Option<HookModification>, HookError> {
+    if let Some(field) = READ_ONLY_FIELDS
+        .iter()
+        .find(|f| patch.get(**f).is_some_and(|v| request.get(**f) != Some(v)))
+    {
+        return Err(HookError::InvalidPatch {
+            pattern: pattern.to_string(),
+            reason: format!("`{field}` cannot be changed"),
+        });
+    }
+    let mut changes = Vec::new();
+    merge(request, patch, "", &mut changes);
+    Ok((!changes.is_empty()).then(|| HookModification {
+        pattern: pattern.to_string(),
+        changes,
+    }))
+}
+
+fn merge(
+    target: &mut Map<String, Value>,
+    patch: Map<String, Value>,
+    prefix: &str,
+    changes: &mut Vec<PatchedField>,
+) {
+    for (key, value) in patch {
+        let field = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        if let (Value::Object(nested), Some(Value::Object(existing))) =
+            (&value, target.get_mut(&key))
+        {
+            merge(existing, nested.clone(), &field, changes);
+            continue;
+        }
+        let after = without_nulls(value);
+        let before = match after.clone() {
+            Some(set) => target.insert(key, set),
+            None => target.remove(&key),
+        };
+        if before != after {
+            changes.push(PatchedField {
+                field,
+                before,
+                after,
+            });
+        }
+    }
+}
+
+/// A patch value as it is stored: `null` members are dropped, since there
+/// is nothing for them to remove.
+fn without_nulls(value: Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::Object(members) => Some(Value::Object(
+            members
+                .into_iter()
+                .filter_map(|(k, v)| without_nulls(v).map(|kept| (k, kept)))
+                .collect(),
+        )),
+        Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Array(_) => Some(value),
+    }
+}
+
+#[cfg(test)]
+#[path = "patch_tests.rs"]
+mod patch_tests;
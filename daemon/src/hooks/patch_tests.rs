@@ -0,0 +1,128 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::*;
+use serde_json::json;
+
+fn object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Array(_) => {
+            panic!("not an object")
+        }
+    }
+}
+
+#[test]
+fn test_parse_patch_ignores_plain_output() {
+    assert_eq!(parse_patch("p", "").unwrap(), None);
+    assert_eq!(parse_patch("p", "all good\n").unwrap(), None);
+    assert_eq!(
+        parse_patch("p", "  {\"title\": \"T\"}\n").unwrap(),
+        Some(object(json!({"title": "T"})))
+    );
+}
+
+#[test]
+fn test_parse_patch_rejects_broken_json() {
+    let err = parse_patch("issue.creating", "{\"title\":").unwrap_err();
+    assert!(matches!(&err, HookError::InvalidPatch { pattern, .. } if pattern == "issue.creating"));
+}
+
+#[test]
+fn test_apply_patch_merges_and_records_changes() {
+    let mut request = object(json!({
+        "title": "  login fails ",
+        "status": "open",
+        "tags": ["bug"],
+        "custom_fields": {"team": "web", "severity": "low"},
+    }));
+    let patch = object(json!({
+        "title": "Login fails",
+        "status": "open",
+        "tags": ["bug", "triage"],
+        "custom_fields": {"severity": null, "owner": "alice"},
+    }));
+
+    let modification = apply_patch("issue.creating", &mut request, patch)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        Value::Object(request),
+        json!({
+            "title": "Login fails",
+            "status": "open",
+            "tags": ["bug", "triage"],
+            "custom_fields": {"team": "web", "owner": "alice"},
+        })
+    );
+    assert_eq!(modification.pattern, "issue.creating");
+    let mut fields: Vec<&str> = modification
+        .changes
+        .iter()
+        .map(|c| c.field.as_str())
+        .collect();
+    fields.sort_unstable();
+    assert_eq!(
+        fields,
+        vec![
+            "custom_fields.owner",
+            "custom_fields.severity",
+            "tags",
+            "title"
+        ]
+    );
+    let severity = modification
+        .changes
+        .iter()
+        .find(|c| c.field == "custom_fields.severity")
+        .unwrap();
+    assert_eq!(severity.before, Some(json!("low")));
+    assert_eq!(severity.after, None);
+}
+
+#[test]
+fn test_apply_patch_without_changes() {
+    let mut request = object(json!({"title": "T"}));
+
+    let modification = apply_patch("p", &mut request, object(json!({"title": "T"}))).unwrap();
+
+    assert_eq!(modification, None);
+}
+
+#[test]
+fn test_apply_patch_rejects_read_only_fields() {
+    let mut request = object(json!({"item_type": "issues", "title": "T"}));
+
+    let same = apply_patch("p", &mut request, object(json!({"item_type": "issues"})));
+    let changed = apply_patch("p", &mut request, object(json!({"item_type": "docs"})));
+
+    assert!(same.is_ok());
+    assert!(matches!(changed, Err(HookError::InvalidPatch { .. })));
+}
+
+#[test]
+fn test_patched_by_finds_the_last_hook() {
+    let change = |field: &str| PatchedField {
+        field: field.to_string(),
+        before: None,
+        after: Some(json!(1i32)),
+    };
+    let outcome = PreHookOutcome {
+        request_data: None,
+        modifications: vec![
+            HookModification {
+                pattern: "*.creating".to_string(),
+                changes: vec![change("title"), change("custom_fields.team")],
+            },
+            HookModification {
+                pattern: "issue.creating".to_string(),
+                changes: vec![change("title")],
+            },
+        ],
+    };
+
+    assert!(outcome.is_patched());
+    assert_eq!(outcome.patched_by("title"), Some("issue.creating"));
+    assert_eq!(outcome.patched_by("custom_fields"), Some("*.creating"));
+    assert_eq!(outcome.patched_by("status"), None);
+}
@@ -0,0 +1,64 @@
+use super::super::config::HookDefinition;
+use super::super::context::HookContext;
+use super::super::error::HookError;
+use super::delivery::{DeliveryStatus, HookDelivery};
+use super::store::{read_deliveries, update_deliveries};
+use super::worker::spawn_delivery;
+use chrono::Utc;
+use std::path::Path;
+
+/// Queue an async run of `hook` and start it in the background.
+pub async fn enqueue(
+    project_path: &Path,
+    hook: &HookDefinition,
+    context: &HookContext,
+) -> Result<(), HookError> {
+    let delivery = HookDelivery::new(
+        &project_path.to_string_lossy(),
+        hook.clone(),
+        context.clone(),
+    );
+    let id = delivery.id.clone();
+    update_deliveries(|all| all.push(delivery)).await?;
+    spawn_delivery(id);
+    Ok(())
+}
+
+/// A project's queued deliveries, pending and dead, oldest first.
+pub async fn project_deliveries(project_path: &Path) -> Result<Vec<HookDelivery>, HookError> {
+    let project = project_path.to_string_lossy();
+    let deliveries = read_deliveries().await?;
+    Ok(deliveries
+        .into_iter()
+        .filter(|d| d.project_path == project)
+        .collect())
+}
+
+/// Put a project's dead delivery back in the queue and start it.
+pub async fn retry_delivery(project_path: &Path, id: &str) -> Result<HookDelivery, HookError> {
+    let project = project_path.to_string_lossy();
+    let requeued = update_deliveries(|all| {
+        let entry = all.iter_mut().find(|d| {
+            d.id == id && d.project_path == project && d.status == DeliveryStatus::Dead
+        })?;
+        entry.requeue(Utc::now());
+        Some(entry.clone())
+    })
+    .await?
+    .ok_or_else(|| HookError::DeliveryNotFound(id.to_string()))?;
+    spawn_delivery(requeued.id.clone());
+    Ok(requeued)
+}
+
+/// Drop a project's delivery, dead or pending, from the queue.
+pub async fn discard_delivery(project_path: &Path, id: &str) -> Result<HookDelivery, HookError> {
+    let project = project_path.to_string_lossy();
+    update_deliveries(|all| {
+        let index = all
+            .iter()
+            .position(|d| d.id == id && d.project_path == project)?;
+        Some(all.remove(index))
+    })
+    .await?
+    .ok_or_else(|| HookError::DeliveryNotFound(id.to_string()))
+}
@@ -0,0 +1,138 @@
+use super::super::config::HookDefinition;
+use super::super::context::HookContext;
+use super::super::error::HookError;
+use super::super::executor::HookExecResult;
+use super::super::webhook::{is_retryable, retry_delay};
+use crate::utils::now_iso;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Where a queued delivery stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting for its next attempt.
+    Pending,
+    /// Out of retries; kept until retried or discarded.
+    Dead,
+}
+
+impl DeliveryStatus {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+/// One async hook run waiting in the queue.
+///
+/// The hook definition is kept as it was when the event fired, except for
+/// its `secret`, which is never written to the queue file and is looked up
+/// again when the delivery runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookDelivery {
+    pub id: String,
+    pub project_path: String,
+    #[serde(serialize_with = "serialize_without_secret")]
+    pub hook: HookDefinition,
+    pub context: HookContext,
+    pub status: DeliveryStatus,
+    /// Failed attempts so far.
+    pub attempts: u32,
+    pub created_at: String,
+    pub next_attempt_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl HookDelivery {
+    /// A delivery of `hook` for `context`, due now.
+    #[must_use]
+    pub fn new(project_path: &str, hook: HookDefinition, context: HookContext) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            project_path: project_path.to_string(),
+            hook,
+            context,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            created_at: now_iso(),
+            next_attempt_at: Utc::now(),
+            last_error: None,
+        }
+    }
+
+    /// Count a failed attempt and schedule the next one, or give up when
+    /// the failure is `permanent` or the hook's `retries` are used up.
+    pub fn record_failure(&mut self, error: String, permanent: bool, now: DateTime<Utc>) {
+        self.last_error = Some(error);
+        if permanent || self.attempts >= self.hook.retries {
+            self.attempts = self.attempts.saturating_add(1);
+            self.status = DeliveryStatus::Dead;
+            return;
+        }
+        let delay = retry_delay(self.hook.backoff, self.attempts);
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt_at = chrono::Duration::from_std(delay)
+            .ok()
+            .and_then(|d| now.checked_add_signed(d))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+    }
+
+    /// The queued hook with the `secret` of the matching hook in `hooks`,
+    /// the project's current hooks.yaml. A hook matches on its pattern and
+    /// `url`; without a match the delivery is sent unsigned.
+    #[must_use]
+    pub fn hook_with_secret(&self, hooks: &[HookDefinition]) -> HookDefinition {
+        let secret = hooks
+            .iter()
+            .find(|h| h.pattern == self.hook.pattern && h.url == self.hook.url)
+            .and_then(|h| h.secret.clone());
+        HookDefinition {
+            secret,
+            ..self.hook.clone()
+        }
+    }
+
+    /// Put a dead delivery back in the queue with its retries restored.
+    pub fn requeue(&mut self, now: DateTime<Utc>) {
+        self.status = DeliveryStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = now;
+    }
+}
+
+fn serialize_without_secret<S: Serializer>(
+    hook: &HookDefinition,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    HookDefinition {
+        secret: None,
+        ..hook.clone()
+    }
+    .serialize(serializer)
+}
+
+/// Why an attempt failed, and whether retrying is pointless; `None` when it
+/// succeeded.
+///
+/// Non-zero exits, timeouts and unreachable endpoints are worth
+/// retrying, as are 429 and 5xx responses; other webhook statuses are not.
+#[must_use]
+pub fn attempt_failure(result: &Result<HookExecResult, HookError>) -> Option<(String, bool)> {
+    match result {
+        Ok(exec) if exec.exit_code == 0i32 => None,
+        Ok(exec) => Some((
+            format!(
+                "exited with code {}: {}",
+                exec.exit_code,
+                exec.stderr.trim()
+            ),
+            false,
+        )),
+        Err(e) => Some((e.to_string(), !is_retryable(result))),
+    }
+}
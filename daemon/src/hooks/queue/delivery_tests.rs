@@ -0,0 +1,167 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+use crate::hooks::executor::HookExecResult;
+use crate::hooks::{HookContext, HookDefinition, HookError, HookOperation, Phase};
+use chrono::{Duration, Utc};
+
+fn delivery(retries: u32, backoff: u64) -> HookDelivery {
+    let hook = HookDefinition {
+        pattern: "*.created".to_string(),
+        command: "notify.sh".to_string(),
+        is_async: true,
+        retries,
+        backoff,
+        ..HookDefinition::default()
+    };
+    let context = HookContext::new(
+        Phase::Post,
+        "issue",
+        HookOperation::Create,
+        "/tmp/project",
+        Some("abc"),
+        None,
+        Some(true),
+    );
+    HookDelivery::new("/tmp/project", hook, context)
+}
+
+fn exec(exit_code: i32) -> Result<HookExecResult, HookError> {
+    Ok(HookExecResult {
+        exit_code,
+        stdout: String::new(),
+        stderr: "boom\n".to_string(),
+    })
+}
+
+#[test]
+fn test_new_delivery_is_pending_and_due() {
+    let d = delivery(3, 1);
+    assert_eq!(d.status, DeliveryStatus::Pending);
+    assert_eq!(d.attempts, 0);
+    assert!(d.next_attempt_at <= Utc::now());
+    assert!(!d.id.is_empty());
+}
+
+#[test]
+fn test_record_failure_backs_off_exponentially() {
+    let mut d = delivery(3, 10);
+    let now = Utc::now();
+
+    d.record_failure("first".to_string(), false, now);
+    assert_eq!(d.attempts, 1);
+    assert_eq!(d.status, DeliveryStatus::Pending);
+    assert_eq!(d.next_attempt_at, now + Duration::seconds(10));
+
+    d.record_failure("second".to_string(), false, now);
+    assert_eq!(d.next_attempt_at, now + Duration::seconds(20));
+    assert_eq!(d.last_error.as_deref(), Some("second"));
+}
+
+#[test]
+fn test_record_failure_dies_after_retries() {
+    let mut d = delivery(1, 0);
+    let now = Utc::now();
+    d.record_failure("first".to_string(), false, now);
+    assert_eq!(d.status, DeliveryStatus::Pending);
+
+    d.record_failure("second".to_string(), false, now);
+    assert_eq!(d.status, DeliveryStatus::Dead);
+    assert_eq!(d.attempts, 2);
+}
+
+#[test]
+fn test_permanent_failure_dies_at_once() {
+    let mut d = delivery(3, 1);
+    d.record_failure("400".to_string(), true, Utc::now());
+    assert_eq!(d.status, DeliveryStatus::Dead);
+    assert_eq!(d.attempts, 1);
+}
+
+#[test]
+fn test_requeue_restores_retries() {
+    let mut d = delivery(0, 1);
+    d.record_failure("nope".to_string(), false, Utc::now());
+    assert_eq!(d.status, DeliveryStatus::Dead);
+
+    let now = Utc::now();
+    d.requeue(now);
+    assert_eq!(d.status, DeliveryStatus::Pending);
+    assert_eq!(d.attempts, 0);
+    assert_eq!(d.next_attempt_at, now);
+    assert_eq!(d.last_error.as_deref(), Some("nope"));
+}
+
+#[test]
+fn test_attempt_failure_classifies_results() {
+    assert!(attempt_failure(&exec(0)).is_none());
+    assert_eq!(
+        attempt_failure(&exec(2)),
+        Some(("exited with code 2: boom".to_string(), false))
+    );
+    let timeout = Err(HookError::Timeout {
+        pattern: "*.created".to_string(),
+        timeout_secs: 5,
+    });
+    assert!(!attempt_failure(&timeout).unwrap().1);
+    let server_error = Err(HookError::WebhookFailed {
+        pattern: "*.created".to_string(),
+        status: 503,
+        body: String::new(),
+    });
+    assert!(!attempt_failure(&server_error).unwrap().1);
+    let client_error = Err(HookError::WebhookFailed {
+        pattern: "*.created".to_string(),
+        status: 404,
+        body: String::new(),
+    });
+    assert!(attempt_failure(&client_error).unwrap().1);
+}
+
+#[test]
+fn test_delivery_serde_roundtrip() {
+    let mut d = delivery(2, 5);
+    d.record_failure("nope".to_string(), false, Utc::now());
+    let json = serde_json::to_string(&d).unwrap();
+    assert!(json.contains("\"status\":\"pending\""));
+
+    let back: HookDelivery = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.id, d.id);
+    assert_eq!(back.attempts, 1);
+    assert_eq!(back.next_attempt_at, d.next_attempt_at);
+    assert_eq!(back.hook.backoff, 5);
+    assert_eq!(back.context.item_id.as_deref(), Some("abc"));
+}
+
+#[test]
+fn test_secret_is_not_serialized() {
+    let mut d = delivery(3, 1);
+    d.hook.url = Some("https://example.com/hook".to_string());
+    d.hook.secret = Some("s3cret".to_string());
+    let json = serde_json::to_string(&d).unwrap();
+    assert!(!json.contains("s3cret"));
+    let read: HookDelivery = serde_json::from_str(&json).unwrap();
+    assert_eq!(read.hook.secret, None);
+    assert_eq!(read.hook.url, d.hook.url);
+}
+
+#[test]
+fn test_hook_with_secret_reads_the_matching_hook() {
+    let mut d = delivery(3, 1);
+    d.hook.url = Some("https://example.com/hook".to_string());
+    let current = HookDefinition {
+        pattern: d.hook.pattern.clone(),
+        url: d.hook.url.clone(),
+        secret: Some("${CENTY_WEBHOOK_SECRET}".to_string()),
+        ..HookDefinition::default()
+    };
+    let other = HookDefinition {
+        pattern: d.hook.pattern.clone(),
+        url: Some("https://example.com/other".to_string()),
+        secret: Some("other".to_string()),
+        ..HookDefinition::default()
+    };
+    let hook = d.hook_with_secret(&[other.clone(), current]);
+    assert_eq!(hook.secret.as_deref(), Some("${CENTY_WEBHOOK_SECRET}"));
+    assert_eq!(d.hook_with_secret(&[other]).secret, None);
+}
@@ -0,0 +1,18 @@
+//! Durable queue for async hooks.
+//!
+//! Async post-hooks are written to `~/.centy/hook-queue.json` before they
+//! run, so a restart or a failing command does not lose the event. Failed
+//! attempts are retried with the hook's `retries` and `backoff`; deliveries
+//! that run out of retries stay in the queue as dead until they are retried
+//! or discarded. The daemon resumes pending deliveries on startup.
+mod control;
+mod delivery;
+mod store;
+mod worker;
+pub use control::{discard_delivery, enqueue, project_deliveries, retry_delivery};
+pub use delivery::{attempt_failure, DeliveryStatus, HookDelivery};
+pub use store::{queue_path, read_deliveries, update_deliveries};
+pub use worker::{resume_pending, spawn_delivery};
+#[cfg(test)]
+#[path = "delivery_tests.rs"]
+mod delivery_tests;
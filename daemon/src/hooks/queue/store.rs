@@ -0,0 +1,86 @@
+use super::super::error::HookError;
+use super::delivery::HookDelivery;
+use crate::registry::get_centy_config_dir;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::fs;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Queue file name inside `~/.centy`.
+const QUEUE_FILE: &str = "hook-queue.json";
+
+/// Serializes read-modify-write cycles on the queue file.
+static QUEUE_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+fn get_lock() -> &'static Mutex<()> {
+    QUEUE_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// The queue file, `~/.centy/hook-queue.json` (or under `CENTY_HOME`).
+pub fn queue_path() -> Result<PathBuf, HookError> {
+    get_centy_config_dir()
+        .map(|dir| dir.join(QUEUE_FILE))
+        .map_err(|e| HookError::ExecutionError(format!("Cannot locate the hook queue: {e}")))
+}
+
+/// The queued deliveries. A queue file that does not parse is moved aside
+/// to `hook-queue.json.corrupt-<timestamp>` and the queue starts empty, so
+/// one bad write does not stop every later hook.
+async fn read_unlocked(path: &Path) -> Result<Vec<HookDelivery>, HookError> {
+    let content = match fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    match serde_json::from_str(&content) {
+        Ok(deliveries) => Ok(deliveries),
+        Err(e) => {
+            let aside = corrupt_path(path);
+            fs::rename(path, &aside).await?;
+            warn!(
+                error = %e,
+                moved_to = %aside.display(),
+                "Hook queue could not be parsed; starting with an empty queue"
+            );
+            Ok(Vec::new())
+        }
+    }
+}
+
+fn corrupt_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(
+        ".corrupt-{}",
+        chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+    ));
+    PathBuf::from(name)
+}
+
+/// Every queued delivery, pending and dead, oldest first.
+pub async fn read_deliveries() -> Result<Vec<HookDelivery>, HookError> {
+    let path = queue_path()?;
+    let guard = get_lock().lock().await;
+    let deliveries = read_unlocked(&path).await;
+    drop(guard);
+    deliveries
+}
+
+/// Apply `change` to the queue and save it, holding the lock throughout.
+pub async fn update_deliveries<T, F>(change: F) -> Result<T, HookError>
+where
+    F: FnOnce(&mut Vec<HookDelivery>) -> T,
+{
+    let path = queue_path()?;
+    let guard = get_lock().lock().await;
+    let mut deliveries = read_unlocked(&path).await?;
+    let result = change(&mut deliveries);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_string_pretty(&deliveries)?).await?;
+    fs::rename(&tmp, &path).await?;
+    drop(guard);
+    Ok(result)
+}
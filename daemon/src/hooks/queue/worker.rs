@@ -0,0 +1,122 @@
+use super::super::runner::{load_hooks_config, run_hook};
+use super::delivery::{attempt_failure, DeliveryStatus, HookDelivery};
+use super::store::{read_deliveries, update_deliveries};
+use chrono::Utc;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Deliveries with a task driving them, so each runs one attempt at a time.
+static ACTIVE: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+fn active() -> &'static Mutex<HashSet<String>> {
+    ACTIVE.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Drive the delivery `id` in the background until it succeeds, dies or is
+/// discarded.
+pub fn spawn_delivery(id: String) {
+    tokio::spawn(drive(id));
+}
+
+/// Resume every pending delivery, e.g. after a restart. Returns how many.
+pub async fn resume_pending() -> usize {
+    let deliveries = match read_deliveries().await {
+        Ok(deliveries) => deliveries,
+        Err(e) => {
+            warn!("Failed to read the hook queue: {}", e);
+            return 0;
+        }
+    };
+    let pending: Vec<String> = deliveries
+        .into_iter()
+        .filter(|d| d.status == DeliveryStatus::Pending)
+        .map(|d| d.id)
+        .collect();
+    let count = pending.len();
+    for id in pending {
+        spawn_delivery(id);
+    }
+    count
+}
+
+async fn drive(id: String) {
+    if !active().lock().await.insert(id.clone()) {
+        return;
+    }
+    while let Some(delivery) = due_delivery(&id).await {
+        if !attempt(delivery).await {
+            break;
+        }
+    }
+    active().lock().await.remove(&id);
+}
+
+/// Wait for the delivery's next attempt and return it, or `None` once it
+/// has been delivered, discarded or given up on.
+async fn due_delivery(id: &str) -> Option<HookDelivery> {
+    loop {
+        let delivery = match read_deliveries().await {
+            Ok(deliveries) => deliveries.into_iter().find(|d| d.id == id)?,
+            Err(e) => {
+                warn!("Failed to read the hook queue: {}", e);
+                return None;
+            }
+        };
+        if delivery.status == DeliveryStatus::Dead {
+            return None;
+        }
+        let Ok(wait) = delivery
+            .next_attempt_at
+            .signed_duration_since(Utc::now())
+            .to_std()
+        else {
+            return Some(delivery);
+        };
+        if wait.is_zero() {
+            return Some(delivery);
+        }
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Run one attempt and record how it went. Returns `false` when the queue
+/// could not be updated, so the delivery is left for the next resume.
+async fn attempt(delivery: HookDelivery) -> bool {
+    let project_path = Path::new(&delivery.project_path);
+    let hook = delivery.hook_with_secret(&load_hooks_config(project_path).await);
+    let result = run_hook(&hook, &delivery.context, project_path, 0).await;
+    let failure = attempt_failure(&result);
+    let id = delivery.id.as_str();
+    let settled = update_deliveries(|all| {
+        let index = all.iter().position(|d| d.id == id)?;
+        let Some((error, permanent)) = failure else {
+            all.remove(index);
+            return None;
+        };
+        let entry = all.get_mut(index)?;
+        entry.record_failure(error, permanent, Utc::now());
+        Some(entry.clone())
+    })
+    .await;
+    match settled {
+        Ok(Some(entry)) if entry.status == DeliveryStatus::Dead => warn!(
+            "Async hook '{}' gave up after {} attempts: {}",
+            entry.hook.pattern,
+            entry.attempts,
+            entry.last_error.unwrap_or_default()
+        ),
+        Ok(Some(entry)) => debug!(
+            "Async hook '{}' attempt {} failed, retrying at {}",
+            entry.hook.pattern, entry.attempts, entry.next_attempt_at
+        ),
+        Ok(None) => {}
+        Err(e) => {
+            warn!("Failed to update the hook queue: {}", e);
+            return false;
+        }
+    }
+    true
+}
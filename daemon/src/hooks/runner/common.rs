@@ -1,6 +1,15 @@
-use super::super::config::{HookDefinition, HookOperation, HooksFile, ParsedPattern, Phase};
+use super::super::config::{
+    HookDefinition, HookOperation, HookTarget, HooksFile, ParsedPattern, Phase,
+};
+use super::super::context::HookContext;
+use super::super::error::HookError;
+use super::super::executor::{execute_hook, HookExecResult};
+use super::super::runs::{record_run, HookRun};
+use super::super::webhook::deliver;
 use crate::utils::get_centy_path;
 use std::path::Path;
+use std::time::Instant;
+use tracing::{debug, warn};
 /// Load hooks configuration from the project's hooks.yaml.
 /// Returns an empty vec if the file does not exist or has no hooks.
 pub async fn load_hooks_config(project_path: &Path) -> Vec<HookDefinition> {
@@ -34,3 +43,77 @@ pub fn find_matching_hooks<'hook>(
     matching.sort_by(|a, b| b.1.cmp(&a.1));
     matching.into_iter().map(|(h, _)| h).collect()
 }
+/// The hooks to run for an event and the operation each runs as: the
+/// hooks matching it, then those matching only the event it replaced (see
+/// [`HookOperation::legacy`]), which run as the old operation with a
+/// deprecation warning.
+pub(super) fn hooks_for_event<'hook>(
+    hooks: &'hook [HookDefinition],
+    phase: Phase,
+    item_type: &str,
+    operation: HookOperation,
+) -> Vec<(&'hook HookDefinition, HookOperation)> {
+    let matching = find_matching_hooks(hooks, phase, item_type, operation);
+    let mut run: Vec<_> = matching.iter().map(|hook| (*hook, operation)).collect();
+    let Some(legacy) = operation.legacy(item_type) else {
+        return run;
+    };
+    for hook in find_matching_hooks(hooks, phase, item_type, legacy) {
+        if matching.iter().any(|m| std::ptr::eq(*m, hook)) {
+            continue;
+        }
+        warn!(
+            "Hook '{}' runs for {} {} only through the deprecated {} event; \
+             match the {} event instead, the old one stops firing in the next release",
+            hook.pattern,
+            item_type,
+            operation.as_str(),
+            legacy.as_str(),
+            operation.as_str()
+        );
+        run.push((hook, legacy));
+    }
+    run
+}
+/// Run a hook's command, or post the context to its URL with up to
+/// `retries` extra attempts. A hook must set exactly one of the two.
+pub async fn invoke_hook(
+    hook: &HookDefinition,
+    context: &HookContext,
+    project_path: &Path,
+    retries: u32,
+) -> Result<HookExecResult, HookError> {
+    match hook.target() {
+        Some(HookTarget::Command(command)) => {
+            execute_hook(command, context, project_path, hook.timeout, &hook.pattern).await
+        }
+        Some(HookTarget::Url(url)) => deliver(hook, url, context, retries).await,
+        None => Err(HookError::ExecutionError(format!(
+            "Hook '{}' must set exactly one of `command` and `url`",
+            hook.pattern
+        ))),
+    }
+}
+/// [`invoke_hook`], recording the run in the project's hook run history.
+pub async fn run_hook(
+    hook: &HookDefinition,
+    context: &HookContext,
+    project_path: &Path,
+    retries: u32,
+) -> Result<HookExecResult, HookError> {
+    let started = Instant::now();
+    let result = invoke_hook(hook, context, project_path, retries).await;
+    let run = HookRun::finished(hook, context, started.elapsed(), &result);
+    record_run(project_path, &run).await;
+    result
+}
+
+/// Record that `hook` was not started because its `when` clause does not
+/// match.
+pub(super) async fn skip_hook(hook: &HookDefinition, context: &HookContext, project_path: &Path) {
+    debug!(
+        "Skipping {}-hook '{}': `when` does not match",
+        context.phase, hook.pattern
+    );
+    record_run(project_path, &HookRun::skipped(hook, context)).await;
+}
@@ -3,7 +3,9 @@ mod post_hooks;
 mod pre_hooks;
 pub use super::config::{HookDefinition, HookOperation, Phase};
 pub use common::find_matching_hooks;
+pub use common::invoke_hook;
 pub use common::load_hooks_config;
+pub(crate) use common::run_hook;
 pub use post_hooks::run_post_hooks;
 pub use pre_hooks::run_pre_hooks;
 #[cfg(test)]
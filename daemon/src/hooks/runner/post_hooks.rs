@@ -1,18 +1,27 @@
+use super::super::condition::when_matches;
 use super::super::config::{HookDefinition, HookOperation, Phase};
 use super::super::context::HookContext;
-use super::super::executor::execute_hook;
-use super::common::{find_matching_hooks, load_hooks_config};
+use super::super::queue::enqueue;
+use super::common::{hooks_for_event, load_hooks_config, run_hook, skip_hook};
 use std::path::Path;
 use tracing::{debug, warn};
 
+/// Queue an async hook. If the queue cannot be written the hook runs once
+/// in the background instead.
+async fn queue_async_hook(hook: &HookDefinition, context: &HookContext, project_path: &Path) {
+    if let Err(e) = enqueue(project_path, hook, context).await {
+        warn!("Failed to queue async post-hook '{}': {}", hook.pattern, e);
+        spawn_async_hook(hook, context, project_path);
+    }
+}
+
 fn spawn_async_hook(hook: &HookDefinition, context: &HookContext, project_path: &Path) {
-    let command = hook.command.clone();
+    let owned = hook.clone();
     let ctx = context.clone();
     let path = project_path.to_path_buf();
-    let timeout = hook.timeout;
-    let pattern = hook.pattern.clone();
     tokio::spawn(async move {
-        match execute_hook(&command, &ctx, &path, timeout, &pattern).await {
+        let pattern = &owned.pattern;
+        match run_hook(&owned, &ctx, &path, owned.retries).await {
             Ok(result) if result.exit_code != 0i32 => {
                 debug!(
                     "Async post-hook '{}' exited with code {}: {}",
@@ -26,15 +35,7 @@ fn spawn_async_hook(hook: &HookDefinition, context: &HookContext, project_path:
 }
 
 async fn run_sync_hook(hook: &HookDefinition, context: &HookContext, project_path: &Path) {
-    match execute_hook(
-        &hook.command,
-        context,
-        project_path,
-        hook.timeout,
-        &hook.pattern,
-    )
-    .await
-    {
+    match run_hook(hook, context, project_path, hook.retries).await {
         Ok(result) if result.exit_code != 0i32 => {
             warn!(
                 "Post-hook '{}' exited with code {}: {}",
@@ -48,7 +49,9 @@ async fn run_sync_hook(hook: &HookDefinition, context: &HookContext, project_pat
 
 /// Run post-hooks for the given `item_type` and operation.
 /// Synchronous post-hooks run inline (failures logged as warnings).
-/// Async post-hooks are spawned in background (failures logged as debug).
+/// Async post-hooks go through the durable hook queue, which retries them.
+///
+/// Failed webhooks are retried with exponential backoff, up to `retries` times.
 pub async fn run_post_hooks(
     project_path: &Path,
     item_type: &str,
@@ -56,7 +59,7 @@ pub async fn run_post_hooks(
     context: &HookContext,
 ) {
     let hooks = load_hooks_config(project_path).await;
-    let matching = find_matching_hooks(&hooks, Phase::Post, item_type, operation);
+    let matching = hooks_for_event(&hooks, Phase::Post, item_type, operation);
     if matching.is_empty() {
         return;
     }
@@ -66,11 +69,17 @@ pub async fn run_post_hooks(
         item_type,
         operation.as_str()
     );
-    for hook in matching {
+    let mut current = context.clone();
+    for (hook, fired_as) in matching {
+        current.operation = fired_as.as_str().to_string();
+        if !when_matches(&hook.when, &current) {
+            skip_hook(hook, &current, project_path).await;
+            continue;
+        }
         if hook.is_async {
-            spawn_async_hook(hook, context, project_path);
+            queue_async_hook(hook, &current, project_path).await;
         } else {
-            run_sync_hook(hook, context, project_path).await;
+            run_sync_hook(hook, &current, project_path).await;
         }
     }
 }
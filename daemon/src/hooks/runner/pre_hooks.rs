@@ -1,22 +1,35 @@
+use super::super::condition::when_matches;
 use super::super::config::{HookOperation, Phase};
 use super::super::context::HookContext;
 use super::super::error::HookError;
-use super::super::executor::execute_hook;
-use super::common::{find_matching_hooks, load_hooks_config};
+use super::super::patch::{apply_patch, parse_patch, PreHookOutcome};
+use super::common::{hooks_for_event, load_hooks_config, run_hook, skip_hook};
+use serde_json::Value;
 use std::path::Path;
-use tracing::debug;
+use tracing::{debug, warn};
 /// Run pre-hooks for the given `item_type` and operation.
 /// Pre-hooks run synchronously; the first non-zero exit code aborts with an error.
+///
+/// Webhooks get one attempt, and a non-2xx response aborts the same way.
+/// Hooks whose `when` clause does not match are skipped.
+///
+/// A hook that prints a JSON object on stdout patches the request data; the
+/// next hook sees the patched request. The outcome holds the final request
+/// and which hook changed what.
 pub async fn run_pre_hooks(
     project_path: &Path,
     item_type: &str,
     operation: HookOperation,
     context: &HookContext,
-) -> Result<(), HookError> {
+) -> Result<PreHookOutcome, HookError> {
+    let mut outcome = PreHookOutcome {
+        request_data: context.request_data.clone(),
+        modifications: Vec::new(),
+    };
     let hooks = load_hooks_config(project_path).await;
-    let matching = find_matching_hooks(&hooks, Phase::Pre, item_type, operation);
+    let matching = hooks_for_event(&hooks, Phase::Pre, item_type, operation);
     if matching.is_empty() {
-        return Ok(());
+        return Ok(outcome);
     }
     debug!(
         "Running {} pre-hooks for {}:{}",
@@ -24,15 +37,15 @@ pub async fn run_pre_hooks(
         item_type,
         operation.as_str()
     );
-    for hook in matching {
-        let result = execute_hook(
-            &hook.command,
-            context,
-            project_path,
-            hook.timeout,
-            &hook.pattern,
-        )
-        .await?;
+    let mut current = context.clone();
+    for (hook, fired_as) in matching {
+        current.operation = fired_as.as_str().to_string();
+        current.request_data.clone_from(&outcome.request_data);
+        if !when_matches(&hook.when, &current) {
+            skip_hook(hook, &current, project_path).await;
+            continue;
+        }
+        let result = run_hook(hook, &current, project_path, 0).await?;
         if result.exit_code != 0i32 {
             return Err(HookError::PreHookFailed {
                 pattern: hook.pattern.clone(),
@@ -40,6 +53,19 @@ pub async fn run_pre_hooks(
                 stderr: result.stderr,
             });
         }
+        let Some(patch) = parse_patch(&hook.pattern, &result.stdout)? else {
+            continue;
+        };
+        let Some(Value::Object(request)) = outcome.request_data.as_mut() else {
+            warn!(
+                "Pre-hook '{}' printed a patch, but there is no request to patch",
+                hook.pattern
+            );
+            continue;
+        };
+        if let Some(modification) = apply_patch(&hook.pattern, request, patch)? {
+            outcome.modifications.push(modification);
+        }
     }
-    Ok(())
+    Ok(outcome)
 }
@@ -0,0 +1,21 @@
+//! History of hook runs.
+//!
+//! Every hook the runners start, or skip because its `when` clause does not
+//! match, is recorded in `.centy/hook-runs.local.jsonl` with its outcome,
+//! exit code, duration and the tail of its output. The file keeps the most
+//! recent runs only and is not synced. The `ListHookRuns` RPC queries it.
+mod query;
+mod record;
+mod store;
+pub use query::{select_runs, HookRunFilter};
+pub use record::{HookRun, HookRunOutcome};
+pub use store::{append_run, read_runs, record_run, runs_path, MAX_RUNS};
+#[cfg(test)]
+#[path = "query_tests.rs"]
+mod query_tests;
+#[cfg(test)]
+#[path = "record_tests.rs"]
+mod record_tests;
+#[cfg(test)]
+#[path = "store_tests.rs"]
+mod store_tests;
@@ -0,0 +1,43 @@
+use super::record::HookRun;
+
+/// Which hook runs to return. Empty fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct HookRunFilter {
+    /// Hook item type, e.g. `issue`.
+    pub item_type: String,
+    pub item_id: String,
+    /// The hook's pattern, exactly as in `hooks.yaml`.
+    pub pattern: String,
+    /// `pre` or `post`.
+    pub phase: String,
+    /// An outcome name, e.g. `failed` or `skipped`.
+    pub outcome: String,
+}
+
+impl HookRunFilter {
+    #[must_use]
+    pub fn matches(&self, run: &HookRun) -> bool {
+        (self.item_type.is_empty() || run.item_type == self.item_type)
+            && (self.item_id.is_empty() || run.item_id.as_deref() == Some(self.item_id.as_str()))
+            && (self.pattern.is_empty() || run.pattern == self.pattern)
+            && (self.phase.is_empty() || run.phase == self.phase)
+            && (self.outcome.is_empty() || run.outcome.as_str() == self.outcome)
+    }
+}
+
+/// The runs matching `filter`, newest first, at most `limit` of them (`0`
+/// for no limit), together with the number that matched.
+#[must_use]
+pub fn select_runs(
+    runs: Vec<HookRun>,
+    filter: &HookRunFilter,
+    limit: usize,
+) -> (Vec<HookRun>, usize) {
+    let mut matched: Vec<HookRun> = runs.into_iter().filter(|r| filter.matches(r)).collect();
+    matched.reverse();
+    let total = matched.len();
+    if limit > 0 {
+        matched.truncate(limit);
+    }
+    (matched, total)
+}
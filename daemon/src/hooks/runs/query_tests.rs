@@ -0,0 +1,82 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+
+fn run(pattern: &str, item_id: &str, outcome: HookRunOutcome) -> HookRun {
+    HookRun {
+        timestamp: "2026-10-17T10:00:00+00:00".to_string(),
+        pattern: pattern.to_string(),
+        target: "notify.sh".to_string(),
+        phase: if pattern.ends_with("ing") {
+            "pre"
+        } else {
+            "post"
+        }
+        .to_string(),
+        item_type: "issue".to_string(),
+        operation: "create".to_string(),
+        item_id: Some(item_id.to_string()),
+        is_async: false,
+        outcome,
+        exit_code: None,
+        duration_ms: 1,
+        stdout: String::new(),
+        stderr: String::new(),
+        error: None,
+    }
+}
+
+fn runs() -> Vec<HookRun> {
+    vec![
+        run("issue.creating", "a", HookRunOutcome::Succeeded),
+        run("issue.created", "a", HookRunOutcome::Failed),
+        run("issue.created", "b", HookRunOutcome::Skipped),
+    ]
+}
+
+#[test]
+fn test_empty_filter_returns_newest_first() {
+    let (selected, total) = select_runs(runs(), &HookRunFilter::default(), 0);
+
+    assert_eq!(total, 3);
+    assert_eq!(selected[0].item_id.as_deref(), Some("b"));
+    assert_eq!(selected[2].pattern, "issue.creating");
+}
+
+#[test]
+fn test_filter_fields() {
+    let by_item = HookRunFilter {
+        item_id: "a".to_string(),
+        ..HookRunFilter::default()
+    };
+    let by_pattern = HookRunFilter {
+        pattern: "issue.created".to_string(),
+        ..HookRunFilter::default()
+    };
+    let by_phase = HookRunFilter {
+        phase: "pre".to_string(),
+        ..HookRunFilter::default()
+    };
+    let by_outcome = HookRunFilter {
+        outcome: "skipped".to_string(),
+        ..HookRunFilter::default()
+    };
+    let other_type = HookRunFilter {
+        item_type: "doc".to_string(),
+        ..HookRunFilter::default()
+    };
+
+    assert_eq!(select_runs(runs(), &by_item, 0).1, 2);
+    assert_eq!(select_runs(runs(), &by_pattern, 0).1, 2);
+    assert_eq!(select_runs(runs(), &by_phase, 0).1, 1);
+    assert_eq!(select_runs(runs(), &by_outcome, 0).1, 1);
+    assert_eq!(select_runs(runs(), &other_type, 0).1, 0);
+}
+
+#[test]
+fn test_limit_keeps_total() {
+    let (selected, total) = select_runs(runs(), &HookRunFilter::default(), 1);
+
+    assert_eq!(selected.len(), 1);
+    assert_eq!(total, 3);
+}
@@ -0,0 +1,149 @@
+use super::super::config::{HookDefinition, HookTarget};
+use super::super::context::HookContext;
+use super::super::error::HookError;
+use super::super::executor::HookExecResult;
+use crate::utils::now_iso;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Bytes of stdout and stderr kept per run; longer output keeps its tail.
+const MAX_OUTPUT: usize = 2048;
+
+/// How a hook run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookRunOutcome {
+    /// Exit code 0, or a 2xx webhook response.
+    Succeeded,
+    /// A non-zero exit code or a non-2xx webhook response.
+    Failed,
+    TimedOut,
+    /// The hook could not be started or the webhook not reached.
+    Errored,
+    /// The `when` clause did not match; nothing was started.
+    Skipped,
+}
+
+impl HookRunOutcome {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::TimedOut => "timed_out",
+            Self::Errored => "errored",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+/// One recorded hook run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookRun {
+    pub timestamp: String,
+    pub pattern: String,
+    /// The command run, or the URL posted to.
+    pub target: String,
+    pub phase: String,
+    pub item_type: String,
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    #[serde(default, rename = "async")]
+    pub is_async: bool,
+    pub outcome: HookRunOutcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub stdout: String,
+    #[serde(default)]
+    pub stderr: String,
+    /// Why the run failed when there is no exit code to say so.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HookRun {
+    /// A run of `hook` for the operation in `context` that was skipped.
+    #[must_use]
+    pub fn skipped(hook: &HookDefinition, context: &HookContext) -> Self {
+        Self {
+            timestamp: now_iso(),
+            pattern: hook.pattern.clone(),
+            target: match hook.target() {
+                Some(HookTarget::Command(command)) => command.to_string(),
+                Some(HookTarget::Url(url)) => url.to_string(),
+                None => String::new(),
+            },
+            phase: context.phase.clone(),
+            item_type: context.item_type.clone(),
+            operation: context.operation.clone(),
+            item_id: context.item_id.clone(),
+            is_async: hook.is_async,
+            outcome: HookRunOutcome::Skipped,
+            exit_code: None,
+            duration_ms: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: None,
+        }
+    }
+
+    /// A run of `hook` that took `elapsed` and ended with `result`.
+    #[must_use]
+    pub fn finished(
+        hook: &HookDefinition,
+        context: &HookContext,
+        elapsed: Duration,
+        result: &Result<HookExecResult, HookError>,
+    ) -> Self {
+        let mut run = Self::skipped(hook, context);
+        run.duration_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        match result {
+            Ok(exec) => {
+                run.outcome = if exec.exit_code == 0i32 {
+                    HookRunOutcome::Succeeded
+                } else {
+                    HookRunOutcome::Failed
+                };
+                run.exit_code = Some(exec.exit_code);
+                run.stdout = tail(&exec.stdout);
+                run.stderr = tail(&exec.stderr);
+            }
+            Err(e) => {
+                run.outcome = error_outcome(e);
+                run.error = Some(e.to_string());
+            }
+        }
+        run
+    }
+
+    /// Whether the run timed out.
+    #[must_use]
+    pub fn timed_out(&self) -> bool {
+        self.outcome == HookRunOutcome::TimedOut
+    }
+}
+
+fn error_outcome(err: &HookError) -> HookRunOutcome {
+    match err {
+        HookError::WebhookFailed { .. } | HookError::PreHookFailed { .. } => HookRunOutcome::Failed,
+        HookError::Timeout { .. } => HookRunOutcome::TimedOut,
+        HookError::InvalidPatch { .. }
+        | HookError::ExecutionError(_)
+        | HookError::InvalidPattern(_)
+        | HookError::DeliveryNotFound(_)
+        | HookError::IoError(_)
+        | HookError::JsonError(_) => HookRunOutcome::Errored,
+    }
+}
+
+/// The last `MAX_OUTPUT` bytes of `output`, cut at a character boundary.
+fn tail(output: &str) -> String {
+    let mut start = output.len().saturating_sub(MAX_OUTPUT);
+    while !output.is_char_boundary(start) {
+        start = start.saturating_add(1);
+    }
+    output.get(start..).unwrap_or_default().to_string()
+}
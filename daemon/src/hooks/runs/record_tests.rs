@@ -0,0 +1,118 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+use crate::hooks::executor::HookExecResult;
+use crate::hooks::{HookContext, HookDefinition, HookError, HookOperation, Phase};
+use std::time::Duration;
+
+fn hook() -> HookDefinition {
+    HookDefinition {
+        pattern: "issue.created".to_string(),
+        command: "notify.sh".to_string(),
+        is_async: true,
+        ..HookDefinition::default()
+    }
+}
+
+fn context() -> HookContext {
+    HookContext::new(
+        Phase::Post,
+        "issue",
+        HookOperation::Create,
+        "/tmp/project",
+        Some("abc"),
+        None,
+        Some(true),
+    )
+}
+
+fn finished(result: &Result<HookExecResult, HookError>) -> HookRun {
+    HookRun::finished(&hook(), &context(), Duration::from_millis(42), result)
+}
+
+#[test]
+fn test_skipped_run_describes_hook_and_operation() {
+    let run = HookRun::skipped(&hook(), &context());
+
+    assert_eq!(run.pattern, "issue.created");
+    assert_eq!(run.target, "notify.sh");
+    assert_eq!(run.phase, "post");
+    assert_eq!(run.item_type, "issue");
+    assert_eq!(run.operation, "create");
+    assert_eq!(run.item_id.as_deref(), Some("abc"));
+    assert!(run.is_async);
+    assert_eq!(run.outcome, HookRunOutcome::Skipped);
+    assert_eq!(run.exit_code, None);
+}
+
+#[test]
+fn test_finished_run_records_exit_code_and_output() {
+    let ok = finished(&Ok(HookExecResult {
+        exit_code: 0i32,
+        stdout: "done".to_string(),
+        stderr: String::new(),
+    }));
+    let failed = finished(&Ok(HookExecResult {
+        exit_code: 2i32,
+        stdout: String::new(),
+        stderr: "boom".to_string(),
+    }));
+
+    assert_eq!(ok.outcome, HookRunOutcome::Succeeded);
+    assert_eq!(ok.exit_code, Some(0i32));
+    assert_eq!(ok.duration_ms, 42);
+    assert_eq!(ok.stdout, "done");
+    assert_eq!(failed.outcome, HookRunOutcome::Failed);
+    assert_eq!(failed.exit_code, Some(2i32));
+    assert_eq!(failed.stderr, "boom");
+}
+
+#[test]
+fn test_finished_run_records_errors() {
+    let timed_out = finished(&Err(HookError::Timeout {
+        pattern: "issue.created".to_string(),
+        timeout_secs: 30,
+    }));
+    let rejected = finished(&Err(HookError::WebhookFailed {
+        pattern: "issue.created".to_string(),
+        status: 500,
+        body: "down".to_string(),
+    }));
+    let errored = finished(&Err(HookError::ExecutionError("no bash".to_string())));
+
+    assert_eq!(timed_out.outcome, HookRunOutcome::TimedOut);
+    assert!(timed_out.timed_out());
+    assert!(timed_out.error.unwrap().contains("timed out"));
+    assert_eq!(rejected.outcome, HookRunOutcome::Failed);
+    assert!(rejected.error.unwrap().contains("500"));
+    assert_eq!(errored.outcome, HookRunOutcome::Errored);
+    assert!(!errored.timed_out());
+}
+
+#[test]
+fn test_output_is_truncated_to_its_tail() {
+    let stdout = format!("{}end", "\u{e9}".repeat(3000));
+    let run = finished(&Ok(HookExecResult {
+        exit_code: 0i32,
+        stdout,
+        stderr: String::new(),
+    }));
+
+    assert!(run.stdout.len() <= 2048);
+    assert!(run.stdout.ends_with("end"));
+}
+
+#[test]
+fn test_run_round_trips_through_json() {
+    let run = finished(&Ok(HookExecResult {
+        exit_code: 1i32,
+        stdout: String::new(),
+        stderr: "bad".to_string(),
+    }));
+
+    let json = serde_json::to_string(&run).unwrap();
+    assert!(json.contains("\"outcome\":\"failed\""));
+    assert!(json.contains("\"async\":true"));
+    let parsed: HookRun = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, run);
+}
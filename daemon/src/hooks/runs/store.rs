@@ -0,0 +1,76 @@
+use super::super::error::HookError;
+use super::record::HookRun;
+use crate::utils::get_centy_path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::fs;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Run history file name. It is local to this machine (`*.local.*` files
+/// are git-ignored and never synced).
+const RUNS_FILE: &str = "hook-runs.local.jsonl";
+
+/// Runs kept per project; older ones are dropped as new ones arrive.
+pub const MAX_RUNS: usize = 500;
+
+/// Keeps concurrent hooks from losing each other's records.
+static RUNS_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+fn get_lock() -> &'static Mutex<()> {
+    RUNS_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+#[must_use]
+pub fn runs_path(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join(RUNS_FILE)
+}
+
+/// Append one run, dropping the oldest runs beyond [`MAX_RUNS`].
+pub async fn append_run(project_path: &Path, run: &HookRun) -> Result<(), HookError> {
+    let line = serde_json::to_string(run)?;
+    let path = runs_path(project_path);
+    let guard = get_lock().lock().await;
+    let existing = match fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut lines: Vec<&str> = existing.lines().filter(|l| !l.trim().is_empty()).collect();
+    lines.push(&line);
+    let keep = lines.len().saturating_sub(MAX_RUNS);
+    let mut content = lines.get(keep..).unwrap_or_default().join("\n");
+    content.push('\n');
+    fs::write(&path, content).await?;
+    drop(guard);
+    Ok(())
+}
+
+/// Read every recorded run, oldest first. Lines that do not parse are
+/// skipped with a warning.
+pub async fn read_runs(project_path: &Path) -> Result<Vec<HookRun>, HookError> {
+    let path = runs_path(project_path);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).await?;
+    let runs = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(run) => Some(run),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Skipping malformed hook run line");
+                None
+            }
+        })
+        .collect();
+    Ok(runs)
+}
+
+/// Record a run. Failing to record never fails the hook; it is logged.
+pub async fn record_run(project_path: &Path, run: &HookRun) {
+    if let Err(e) = append_run(project_path, run).await {
+        warn!(pattern = %run.pattern, error = %e, "Failed to record hook run");
+    }
+}
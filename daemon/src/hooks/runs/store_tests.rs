@@ -0,0 +1,80 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+use super::*;
+use crate::hooks::{HookContext, HookDefinition, HookOperation, Phase};
+
+async fn project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    tokio::fs::create_dir_all(temp.path().join(".centy"))
+        .await
+        .unwrap();
+    temp
+}
+
+fn run(pattern: &str) -> HookRun {
+    let hook = HookDefinition {
+        pattern: pattern.to_string(),
+        command: "true".to_string(),
+        ..HookDefinition::default()
+    };
+    let context = HookContext::new(
+        Phase::Pre,
+        "issue",
+        HookOperation::Create,
+        "/tmp/project",
+        None,
+        None,
+        None,
+    );
+    HookRun::skipped(&hook, &context)
+}
+
+#[tokio::test]
+async fn test_read_runs_without_file_is_empty() {
+    let temp = project().await;
+    assert!(read_runs(temp.path()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_record_appends_in_order() {
+    let temp = project().await;
+    record_run(temp.path(), &run("issue.creating")).await;
+    record_run(temp.path(), &run("doc.creating")).await;
+
+    let runs = read_runs(temp.path()).await.unwrap();
+    let patterns: Vec<&str> = runs.iter().map(|r| r.pattern.as_str()).collect();
+    assert_eq!(patterns, vec!["issue.creating", "doc.creating"]);
+    assert!(runs_path(temp.path())
+        .to_string_lossy()
+        .ends_with(".local.jsonl"));
+}
+
+#[tokio::test]
+async fn test_history_is_bounded() {
+    let temp = project().await;
+    let old = std::iter::repeat_n(
+        serde_json::to_string(&run("old.creating")).unwrap(),
+        MAX_RUNS,
+    )
+    .collect::<Vec<_>>()
+    .join("\n");
+    tokio::fs::write(runs_path(temp.path()), old).await.unwrap();
+
+    append_run(temp.path(), &run("new.creating")).await.unwrap();
+
+    let runs = read_runs(temp.path()).await.unwrap();
+    assert_eq!(runs.len(), MAX_RUNS);
+    assert_eq!(runs.last().unwrap().pattern, "new.creating");
+}
+
+#[tokio::test]
+async fn test_malformed_lines_are_skipped() {
+    let temp = project().await;
+    let good = serde_json::to_string(&run("issue.creating")).unwrap();
+    tokio::fs::write(runs_path(temp.path()), format!("{{\"cut\n{good}\n"))
+        .await
+        .unwrap();
+
+    let runs = read_runs(temp.path()).await.unwrap();
+    assert_eq!(runs.len(), 1);
+}
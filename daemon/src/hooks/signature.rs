@@ -0,0 +1,48 @@
+use hmac::digest::InvalidLength;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// HMAC-SHA256 (RFC 2104) of `message` under `key`.
+///
+/// # Errors
+///
+/// Only if the key is refused; HMAC takes keys of any length.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<[u8; 32], InvalidLength> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key)?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().into())
+}
+
+/// The `X-Centy-Signature` value for `body`: `sha256=<hex digest>`.
+///
+/// # Errors
+///
+/// See [`hmac_sha256`].
+pub fn signature_header(secret: &str, body: &[u8]) -> Result<String, InvalidLength> {
+    let mac = hmac_sha256(secret.as_bytes(), body)?;
+    Ok(format!("sha256={}", hex::encode(mac)))
+}
+
+/// Replace `${NAME}` with the environment variable `NAME`, so secrets stay
+/// out of `hooks.yaml`. Unset variables expand to nothing.
+#[must_use]
+pub fn expand_env(value: &str) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some((before, after)) = rest.split_once("${") {
+        expanded.push_str(before);
+        let Some((name, tail)) = after.split_once('}') else {
+            expanded.push_str("${");
+            rest = after;
+            break;
+        };
+        expanded.push_str(&std::env::var(name).unwrap_or_default());
+        rest = tail;
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+#[path = "signature_tests.rs"]
+mod signature_tests;
@@ -0,0 +1,57 @@
+#![allow(clippy::unwrap_used)]
+use super::*;
+
+// Test vectors from RFC 4231.
+
+#[test]
+fn test_hmac_sha256_short_key() {
+    let mac = hmac_sha256(&[0x0b; 20], b"Hi There").unwrap();
+    assert_eq!(
+        hex::encode(mac),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+}
+
+#[test]
+fn test_hmac_sha256_text_key() {
+    let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?").unwrap();
+    assert_eq!(
+        hex::encode(mac),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn test_hmac_sha256_key_longer_than_block() {
+    let mac = hmac_sha256(
+        &[0xaa; 131],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+    )
+    .unwrap();
+    assert_eq!(
+        hex::encode(mac),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn test_signature_header_format() {
+    let header = signature_header("Jefe", b"what do ya want for nothing?").unwrap();
+    assert_eq!(
+        header,
+        "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn test_expand_env() {
+    std::env::set_var("CENTY_SIGNATURE_TEST_TOKEN", "s3cret");
+
+    assert_eq!(
+        expand_env("Bearer ${CENTY_SIGNATURE_TEST_TOKEN}"),
+        "Bearer s3cret"
+    );
+    assert_eq!(expand_env("${CENTY_SIGNATURE_TEST_UNSET}x"), "x");
+    assert_eq!(expand_env("plain"), "plain");
+    assert_eq!(expand_env("a ${unclosed"), "a ${unclosed");
+}
@@ -0,0 +1,119 @@
+use super::config::HookDefinition;
+use super::context::HookContext;
+use super::error::HookError;
+use super::executor::HookExecResult;
+use super::signature::{expand_env, signature_header};
+use std::time::Duration;
+use tracing::debug;
+
+/// Header carrying `sha256=<hex>`, the HMAC-SHA256 of the body under the
+/// hook's `secret`.
+pub const SIGNATURE_HEADER: &str = "X-Centy-Signature";
+
+/// POST the hook context to `url` as JSON, retrying up to `retries` more
+/// times on connection errors, timeouts, 429 and 5xx responses. Retries
+/// back off exponentially from the hook's `backoff`.
+///
+/// A 2xx response succeeds with its body as `stdout`, so pre-hook webhooks
+/// can patch the request the same way commands do. Any other status fails
+/// with [`HookError::WebhookFailed`].
+pub async fn deliver(
+    hook: &HookDefinition,
+    url: &str,
+    context: &HookContext,
+    retries: u32,
+) -> Result<HookExecResult, HookError> {
+    let body = context.to_json()?;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(hook.timeout))
+        .build()
+        .map_err(|e| HookError::ExecutionError(format!("Failed to build HTTP client: {e}")))?;
+    let mut attempt = 0u32;
+    loop {
+        let result = post(&client, hook, url, &body).await;
+        if attempt >= retries || !is_retryable(&result) {
+            return result;
+        }
+        let delay = retry_delay(hook.backoff, attempt);
+        debug!(
+            "Webhook '{}' attempt {} failed, retrying in {:?}",
+            hook.pattern,
+            attempt.saturating_add(1),
+            delay
+        );
+        tokio::time::sleep(delay).await;
+        attempt = attempt.saturating_add(1);
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    hook: &HookDefinition,
+    url: &str,
+    body: &str,
+) -> Result<HookExecResult, HookError> {
+    let mut request = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    for (name, value) in &hook.headers {
+        request = request.header(name, expand_env(value));
+    }
+    if let Some(secret) = &hook.secret {
+        let signature = signature_header(&expand_env(secret), body.as_bytes())
+            .map_err(|e| HookError::ExecutionError(format!("Cannot sign webhook body: {e}")))?;
+        request = request.header(SIGNATURE_HEADER, signature);
+    }
+    let response = request
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(|e| transport_error(hook, &e))?;
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(|e| transport_error(hook, &e))?;
+    if status.is_success() {
+        Ok(HookExecResult {
+            exit_code: 0i32,
+            stdout: text,
+            stderr: String::new(),
+        })
+    } else {
+        Err(HookError::WebhookFailed {
+            pattern: hook.pattern.clone(),
+            status: status.as_u16(),
+            body: text.trim().to_string(),
+        })
+    }
+}
+
+/// The wait before retry number `attempt` (counting from 0): `backoff`
+/// seconds, doubled for each earlier retry.
+#[must_use]
+pub fn retry_delay(backoff: u64, attempt: u32) -> Duration {
+    Duration::from_secs(backoff).saturating_mul(2u32.saturating_pow(attempt))
+}
+
+fn transport_error(hook: &HookDefinition, err: &reqwest::Error) -> HookError {
+    if err.is_timeout() {
+        HookError::Timeout {
+            pattern: hook.pattern.clone(),
+            timeout_secs: hook.timeout,
+        }
+    } else {
+        HookError::ExecutionError(format!("Webhook '{}' request failed: {err}", hook.pattern))
+    }
+}
+
+/// Whether another attempt could succeed: the endpoint was unreachable,
+/// slow, rate limiting or failing on its side.
+pub(super) fn is_retryable(result: &Result<HookExecResult, HookError>) -> bool {
+    matches!(
+        result,
+        Err(HookError::Timeout { .. } | HookError::ExecutionError(_))
+    ) || matches!(
+        result,
+        Err(HookError::WebhookFailed { status, .. }) if *status == 429u16 || *status >= 500u16
+    )
+}
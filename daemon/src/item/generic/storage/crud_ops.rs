@@ -1,7 +1,13 @@
 //! Basic CRUD operations for generic items.
 use super::helpers::{type_storage_path, update_project_manifest};
+use super::journal_sync::{journal_write, read_state};
+use super::search_sync::{refresh_item, reindex_item, unindex_item};
+use super::undo_sync::{entity_link_targets, item_targets};
 use crate::item::core::error::ItemError;
+use crate::journal::Operation;
+use crate::undo::{self, Target};
 use crate::utils::get_centy_path;
+use crate::watch::{publish, ChangeEvent, ChangeKind};
 use mdstore::{CreateOptions, Filters, TypeConfig, UpdateOptions};
 use std::path::Path;
 use tokio::fs;
@@ -15,7 +21,17 @@ pub async fn generic_create(
 ) -> Result<mdstore::Item, ItemError> {
     let type_dir = type_storage_path(project_path, folder);
     let item = mdstore::create(&type_dir, config, options).await?;
+    let created = item_targets(project_path, folder, config, &item.id);
+    undo::record_created(project_path, Operation::Create, folder, &item.id, created).await;
     update_project_manifest(project_path).await?;
+    reindex_item(project_path, folder, &item).await;
+    journal_write(project_path, folder, &item.id, None, Some(&item)).await;
+    publish(ChangeEvent::item(
+        ChangeKind::Created,
+        project_path,
+        folder,
+        &item.id,
+    ));
     Ok(item)
 }
 /// Get a single generic item by ID.
@@ -37,6 +53,7 @@ pub async fn generic_list(
     Ok(mdstore::list(&type_dir, filters).await?)
 }
 /// Update an existing generic item.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_update(
     project_path: &Path,
     folder: &str,
@@ -45,8 +62,20 @@ pub async fn generic_update(
     options: UpdateOptions,
 ) -> Result<mdstore::Item, ItemError> {
     let type_dir = type_storage_path(project_path, folder);
+    let before = read_state(project_path, folder, id).await;
+    let target = Target::item_file(project_path, folder, id);
+    let pending = undo::begin(project_path, Operation::Update, folder, id, vec![target]).await;
     let item = mdstore::update(&type_dir, config, id, options).await?;
+    undo::finish(pending).await;
     update_project_manifest(project_path).await?;
+    reindex_item(project_path, folder, &item).await;
+    journal_write(project_path, folder, id, before.as_ref(), Some(&item)).await;
+    publish(ChangeEvent::item(
+        ChangeKind::Updated,
+        project_path,
+        folder,
+        id,
+    ));
     Ok(item)
 }
 /// Delete item assets directory if it exists.
@@ -61,6 +90,7 @@ async fn delete_item_assets(project_path: &Path, folder: &str, id: &str) -> Resu
     Ok(())
 }
 /// Delete an item (hard or soft delete).
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_delete(
     project_path: &Path,
     folder: &str,
@@ -69,6 +99,15 @@ pub async fn generic_delete(
     force: bool,
 ) -> Result<(), ItemError> {
     let type_dir = type_storage_path(project_path, folder);
+    let before = read_state(project_path, folder, id).await;
+    let mut targets = item_targets(project_path, folder, config, id);
+    let operation = if force {
+        targets.extend(entity_link_targets(project_path, id).await);
+        Operation::Delete
+    } else {
+        Operation::SoftDelete
+    };
+    let pending = undo::begin(project_path, operation, folder, id, targets).await;
     if force {
         // Cascade-delete all links referencing this entity before removing the
         // item itself so no orphan link records are ever left behind.
@@ -80,24 +119,71 @@ pub async fn generic_delete(
     if force && config.features.assets {
         delete_item_assets(project_path, folder, id).await?;
     }
+    undo::finish(pending).await;
     update_project_manifest(project_path).await?;
+    // A soft delete only stamps `deletedAt`, so the item is updated.
+    let kind = if force {
+        unindex_item(project_path, folder, id).await;
+        ChangeKind::Deleted
+    } else {
+        refresh_item(project_path, folder, id).await;
+        ChangeKind::Updated
+    };
+    let after = read_state(project_path, folder, id).await;
+    journal_write(project_path, folder, id, before.as_ref(), after.as_ref()).await;
+    publish(ChangeEvent::item(kind, project_path, folder, id));
     Ok(())
 }
 /// Soft-delete an item by setting the `deleted_at` timestamp.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_soft_delete(
     project_path: &Path,
     folder: &str,
     id: &str,
 ) -> Result<(), ItemError> {
     let type_dir = type_storage_path(project_path, folder);
+    let before = read_state(project_path, folder, id).await;
+    let target = Target::item_file(project_path, folder, id);
+    let pending = undo::begin(
+        project_path,
+        Operation::SoftDelete,
+        folder,
+        id,
+        vec![target],
+    )
+    .await;
     mdstore::soft_delete(&type_dir, id).await?;
+    undo::finish(pending).await;
     update_project_manifest(project_path).await?;
+    refresh_item(project_path, folder, id).await;
+    let after = read_state(project_path, folder, id).await;
+    journal_write(project_path, folder, id, before.as_ref(), after.as_ref()).await;
+    publish(ChangeEvent::item(
+        ChangeKind::Updated,
+        project_path,
+        folder,
+        id,
+    ));
     Ok(())
 }
 /// Restore a soft-deleted item by clearing the `deleted_at` timestamp.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_restore(project_path: &Path, folder: &str, id: &str) -> Result<(), ItemError> {
     let type_dir = type_storage_path(project_path, folder);
+    let before = read_state(project_path, folder, id).await;
+    let target = Target::item_file(project_path, folder, id);
+    let pending = undo::begin(project_path, Operation::Restore, folder, id, vec![target]).await;
     mdstore::restore(&type_dir, id).await?;
+    undo::finish(pending).await;
     update_project_manifest(project_path).await?;
+    refresh_item(project_path, folder, id).await;
+    let after = read_state(project_path, folder, id).await;
+    journal_write(project_path, folder, id, before.as_ref(), after.as_ref()).await;
+    publish(ChangeEvent::item(
+        ChangeKind::Updated,
+        project_path,
+        folder,
+        id,
+    ));
     Ok(())
 }
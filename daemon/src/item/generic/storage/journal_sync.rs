@@ -0,0 +1,110 @@
+//! Records generic storage writes in the project journal.
+//!
+//! Like the search index, the journal never fails the write itself; see
+//! [`crate::journal::record`].
+use super::helpers::type_storage_path;
+use crate::journal::{
+    diff_fields, item_fields, operation_for, record, Change, FieldChange, JournalEntity, Operation,
+};
+use serde_json::Value;
+use std::path::Path;
+
+/// Item type folder that archiving moves items into.
+const ARCHIVED_FOLDER: &str = "archived";
+
+/// Read an item's current state to diff a write against.
+pub async fn read_state(project_path: &Path, folder: &str, id: &str) -> Option<mdstore::Item> {
+    mdstore::get(&type_storage_path(project_path, folder), id)
+        .await
+        .ok()
+}
+
+/// Journal a write to one item, naming the operation from the states
+/// around it (create, update, delete, soft delete or restore).
+pub async fn journal_write(
+    project_path: &Path,
+    folder: &str,
+    id: &str,
+    before: Option<&mdstore::Item>,
+    after: Option<&mdstore::Item>,
+) {
+    let old = before.map(item_fields);
+    let current = after.map(item_fields);
+    let operation = operation_for(old.as_ref(), current.as_ref());
+    let changes = diff_fields(old.as_ref(), current.as_ref());
+    journal_item(project_path, operation, folder, id, changes).await;
+}
+
+/// Journal an operation on one item with precomputed changes.
+pub async fn journal_item(
+    project_path: &Path,
+    operation: Operation,
+    folder: &str,
+    id: &str,
+    changes: Vec<FieldChange>,
+) {
+    let change = Change {
+        operation,
+        entity: JournalEntity::Item,
+        item_type: folder.to_string(),
+        item_id: id.to_string(),
+        changes,
+    };
+    record(project_path, change).await;
+}
+
+/// Journal a move, including archiving and unarchiving. The entry names the
+/// item as it is after the move; a move across projects is journaled in
+/// both projects.
+pub async fn journal_move(
+    source_project: &Path,
+    target_project: &Path,
+    source_folder: &str,
+    target_folder: &str,
+    old_id: &str,
+    new_id: &str,
+) {
+    let operation = move_operation(source_folder, target_folder);
+    let source_path = source_project.to_string_lossy();
+    let target_path = target_project.to_string_lossy();
+    let moved: Vec<FieldChange> = [
+        ("projectPath", source_path.as_ref(), target_path.as_ref()),
+        ("itemType", source_folder, target_folder),
+        ("id", old_id, new_id),
+    ]
+    .into_iter()
+    .filter(|(_, old, current)| old != current)
+    .map(|(field, old, current)| string_change(field, old, current))
+    .collect();
+    if source_project != target_project {
+        journal_item(
+            source_project,
+            operation,
+            source_folder,
+            old_id,
+            moved.clone(),
+        )
+        .await;
+    }
+    journal_item(target_project, operation, target_folder, new_id, moved).await;
+}
+
+/// Name a move: archiving, unarchiving or a plain move.
+pub fn move_operation(source_folder: &str, target_folder: &str) -> Operation {
+    if source_folder == ARCHIVED_FOLDER {
+        Operation::Unarchive
+    } else if target_folder == ARCHIVED_FOLDER {
+        Operation::Archive
+    } else {
+        Operation::Move
+    }
+}
+
+/// A change between two string values.
+pub fn string_change(field: &str, before: &str, after: &str) -> FieldChange {
+    FieldChange {
+        field: field.to_string(),
+        before: Some(Value::String(before.to_string())),
+        after: Some(Value::String(after.to_string())),
+    }
+}
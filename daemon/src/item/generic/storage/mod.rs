@@ -2,8 +2,12 @@
 mod crud_ops;
 mod crud_search;
 mod helpers;
+mod journal_sync;
 mod move_item;
 mod move_ops;
+mod search_sync;
+mod undo_ops;
+mod undo_sync;
 #[cfg(test)]
 use crate::item::core::error::ItemError;
 #[cfg(test)]
@@ -19,6 +23,7 @@ pub use move_item::generic_move;
 pub use move_ops::{generic_duplicate, generic_rename_slug};
 #[cfg(test)]
 use tokio::fs;
+pub use undo_ops::generic_undo;
 #[cfg(test)]
 #[path = "cascade_delete_tests.rs"]
 mod cascade_delete_tests;
@@ -1,11 +1,17 @@
 //! Move operation for generic items.
 use super::helpers::{copy_item_assets, type_storage_path, update_project_manifest};
+use super::journal_sync::{journal_move, move_operation};
+use super::search_sync::{reindex_item, unindex_item};
+use super::undo_sync::item_targets;
 use crate::item::core::error::ItemError;
 use crate::manifest;
+use crate::undo::{self, Target};
+use crate::watch::{publish, ChangeEvent, ChangeKind};
 use mdstore::TypeConfig;
 use std::path::Path;
 use tokio::fs;
 /// Move an item from one project to another.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_move(
     source_project_path: &Path,
     target_project_path: &Path,
@@ -24,6 +30,18 @@ pub async fn generic_move(
         .ok_or(ItemError::TargetNotInitialized)?;
     let source_dir = type_storage_path(source_project_path, source_folder);
     let target_dir = type_storage_path(target_project_path, target_folder);
+    let mut targets = item_targets(source_project_path, source_folder, source_config, item_id);
+    if source_config.features.assets {
+        targets.push(Target::dir(source_dir.join("assets").join(item_id)));
+    }
+    let mut pending = undo::begin(
+        source_project_path,
+        move_operation(source_folder, target_folder),
+        source_folder,
+        item_id,
+        targets,
+    )
+    .await;
     let copied_assets = copy_item_assets(
         source_project_path,
         target_project_path,
@@ -55,7 +73,43 @@ pub async fn generic_move(
             fs::remove_dir_all(&source_assets_legacy).await?;
         }
     }
+    if let Some(p) = pending.as_mut() {
+        p.add_created(item_targets(
+            target_project_path,
+            target_folder,
+            target_config,
+            &result.item.id,
+        ));
+    }
+    undo::finish(pending).await;
     update_project_manifest(source_project_path).await?;
     update_project_manifest(target_project_path).await?;
+    unindex_item(source_project_path, source_folder, item_id).await;
+    reindex_item(target_project_path, target_folder, &result.item).await;
+    let new_item_id = &result.item.id;
+    journal_move(
+        source_project_path,
+        target_project_path,
+        source_folder,
+        target_folder,
+        item_id,
+        new_item_id,
+    )
+    .await;
+    let moved = ChangeEvent::item(
+        ChangeKind::Moved,
+        source_project_path,
+        source_folder,
+        new_item_id,
+    );
+    publish(moved.moved(item_id, Some(target_project_path)));
+    if target_project_path != source_project_path {
+        publish(ChangeEvent::item(
+            ChangeKind::Created,
+            target_project_path,
+            target_folder,
+            new_item_id,
+        ));
+    }
     Ok(result)
 }
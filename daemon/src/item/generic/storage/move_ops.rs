@@ -1,7 +1,13 @@
 //! Move and duplicate operations for generic items.
 use super::super::types::DuplicateGenericItemOptions;
 use super::helpers::{copy_dir_contents, type_storage_path, update_project_manifest};
+use super::journal_sync::{journal_item, string_change};
+use super::search_sync::{reindex_item, unindex_item};
+use super::undo_sync::item_targets;
 use crate::item::core::error::ItemError;
+use crate::journal::{diff_fields, item_fields, Operation};
+use crate::undo::{self, Target};
+use crate::watch::{publish, ChangeEvent, ChangeKind};
 use mdstore::TypeConfig;
 use std::path::Path;
 use tokio::fs;
@@ -29,10 +35,41 @@ pub async fn generic_duplicate(
             copy_dir_contents(&source_assets, &target_assets).await?;
         }
     }
+    let created = item_targets(
+        &options.target_project_path,
+        folder,
+        config,
+        &result.item.id,
+    );
+    undo::record_created(
+        &options.target_project_path,
+        Operation::Duplicate,
+        folder,
+        &result.item.id,
+        created,
+    )
+    .await;
     update_project_manifest(&options.target_project_path).await?;
+    reindex_item(&options.target_project_path, folder, &result.item).await;
+    let copied = item_fields(&result.item);
+    journal_item(
+        &options.target_project_path,
+        Operation::Duplicate,
+        folder,
+        &result.item.id,
+        diff_fields(None, Some(&copied)),
+    )
+    .await;
+    publish(ChangeEvent::item(
+        ChangeKind::Created,
+        &options.target_project_path,
+        folder,
+        &result.item.id,
+    ));
     Ok(result)
 }
 /// Rename a slug-based item within the same project folder.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
 pub async fn generic_rename_slug(
     project_path: &Path,
     folder: &str,
@@ -53,8 +90,21 @@ pub async fn generic_rename_slug(
     }
     let mut item = mdstore::get(&type_dir, item_id).await?;
     item.id = new_id.to_string();
+    let targets = vec![
+        Target::item_file(project_path, folder, item_id),
+        Target::item_file(project_path, folder, new_id),
+    ];
+    let pending = undo::begin(project_path, Operation::Rename, folder, new_id, targets).await;
     tokio::fs::rename(&source_file, &target_file).await?;
+    undo::finish(pending).await;
     update_project_manifest(project_path).await?;
+    unindex_item(project_path, folder, item_id).await;
+    reindex_item(project_path, folder, &item).await;
+    let renamed = vec![string_change("id", item_id, new_id)];
+    journal_item(project_path, Operation::Rename, folder, new_id, renamed).await;
+    publish(
+        ChangeEvent::item(ChangeKind::Moved, project_path, folder, new_id).moved(item_id, None),
+    );
     Ok(mdstore::MoveResult {
         item,
         old_id: item_id.to_string(),
@@ -0,0 +1,31 @@
+//! Keeps the project's search index in step with generic storage writes.
+//!
+//! Index failures never fail the write itself; they are logged, and the
+//! search module drops the index so the next search rebuilds it.
+use super::helpers::type_storage_path;
+use crate::search;
+use std::path::Path;
+use tracing::warn;
+
+/// Index (or re-index) an item that was just written.
+pub async fn reindex_item(project_path: &Path, folder: &str, item: &mdstore::Item) {
+    if let Err(e) = search::index_item(project_path, folder, item).await {
+        warn!(id = %item.id, folder = %folder, error = %e, "Failed to update search index");
+    }
+}
+
+/// Re-read an item from disk and index its current state, or drop it if it is gone.
+pub async fn refresh_item(project_path: &Path, folder: &str, id: &str) {
+    let type_dir = type_storage_path(project_path, folder);
+    match mdstore::get(&type_dir, id).await {
+        Ok(item) => reindex_item(project_path, folder, &item).await,
+        Err(_) => unindex_item(project_path, folder, id).await,
+    }
+}
+
+/// Remove an item from the index.
+pub async fn unindex_item(project_path: &Path, folder: &str, id: &str) {
+    if let Err(e) = search::remove_item(project_path, folder, id).await {
+        warn!(id = %id, folder = %folder, error = %e, "Failed to update search index");
+    }
+}
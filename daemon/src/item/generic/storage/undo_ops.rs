@@ -0,0 +1,74 @@
+//! Undo for generic storage writes.
+use super::helpers::update_project_manifest;
+use super::journal_sync::{journal_item, read_state};
+use super::search_sync::refresh_item;
+use crate::journal::{diff_fields, item_fields, Operation};
+use crate::undo::{plan_undo, ItemRef, UndoEntry, UndoError};
+use crate::watch::{publish, ChangeEvent, ChangeKind};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Undo the last `count` operations recorded in a project, newest first.
+///
+/// Fails without changing anything when a file was changed by something
+/// else since. Afterwards every affected item is re-indexed, journaled as
+/// an `undo` with its field changes and announced to watchers.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `plan.apply()`
+pub async fn generic_undo(project_path: &Path, count: usize) -> Result<Vec<UndoEntry>, UndoError> {
+    let plan = plan_undo(project_path, count).await?;
+    let mut items: Vec<ItemRef> = Vec::new();
+    for item in plan
+        .entries()
+        .flat_map(|entry| &entry.snapshots)
+        .filter_map(|snapshot| snapshot.item.as_ref())
+    {
+        if !items.contains(item) {
+            items.push(item.clone());
+        }
+    }
+    let mut before = Vec::new();
+    for item in &items {
+        before.push(read_state(&item.project_path, &item.folder, &item.id).await);
+    }
+    let undone = plan.apply().await?;
+
+    for (item, old) in items.iter().zip(before) {
+        let current = read_state(&item.project_path, &item.folder, &item.id).await;
+        refresh_item(&item.project_path, &item.folder, &item.id).await;
+        let changes = diff_fields(
+            old.as_ref().map(item_fields).as_ref(),
+            current.as_ref().map(item_fields).as_ref(),
+        );
+        journal_item(
+            &item.project_path,
+            Operation::Undo,
+            &item.folder,
+            &item.id,
+            changes,
+        )
+        .await;
+        let kind = match (&old, &current) {
+            (None, Some(_)) => ChangeKind::Created,
+            (Some(_), None) => ChangeKind::Deleted,
+            (Some(_), Some(_)) | (None, None) => ChangeKind::Updated,
+        };
+        publish(ChangeEvent::item(
+            kind,
+            &item.project_path,
+            &item.folder,
+            &item.id,
+        ));
+    }
+    let mut projects: Vec<PathBuf> = vec![project_path.to_path_buf()];
+    for item in &items {
+        if !projects.contains(&item.project_path) {
+            projects.push(item.project_path.clone());
+        }
+    }
+    for project in &projects {
+        if let Err(e) = update_project_manifest(project).await {
+            warn!(project = %project.display(), error = %e, "Failed to update manifest after undo");
+        }
+    }
+    Ok(undone)
+}
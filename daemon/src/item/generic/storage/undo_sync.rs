@@ -0,0 +1,40 @@
+//! Snapshots generic storage writes so they can be undone.
+//!
+//! Like the journal, snapshots never fail the write itself; a write whose
+//! snapshot failed just cannot be undone. See [`crate::undo::begin`].
+use crate::link::list_all_links;
+use crate::undo::Target;
+use mdstore::TypeConfig;
+use std::path::Path;
+use tracing::warn;
+
+/// What a write to one item can change: its file and, for types with
+/// assets, its asset directory.
+#[must_use]
+pub fn item_targets(
+    project_path: &Path,
+    folder: &str,
+    config: &TypeConfig,
+    id: &str,
+) -> Vec<Target> {
+    let mut targets = vec![Target::item_file(project_path, folder, id)];
+    if config.features.assets {
+        targets.push(Target::asset_dir(project_path, folder, id));
+    }
+    targets
+}
+
+/// The link files that reference an entity, which a hard delete removes.
+pub async fn entity_link_targets(project_path: &Path, id: &str) -> Vec<Target> {
+    match list_all_links(project_path).await {
+        Ok(links) => links
+            .iter()
+            .filter(|link| link.source_id == id || link.target_id == id)
+            .map(|link| Target::link_file(project_path, &link.id))
+            .collect(),
+        Err(e) => {
+            warn!(id = %id, error = %e, "Failed to list links to snapshot for undo");
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use git2::{Config, Repository};
+use std::path::Path;
+
+/// Recorded when no identity can be found.
+const UNKNOWN_ACTOR: &str = "unknown";
+
+/// Who is making changes to a project.
+///
+/// The daemon runs as the user driving it, so the actor is their git
+/// identity (`Name <email>`) as seen from the project, falling back to the
+/// global git config and then to the OS user name. Reading git config
+/// blocks, so it runs on a blocking thread.
+pub async fn resolve_actor(project_path: &Path) -> String {
+    let path = project_path.to_path_buf();
+    tokio::task::spawn_blocking(move || git_actor(&path))
+        .await
+        .unwrap_or_else(|_e| UNKNOWN_ACTOR.to_string())
+}
+
+fn git_actor(project_path: &Path) -> String {
+    let config = Repository::discover(project_path)
+        .and_then(|repo| repo.config())
+        .or_else(|_e| Config::open_default());
+    config
+        .ok()
+        .and_then(|c| git_identity(&c))
+        .or_else(os_user)
+        .unwrap_or_else(|| UNKNOWN_ACTOR.to_string())
+}
+
+fn git_identity(config: &Config) -> Option<String> {
+    let name = config
+        .get_string("user.name")
+        .ok()
+        .filter(|n| !n.is_empty());
+    let email = config
+        .get_string("user.email")
+        .ok()
+        .filter(|e| !e.is_empty());
+    match (name, email) {
+        (Some(n), Some(e)) => Some(format!("{n} <{e}>")),
+        (Some(only), None) | (None, Some(only)) => Some(only),
+        (None, None) => None,
+    }
+}
+
+fn os_user() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_e| std::env::var("USERNAME"))
+        .ok()
+        .filter(|u| !u.is_empty())
+}
@@ -0,0 +1,86 @@
+use super::types::{FieldChange, Operation};
+use crate::query::field_value;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Journaled fields of a record, by name.
+pub type Fields = BTreeMap<String, Value>;
+
+/// Built-in item fields recorded in the journal. `createdAt` and
+/// `updatedAt` change on every write and are left out.
+const ITEM_FIELDS: &[&str] = &[
+    "title",
+    "body",
+    "status",
+    "priority",
+    "displayNumber",
+    "tags",
+    "deletedAt",
+];
+
+/// The journaled fields of an item; custom fields are `customFields.<name>`.
+/// An empty title or body counts as absent.
+#[must_use]
+pub fn item_fields(item: &mdstore::Item) -> Fields {
+    let mut fields: Fields = ITEM_FIELDS
+        .iter()
+        .filter_map(|name| field_value(item, name).map(|v| ((*name).to_string(), v)))
+        .filter(|(_, v)| v.as_str() != Some(""))
+        .collect();
+    for (name, value) in &item.frontmatter.custom_fields {
+        if !value.is_null() {
+            fields.insert(format!("customFields.{name}"), value.clone());
+        }
+    }
+    fields
+}
+
+/// The top-level fields of a serialized record (a user, a link), leaving
+/// out `createdAt` and `updatedAt`.
+#[must_use]
+pub fn object_fields(value: &Value) -> Fields {
+    value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(name, v)| !v.is_null() && !matches!(name.as_str(), "createdAt" | "updatedAt"))
+        .map(|(name, v)| (name.clone(), v.clone()))
+        .collect()
+}
+
+/// Field-level changes between two states, in field order. A missing state
+/// means the record did not exist.
+#[must_use]
+pub fn diff_fields(before: Option<&Fields>, after: Option<&Fields>) -> Vec<FieldChange> {
+    let empty = Fields::new();
+    let old = before.unwrap_or(&empty);
+    let current = after.unwrap_or(&empty);
+    let mut names: Vec<&String> = old.keys().chain(current.keys()).collect();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .filter(|name| old.get(*name) != current.get(*name))
+        .map(|name| FieldChange {
+            field: name.clone(),
+            before: old.get(name).cloned(),
+            after: current.get(name).cloned(),
+        })
+        .collect()
+}
+
+/// Name a change from the states around it: creation, hard deletion, a
+/// soft delete or restore (`deletedAt` set or cleared), or an update.
+#[must_use]
+pub fn operation_for(before: Option<&Fields>, after: Option<&Fields>) -> Operation {
+    let deleted = |fields: &Fields| fields.contains_key("deletedAt");
+    match (before, after) {
+        (None, _) => Operation::Create,
+        (Some(_), None) => Operation::Delete,
+        (Some(old), Some(current)) => match (deleted(old), deleted(current)) {
+            (false, true) => Operation::SoftDelete,
+            (true, false) => Operation::Restore,
+            (false, false) | (true, true) => Operation::Update,
+        },
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn item(status: &str, priority: u32) -> mdstore::Item {
+    let mut custom_fields = HashMap::new();
+    custom_fields.insert("team".to_string(), json!("Platform"));
+    custom_fields.insert("unset".to_string(), serde_json::Value::Null);
+    mdstore::Item {
+        id: "a".to_string(),
+        title: "Login bug".to_string(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: Some(1),
+            status: Some(status.to_string()),
+            priority: Some(priority),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-02T00:00:00Z".to_string(),
+            deleted_at: None,
+            tags: None,
+            custom_fields,
+        },
+        comment: None,
+    }
+}
+
+#[test]
+fn test_item_fields_skip_timestamps_and_nulls() {
+    let fields = item_fields(&item("open", 2));
+    assert_eq!(fields.get("status"), Some(&json!("open")));
+    assert_eq!(fields.get("priority"), Some(&json!(2u32)));
+    assert_eq!(fields.get("customFields.team"), Some(&json!("Platform")));
+    assert!(!fields.contains_key("customFields.unset"));
+    assert!(!fields.contains_key("updatedAt"));
+    assert!(!fields.contains_key("body"));
+}
+
+#[test]
+fn test_diff_reports_only_changed_fields() {
+    let before = item_fields(&item("open", 2));
+    let after = item_fields(&item("closed", 2));
+    let changes = diff_fields(Some(&before), Some(&after));
+    assert_eq!(
+        changes,
+        vec![FieldChange {
+            field: "status".to_string(),
+            before: Some(json!("open")),
+            after: Some(json!("closed")),
+        }]
+    );
+}
+
+#[test]
+fn test_diff_of_creation_lists_every_field() {
+    let after = item_fields(&item("open", 2));
+    let changes = diff_fields(None, Some(&after));
+    assert_eq!(changes.len(), after.len());
+    assert!(changes
+        .iter()
+        .all(|c| c.before.is_none() && c.after.is_some()));
+}
+
+#[test]
+fn test_object_fields_skip_timestamps() {
+    let user = json!({
+        "id": "alice",
+        "name": "Alice",
+        "createdAt": "2024-01-01T00:00:00Z",
+        "updatedAt": "2024-01-01T00:00:00Z",
+        "email": null
+    });
+    let fields = object_fields(&user);
+    assert_eq!(fields.keys().collect::<Vec<_>>(), vec!["id", "name"]);
+}
+
+#[test]
+fn test_operation_for_states() {
+    let live = object_fields(&json!({"id": "a"}));
+    let deleted = object_fields(&json!({"id": "a", "deletedAt": "2024-01-01T00:00:00Z"}));
+    assert_eq!(operation_for(None, Some(&live)), Operation::Create);
+    assert_eq!(operation_for(Some(&live), None), Operation::Delete);
+    assert_eq!(
+        operation_for(Some(&live), Some(&deleted)),
+        Operation::SoftDelete
+    );
+    assert_eq!(
+        operation_for(Some(&deleted), Some(&live)),
+        Operation::Restore
+    );
+    assert_eq!(operation_for(Some(&live), Some(&live)), Operation::Update);
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+/// Errors raised while reading or appending to a project's journal.
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
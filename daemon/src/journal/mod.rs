@@ -0,0 +1,27 @@
+//! Append-only mutation journal.
+//!
+//! Every successful change to an item, link or user is appended to
+//! `.centy/journal.jsonl` with the actor, the time, the operation and a
+//! field-level diff, so it can be audited without reading git history.
+//! The `ListJournalEntries` RPC queries it by item, actor or time range.
+mod actor;
+mod diff;
+mod error;
+mod query;
+mod store;
+mod types;
+pub use actor::resolve_actor;
+pub use diff::{diff_fields, item_fields, object_fields, operation_for, Fields};
+pub use error::JournalError;
+pub use query::{select_entries, JournalFilter};
+pub use store::{append_entry, journal_path, on_record, read_entries, record, RecordListener};
+pub use types::{Change, FieldChange, JournalEntity, JournalEntry, Operation};
+#[cfg(test)]
+#[path = "diff_tests.rs"]
+mod diff_tests;
+#[cfg(test)]
+#[path = "query_tests.rs"]
+mod query_tests;
+#[cfg(test)]
+#[path = "store_tests.rs"]
+mod store_tests;
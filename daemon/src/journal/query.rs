@@ -0,0 +1,59 @@
+use super::types::JournalEntry;
+use chrono::{DateTime, Utc};
+
+/// Which journal entries to return. Empty fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct JournalFilter {
+    /// Item type folder.
+    pub item_type: String,
+    /// Item, link or user id.
+    pub item_id: String,
+    /// Case-insensitive substring of the actor, so a name or an email works.
+    pub actor: String,
+    /// Inclusive lower bound.
+    pub since: Option<DateTime<Utc>>,
+    /// Exclusive upper bound.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl JournalFilter {
+    #[must_use]
+    pub fn matches(&self, entry: &JournalEntry) -> bool {
+        (self.item_type.is_empty() || entry.item_type == self.item_type)
+            && (self.item_id.is_empty() || entry.item_id == self.item_id)
+            && (self.actor.is_empty()
+                || entry
+                    .actor
+                    .to_lowercase()
+                    .contains(&self.actor.to_lowercase()))
+            && self.in_range(&entry.timestamp)
+    }
+
+    fn in_range(&self, timestamp: &str) -> bool {
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let Ok(at) = DateTime::parse_from_rfc3339(timestamp) else {
+            return false;
+        };
+        self.since.is_none_or(|since| at >= since) && self.until.is_none_or(|until| at < until)
+    }
+}
+
+/// The entries matching `filter`, newest first, at most `limit` of them
+/// (`0` for no limit), together with the number that matched.
+#[must_use]
+pub fn select_entries(
+    entries: Vec<JournalEntry>,
+    filter: &JournalFilter,
+    limit: usize,
+) -> (Vec<JournalEntry>, usize) {
+    let mut matched: Vec<JournalEntry> =
+        entries.into_iter().filter(|e| filter.matches(e)).collect();
+    matched.reverse();
+    let total = matched.len();
+    if limit > 0 {
+        matched.truncate(limit);
+    }
+    (matched, total)
+}
@@ -0,0 +1,69 @@
+use super::*;
+use chrono::TimeZone as _;
+
+fn entry(timestamp: &str, actor: &str, item_id: &str) -> JournalEntry {
+    JournalEntry {
+        timestamp: timestamp.to_string(),
+        actor: actor.to_string(),
+        operation: Operation::Update,
+        entity: JournalEntity::Item,
+        item_type: "issues".to_string(),
+        item_id: item_id.to_string(),
+        changes: Vec::new(),
+    }
+}
+
+fn entries() -> Vec<JournalEntry> {
+    vec![
+        entry(
+            "2024-01-01T10:00:00+00:00",
+            "Alice <alice@example.com>",
+            "a",
+        ),
+        entry("2024-01-02T10:00:00+00:00", "Bob <bob@example.com>", "b"),
+        entry(
+            "2024-01-03T10:00:00+00:00",
+            "Alice <alice@example.com>",
+            "b",
+        ),
+    ]
+}
+
+#[test]
+fn test_select_returns_newest_first_with_total() {
+    let (selected, total) = select_entries(entries(), &JournalFilter::default(), 2);
+    assert_eq!(total, 3);
+    let times: Vec<_> = selected.iter().map(|e| e.timestamp.as_str()).collect();
+    assert_eq!(
+        times,
+        vec!["2024-01-03T10:00:00+00:00", "2024-01-02T10:00:00+00:00"]
+    );
+}
+
+#[test]
+fn test_filter_by_item_and_actor() {
+    let filter = JournalFilter {
+        item_id: "b".to_string(),
+        actor: "ALICE@".to_string(),
+        ..Default::default()
+    };
+    let (selected, total) = select_entries(entries(), &filter, 0);
+    assert_eq!(total, 1);
+    assert_eq!(
+        selected.first().unwrap().timestamp,
+        "2024-01-03T10:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_filter_by_time_range() {
+    let filter = JournalFilter {
+        since: Some(chrono::Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()),
+        until: Some(chrono::Utc.with_ymd_and_hms(2024, 1, 3, 10, 0, 0).unwrap()),
+        ..Default::default()
+    };
+    let (selected, _) = select_entries(entries(), &filter, 0);
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected.first().unwrap().item_id, "b");
+    assert_eq!(selected.first().unwrap().actor, "Bob <bob@example.com>");
+}
@@ -0,0 +1,101 @@
+use super::actor::resolve_actor;
+use super::error::JournalError;
+use super::types::{Change, JournalEntry, Operation};
+use crate::utils::{get_centy_path, now_iso};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt as _;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Journal file name. It is tracked in git so the history travels with the
+/// project.
+const JOURNAL_FILE: &str = "journal.jsonl";
+
+/// Keeps concurrent appends from interleaving their lines.
+static JOURNAL_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+fn get_lock() -> &'static Mutex<()> {
+    JOURNAL_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// Called with each entry [`record`] writes.
+pub type RecordListener = fn(&Path, &JournalEntry);
+
+static RECORD_LISTENER: OnceLock<RecordListener> = OnceLock::new();
+
+/// Have `listener` called with every entry recorded from now on, e.g. to
+/// auto-commit it. Only the first listener registered is kept.
+pub fn on_record(listener: RecordListener) {
+    // Setting only fails when a listener is already registered.
+    let _res = RECORD_LISTENER.set(listener);
+}
+
+#[must_use]
+pub fn journal_path(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join(JOURNAL_FILE)
+}
+
+/// Append one entry as a JSON line.
+pub async fn append_entry(project_path: &Path, entry: &JournalEntry) -> Result<(), JournalError> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    let _guard = get_lock().lock().await;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(journal_path(project_path))
+        .await?;
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await?;
+    Ok(())
+}
+
+/// Read every entry, oldest first. Lines that do not parse (for example a
+/// line cut short by a crash) are skipped with a warning.
+pub async fn read_entries(project_path: &Path) -> Result<Vec<JournalEntry>, JournalError> {
+    let path = journal_path(project_path);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).await?;
+    let entries = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Skipping malformed journal line");
+                None
+            }
+        })
+        .collect();
+    Ok(entries)
+}
+
+/// Journal a mutation that has just succeeded, and pass the entry to the
+/// [record listener](on_record).
+///
+/// Updates that changed no journaled field are not recorded. Journal
+/// failures never fail the mutation itself; they are logged.
+pub async fn record(project_path: &Path, change: Change) {
+    if change.operation == Operation::Update && change.changes.is_empty() {
+        return;
+    }
+    let entry = JournalEntry {
+        timestamp: now_iso(),
+        actor: resolve_actor(project_path).await,
+        operation: change.operation,
+        entity: change.entity,
+        item_type: change.item_type,
+        item_id: change.item_id,
+        changes: change.changes,
+    };
+    if let Err(e) = append_entry(project_path, &entry).await {
+        warn!(id = %entry.item_id, error = %e, "Failed to append to journal");
+    }
+    if let Some(listener) = RECORD_LISTENER.get() {
+        listener(project_path, &entry);
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+async fn project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    tokio::fs::create_dir_all(temp.path().join(".centy"))
+        .await
+        .unwrap();
+    temp
+}
+
+fn change(operation: Operation, changes: Vec<FieldChange>) -> Change {
+    Change {
+        operation,
+        entity: JournalEntity::Item,
+        item_type: "issues".to_string(),
+        item_id: "a".to_string(),
+        changes,
+    }
+}
+
+#[tokio::test]
+async fn test_record_appends_lines_in_order() {
+    let temp = project().await;
+    let status = FieldChange {
+        field: "status".to_string(),
+        before: Some(serde_json::json!("open")),
+        after: Some(serde_json::json!("closed")),
+    };
+    record(temp.path(), change(Operation::Create, Vec::new())).await;
+    record(temp.path(), change(Operation::Update, vec![status.clone()])).await;
+
+    let entries = read_entries(temp.path()).await.unwrap();
+    let operations: Vec<_> = entries.iter().map(|e| e.operation).collect();
+    assert_eq!(operations, vec![Operation::Create, Operation::Update]);
+    let last = entries.last().unwrap();
+    assert_eq!(last.changes, vec![status]);
+    assert!(!last.actor.is_empty());
+    assert!(chrono::DateTime::parse_from_rfc3339(&last.timestamp).is_ok());
+}
+
+#[tokio::test]
+async fn test_empty_update_is_not_recorded() {
+    let temp = project().await;
+    record(temp.path(), change(Operation::Update, Vec::new())).await;
+    assert!(read_entries(temp.path()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_read_skips_malformed_lines() {
+    let temp = project().await;
+    record(temp.path(), change(Operation::Delete, Vec::new())).await;
+    let path = journal_path(temp.path());
+    let mut content = tokio::fs::read_to_string(&path).await.unwrap();
+    content.push_str("{\"timestamp\": \"cut sho");
+    tokio::fs::write(&path, content).await.unwrap();
+
+    let entries = read_entries(temp.path()).await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries.first().unwrap().operation, Operation::Delete);
+}
+
+#[test]
+fn test_entry_serializes_camel_case() {
+    let entry = JournalEntry {
+        timestamp: "2024-01-01T00:00:00+00:00".to_string(),
+        actor: "Alice".to_string(),
+        operation: Operation::SoftDelete,
+        entity: JournalEntity::Item,
+        item_type: "issues".to_string(),
+        item_id: "a".to_string(),
+        changes: vec![FieldChange {
+            field: "deletedAt".to_string(),
+            before: None,
+            after: Some(serde_json::json!("2024-01-01T00:00:00+00:00")),
+        }],
+    };
+    let json = serde_json::to_value(&entry).unwrap();
+    assert_eq!(json["operation"], "soft_delete");
+    assert_eq!(json["itemType"], "issues");
+    assert!(json["changes"][0].get("before").is_none());
+}
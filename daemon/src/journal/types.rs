@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// What a journaled mutation did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Create,
+    Update,
+    /// Hard delete.
+    Delete,
+    SoftDelete,
+    Restore,
+    /// Moved to another item type or project.
+    Move,
+    /// Moved into the `archived` item type.
+    Archive,
+    /// Moved out of the `archived` item type.
+    Unarchive,
+    Duplicate,
+    /// A slug-based item got a new id.
+    Rename,
+    /// Earlier operations were reverted with `Undo`.
+    Undo,
+}
+
+impl Operation {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::SoftDelete => "soft_delete",
+            Self::Restore => "restore",
+            Self::Move => "move",
+            Self::Archive => "archive",
+            Self::Unarchive => "unarchive",
+            Self::Duplicate => "duplicate",
+            Self::Rename => "rename",
+            Self::Undo => "undo",
+        }
+    }
+}
+
+/// The kind of record a journal entry is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalEntity {
+    Item,
+    Link,
+    User,
+}
+
+impl JournalEntity {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Item => "item",
+            Self::Link => "link",
+            Self::User => "user",
+        }
+    }
+}
+
+/// One field's value before and after a mutation; `None` when absent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// A mutation about to be journaled; the actor and time are added on write.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub operation: Operation,
+    pub entity: JournalEntity,
+    /// Item type folder for items; empty for links and users.
+    pub item_type: String,
+    /// Item, link or user id.
+    pub item_id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// One line of `.centy/journal.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// RFC 3339 time of the mutation.
+    pub timestamp: String,
+    pub actor: String,
+    pub operation: Operation,
+    pub entity: JournalEntity,
+    #[serde(default)]
+    pub item_type: String,
+    pub item_id: String,
+    #[serde(default)]
+    pub changes: Vec<FieldChange>,
+}
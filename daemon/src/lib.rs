@@ -19,19 +19,27 @@
 
 pub mod common;
 pub mod config;
+pub mod history;
 pub mod hooks;
 pub mod item;
+pub mod journal;
 pub mod link;
 pub mod logging;
 pub mod manifest;
 pub mod metrics;
+pub mod query;
 pub mod reconciliation;
 pub mod registry;
+pub mod search;
 pub mod server;
+pub mod sync;
 pub mod template;
+pub mod undo;
 pub mod user;
 pub mod user_config;
 pub mod utils;
+pub mod view;
+pub mod watch;
 pub mod workspace;
 
 // Re-export commonly used types
@@ -0,0 +1,73 @@
+use super::crud_types::LinkError;
+use super::storage::list_all_link_records;
+use super::types::{LinkRecord, TargetType};
+use crate::config::item_type_config::ItemTypeRegistry;
+use crate::item::generic::storage::generic_get;
+use crate::query::{resolve_links, uses_links, LinkEdge, LinkIndex, Query};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Status that marks a blocking item as resolved.
+const CLOSED_STATUS: &str = "closed";
+
+/// Resolve the link conditions of `query` (`$linked`, `hasLinks`,
+/// `$blocked`) against the project's links. Queries without link
+/// conditions are returned unchanged without reading the links folder.
+pub async fn resolve_link_filters(project_path: &Path, query: Query) -> Result<Query, LinkError> {
+    if !uses_links(&query) {
+        return Ok(query);
+    }
+    let index = load_link_index(project_path).await?;
+    Ok(resolve_links(&query, &index))
+}
+
+/// Build the [`LinkIndex`] of a project, looking up the status of every
+/// blocking item.
+///
+/// A blocker is open unless it is closed, soft-deleted or missing.
+pub async fn load_link_index(project_path: &Path) -> Result<LinkIndex, LinkError> {
+    let records = list_all_link_records(project_path).await?;
+    let registry = ItemTypeRegistry::build(project_path).await.ok();
+    let mut open_blockers = BTreeSet::new();
+    let mut edges = Vec::with_capacity(records.len());
+    for record in &records {
+        let edge = LinkEdge {
+            source_id: record.source_id.clone(),
+            target_id: record.target_id.clone(),
+            link_type: record.link_type.clone(),
+        };
+        if let Some((_, blocker)) = edge.blocking_pair() {
+            if !open_blockers.contains(blocker) {
+                let folder = folder_for(registry.as_ref(), blocker_type(record, blocker));
+                if is_open(project_path, &folder, blocker).await {
+                    open_blockers.insert(blocker.to_string());
+                }
+            }
+        }
+        edges.push(edge);
+    }
+    Ok(LinkIndex::new(edges, open_blockers))
+}
+
+fn blocker_type<'record>(record: &'record LinkRecord, blocker: &str) -> &'record TargetType {
+    if record.source_id == blocker {
+        &record.source_type
+    } else {
+        &record.target_type
+    }
+}
+
+fn folder_for(registry: Option<&ItemTypeRegistry>, entity_type: &TargetType) -> String {
+    registry
+        .and_then(|r| r.resolve(entity_type.as_str()).map(|(f, _)| f.clone()))
+        .unwrap_or_else(|| entity_type.folder_name())
+}
+
+async fn is_open(project_path: &Path, folder: &str, id: &str) -> bool {
+    generic_get(project_path, folder, id)
+        .await
+        .is_ok_and(|item| {
+            item.frontmatter.deleted_at.is_none()
+                && item.frontmatter.status.as_deref() != Some(CLOSED_STATUS)
+        })
+}
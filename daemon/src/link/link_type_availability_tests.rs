@@ -64,3 +64,12 @@ fn test_link_error_from_io() {
     let err = LinkError::from(io_err);
     assert!(matches!(err, LinkError::IoError(_)));
 }
+#[test]
+fn test_inverse_link_type() {
+    use crate::link::inverse_link_type;
+    assert_eq!(inverse_link_type("blocks"), Some("blocked-by"));
+    assert_eq!(inverse_link_type("blocked-by"), Some("blocks"));
+    assert_eq!(inverse_link_type("child-of"), Some("parent-of"));
+    assert_eq!(inverse_link_type("duplicates"), Some("duplicated-by"));
+    assert_eq!(inverse_link_type("depends-on"), None);
+}
@@ -18,3 +18,13 @@ pub fn is_valid_link_type(link_type: &str, custom_types: &[CustomLinkTypeDefinit
     }
     custom_types.iter().any(|c| c.name == link_type)
 }
+/// The built-in link type that describes the same link from the target's
+/// perspective (`blocks` ↔ `blocked-by`). Custom link types have no inverse.
+#[must_use]
+pub fn inverse_link_type(link_type: &str) -> Option<&'static str> {
+    BUILTIN_LINK_TYPES.chunks(2).find_map(|pair| match pair {
+        [forward, backward] if *forward == link_type => Some(*backward),
+        [forward, backward] if *backward == link_type => Some(*forward),
+        _ => None,
+    })
+}
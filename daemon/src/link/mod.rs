@@ -3,6 +3,7 @@ mod crud_fns;
 mod crud_helpers;
 mod crud_read;
 mod crud_types;
+mod filters;
 mod link_types;
 mod storage;
 mod types;
@@ -12,7 +13,8 @@ pub use crud::{
     get_available_link_types, list_all_links, list_links, update_link, CreateLinkOptions,
     DeleteLinkOptions, LinkError, UpdateLinkOptions,
 };
-pub use link_types::{is_valid_link_type, BUILTIN_LINK_TYPES};
+pub use filters::{load_link_index, resolve_link_filters};
+pub use link_types::{inverse_link_type, is_valid_link_type, BUILTIN_LINK_TYPES};
 pub use types::{CustomLinkTypeDefinition, LinkDirection, LinkRecord, LinkView, TargetType};
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 use super::super::types::{LinkRecord, TargetType};
 use super::serialization::{create_link_fields, item_to_link_record, update_link_fields};
 use super::validation::{validate_link_ids, validate_link_type};
+use crate::journal::{self, diff_fields, operation_for, Change, Fields, JournalEntity};
 use crate::utils::get_centy_path;
+use crate::watch::{publish, ChangeEvent, ChangeKind, EntityKind};
 use mdstore::{CreateOptions, Filters, IdStrategy, TypeConfig, TypeFeatures};
 use std::path::Path;
 
@@ -19,6 +21,41 @@ fn link_type_config() -> TypeConfig {
     }
 }
 
+fn publish_link(kind: ChangeKind, project_path: &Path, link_id: &str) {
+    publish(ChangeEvent::new(
+        kind,
+        EntityKind::Link,
+        project_path,
+        "",
+        link_id,
+    ));
+}
+
+/// The journaled fields of a link: its endpoints and type.
+fn link_state(item: &mdstore::Item) -> Fields {
+    item.frontmatter
+        .custom_fields
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
+
+async fn journal_link(
+    project_path: &Path,
+    link_id: &str,
+    before: Option<&Fields>,
+    after: Option<&Fields>,
+) {
+    let change = Change {
+        operation: operation_for(before, after),
+        entity: JournalEntity::Link,
+        item_type: String::new(),
+        item_id: link_id.to_string(),
+        changes: diff_fields(before, after),
+    };
+    journal::record(project_path, change).await;
+}
+
 fn links_dir(project_path: &Path) -> std::path::PathBuf {
     get_centy_path(project_path).join(LINKS_FOLDER)
 }
@@ -53,6 +90,8 @@ pub async fn create_link_file(
         comment: None,
     };
     let item = mdstore::create(&dir, &config, options).await?;
+    journal_link(project_path, &item.id, None, Some(&link_state(&item))).await;
+    publish_link(ChangeKind::Created, project_path, &item.id);
     item_to_link_record(item).ok_or_else(|| {
         mdstore::StoreError::custom("Created link item is missing required custom fields")
     })
@@ -76,7 +115,19 @@ pub async fn update_link_file(
         custom_fields: update_link_fields(link_type),
         comment: None,
     };
+    let before = mdstore::get(&dir, link_id)
+        .await
+        .ok()
+        .map(|i| link_state(&i));
     let item = mdstore::update(&dir, &config, link_id, options).await?;
+    journal_link(
+        project_path,
+        link_id,
+        before.as_ref(),
+        Some(&link_state(&item)),
+    )
+    .await;
+    publish_link(ChangeKind::Updated, project_path, link_id);
     item_to_link_record(item).ok_or_else(|| {
         mdstore::StoreError::custom("Updated link item is missing required custom fields")
     })
@@ -88,7 +139,14 @@ pub async fn delete_link_file(
     link_id: &str,
 ) -> Result<(), mdstore::StoreError> {
     let dir = links_dir(project_path);
-    mdstore::delete(&dir, link_id, true).await
+    let before = mdstore::get(&dir, link_id)
+        .await
+        .ok()
+        .map(|i| link_state(&i));
+    mdstore::delete(&dir, link_id, true).await?;
+    journal_link(project_path, link_id, before.as_ref(), None).await;
+    publish_link(ChangeKind::Deleted, project_path, link_id);
+    Ok(())
 }
 
 /// Load all link records from `.centy/links/`, skipping any malformed files.
@@ -25,30 +25,45 @@ mod cleanup;
 mod common;
 mod config;
 mod cors;
+mod history;
 mod hooks;
 mod item;
+mod journal;
 mod link;
 mod logging;
 mod manifest;
 mod metrics;
+mod query;
 mod reconciliation;
 mod registry;
 mod run;
+mod search;
 mod server;
+mod sync;
 mod template;
+mod undo;
 mod user;
 mod user_config;
 mod utils;
+mod view;
+mod watch;
 mod workspace;
 
 use clap::Parser as _;
 use color_eyre::eyre::Result;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     color_eyre::install()?;
-    tokio::runtime::Builder::new_multi_thread()
+    let mut args = app::Args::parse();
+    let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
-        .map_err(color_eyre::eyre::Report::from)?
-        .block_on(run::run(app::Args::parse()))
+        .map_err(color_eyre::eyre::Report::from)?;
+    match args.command.take() {
+        Some(app::Command::MergeDriver(driver)) => runtime.block_on(run::merge_driver(driver)),
+        Some(app::Command::PostMerge(args)) => runtime.block_on(run::post_merge(args)),
+        Some(app::Command::Hooks(hooks)) => runtime.block_on(run::hooks(hooks)),
+        None => runtime.block_on(run::run(args)).map(|()| ExitCode::SUCCESS),
+    }
 }
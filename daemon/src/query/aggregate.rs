@@ -0,0 +1,125 @@
+use super::compare::parse_timestamp;
+use super::error::QueryError;
+use super::field::field_value;
+use chrono::Datelike as _;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// What `AggregateItems` groups matching items by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupBy {
+    /// The value of a field, as accepted by [`field_value`]. Array fields
+    /// such as `tags` count an item once under each element.
+    Field(String),
+    /// The ISO week (`2026-W07`) of a timestamp field, written `createdAt:week`.
+    Week(String),
+}
+
+/// Parse a `group_by` spec such as `status`, `customFields.assignee` or
+/// `updatedAt:week`.
+pub fn parse_group_by(spec: &str) -> Result<GroupBy, QueryError> {
+    let trimmed = spec.trim();
+    if trimmed.is_empty() {
+        return Err(QueryError::new(
+            "group_by",
+            "a field to group by is required",
+        ));
+    }
+    match trimmed.split_once(':') {
+        None => Ok(GroupBy::Field(trimmed.to_string())),
+        Some(("", _)) => Err(QueryError::new("group_by", "missing field name before ':'")),
+        Some((field, "week")) => Ok(GroupBy::Week(field.to_string())),
+        Some((_, bucket)) => Err(QueryError::new(
+            "group_by",
+            format!("unknown bucket '{bucket}' (expected 'week')"),
+        )),
+    }
+}
+
+/// One group of an aggregation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// The grouped value; empty for items that have no value.
+    pub key: String,
+    pub count: u64,
+}
+
+/// Running per-key counts, fed one item at a time so results from several
+/// projects can be combined.
+#[derive(Debug, Clone, Default)]
+pub struct GroupCounts {
+    counts: BTreeMap<String, u64>,
+    total: u64,
+}
+
+impl GroupCounts {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count `item` under each of its keys.
+    pub fn add(&mut self, item: &mdstore::Item, group_by: &GroupBy) {
+        self.total = self.total.saturating_add(1);
+        for key in group_keys(item, group_by) {
+            let count = self.counts.entry(key).or_insert(0);
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Number of items added, regardless of how many groups each fell into.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Groups ordered by count (largest first), then by key.
+    #[must_use]
+    pub fn into_groups(self) -> Vec<Group> {
+        let mut groups: Vec<Group> = self
+            .counts
+            .into_iter()
+            .map(|(key, count)| Group { key, count })
+            .collect();
+        groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        groups
+    }
+}
+
+/// The keys an item is counted under. An item with no value (or an empty
+/// array) falls into the single empty key.
+#[must_use]
+pub fn group_keys(item: &mdstore::Item, group_by: &GroupBy) -> Vec<String> {
+    let keys: Vec<String> = match group_by {
+        GroupBy::Field(field) => match field_value(item, field) {
+            Some(Value::Array(values)) => values.iter().filter_map(key_string).collect(),
+            Some(value) => key_string(&value).into_iter().collect(),
+            None => Vec::new(),
+        },
+        GroupBy::Week(field) => field_value(item, field)
+            .as_ref()
+            .and_then(Value::as_str)
+            .and_then(parse_timestamp)
+            .map(|ts| {
+                let week = ts.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            })
+            .into_iter()
+            .collect(),
+    };
+    if keys.is_empty() {
+        vec![String::new()]
+    } else {
+        keys
+    }
+}
+
+fn key_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => {
+            Some(value.to_string())
+        }
+    }
+}
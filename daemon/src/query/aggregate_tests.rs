@@ -0,0 +1,121 @@
+use super::*;
+use std::collections::HashMap;
+
+fn item(status: Option<&str>, tags: Option<&[&str]>, created_at: &str) -> mdstore::Item {
+    let mut custom_fields = HashMap::new();
+    custom_fields.insert("assignee".to_string(), serde_json::json!("alice"));
+    mdstore::Item {
+        id: created_at.to_string(),
+        title: String::new(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: status.map(str::to_string),
+            priority: Some(2),
+            created_at: created_at.to_string(),
+            updated_at: String::new(),
+            deleted_at: None,
+            tags: tags.map(|t| t.iter().map(|s| (*s).to_string()).collect()),
+            custom_fields,
+        },
+        comment: None,
+    }
+}
+
+fn counts(items: &[mdstore::Item], spec: &str) -> Vec<(String, u64)> {
+    let group_by = parse_group_by(spec).unwrap();
+    let mut counts = GroupCounts::new();
+    for item in items {
+        counts.add(item, &group_by);
+    }
+    counts
+        .into_groups()
+        .into_iter()
+        .map(|g| (g.key, g.count))
+        .collect()
+}
+
+#[test]
+fn test_parse_group_by() {
+    assert_eq!(
+        parse_group_by("status").unwrap(),
+        GroupBy::Field("status".to_string())
+    );
+    assert_eq!(
+        parse_group_by(" createdAt:week ").unwrap(),
+        GroupBy::Week("createdAt".to_string())
+    );
+    assert!(parse_group_by("").is_err());
+    assert!(parse_group_by("createdAt:month").is_err());
+    assert!(parse_group_by(":week").is_err());
+}
+
+#[test]
+fn test_group_by_status_orders_by_count() {
+    let items = vec![
+        item(Some("open"), None, "2024-01-01T00:00:00Z"),
+        item(Some("closed"), None, "2024-01-02T00:00:00Z"),
+        item(Some("open"), None, "2024-01-03T00:00:00Z"),
+        item(None, None, "2024-01-04T00:00:00Z"),
+    ];
+    assert_eq!(
+        counts(&items, "status"),
+        vec![
+            ("open".to_string(), 2),
+            (String::new(), 1),
+            ("closed".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_group_by_tags_counts_each_element() {
+    let items = vec![
+        item(None, Some(&["ui", "bug"]), "2024-01-01T00:00:00Z"),
+        item(None, Some(&["bug"]), "2024-01-02T00:00:00Z"),
+        item(None, Some(&[]), "2024-01-03T00:00:00Z"),
+    ];
+    assert_eq!(
+        counts(&items, "tags"),
+        vec![
+            ("bug".to_string(), 2),
+            (String::new(), 1),
+            ("ui".to_string(), 1),
+        ]
+    );
+    let mut all = GroupCounts::new();
+    for i in &items {
+        all.add(i, &GroupBy::Field("tags".to_string()));
+    }
+    assert_eq!(all.total(), 3);
+}
+
+#[test]
+fn test_group_by_custom_field_and_priority() {
+    let items = vec![item(None, None, "2024-01-01T00:00:00Z")];
+    assert_eq!(
+        counts(&items, "customFields.assignee"),
+        vec![("alice".to_string(), 1)]
+    );
+    assert_eq!(counts(&items, "priority"), vec![("2".to_string(), 1)]);
+}
+
+#[test]
+fn test_group_by_week() {
+    let items = vec![
+        item(None, None, "2024-01-01T09:00:00Z"),
+        item(None, None, "2024-01-07T23:00:00Z"),
+        item(None, None, "2024-01-08T00:00:00Z"),
+        item(None, None, "2023-01-01T00:00:00Z"),
+        item(None, None, "not a date"),
+    ];
+    assert_eq!(
+        counts(&items, "createdAt:week"),
+        vec![
+            ("2024-W01".to_string(), 2),
+            (String::new(), 1),
+            ("2022-W52".to_string(), 1),
+            ("2024-W02".to_string(), 1),
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// Parse an RFC 3339 timestamp or a plain `YYYY-MM-DD` date (midnight UTC).
+#[must_use]
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()?
+        .and_hms_opt(0, 0, 0)
+        .map(|naive| naive.and_utc())
+}
+
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok()
+}
+
+/// Order two JSON scalars.
+///
+/// Numbers compare numerically (numeric strings are coerced), strings that
+/// both parse as timestamps compare chronologically, other strings compare
+/// lexically. Returns `None` when the values are not comparable.
+#[must_use]
+pub fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64()?.partial_cmp(&y.as_f64()?),
+        (Value::Number(x), Value::String(y)) => x.as_f64()?.partial_cmp(&parse_number(y)?),
+        (Value::String(x), Value::Number(y)) => parse_number(x)?.partial_cmp(&y.as_f64()?),
+        (Value::String(x), Value::String(y)) => Some(compare_strings(x, y)),
+        (Value::Bool(x), Value::Bool(y)) => Some(x.cmp(y)),
+        _ => None,
+    }
+}
+
+fn compare_strings(a: &str, b: &str) -> Ordering {
+    if let (Some(x), Some(y)) = (parse_timestamp(a), parse_timestamp(b)) {
+        return x.cmp(&y);
+    }
+    a.cmp(b)
+}
+
+/// Equality used by `$eq`/`$in`/`$ne`/`$nin`: exact for strings, numeric
+/// for numbers (so `"2"` in a custom field equals `2`).
+#[must_use]
+pub fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(x), Value::String(y)) => x == y,
+        (Value::Number(_), Value::Number(_) | Value::String(_))
+        | (Value::String(_), Value::Number(_)) => compare_values(a, b) == Some(Ordering::Equal),
+        _ => a == b,
+    }
+}
@@ -0,0 +1,71 @@
+use super::error::QueryError;
+use super::sort::{compare_positions, format_sort, sort_values, SortKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// A position in a sorted result set: the sort values and id of the last
+/// item on a page.
+///
+/// Pages continue strictly after this position, so creating or deleting
+/// items between requests neither repeats nor skips the remaining items.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    /// Canonical sort string the cursor was issued for.
+    sort: String,
+    values: Vec<Value>,
+    id: String,
+}
+
+impl Cursor {
+    /// The position of `item` under the given sort.
+    #[must_use]
+    pub fn after(item: &mdstore::Item, keys: &[SortKey], statuses: &[String]) -> Self {
+        Self {
+            sort: format_sort(keys),
+            values: sort_values(item, keys, statuses)
+                .into_iter()
+                .map(|v| v.unwrap_or(Value::Null))
+                .collect(),
+            id: item.id.clone(),
+        }
+    }
+
+    /// Encode as an opaque token.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        hex::encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    /// Decode a token produced by [`Cursor::encode`] for the same sort.
+    pub fn decode(token: &str, keys: &[SortKey]) -> Result<Self, QueryError> {
+        let bytes = hex::decode(token)
+            .map_err(|e| QueryError::new("cursor", format!("malformed cursor token: {e}")))?;
+        let cursor: Self = serde_json::from_slice(&bytes)
+            .map_err(|e| QueryError::new("cursor", format!("malformed cursor token: {e}")))?;
+        if cursor.sort != format_sort(keys) || cursor.values.len() != keys.len() {
+            return Err(QueryError::new(
+                "cursor",
+                "cursor was issued for a different sort order",
+            ));
+        }
+        Ok(cursor)
+    }
+
+    /// Whether `item` sorts strictly after this position.
+    #[must_use]
+    pub fn precedes(&self, item: &mdstore::Item, keys: &[SortKey], statuses: &[String]) -> bool {
+        let own: Vec<Option<Value>> = self
+            .values
+            .iter()
+            .map(|v| (!v.is_null()).then(|| v.clone()))
+            .collect();
+        compare_positions(
+            &own,
+            &self.id,
+            &sort_values(item, keys, statuses),
+            &item.id,
+            keys,
+        ) == Ordering::Less
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+use std::collections::HashMap;
+
+fn item(id: &str, priority: Option<u32>) -> mdstore::Item {
+    mdstore::Item {
+        id: id.to_string(),
+        title: String::new(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: None,
+            priority,
+            created_at: String::new(),
+            updated_at: String::new(),
+            deleted_at: None,
+            tags: None,
+            custom_fields: HashMap::new(),
+        },
+        comment: None,
+    }
+}
+
+#[test]
+fn test_cursor_round_trip() {
+    let keys = parse_sort("-priority");
+    let cursor = Cursor::after(&item("b", Some(2)), &keys, &[]);
+    let decoded = Cursor::decode(&cursor.encode(), &keys).unwrap();
+    assert_eq!(decoded, cursor);
+}
+
+#[test]
+fn test_precedes_follows_sort_order_and_id() {
+    let keys = parse_sort("-priority");
+    let cursor = Cursor::after(&item("b", Some(2)), &keys, &[]);
+    assert!(!cursor.precedes(&item("a", Some(3)), &keys, &[]));
+    assert!(!cursor.precedes(&item("a", Some(2)), &keys, &[]));
+    assert!(!cursor.precedes(&item("b", Some(2)), &keys, &[]));
+    assert!(cursor.precedes(&item("c", Some(2)), &keys, &[]));
+    assert!(cursor.precedes(&item("a", Some(1)), &keys, &[]));
+    assert!(cursor.precedes(&item("a", None), &keys, &[]));
+}
+
+#[test]
+fn test_cursor_at_missing_value() {
+    let keys = parse_sort("priority");
+    let cursor = Cursor::after(&item("b", None), &keys, &[]);
+    let decoded = Cursor::decode(&cursor.encode(), &keys).unwrap();
+    assert!(!decoded.precedes(&item("a", Some(9)), &keys, &[]));
+    assert!(decoded.precedes(&item("c", None), &keys, &[]));
+}
+
+#[test]
+fn test_decode_rejects_bad_tokens() {
+    let keys = parse_sort("priority");
+    assert!(Cursor::decode("not hex", &keys).is_err());
+    assert!(Cursor::decode(&hex::encode("{}"), &keys).is_err());
+    let other = Cursor::after(&item("a", Some(1)), &parse_sort("title"), &[]);
+    assert!(Cursor::decode(&other.encode(), &keys).is_err());
+}
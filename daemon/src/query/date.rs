@@ -0,0 +1,89 @@
+use chrono::{DateTime, Datelike as _, Days, Duration, NaiveDate, SecondsFormat, TimeZone, Utc};
+
+/// Named points in time a relative date expression can start from.
+const ANCHORS: &[&str] = &[
+    "now",
+    "today",
+    "startOfDay",
+    "startOfWeek",
+    "startOfMonth",
+    "startOfYear",
+];
+
+/// Resolve a relative date expression against `now`.
+///
+/// An expression is an anchor (`now`, `today`, `startOfDay`, `startOfWeek`,
+/// `startOfMonth`, `startOfYear`), a signed `humantime` duration (`-7d`,
+/// `+2h`, `-1week`), or an anchor followed by a signed duration
+/// (`startOfWeek-7d`). A bare duration is relative to `now`. Day, week,
+/// month and year boundaries are taken in `now`'s time zone; weeks start on
+/// Monday.
+///
+/// Returns `Ok(None)` when `expr` is not a relative expression (an ISO date,
+/// a number, plain text) and `Err` when it looks like one but is malformed.
+pub fn resolve_relative_date<Tz: TimeZone>(
+    expr: &str,
+    now: &DateTime<Tz>,
+) -> Result<Option<DateTime<Utc>>, String> {
+    let trimmed = expr.trim();
+    if trimmed.parse::<f64>().is_ok() {
+        return Ok(None);
+    }
+    let (anchor, offset) = match ANCHORS
+        .iter()
+        .find_map(|a| trimmed.strip_prefix(a).map(|rest| (*a, rest)))
+        .filter(|(_, rest)| rest.is_empty() || rest.starts_with(['-', '+']))
+    {
+        Some(split) => split,
+        None if trimmed.starts_with(['-', '+']) => ("now", trimmed),
+        None => return Ok(None),
+    };
+    let start = anchor_time(anchor, now)
+        .ok_or_else(|| format!("cannot resolve '{anchor}' in the local time zone"))?;
+    if offset.is_empty() {
+        return Ok(Some(start));
+    }
+    let shifted = parse_offset(offset).and_then(|d| start.checked_add_signed(d));
+    shifted.map(Some).ok_or_else(|| {
+        format!(
+            "invalid relative date '{trimmed}' (expected e.g. -7d, +2h, startOfWeek or startOfMonth-1month)"
+        )
+    })
+}
+
+/// Format a resolved date the way item timestamps are stored.
+#[must_use]
+pub fn format_date(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn anchor_time<Tz: TimeZone>(anchor: &str, now: &DateTime<Tz>) -> Option<DateTime<Utc>> {
+    let today = now.date_naive();
+    let day = match anchor {
+        "now" => return Some(now.with_timezone(&Utc)),
+        "startOfWeek" => {
+            today.checked_sub_days(Days::new(today.weekday().num_days_from_monday().into()))?
+        }
+        "startOfMonth" => today.with_day(1)?,
+        "startOfYear" => NaiveDate::from_yo_opt(today.year(), 1)?,
+        _ => today,
+    };
+    now.timezone()
+        .from_local_datetime(&day.and_hms_opt(0, 0, 0)?)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse `-7d` / `+1h 30m` into a signed duration.
+fn parse_offset(offset: &str) -> Option<Duration> {
+    let (negative, magnitude) = match offset.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, offset.strip_prefix('+')?),
+    };
+    let duration = Duration::from_std(humantime::parse_duration(magnitude.trim()).ok()?).ok()?;
+    if negative {
+        Duration::zero().checked_sub(&duration)
+    } else {
+        Some(duration)
+    }
+}
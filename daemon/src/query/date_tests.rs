@@ -0,0 +1,112 @@
+use super::date::{format_date, resolve_relative_date};
+use super::*;
+use chrono::{DateTime, Utc};
+
+fn wednesday() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-10-14T15:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn resolve(expr: &str) -> String {
+    format_date(&resolve_relative_date(expr, &wednesday()).unwrap().unwrap())
+}
+
+#[test]
+fn test_anchors() {
+    assert_eq!(resolve("now"), "2026-10-14T15:30:00Z");
+    assert_eq!(resolve("today"), "2026-10-14T00:00:00Z");
+    assert_eq!(resolve("startOfDay"), "2026-10-14T00:00:00Z");
+    assert_eq!(resolve("startOfWeek"), "2026-10-12T00:00:00Z");
+    assert_eq!(resolve("startOfMonth"), "2026-10-01T00:00:00Z");
+    assert_eq!(resolve("startOfYear"), "2026-01-01T00:00:00Z");
+}
+
+#[test]
+fn test_offsets() {
+    assert_eq!(resolve("-7d"), "2026-10-07T15:30:00Z");
+    assert_eq!(resolve("+2h"), "2026-10-14T17:30:00Z");
+    assert_eq!(resolve("-1h 30m"), "2026-10-14T14:00:00Z");
+    assert_eq!(resolve("startOfWeek-1week"), "2026-10-05T00:00:00Z");
+    assert_eq!(resolve(" today+1d "), "2026-10-15T00:00:00Z");
+}
+
+#[test]
+fn test_boundaries_follow_the_time_zone() {
+    // 01:00 on Monday in UTC+2 is still Sunday in UTC.
+    let now = DateTime::parse_from_rfc3339("2026-10-12T01:00:00+02:00").unwrap();
+    let start = resolve_relative_date("startOfWeek", &now).unwrap().unwrap();
+    assert_eq!(format_date(&start), "2026-10-11T22:00:00Z");
+    let today = resolve_relative_date("today", &now).unwrap().unwrap();
+    assert_eq!(format_date(&today), "2026-10-11T22:00:00Z");
+}
+
+#[test]
+fn test_non_expressions_are_left_alone() {
+    for expr in [
+        "2024-03-01",
+        "2024-03-01T10:00:00Z",
+        "-5",
+        "+1.5",
+        "open",
+        "nowhere",
+    ] {
+        assert_eq!(
+            resolve_relative_date(expr, &wednesday()),
+            Ok(None),
+            "{expr}"
+        );
+    }
+}
+
+#[test]
+fn test_malformed_expressions_are_errors() {
+    for expr in ["-7x", "+", "startOfWeek-", "today+soon"] {
+        assert!(resolve_relative_date(expr, &wednesday()).is_err(), "{expr}");
+    }
+}
+
+#[test]
+fn test_relative_bounds_are_resolved_when_parsing() {
+    let q = parse_filter_json(r#"{"updatedAt":{"$gte":"-7d"}}"#).unwrap();
+    let Query::Field {
+        op: Operator::Gte(serde_json::Value::String(bound)),
+        ..
+    } = q
+    else {
+        panic!("expected a $gte condition");
+    };
+    let resolved = DateTime::parse_from_rfc3339(&bound).unwrap();
+    let age = Utc::now().signed_duration_since(resolved);
+    assert!(age >= chrono::Duration::days(7) && age < chrono::Duration::days(8));
+}
+
+#[test]
+fn test_malformed_bound_is_rejected_with_path() {
+    let err = parse_filter_json(r#"{"createdAt":{"$lt":"-3 fortnights"}}"#).unwrap_err();
+    assert_eq!(err.path, "createdAt.$lt");
+}
+
+#[test]
+fn test_relative_bound_matches_recent_items() {
+    let mut item = mdstore::Item {
+        id: "id-1".to_string(),
+        title: "Recent".to_string(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: None,
+            priority: None,
+            created_at: format_date(&Utc::now()),
+            updated_at: format_date(&Utc::now()),
+            deleted_at: None,
+            tags: None,
+            custom_fields: std::collections::HashMap::new(),
+        },
+        comment: None,
+    };
+    let this_week = parse_filter_json(r#"{"createdAt":{"$gte":"-7d"}}"#).unwrap();
+    assert!(this_week.matches(&item));
+    item.frontmatter.created_at = "2020-01-01T00:00:00Z".to_string();
+    assert!(!this_week.matches(&item));
+}
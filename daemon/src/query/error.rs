@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+/// A filter document that could not be parsed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid filter at '{path}': {message}")]
+pub struct QueryError {
+    /// Location of the offending node, e.g. `$or[1].priority.$lte`.
+    /// `$` denotes the document root.
+    pub path: String,
+    pub message: String,
+}
+
+impl QueryError {
+    #[must_use]
+    pub fn new<M: Into<String>>(path: &str, message: M) -> Self {
+        Self {
+            path: if path.is_empty() {
+                "$".to_string()
+            } else {
+                path.to_string()
+            },
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::compare::{compare_values, values_equal};
+use super::field::field_value;
+use super::types::{Operator, Query};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+impl Query {
+    /// Evaluate the query against a single item.
+    ///
+    /// Link conditions must have been resolved with
+    /// [`resolve_links`](super::resolve_links) first.
+    #[must_use]
+    pub fn matches(&self, item: &mdstore::Item) -> bool {
+        match self {
+            Query::All => true,
+            Query::And(clauses) => clauses.iter().all(|q| q.matches(item)),
+            Query::Or(clauses) => clauses.iter().any(|q| q.matches(item)),
+            Query::Not(inner) => !inner.matches(item),
+            Query::Field { field, op } => op.matches(field_value(item, field).as_ref()),
+            Query::Link(_) => false,
+        }
+    }
+}
+
+impl Operator {
+    /// Apply the operator to a field value (`None` when the field is absent).
+    ///
+    /// Array values (e.g. `tags`) match when any element matches, so
+    /// `{"tags": "backend"}` selects items tagged `backend`.
+    #[must_use]
+    pub fn matches(&self, value: Option<&Value>) -> bool {
+        let Some(actual) = value else {
+            return matches!(
+                self,
+                Operator::Exists(false) | Operator::Ne(_) | Operator::Nin(_)
+            );
+        };
+        match self {
+            Operator::Exists(expected) => *expected,
+            Operator::Eq(v) => equals(actual, v),
+            Operator::Ne(v) => !equals(actual, v),
+            Operator::In(vs) => vs.iter().any(|v| equals(actual, v)),
+            Operator::Nin(vs) => !vs.iter().any(|v| equals(actual, v)),
+            Operator::All(vs) => vs
+                .iter()
+                .all(|v| any_element(actual, |x| values_equal(x, v))),
+            Operator::Gt(v) => ordered(actual, v, |o| o == Ordering::Greater),
+            Operator::Gte(v) => ordered(actual, v, |o| o != Ordering::Less),
+            Operator::Lt(v) => ordered(actual, v, |o| o == Ordering::Less),
+            Operator::Lte(v) => ordered(actual, v, |o| o != Ordering::Greater),
+            Operator::Regex(re) => {
+                any_element(actual, |x| x.as_str().is_some_and(|s| re.is_match(s)))
+            }
+            Operator::Contains(needle) => any_element(actual, |x| contains(x, needle)),
+        }
+    }
+}
+
+fn any_element(actual: &Value, pred: impl Fn(&Value) -> bool) -> bool {
+    match actual {
+        Value::Array(elements) => elements.iter().any(pred),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
+            pred(actual)
+        }
+    }
+}
+
+fn equals(actual: &Value, expected: &Value) -> bool {
+    values_equal(actual, expected) || any_element(actual, |x| values_equal(x, expected))
+}
+
+fn ordered(actual: &Value, bound: &Value, accept: impl Fn(Ordering) -> bool) -> bool {
+    any_element(actual, |x| compare_values(x, bound).is_some_and(&accept))
+}
+
+/// Case-insensitive substring match for strings, equality for other scalars.
+fn contains(actual: &Value, needle: &Value) -> bool {
+    match (actual, needle) {
+        (Value::String(haystack), Value::String(n)) => {
+            haystack.to_lowercase().contains(&n.to_lowercase())
+        }
+        _ => values_equal(actual, needle),
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+use std::collections::HashMap;
+
+fn item(status: &str, priority: u32, tags: &[&str], team: Option<&str>) -> mdstore::Item {
+    let mut custom_fields = HashMap::new();
+    if let Some(name) = team {
+        custom_fields.insert("team".to_string(), serde_json::json!(name));
+    }
+    custom_fields.insert("estimate".to_string(), serde_json::json!("5"));
+    mdstore::Item {
+        id: "id-1".to_string(),
+        title: "Fix login timeout".to_string(),
+        body: "Users are logged out after a minute.".to_string(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: Some(7),
+            status: Some(status.to_string()),
+            priority: Some(priority),
+            created_at: "2024-03-10T09:00:00Z".to_string(),
+            updated_at: "2024-03-12T09:00:00Z".to_string(),
+            deleted_at: None,
+            tags: Some(tags.iter().map(|t| (*t).to_string()).collect()),
+            custom_fields,
+        },
+        comment: None,
+    }
+}
+
+fn matches(filter: &str, item: &mdstore::Item) -> bool {
+    parse_filter_json(filter).unwrap().matches(item)
+}
+
+#[test]
+fn test_dashboard_query() {
+    let filter =
+        r#"{"priority":{"$lte":2},"$or":[{"tags":"backend"},{"customFields.team":"infra"}]}"#;
+    assert!(matches(filter, &item("open", 1, &["backend"], None)));
+    assert!(matches(filter, &item("open", 2, &[], Some("infra"))));
+    assert!(!matches(
+        filter,
+        &item("open", 3, &["backend"], Some("infra"))
+    ));
+    assert!(!matches(
+        filter,
+        &item("open", 1, &["frontend"], Some("web"))
+    ));
+}
+
+#[test]
+fn test_ne_and_nin_match_missing_fields() {
+    let it = item("open", 1, &[], None);
+    assert!(matches(r#"{"team":{"$ne":"infra"}}"#, &it));
+    assert!(matches(r#"{"team":{"$nin":["infra","web"]}}"#, &it));
+    assert!(!matches(r#"{"status":{"$ne":"open"}}"#, &it));
+    assert!(!matches(r#"{"status":{"$nin":["open"]}}"#, &it));
+}
+
+#[test]
+fn test_exists() {
+    let it = item("open", 1, &[], Some("infra"));
+    assert!(matches(r#"{"team":{"$exists":true}}"#, &it));
+    assert!(matches(r#"{"owner":{"$exists":false}}"#, &it));
+    assert!(!matches(r#"{"deletedAt":{"$exists":true}}"#, &it));
+}
+
+#[test]
+fn test_array_fields_match_any_element() {
+    let it = item("open", 1, &["backend", "auth"], None);
+    assert!(matches(r#"{"tags":"auth"}"#, &it));
+    assert!(matches(r#"{"tags":{"$in":["ui","auth"]}}"#, &it));
+    assert!(matches(r#"{"tags":{"$all":["auth","backend"]}}"#, &it));
+    assert!(!matches(r#"{"tags":{"$all":["auth","ui"]}}"#, &it));
+    assert!(!matches(r#"{"tags":{"$ne":"auth"}}"#, &it));
+}
+
+#[test]
+fn test_regex_and_contains() {
+    let it = item("open", 1, &["backend"], None);
+    assert!(!matches(r#"{"title":{"$regex":"^fix"}}"#, &it));
+    assert!(matches(
+        r#"{"title":{"$regex":"^fix","$options":"i"}}"#,
+        &it
+    ));
+    assert!(matches(r#"{"body":{"$contains":"LOGGED OUT"}}"#, &it));
+    assert!(matches(r#"{"tags":{"$contains":"back"}}"#, &it));
+}
+
+#[test]
+fn test_numeric_comparison_coerces_strings() {
+    let it = item("open", 1, &[], None);
+    assert!(matches(r#"{"estimate":{"$gte":3}}"#, &it));
+    assert!(matches(r#"{"estimate":5}"#, &it));
+    assert!(!matches(r#"{"estimate":{"$lt":5}}"#, &it));
+}
+
+#[test]
+fn test_date_comparison() {
+    let it = item("open", 1, &[], None);
+    assert!(matches(r#"{"createdAt":{"$gte":"2024-03-01"}}"#, &it));
+    assert!(!matches(
+        r#"{"updatedAt":{"$lt":"2024-03-12T08:00:00Z"}}"#,
+        &it
+    ));
+    assert!(matches(
+        r#"{"createdAt":{"$lt":"2024-03-10T09:00:01Z"}}"#,
+        &it
+    ));
+}
+
+#[test]
+fn test_not() {
+    let it = item("open", 3, &[], None);
+    assert!(matches(r#"{"$not":{"status":"closed"}}"#, &it));
+    assert!(matches(r#"{"priority":{"$not":{"$lt":2}}}"#, &it));
+    assert!(!matches(r#"{"$not":{"displayNumber":7}}"#, &it));
+}
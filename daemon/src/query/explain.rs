@@ -0,0 +1,114 @@
+use super::pushdown::pushdown;
+use super::types::{LinkFilter, Operator, Query};
+use serde_json::{json, Map, Value};
+
+/// How a filter will be executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The parsed filter written back as canonical MQL.
+    pub normalized: Value,
+    /// The `mdstore::Filters` the store applies while reading items.
+    pub pushed_down: Value,
+    /// The clauses evaluated in memory afterwards, as MQL (`{}` when none).
+    pub residual: Value,
+    /// `true` when the store filters alone select exactly the matching items.
+    pub complete: bool,
+}
+
+/// Explain how `query` is split between the store and in-memory evaluation.
+#[must_use]
+pub fn explain(query: &Query) -> Explanation {
+    let plan = pushdown(query);
+    Explanation {
+        normalized: to_document(query),
+        pushed_down: filters_document(&plan.filters),
+        residual: to_document(&plan.residual),
+        complete: plan.complete,
+    }
+}
+
+/// Write a query as an MQL document in canonical form: every condition
+/// uses an explicit operator and multiple clauses are wrapped in `$and`.
+/// Parsing the result yields an equivalent query.
+#[must_use]
+pub fn to_document(query: &Query) -> Value {
+    match query {
+        Query::All => json!({}),
+        Query::And(clauses) => {
+            json!({ "$and": clauses.iter().map(to_document).collect::<Vec<_>>() })
+        }
+        Query::Or(clauses) => json!({ "$or": clauses.iter().map(to_document).collect::<Vec<_>>() }),
+        Query::Not(inner) => json!({ "$not": to_document(inner) }),
+        Query::Field { field, op } => {
+            let (name, operand) = operator_document(op);
+            let mut condition = Map::new();
+            condition.insert(name.to_string(), operand);
+            let mut doc = Map::new();
+            doc.insert(field.clone(), Value::Object(condition));
+            Value::Object(doc)
+        }
+        Query::Link(filter) => link_document(filter),
+    }
+}
+
+fn link_document(filter: &LinkFilter) -> Value {
+    match filter {
+        LinkFilter::Linked { link_type, to } => {
+            let mut spec = Map::new();
+            if let Some(t) = link_type {
+                spec.insert("type".to_string(), Value::String(t.clone()));
+            }
+            if let Some(id) = to {
+                spec.insert("to".to_string(), Value::String(id.clone()));
+            }
+            json!({ "$linked": spec })
+        }
+        LinkFilter::HasLinks(flag) => json!({ "hasLinks": flag }),
+        LinkFilter::Blocked(flag) => json!({ "$blocked": flag }),
+    }
+}
+
+fn operator_document(op: &Operator) -> (&'static str, Value) {
+    match op {
+        Operator::Eq(v) => ("$eq", v.clone()),
+        Operator::Ne(v) => ("$ne", v.clone()),
+        Operator::In(vs) => ("$in", Value::Array(vs.clone())),
+        Operator::Nin(vs) => ("$nin", Value::Array(vs.clone())),
+        Operator::All(vs) => ("$all", Value::Array(vs.clone())),
+        Operator::Gt(v) => ("$gt", v.clone()),
+        Operator::Gte(v) => ("$gte", v.clone()),
+        Operator::Lt(v) => ("$lt", v.clone()),
+        Operator::Lte(v) => ("$lte", v.clone()),
+        Operator::Exists(b) => ("$exists", Value::Bool(*b)),
+        Operator::Regex(re) => ("$regex", Value::String(re.as_str().to_string())),
+        Operator::Contains(v) => ("$contains", v.clone()),
+    }
+}
+
+/// The store filters that are set, keyed by their `mdstore::Filters` field.
+#[must_use]
+pub fn filters_document(filters: &mdstore::Filters) -> Value {
+    let mut doc = Map::new();
+    if let Some(statuses) = &filters.statuses {
+        doc.insert("statuses".to_string(), json!(statuses));
+    }
+    if let Some(p) = filters.priority {
+        doc.insert("priority".to_string(), json!(p));
+    }
+    if let Some(p) = filters.priority_lte {
+        doc.insert("priorityLte".to_string(), json!(p));
+    }
+    if let Some(p) = filters.priority_gte {
+        doc.insert("priorityGte".to_string(), json!(p));
+    }
+    if let Some(tags) = &filters.tags_any {
+        doc.insert("tagsAny".to_string(), json!(tags));
+    }
+    if let Some(tags) = &filters.tags_all {
+        doc.insert("tagsAll".to_string(), json!(tags));
+    }
+    if filters.include_deleted {
+        doc.insert("includeDeleted".to_string(), Value::Bool(true));
+    }
+    Value::Object(doc)
+}
@@ -0,0 +1,82 @@
+use super::*;
+use serde_json::json;
+
+fn explain_json(filter: &str) -> Explanation {
+    explain(&parse_filter_json(filter).unwrap())
+}
+
+#[test]
+fn test_normalized_form_is_explicit_and_reparses() {
+    let e = explain_json(
+        r#"{"status":"open","priority":{"$lte":2},"title":{"$regex":"^fix","$options":"i"}}"#,
+    );
+    assert_eq!(
+        e.normalized,
+        json!({ "$and": [
+            { "priority": { "$lte": 2u32 } },
+            { "status": { "$eq": "open" } },
+            { "title": { "$regex": "(?i)^fix" } },
+        ]})
+    );
+    let reparsed = explain(&parse_filter(&e.normalized).unwrap());
+    assert_eq!(reparsed.normalized, e.normalized);
+}
+
+#[test]
+fn test_split_between_store_and_memory() {
+    let e = explain_json(
+        r#"{"status":{"$in":["open","planning"]},"$or":[{"tags":"ui"},{"priority":1}]}"#,
+    );
+    assert_eq!(e.pushed_down, json!({ "statuses": ["open", "planning"] }));
+    assert_eq!(
+        e.residual,
+        json!({ "$or": [
+            { "tags": { "$eq": "ui" } },
+            { "priority": { "$eq": 1u32 } },
+        ]})
+    );
+    assert!(!e.complete);
+}
+
+#[test]
+fn test_fully_pushed_query_has_empty_residual() {
+    let e = explain_json(r#"{"priority":{"$gt":1},"deletedAt":{"$exists":true}}"#);
+    assert_eq!(
+        e.pushed_down,
+        json!({ "priorityGte": 2u32, "includeDeleted": true })
+    );
+    assert!(!e.complete);
+    assert_eq!(e.residual, json!({ "deletedAt": { "$exists": true } }));
+
+    let e = explain_json(r#"{"tags":{"$all":["a","b"]}}"#);
+    assert!(e.complete);
+    assert_eq!(e.residual, json!({}));
+    assert_eq!(e.normalized, json!({ "tags": { "$all": ["a", "b"] } }));
+}
+
+#[test]
+fn test_not_is_normalized_at_top_level() {
+    let e = explain_json(r#"{"status":{"$not":{"$in":["closed"]}}}"#);
+    assert_eq!(
+        e.normalized,
+        json!({ "$not": { "status": { "$in": ["closed"] } } })
+    );
+}
+
+#[test]
+fn test_link_conditions_stay_in_memory() {
+    let e = explain_json(
+        r#"{"status":"open","$linked":{"type":"child-of","to":"epic"},"$blocked":false}"#,
+    );
+    assert_eq!(e.pushed_down, json!({ "statuses": ["open"] }));
+    assert_eq!(
+        e.residual,
+        json!({ "$and": [
+            { "$blocked": false },
+            { "$linked": { "type": "child-of", "to": "epic" } },
+        ]})
+    );
+    assert!(!e.complete);
+    let reparsed = explain(&parse_filter(&e.normalized).unwrap());
+    assert_eq!(reparsed.normalized, e.normalized);
+}
@@ -0,0 +1,33 @@
+use serde_json::Value;
+
+/// Resolve a filter field name to its value on an item.
+///
+/// Built-in frontmatter fields are addressed by their camelCase name
+/// (`status`, `priority`, `displayNumber`, `createdAt`, `updatedAt`,
+/// `deletedAt`, `tags`) plus `id`, `title` and `body`. Custom fields are
+/// addressed as `customFields.<name>` or simply `<name>`.
+/// Returns `None` when the field is absent or null.
+#[must_use]
+pub fn field_value(item: &mdstore::Item, field: &str) -> Option<Value> {
+    let fm = &item.frontmatter;
+    match field {
+        "id" => Some(Value::String(item.id.clone())),
+        "title" => Some(Value::String(item.title.clone())),
+        "body" => Some(Value::String(item.body.clone())),
+        "status" => fm.status.clone().map(Value::String),
+        "priority" => fm.priority.map(Value::from),
+        "displayNumber" | "display_number" => fm.display_number.map(Value::from),
+        "createdAt" | "created_at" => non_empty(&fm.created_at),
+        "updatedAt" | "updated_at" => non_empty(&fm.updated_at),
+        "deletedAt" | "deleted_at" => fm.deleted_at.as_deref().and_then(non_empty),
+        "tags" => fm.tags.clone().map(Value::from),
+        _ => {
+            let name = field.strip_prefix("customFields.").unwrap_or(field);
+            fm.custom_fields.get(name).filter(|v| !v.is_null()).cloned()
+        }
+    }
+}
+
+fn non_empty(s: &str) -> Option<Value> {
+    (!s.is_empty()).then(|| Value::String(s.to_string()))
+}
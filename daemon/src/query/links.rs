@@ -0,0 +1,117 @@
+use super::types::{LinkFilter, Operator, Query};
+use crate::link::inverse_link_type;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// One stored link. `link_type` is from the source's perspective.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkEdge {
+    pub source_id: String,
+    pub target_id: String,
+    pub link_type: String,
+}
+
+impl LinkEdge {
+    /// `(blocked, blocker)` when this is a `blocks` or `blocked-by` link.
+    #[must_use]
+    pub fn blocking_pair(&self) -> Option<(&str, &str)> {
+        match self.link_type.as_str() {
+            "blocks" => Some((&self.target_id, &self.source_id)),
+            "blocked-by" => Some((&self.source_id, &self.target_id)),
+            _ => None,
+        }
+    }
+}
+
+/// The links of one project, used to turn link conditions into id sets.
+#[derive(Debug, Clone, Default)]
+pub struct LinkIndex {
+    edges: Vec<LinkEdge>,
+    open_blockers: BTreeSet<String>,
+}
+
+impl LinkIndex {
+    /// `open_blockers` holds the ids of blocking items that are not closed.
+    #[must_use]
+    pub const fn new(edges: Vec<LinkEdge>, open_blockers: BTreeSet<String>) -> Self {
+        Self {
+            edges,
+            open_blockers,
+        }
+    }
+
+    /// Ids of the items for which the positive form of `filter` holds.
+    fn ids(&self, filter: &LinkFilter) -> BTreeSet<&str> {
+        let mut ids = BTreeSet::new();
+        match filter {
+            LinkFilter::Linked { link_type, to } => {
+                let wants = |t: Option<&str>| link_type.is_none() || link_type.as_deref() == t;
+                let other = |id: &str| to.as_ref().is_none_or(|wanted| wanted == id);
+                for edge in &self.edges {
+                    if wants(Some(&edge.link_type)) && other(&edge.target_id) {
+                        ids.insert(edge.source_id.as_str());
+                    }
+                    if wants(inverse_link_type(&edge.link_type)) && other(&edge.source_id) {
+                        ids.insert(edge.target_id.as_str());
+                    }
+                }
+            }
+            LinkFilter::HasLinks(_) => {
+                for edge in &self.edges {
+                    ids.insert(edge.source_id.as_str());
+                    ids.insert(edge.target_id.as_str());
+                }
+            }
+            LinkFilter::Blocked(_) => {
+                for (blocked, blocker) in self.edges.iter().filter_map(LinkEdge::blocking_pair) {
+                    if self.open_blockers.contains(blocker) {
+                        ids.insert(blocked);
+                    }
+                }
+            }
+        }
+        ids
+    }
+}
+
+/// Whether the query contains a link condition.
+#[must_use]
+pub fn uses_links(query: &Query) -> bool {
+    match query {
+        Query::All | Query::Field { .. } => false,
+        Query::And(clauses) | Query::Or(clauses) => clauses.iter().any(uses_links),
+        Query::Not(inner) => uses_links(inner),
+        Query::Link(_) => true,
+    }
+}
+
+/// Replace every link condition with an `id` membership test computed from
+/// `index`, leaving a query that can be evaluated item by item.
+#[must_use]
+pub fn resolve_links(query: &Query, index: &LinkIndex) -> Query {
+    match query {
+        Query::All | Query::Field { .. } => query.clone(),
+        Query::And(clauses) => {
+            Query::And(clauses.iter().map(|q| resolve_links(q, index)).collect())
+        }
+        Query::Or(clauses) => Query::Or(clauses.iter().map(|q| resolve_links(q, index)).collect()),
+        Query::Not(inner) => Query::Not(Box::new(resolve_links(inner, index))),
+        Query::Link(filter) => {
+            let ids: Vec<Value> = index
+                .ids(filter)
+                .into_iter()
+                .map(|id| Value::String(id.to_string()))
+                .collect();
+            let op = match filter {
+                LinkFilter::HasLinks(false) | LinkFilter::Blocked(false) => Operator::Nin(ids),
+                LinkFilter::Linked { .. }
+                | LinkFilter::HasLinks(true)
+                | LinkFilter::Blocked(true) => Operator::In(ids),
+            };
+            Query::Field {
+                field: "id".to_string(),
+                op,
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::*;
+use std::collections::{BTreeSet, HashMap};
+
+fn edge(source: &str, link_type: &str, target: &str) -> LinkEdge {
+    LinkEdge {
+        source_id: source.to_string(),
+        target_id: target.to_string(),
+        link_type: link_type.to_string(),
+    }
+}
+
+fn item(id: &str) -> mdstore::Item {
+    mdstore::Item {
+        id: id.to_string(),
+        title: id.to_string(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: Some("open".to_string()),
+            priority: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            deleted_at: None,
+            tags: None,
+            custom_fields: HashMap::new(),
+        },
+        comment: None,
+    }
+}
+
+/// `epic` is the parent of `a` and `b`; `a` is blocked by the open `b` and
+/// by the closed `done`; `c` is blocked by `done` only; `lonely` has no links.
+fn index() -> LinkIndex {
+    LinkIndex::new(
+        vec![
+            edge("epic", "parent-of", "a"),
+            edge("b", "child-of", "epic"),
+            edge("b", "blocks", "a"),
+            edge("a", "blocked-by", "done"),
+            edge("done", "blocks", "c"),
+        ],
+        BTreeSet::from(["b".to_string()]),
+    )
+}
+
+fn matching(filter: &str) -> Vec<&'static str> {
+    let query = resolve_links(&parse_filter_json(filter).unwrap(), &index());
+    ["epic", "a", "b", "c", "done", "lonely"]
+        .into_iter()
+        .filter(|id| query.matches(&item(id)))
+        .collect()
+}
+
+#[test]
+fn test_linked_uses_the_items_perspective() {
+    let children = r#"{"$linked":{"type":"child-of","to":"epic"}}"#;
+    assert_eq!(matching(children), vec!["a", "b"]);
+    let parents = r#"{"$linked":{"type":"parent-of"}}"#;
+    assert_eq!(matching(parents), vec!["epic"]);
+    let blocked_by_b = r#"{"$linked":{"type":"blocked-by","to":"b"}}"#;
+    assert_eq!(matching(blocked_by_b), vec!["a"]);
+}
+
+#[test]
+fn test_linked_without_type_matches_either_side() {
+    assert_eq!(
+        matching(r#"{"$linked":{"to":"a"}}"#),
+        vec!["epic", "b", "done"]
+    );
+    assert_eq!(
+        matching(r#"{"$linked":{}}"#),
+        vec!["epic", "a", "b", "c", "done"]
+    );
+}
+
+#[test]
+fn test_has_links() {
+    assert_eq!(matching(r#"{"hasLinks":false}"#), vec!["lonely"]);
+    assert_eq!(matching(r#"{"hasLinks":true}"#).len(), 5);
+}
+
+#[test]
+fn test_blocked_ignores_closed_blockers() {
+    assert_eq!(matching(r#"{"$blocked":true}"#), vec!["a"]);
+    assert_eq!(
+        matching(r#"{"$blocked":false}"#),
+        vec!["epic", "b", "c", "done", "lonely"]
+    );
+}
+
+#[test]
+fn test_link_conditions_combine_with_fields() {
+    let filter = r#"{"$or":[{"hasLinks":false},{"$not":{"$blocked":false}}],"title":{"$ne":"a"}}"#;
+    assert_eq!(matching(filter), vec!["lonely"]);
+}
+
+#[test]
+fn test_uses_links() {
+    assert!(!uses_links(
+        &parse_filter_json(r#"{"status":"open"}"#).unwrap()
+    ));
+    assert!(uses_links(
+        &parse_filter_json(r#"{"$or":[{"status":"open"},{"$not":{"hasLinks":true}}]}"#).unwrap()
+    ));
+}
+
+#[test]
+fn test_unresolved_link_condition_matches_nothing() {
+    let query = parse_filter_json(r#"{"hasLinks":false}"#).unwrap();
+    assert!(!query.matches(&item("lonely")));
+}
+
+#[test]
+fn test_link_syntax_errors() {
+    let cases = [
+        (r#"{"$linked":"blocks"}"#, "$linked"),
+        (r#"{"$linked":{"kind":"blocks"}}"#, "$linked.kind"),
+        (r#"{"$linked":{"to":7}}"#, "$linked.to"),
+        (r#"{"hasLinks":"no"}"#, "hasLinks"),
+        (r#"{"$blocked":1}"#, "$blocked"),
+    ];
+    for (filter, path) in cases {
+        assert_eq!(
+            parse_filter_json(filter).unwrap_err().path,
+            path,
+            "{filter}"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! MQL — the Mongo-style filter language accepted by `ListItems`.
+//!
+//! A filter document is parsed once into a [`Query`] tree and then evaluated
+//! in memory against each `mdstore::Item`. The simple parts of a query are
+//! also pushed down to `mdstore::Filters` so the store can skip items early.
+mod aggregate;
+mod compare;
+mod cursor;
+mod date;
+mod error;
+mod eval;
+mod explain;
+mod field;
+mod links;
+mod parse;
+mod pushdown;
+mod sort;
+mod types;
+pub use aggregate::{group_keys, parse_group_by, Group, GroupBy, GroupCounts};
+pub use cursor::Cursor;
+pub use date::{format_date, resolve_relative_date};
+pub use error::QueryError;
+pub use explain::{explain, filters_document, to_document, Explanation};
+pub use field::field_value;
+pub use links::{resolve_links, uses_links, LinkEdge, LinkIndex};
+pub use parse::{parse_filter, parse_filter_json};
+pub use pushdown::{pushdown, Pushdown};
+pub use sort::{
+    compare_items, compare_positions, format_sort, parse_sort, sort_items, sort_values, SortKey,
+};
+pub use types::{LinkFilter, Operator, Query};
+#[cfg(test)]
+#[path = "aggregate_tests.rs"]
+mod aggregate_tests;
+#[cfg(test)]
+#[path = "cursor_tests.rs"]
+mod cursor_tests;
+#[cfg(test)]
+#[path = "date_tests.rs"]
+mod date_tests;
+#[cfg(test)]
+#[path = "eval_tests.rs"]
+mod eval_tests;
+#[cfg(test)]
+#[path = "explain_tests.rs"]
+mod explain_tests;
+#[cfg(test)]
+#[path = "links_tests.rs"]
+mod links_tests;
+#[cfg(test)]
+#[path = "parse_tests.rs"]
+mod parse_tests;
+#[cfg(test)]
+#[path = "pushdown_tests.rs"]
+mod pushdown_tests;
+#[cfg(test)]
+#[path = "sort_tests.rs"]
+mod sort_tests;
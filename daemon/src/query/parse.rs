@@ -0,0 +1,284 @@
+use super::date::{format_date, resolve_relative_date};
+use super::error::QueryError;
+use super::types::{LinkFilter, Operator, Query};
+use chrono::Local;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+/// Parse a JSON-encoded filter document. An empty string matches everything.
+pub fn parse_filter_json(filter_json: &str) -> Result<Query, QueryError> {
+    if filter_json.trim().is_empty() {
+        return Ok(Query::All);
+    }
+    let doc: Value = serde_json::from_str(filter_json)
+        .map_err(|e| QueryError::new("", format!("malformed JSON: {e}")))?;
+    parse_filter(&doc)
+}
+
+/// Parse a filter document into a [`Query`] tree.
+pub fn parse_filter(doc: &Value) -> Result<Query, QueryError> {
+    parse_document(doc, "")
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Combine clauses with AND, collapsing the trivial cases.
+fn conjunction(mut clauses: Vec<Query>) -> Query {
+    match clauses.len() {
+        0 => Query::All,
+        1 => clauses.pop().unwrap_or(Query::All),
+        _ => Query::And(clauses),
+    }
+}
+
+fn is_operator_map(map: &Map<String, Value>) -> bool {
+    map.keys().any(|k| k.starts_with('$'))
+}
+
+fn parse_document(doc: &Value, path: &str) -> Result<Query, QueryError> {
+    let Some(obj) = doc.as_object() else {
+        return Err(QueryError::new(path, "filter must be a JSON object"));
+    };
+    let clauses = obj
+        .iter()
+        .map(|(key, value)| parse_clause(key, value, &join(path, key)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(conjunction(clauses))
+}
+
+fn parse_clause(key: &str, value: &Value, path: &str) -> Result<Query, QueryError> {
+    match key {
+        "$and" => Ok(Query::And(parse_list(value, path)?)),
+        "$or" => Ok(Query::Or(parse_list(value, path)?)),
+        "$not" => Ok(Query::Not(Box::new(parse_document(value, path)?))),
+        "customFields" => parse_custom_fields(value, path),
+        "$linked" => parse_linked(value, path),
+        "$blocked" => Ok(Query::Link(LinkFilter::Blocked(expect_flag(value, path)?))),
+        "hasLinks" => Ok(Query::Link(LinkFilter::HasLinks(expect_flag(value, path)?))),
+        _ if key.starts_with('$') => Err(QueryError::new(
+            path,
+            format!("unknown top-level operator '{key}'"),
+        )),
+        _ => parse_field(key, value, path),
+    }
+}
+
+fn parse_list(value: &Value, path: &str) -> Result<Vec<Query>, QueryError> {
+    let Some(docs) = value.as_array() else {
+        return Err(QueryError::new(
+            path,
+            "expected an array of filter documents",
+        ));
+    };
+    if docs.is_empty() {
+        return Err(QueryError::new(
+            path,
+            "expected at least one filter document",
+        ));
+    }
+    docs.iter()
+        .enumerate()
+        .map(|(i, doc)| parse_document(doc, &format!("{path}[{i}]")))
+        .collect()
+}
+
+/// `{"$linked": {"type": "blocked-by", "to": "<id>"}}`; both keys are optional.
+fn parse_linked(value: &Value, path: &str) -> Result<Query, QueryError> {
+    let Some(spec) = value.as_object() else {
+        return Err(QueryError::new(
+            path,
+            "$linked expects an object with optional 'type' and 'to'",
+        ));
+    };
+    let mut link_type = None;
+    let mut to = None;
+    for (key, v) in spec {
+        let key_path = join(path, key);
+        let slot = match key.as_str() {
+            "type" => &mut link_type,
+            "to" => &mut to,
+            _ => {
+                return Err(QueryError::new(
+                    &key_path,
+                    format!("unknown $linked key '{key}' (expected 'type' or 'to')"),
+                ))
+            }
+        };
+        let Some(s) = v.as_str() else {
+            return Err(QueryError::new(&key_path, "expected a string"));
+        };
+        *slot = Some(s.to_string());
+    }
+    Ok(Query::Link(LinkFilter::Linked { link_type, to }))
+}
+
+fn expect_flag(value: &Value, path: &str) -> Result<bool, QueryError> {
+    value
+        .as_bool()
+        .ok_or_else(|| QueryError::new(path, "expected a boolean"))
+}
+
+/// `{"customFields": {"team": "infra"}}` is shorthand for `{"customFields.team": "infra"}`.
+fn parse_custom_fields(value: &Value, path: &str) -> Result<Query, QueryError> {
+    let Some(fields) = value.as_object().filter(|m| !is_operator_map(m)) else {
+        return Err(QueryError::new(
+            path,
+            "customFields expects an object of field conditions",
+        ));
+    };
+    let clauses = fields
+        .iter()
+        .map(|(name, cond)| parse_field(&format!("customFields.{name}"), cond, &join(path, name)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(conjunction(clauses))
+}
+
+fn parse_field(field: &str, value: &Value, path: &str) -> Result<Query, QueryError> {
+    match value {
+        Value::Object(ops) if is_operator_map(ops) => parse_operators(field, ops, path),
+        Value::Object(_)
+        | Value::Null
+        | Value::Bool(_)
+        | Value::Number(_)
+        | Value::String(_)
+        | Value::Array(_) => Ok(Query::Field {
+            field: field.to_string(),
+            op: Operator::Eq(value.clone()),
+        }),
+    }
+}
+
+fn parse_operators(field: &str, ops: &Map<String, Value>, path: &str) -> Result<Query, QueryError> {
+    let regex_options = match ops.get("$options") {
+        None => None,
+        Some(_) if !ops.contains_key("$regex") => {
+            return Err(QueryError::new(
+                &join(path, "$options"),
+                "$options is only valid alongside $regex",
+            ))
+        }
+        Some(Value::String(s)) => Some(s.as_str()),
+        Some(_) => {
+            return Err(QueryError::new(
+                &join(path, "$options"),
+                "$options expects a string",
+            ))
+        }
+    };
+    let mut clauses = Vec::with_capacity(ops.len());
+    for (name, operand) in ops {
+        let op_path = join(path, name);
+        match name.as_str() {
+            "$options" => {}
+            "$not" => {
+                let Some(inner) = operand.as_object().filter(|m| is_operator_map(m)) else {
+                    return Err(QueryError::new(&op_path, "$not expects an operator object"));
+                };
+                let negated = parse_operators(field, inner, &op_path)?;
+                clauses.push(Query::Not(Box::new(negated)));
+            }
+            _ => clauses.push(Query::Field {
+                field: field.to_string(),
+                op: parse_operator(name, operand, &op_path, regex_options)?,
+            }),
+        }
+    }
+    Ok(conjunction(clauses))
+}
+
+fn parse_operator(
+    name: &str,
+    operand: &Value,
+    path: &str,
+    regex_options: Option<&str>,
+) -> Result<Operator, QueryError> {
+    Ok(match name {
+        "$eq" => Operator::Eq(operand.clone()),
+        "$ne" => Operator::Ne(operand.clone()),
+        "$in" => Operator::In(expect_array(operand, path)?),
+        "$nin" => Operator::Nin(expect_array(operand, path)?),
+        "$all" => Operator::All(expect_array(operand, path)?),
+        "$gt" => Operator::Gt(expect_bound(operand, path)?),
+        "$gte" => Operator::Gte(expect_bound(operand, path)?),
+        "$lt" => Operator::Lt(expect_bound(operand, path)?),
+        "$lte" => Operator::Lte(expect_bound(operand, path)?),
+        "$exists" => Operator::Exists(
+            operand
+                .as_bool()
+                .ok_or_else(|| QueryError::new(path, "$exists expects a boolean"))?,
+        ),
+        "$regex" => Operator::Regex(build_regex(operand, regex_options, path)?),
+        "$contains" => Operator::Contains(expect_scalar(operand, path)?),
+        _ => return Err(QueryError::new(path, format!("unknown operator '{name}'"))),
+    })
+}
+
+fn expect_array(operand: &Value, path: &str) -> Result<Vec<Value>, QueryError> {
+    operand
+        .as_array()
+        .cloned()
+        .ok_or_else(|| QueryError::new(path, "expected an array"))
+}
+
+fn expect_comparable(operand: &Value, path: &str) -> Result<Value, QueryError> {
+    match operand {
+        Value::Number(_) | Value::String(_) => Ok(operand.clone()),
+        Value::Null | Value::Bool(_) | Value::Array(_) | Value::Object(_) => {
+            Err(QueryError::new(path, "expected a number or a string"))
+        }
+    }
+}
+
+/// A range bound: a number or string, with relative date expressions such
+/// as `-7d` or `startOfWeek` resolved to a timestamp against the local clock.
+fn expect_bound(operand: &Value, path: &str) -> Result<Value, QueryError> {
+    let value = expect_comparable(operand, path)?;
+    let Some(expr) = value.as_str() else {
+        return Ok(value);
+    };
+    match resolve_relative_date(expr, &Local::now()) {
+        Ok(Some(date)) => Ok(Value::String(format_date(&date))),
+        Ok(None) => Ok(value),
+        Err(message) => Err(QueryError::new(path, message)),
+    }
+}
+
+fn expect_scalar(operand: &Value, path: &str) -> Result<Value, QueryError> {
+    match operand {
+        Value::Number(_) | Value::String(_) | Value::Bool(_) => Ok(operand.clone()),
+        Value::Null | Value::Array(_) | Value::Object(_) => Err(QueryError::new(
+            path,
+            "expected a string, number or boolean",
+        )),
+    }
+}
+
+fn build_regex(
+    operand: &Value,
+    options: Option<&str>,
+    path: &str,
+) -> Result<regex::Regex, QueryError> {
+    let Some(pattern) = operand.as_str() else {
+        return Err(QueryError::new(path, "$regex expects a string pattern"));
+    };
+    let flags = options.unwrap_or_default();
+    if let Some(flag) = flags.chars().find(|c| !matches!(c, 'i' | 'm' | 's' | 'x')) {
+        return Err(QueryError::new(
+            path,
+            format!("unsupported $options flag '{flag}'"),
+        ));
+    }
+    // Options become inline flags so the compiled pattern carries them.
+    let full = if flags.is_empty() {
+        pattern.to_string()
+    } else {
+        format!("(?{flags}){pattern}")
+    };
+    Regex::new(&full).map_err(|e| QueryError::new(path, format!("invalid regular expression: {e}")))
+}
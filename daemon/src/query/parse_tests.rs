@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn test_empty_filter_matches_all() {
+    assert!(matches!(parse_filter_json("").unwrap(), Query::All));
+    assert!(matches!(parse_filter_json("{}").unwrap(), Query::All));
+}
+
+#[test]
+fn test_literal_value_is_eq() {
+    let q = parse_filter_json(r#"{"status":"open"}"#).unwrap();
+    assert!(matches!(&q, Query::Field { field, op: Operator::Eq(_) } if field == "status"));
+}
+
+#[test]
+fn test_multiple_keys_are_conjunction() {
+    let q = parse_filter_json(r#"{"status":"open","priority":{"$lte":2}}"#).unwrap();
+    assert!(matches!(&q, Query::And(clauses) if clauses.len() == 2));
+}
+
+#[test]
+fn test_logical_operators() {
+    let q = parse_filter_json(
+        r#"{"$or":[{"tags":"backend"},{"customFields.team":"infra"}],"$not":{"status":"closed"}}"#,
+    )
+    .unwrap();
+    let Query::And(clauses) = q else {
+        panic!("expected a conjunction");
+    };
+    assert_eq!(clauses.len(), 2);
+    assert!(clauses
+        .iter()
+        .any(|c| matches!(c, Query::Or(inner) if inner.len() == 2)));
+    assert!(clauses.iter().any(|c| matches!(c, Query::Not(_))));
+}
+
+#[test]
+fn test_custom_fields_shorthand() {
+    let q = parse_filter_json(r#"{"customFields":{"team":"infra"}}"#).unwrap();
+    assert!(matches!(&q, Query::Field { field, .. } if field == "customFields.team"));
+}
+
+#[test]
+fn test_field_level_not() {
+    let q = parse_filter_json(r#"{"priority":{"$not":{"$gt":2}}}"#).unwrap();
+    assert!(matches!(q, Query::Not(_)));
+}
+
+#[test]
+fn test_malformed_json_is_error() {
+    let err = parse_filter_json("not-json").unwrap_err();
+    assert_eq!(err.path, "$");
+}
+
+#[test]
+fn test_unknown_operator_reports_path() {
+    let err = parse_filter_json(r#"{"$or":[{"priority":{"$between":[1,2]}}]}"#).unwrap_err();
+    assert_eq!(err.path, "$or[0].priority.$between");
+}
+
+#[test]
+fn test_unknown_top_level_operator() {
+    let err = parse_filter_json(r#"{"$nor":[{"status":"open"}]}"#).unwrap_err();
+    assert_eq!(err.path, "$nor");
+}
+
+#[test]
+fn test_operand_type_errors() {
+    assert!(parse_filter_json(r#"{"status":{"$in":"open"}}"#).is_err());
+    assert!(parse_filter_json(r#"{"deletedAt":{"$exists":"yes"}}"#).is_err());
+    assert!(parse_filter_json(r#"{"priority":{"$gt":[1]}}"#).is_err());
+    assert!(parse_filter_json(r#"{"$and":[]}"#).is_err());
+    assert!(parse_filter_json(r#"{"title":{"$regex":"("}}"#).is_err());
+    assert!(parse_filter_json(r#"{"title":{"$options":"i"}}"#).is_err());
+}
@@ -0,0 +1,165 @@
+use super::types::{Operator, Query};
+use mdstore::Filters;
+use serde_json::Value;
+
+/// The part of a query that `mdstore::list` can evaluate on its own.
+pub struct Pushdown {
+    pub filters: Filters,
+    /// The clauses `filters` does not capture exactly; these still have to
+    /// be evaluated in memory. [`Query::All`] when nothing is left.
+    pub residual: Query,
+    /// `true` when the filters select exactly the items the query matches,
+    /// so limit/offset may be pushed down as well.
+    pub complete: bool,
+}
+
+/// Derive `mdstore::Filters` from a query.
+///
+/// Only top-level conjuncts on `status`, `priority` and `tags` are pushed
+/// down. The result never excludes an item the query would match; callers
+/// still evaluate the full query in memory unless `complete` is set.
+#[must_use]
+pub fn pushdown(query: &Query) -> Pushdown {
+    let mut filters = Filters::new();
+    if references_field(query, "deletedAt") || references_field(query, "deleted_at") {
+        filters = filters.include_deleted();
+    }
+    let mut conjuncts = Vec::new();
+    collect_conjuncts(query, &mut conjuncts);
+    let mut leftover = Vec::new();
+    for clause in conjuncts {
+        let exact = match clause {
+            Query::Field { field, op } => {
+                let (next, exact) = push_field(filters, field, op);
+                filters = next;
+                exact
+            }
+            Query::All => true,
+            Query::And(_) | Query::Or(_) | Query::Not(_) | Query::Link(_) => false,
+        };
+        if !exact {
+            leftover.push(clause.clone());
+        }
+    }
+    let complete = leftover.is_empty();
+    let residual = match leftover.len() {
+        0 => Query::All,
+        1 => leftover.pop().unwrap_or(Query::All),
+        _ => Query::And(leftover),
+    };
+    Pushdown {
+        filters,
+        residual,
+        complete,
+    }
+}
+
+/// The top-level clauses of a query, with nested `$and`s flattened.
+fn collect_conjuncts<'query>(query: &'query Query, out: &mut Vec<&'query Query>) {
+    match query {
+        Query::All => {}
+        Query::And(clauses) => {
+            for clause in clauses {
+                collect_conjuncts(clause, out);
+            }
+        }
+        Query::Or(_) | Query::Not(_) | Query::Field { .. } | Query::Link(_) => out.push(query),
+    }
+}
+
+/// Whether any clause of the query mentions `field`.
+fn references_field(query: &Query, name: &str) -> bool {
+    match query {
+        Query::All | Query::Link(_) => false,
+        Query::And(clauses) | Query::Or(clauses) => {
+            clauses.iter().any(|q| references_field(q, name))
+        }
+        Query::Not(inner) => references_field(inner, name),
+        Query::Field { field, .. } => field == name,
+    }
+}
+
+fn strings(values: &[Value]) -> Option<Vec<String>> {
+    let out: Vec<String> = values
+        .iter()
+        .map(|v| v.as_str().map(str::to_string))
+        .collect::<Option<_>>()?;
+    (!out.is_empty()).then_some(out)
+}
+
+fn clamp_u32(v: u64) -> u32 {
+    u32::try_from(v).unwrap_or(u32::MAX)
+}
+
+/// Push one field condition down. Returns the updated filters and whether the
+/// condition was captured exactly.
+fn push_field(filters: Filters, field: &str, op: &Operator) -> (Filters, bool) {
+    match (field, op) {
+        // A field can only be pushed once; later conditions on it stay in memory.
+        ("status", _) if filters.statuses.is_some() => (filters, false),
+        ("tags", Operator::Eq(_) | Operator::In(_)) if filters.tags_any.is_some() => {
+            (filters, false)
+        }
+        ("tags", Operator::All(_)) if filters.tags_all.is_some() => (filters, false),
+        ("status", Operator::Eq(Value::String(s))) => {
+            (filters.with_statuses(vec![s.clone()]), true)
+        }
+        ("status", Operator::In(values)) => match strings(values) {
+            Some(statuses) => (filters.with_statuses(statuses), true),
+            None => (filters, false),
+        },
+        ("tags", Operator::Eq(Value::String(s))) => (filters.with_tags_any(vec![s.clone()]), true),
+        ("tags", Operator::In(values)) => match strings(values) {
+            Some(tags) => (filters.with_tags_any(tags), true),
+            None => (filters, false),
+        },
+        ("tags", Operator::All(values)) => match strings(values) {
+            Some(tags) => (filters.with_tags_all(tags), true),
+            None => (filters, false),
+        },
+        ("priority", _) => match priority_bound(op) {
+            Some(PriorityBound::Exact(p)) if filters.priority.is_none() => {
+                (filters.with_priority(p), true)
+            }
+            Some(PriorityBound::AtMost(p)) if filters.priority_lte.is_none() => {
+                (filters.with_priority_lte(p), true)
+            }
+            Some(PriorityBound::AtLeast(p)) if filters.priority_gte.is_none() => {
+                (filters.with_priority_gte(p), true)
+            }
+            Some(
+                PriorityBound::Exact(_) | PriorityBound::AtMost(_) | PriorityBound::AtLeast(_),
+            )
+            | None => (filters, false),
+        },
+        _ => (filters, false),
+    }
+}
+
+enum PriorityBound {
+    Exact(u32),
+    AtMost(u32),
+    AtLeast(u32),
+}
+
+fn priority_bound(op: &Operator) -> Option<PriorityBound> {
+    match op {
+        Operator::Eq(v) => v.as_u64().map(|p| PriorityBound::Exact(clamp_u32(p))),
+        Operator::Lte(v) => v.as_u64().map(|p| PriorityBound::AtMost(clamp_u32(p))),
+        Operator::Lt(v) => v
+            .as_u64()
+            .and_then(|p| p.checked_sub(1))
+            .map(|p| PriorityBound::AtMost(clamp_u32(p))),
+        Operator::Gte(v) => v.as_u64().map(|p| PriorityBound::AtLeast(clamp_u32(p))),
+        Operator::Gt(v) => v
+            .as_u64()
+            .map(|p| PriorityBound::AtLeast(clamp_u32(p.saturating_add(1)))),
+        Operator::Ne(_)
+        | Operator::In(_)
+        | Operator::Nin(_)
+        | Operator::All(_)
+        | Operator::Exists(_)
+        | Operator::Regex(_)
+        | Operator::Contains(_) => None,
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+
+fn push(filter: &str) -> Pushdown {
+    pushdown(&parse_filter_json(filter).unwrap())
+}
+
+#[test]
+fn test_simple_conjunction_is_complete() {
+    let p = push(r#"{"status":{"$in":["open","in-progress"]},"priority":{"$lte":2}}"#);
+    assert!(p.complete);
+    assert_eq!(
+        p.filters.statuses,
+        Some(vec!["open".to_string(), "in-progress".to_string()])
+    );
+    assert_eq!(p.filters.priority_lte, Some(2));
+}
+
+#[test]
+fn test_strict_bounds_are_adjusted() {
+    let p = push(r#"{"priority":{"$lt":3,"$gt":0}}"#);
+    assert!(p.complete);
+    assert_eq!(p.filters.priority_lte, Some(2));
+    assert_eq!(p.filters.priority_gte, Some(1));
+}
+
+#[test]
+fn test_disjunction_is_not_pushed() {
+    let p = push(r#"{"$or":[{"status":"open"},{"priority":1}]}"#);
+    assert!(!p.complete);
+    assert!(p.filters.statuses.is_none());
+    assert!(p.filters.priority.is_none());
+}
+
+#[test]
+fn test_partial_pushdown() {
+    let p = push(r#"{"status":"open","customFields.team":"infra"}"#);
+    assert!(!p.complete);
+    assert_eq!(p.filters.statuses, Some(vec!["open".to_string()]));
+}
+
+#[test]
+fn test_deleted_at_includes_deleted_items() {
+    let p = push(r#"{"$or":[{"deletedAt":{"$exists":true}},{"status":"open"}]}"#);
+    assert!(p.filters.include_deleted);
+    assert!(!p.complete);
+}
+
+#[test]
+fn test_nested_conjunctions_are_flattened() {
+    let query = Query::And(vec![
+        parse_filter_json(r#"{"status":"open"}"#).unwrap(),
+        Query::All,
+        parse_filter_json(r#"{"priority":1}"#).unwrap(),
+    ]);
+    let p = pushdown(&query);
+    assert!(p.complete);
+    assert_eq!(p.filters.statuses, Some(vec!["open".to_string()]));
+    assert_eq!(p.filters.priority, Some(1));
+}
+
+#[test]
+fn test_residual_holds_what_was_not_pushed() {
+    let p = push(r#"{"status":"open","title":{"$regex":"^fix"}}"#);
+    assert!(!p.complete);
+    assert_eq!(p.filters.statuses, Some(vec!["open".to_string()]));
+    assert!(matches!(&p.residual, Query::Field { field, .. } if field == "title"));
+
+    let p = push(r#"{"status":"open"}"#);
+    assert!(matches!(p.residual, Query::All));
+}
+
+#[test]
+fn test_repeated_field_is_pushed_once() {
+    let p = push(r#"{"$and":[{"status":"open"},{"status":{"$in":["open","closed"]}}]}"#);
+    assert!(!p.complete);
+    assert_eq!(p.filters.statuses, Some(vec!["open".to_string()]));
+}
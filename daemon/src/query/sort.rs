@@ -0,0 +1,170 @@
+use super::compare::parse_timestamp;
+use super::field::field_value;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// One `sort` key: a field name and a direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: String,
+    pub descending: bool,
+}
+
+/// Parse a `sort` string such as `"-priority,createdAt"`.
+///
+/// Keys are comma separated and use the same field names as filters. A
+/// leading `-` sorts that key descending; `+` or no prefix sorts ascending.
+#[must_use]
+pub fn parse_sort(spec: &str) -> Vec<SortKey> {
+    spec.split(',')
+        .map(str::trim)
+        .filter_map(|part| {
+            let (descending, rest) = part.strip_prefix('-').map_or_else(
+                || (false, part.strip_prefix('+').unwrap_or(part)),
+                |rest| (true, rest),
+            );
+            let name = rest.trim();
+            (!name.is_empty()).then(|| SortKey {
+                field: name.to_string(),
+                descending,
+            })
+        })
+        .collect()
+}
+
+/// Render sort keys back into their canonical `sort` string.
+#[must_use]
+pub fn format_sort(keys: &[SortKey]) -> String {
+    keys.iter()
+        .map(|key| {
+            if key.descending {
+                format!("-{}", key.field)
+            } else {
+                key.field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Sort items in place by the given keys.
+///
+/// `statuses` is the item type's declared status order, used for `status`.
+pub fn sort_items(items: &mut [mdstore::Item], keys: &[SortKey], statuses: &[String]) {
+    if !keys.is_empty() {
+        items.sort_by(|a, b| compare_items(a, b, keys, statuses));
+    }
+}
+
+/// Compare two items by the sort keys, falling back to their ids.
+#[must_use]
+pub fn compare_items(
+    a: &mdstore::Item,
+    b: &mdstore::Item,
+    keys: &[SortKey],
+    statuses: &[String],
+) -> Ordering {
+    compare_positions(
+        &sort_values(a, keys, statuses),
+        &a.id,
+        &sort_values(b, keys, statuses),
+        &b.id,
+        keys,
+    )
+}
+
+/// The values an item is ordered by, one per key (`None` when missing).
+///
+/// `title` is lowercased so it compares case-insensitively; `status` becomes
+/// its position in `statuses`, with undeclared statuses last.
+#[must_use]
+pub fn sort_values(
+    item: &mdstore::Item,
+    keys: &[SortKey],
+    statuses: &[String],
+) -> Vec<Option<Value>> {
+    keys.iter()
+        .map(|key| sort_value(item, &key.field, statuses))
+        .collect()
+}
+
+/// Compare two positions in the sort order, each given as its sort values and id.
+///
+/// Missing values sort after present ones, in either direction.
+#[must_use]
+pub fn compare_positions(
+    a_values: &[Option<Value>],
+    a_id: &str,
+    b_values: &[Option<Value>],
+    b_id: &str,
+    keys: &[SortKey],
+) -> Ordering {
+    keys.iter()
+        .zip(a_values.iter().zip(b_values))
+        .map(|(key, (a, b))| compare_key(a.as_ref(), b.as_ref(), key.descending))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or_else(|| a_id.cmp(b_id))
+}
+
+fn compare_key(a: Option<&Value>, b: Option<&Value>, descending: bool) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(left), Some(right)) => {
+            let ord = sort_order(left, right);
+            if descending {
+                ord.reverse()
+            } else {
+                ord
+            }
+        }
+    }
+}
+
+/// A total order over sort values: values are ranked by kind first (null,
+/// bool, number, timestamp, other string, then lists and objects) and
+/// compared within their kind.
+///
+/// Unlike filter comparisons, numeric strings are not coerced to numbers;
+/// mixing the two would not give a consistent order.
+fn sort_order(a: &Value, b: &Value) -> Ordering {
+    kind_rank(a).cmp(&kind_rank(b)).then_with(|| match (a, b) {
+        (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+        (Value::Number(x), Value::Number(y)) => x
+            .as_f64()
+            .unwrap_or_default()
+            .total_cmp(&y.as_f64().unwrap_or_default()),
+        (Value::String(x), Value::String(y)) => match (parse_timestamp(x), parse_timestamp(y)) {
+            (Some(x_time), Some(y_time)) => x_time.cmp(&y_time),
+            _ => x.cmp(y),
+        },
+        _ => a.to_string().cmp(&b.to_string()),
+    })
+}
+
+fn kind_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(text) if parse_timestamp(text).is_some() => 3,
+        Value::String(_) => 4,
+        Value::Array(_) | Value::Object(_) => 5,
+    }
+}
+
+fn sort_value(item: &mdstore::Item, field: &str, statuses: &[String]) -> Option<Value> {
+    match field {
+        "status" => {
+            let status = item.frontmatter.status.as_deref()?;
+            let rank = statuses
+                .iter()
+                .position(|s| s == status)
+                .unwrap_or(statuses.len());
+            Some(Value::from(rank))
+        }
+        "title" => Some(Value::String(item.title.to_lowercase())),
+        _ => field_value(item, field),
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn item(id: &str, title: &str, status: Option<&str>, priority: Option<u32>) -> mdstore::Item {
+    let mut custom_fields = HashMap::new();
+    if priority == Some(1) {
+        custom_fields.insert("team".to_string(), serde_json::json!("infra"));
+    }
+    mdstore::Item {
+        id: id.to_string(),
+        title: title.to_string(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: status.map(str::to_string),
+            priority,
+            created_at: format!("2024-01-0{}T00:00:00Z", id.len()),
+            updated_at: String::new(),
+            deleted_at: None,
+            tags: None,
+            custom_fields,
+        },
+        comment: None,
+    }
+}
+
+fn ids(items: &[mdstore::Item]) -> Vec<&str> {
+    items.iter().map(|i| i.id.as_str()).collect()
+}
+
+fn statuses() -> Vec<String> {
+    vec![
+        "open".to_string(),
+        "in-progress".to_string(),
+        "closed".to_string(),
+    ]
+}
+
+#[test]
+fn test_parse_sort() {
+    assert_eq!(
+        parse_sort(" -priority, createdAt,+title,,-"),
+        vec![
+            SortKey {
+                field: "priority".to_string(),
+                descending: true
+            },
+            SortKey {
+                field: "createdAt".to_string(),
+                descending: false
+            },
+            SortKey {
+                field: "title".to_string(),
+                descending: false
+            },
+        ]
+    );
+    assert!(parse_sort("").is_empty());
+}
+
+#[test]
+fn test_sort_by_status_follows_config_order() {
+    let mut items = vec![
+        item("a", "A", Some("closed"), None),
+        item("b", "B", Some("open"), None),
+        item("c", "C", Some("in-progress"), None),
+        item("d", "D", Some("unknown"), None),
+    ];
+    sort_items(&mut items, &parse_sort("status"), &statuses());
+    assert_eq!(ids(&items), vec!["b", "c", "a", "d"]);
+}
+
+#[test]
+fn test_multiple_keys_and_directions() {
+    let mut items = vec![
+        item("a", "beta", Some("open"), Some(2)),
+        item("b", "Alpha", Some("open"), Some(2)),
+        item("c", "gamma", Some("closed"), Some(1)),
+    ];
+    sort_items(&mut items, &parse_sort("-priority,title"), &statuses());
+    assert_eq!(ids(&items), vec!["b", "a", "c"]);
+}
+
+#[test]
+fn test_missing_values_sort_last_in_both_directions() {
+    let mut items = vec![
+        item("a", "A", None, None),
+        item("b", "B", None, Some(3)),
+        item("c", "C", None, Some(1)),
+    ];
+    sort_items(&mut items, &parse_sort("priority"), &statuses());
+    assert_eq!(ids(&items), vec!["c", "b", "a"]);
+    sort_items(&mut items, &parse_sort("-priority"), &statuses());
+    assert_eq!(ids(&items), vec!["b", "c", "a"]);
+}
+
+#[test]
+fn test_sort_by_date_and_custom_field() {
+    let mut items = vec![
+        item("ccc", "C", None, Some(2)),
+        item("a", "A", None, Some(1)),
+        item("bb", "B", None, Some(2)),
+    ];
+    sort_items(&mut items, &parse_sort("-createdAt"), &statuses());
+    assert_eq!(ids(&items), vec!["ccc", "bb", "a"]);
+    sort_items(&mut items, &parse_sort("customFields.team"), &statuses());
+    assert_eq!(ids(&items), vec!["a", "bb", "ccc"]);
+}
+
+#[test]
+fn test_sort_by_mixed_custom_field_is_a_total_order() {
+    let values = [
+        ("five", json!(5u32)),
+        ("ten", json!("10")),
+        ("three", json!("3")),
+        ("abc", json!("abc")),
+        ("day", json!("2024-01-01")),
+    ];
+    let mut items: Vec<mdstore::Item> = values
+        .iter()
+        .map(|(id, value)| {
+            let mut item = item(id, id, None, None);
+            item.frontmatter
+                .custom_fields
+                .insert("rank".to_string(), value.clone());
+            item
+        })
+        .collect();
+    let expected = vec!["five", "day", "ten", "three", "abc"];
+    for _ in 0..values.len() {
+        items.rotate_left(1);
+        sort_items(&mut items, &parse_sort("customFields.rank"), &statuses());
+        assert_eq!(ids(&items), expected);
+    }
+    sort_items(&mut items, &parse_sort("-customFields.rank"), &statuses());
+    assert_eq!(ids(&items), vec!["abc", "three", "ten", "day", "five"]);
+}
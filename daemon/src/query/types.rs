@@ -0,0 +1,53 @@
+use regex::Regex;
+use serde_json::Value;
+
+/// A parsed MQL filter document.
+#[derive(Debug, Clone)]
+pub enum Query {
+    /// Matches every item (the empty filter).
+    All,
+    /// Every sub-query must match (`$and`, or several keys in one document).
+    And(Vec<Query>),
+    /// At least one sub-query must match (`$or`).
+    Or(Vec<Query>),
+    /// The sub-query must not match (`$not`).
+    Not(Box<Query>),
+    /// A single operator applied to one field, e.g. `{"priority": {"$lte": 2}}`.
+    Field { field: String, op: Operator },
+    /// A condition on the item's links. It must be resolved against the
+    /// project's links with [`resolve_links`](super::resolve_links) before
+    /// evaluation; an unresolved link condition matches nothing.
+    Link(LinkFilter),
+}
+
+/// A condition on the links between items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkFilter {
+    /// `{"$linked": {"type": "child-of", "to": "<id>"}}`: the item has a link
+    /// of `type` (from its own perspective) to `to`. Both keys are optional.
+    Linked {
+        link_type: Option<String>,
+        to: Option<String>,
+    },
+    /// `{"hasLinks": bool}`: the item is on either side of at least one link.
+    HasLinks(bool),
+    /// `{"$blocked": bool}`: the item has a blocker that is not closed.
+    Blocked(bool),
+}
+
+/// A comparison applied to the value of a single field.
+#[derive(Debug, Clone)]
+pub enum Operator {
+    Eq(Value),
+    Ne(Value),
+    In(Vec<Value>),
+    Nin(Vec<Value>),
+    All(Vec<Value>),
+    Gt(Value),
+    Gte(Value),
+    Lt(Value),
+    Lte(Value),
+    Exists(bool),
+    Regex(Regex),
+    Contains(Value),
+}
@@ -1,4 +1,5 @@
 mod file_ops;
+mod post_merge_hook;
 mod types;
 use super::managed_files::get_managed_files;
 use super::plan::build_reconciliation_plan;
@@ -8,7 +9,9 @@ use crate::config::item_type_config::{
 use crate::config::{read_config, write_config, CentyConfig};
 use crate::manifest::{create_manifest, read_manifest, update_manifest, write_manifest};
 use crate::utils::get_centy_path;
+use crate::view::seed_default_views;
 use file_ops::{create_file, merge_file};
+use post_merge_hook::install_post_merge_hook;
 use std::path::Path;
 use tokio::fs;
 pub use types::{ExecuteError, ReconciliationDecisions, ReconciliationResult};
@@ -32,6 +35,13 @@ pub async fn execute_reconciliation(
         create_file(&centy_path, &file_info.path, &managed_templates).await?;
         result.created.push(file_info.path.clone());
     }
+    // Seed default views only when the folder is new, so views the user
+    // deleted are not brought back.
+    if plan.to_create.iter().any(|f| f.path == "views/") {
+        result
+            .created
+            .extend(seed_default_views(project_path).await?);
+    }
     for file_info in &plan.to_restore {
         if force || decisions.restore.contains(&file_info.path) {
             create_file(&centy_path, &file_info.path, &managed_templates).await?;
@@ -70,6 +80,7 @@ pub async fn execute_reconciliation(
     update_manifest(&mut manifest);
     write_manifest(project_path, &manifest).await?;
     result.manifest = manifest;
+    install_post_merge_hook(project_path).await;
     Ok(result)
 }
 #[cfg(test)]
@@ -85,5 +96,8 @@ mod execute_hooks_yaml_tests;
 #[path = "../file_ops_tests.rs"]
 mod file_ops_tests;
 #[cfg(test)]
+#[path = "../post_merge_hook_tests.rs"]
+mod post_merge_hook_tests;
+#[cfg(test)]
 #[path = "../execute_tests.rs"]
 mod tests;
@@ -0,0 +1,98 @@
+use git2::Repository;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Second line of a hook centy wrote, so further projects may add to it.
+const MARKER: &str = "# Added by centy: renumber duplicate issue display numbers after a merge";
+
+/// Make sure the repository's `post-merge` hook runs `centy-daemon
+/// post-merge` for this project, so display numbers that collide after a
+/// plain `git merge` are renumbered.
+///
+/// Projects outside a git repository have nothing to install. A hook centy
+/// did not write is left alone with a warning. Failures are logged; they
+/// never fail reconciliation.
+pub async fn install_post_merge_hook(project_path: &Path) {
+    let project = project_path.to_path_buf();
+    match tokio::task::spawn_blocking(move || ensure_post_merge_hook(&project)).await {
+        Ok(Ok(Some(hook))) => info!(hook = %hook.display(), "Installed the centy post-merge hook"),
+        Ok(Ok(None)) => {}
+        Ok(Err(e)) => warn!(error = %e, "Failed to install the centy post-merge hook"),
+        Err(e) => warn!(error = %e, "Post-merge hook task failed"),
+    }
+}
+
+/// Add this project's line to the hook; returns the hook's path when it
+/// was written.
+pub(super) fn ensure_post_merge_hook(project_path: &Path) -> std::io::Result<Option<PathBuf>> {
+    let Ok(repo) = Repository::discover(project_path) else {
+        return Ok(None);
+    };
+    let Some(workdir) = repo.workdir() else {
+        return Ok(None);
+    };
+    let Ok(relative) = project_path
+        .canonicalize()?
+        .strip_prefix(workdir.canonicalize()?)
+        .map(Path::to_path_buf)
+    else {
+        return Ok(None);
+    };
+    let hook = hooks_dir(&repo, workdir).join("post-merge");
+    let line = hook_line(&relative);
+    let content = match std::fs::read_to_string(&hook) {
+        Ok(existing) if existing.lines().any(|l| l == line) => return Ok(None),
+        Ok(existing) if existing.lines().any(|l| l == MARKER) => {
+            let separator = if existing.ends_with('\n') { "" } else { "\n" };
+            format!("{existing}{separator}{line}\n")
+        }
+        Ok(_) => {
+            warn!(
+                hook = %hook.display(),
+                "A post-merge hook already exists; add `{line}` to it to renumber issues after merges"
+            );
+            return Ok(None);
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            format!("#!/bin/sh\n{MARKER}\n{line}\n")
+        }
+        Err(e) => return Err(e),
+    };
+    if let Some(dir) = hook.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&hook, content)?;
+    make_executable(&hook)?;
+    Ok(Some(hook))
+}
+
+/// `core.hooksPath` when set (relative to the work tree), otherwise the
+/// repository's own `hooks` folder, shared by all its worktrees.
+fn hooks_dir(repo: &Repository, workdir: &Path) -> PathBuf {
+    repo.config()
+        .and_then(|config| config.get_path("core.hooksPath"))
+        .map_or_else(
+            |_| repo.commondir().join("hooks"),
+            |path| workdir.join(path),
+        )
+}
+
+/// The hook line for a project at `relative` to the work tree root, which
+/// is where git runs hooks.
+fn hook_line(relative: &Path) -> String {
+    let shown = relative.to_string_lossy();
+    let path = if shown.is_empty() { ".".into() } else { shown };
+    format!("centy-daemon post-merge '{}'", path.replace('\'', "'\\''"))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt as _;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+const fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
@@ -12,6 +12,8 @@ pub enum ExecuteError {
     PlanError(#[from] super::super::plan::PlanError),
     #[error("Config error: {0}")]
     ConfigError(#[from] mdstore::ConfigError),
+    #[error("View error: {0}")]
+    ViewError(#[from] crate::view::ViewError),
 }
 /// User decisions for reconciliation
 #[derive(Debug, Clone, Default)]
@@ -110,3 +110,27 @@ async fn test_execute_reconciliation_creates_config_json() {
         "config.json should not contain hooks key (hooks moved to hooks.yaml)"
     );
 }
+
+#[tokio::test]
+async fn test_execute_reconciliation_seeds_default_views_once() {
+    use tempfile::tempdir;
+    let temp_dir = tempdir().expect("Should create temp dir");
+    let result = execute_reconciliation(temp_dir.path(), ReconciliationDecisions::default(), false)
+        .await
+        .expect("Should execute reconciliation");
+    assert!(result.created.contains(&"views/".to_string()));
+    assert!(result
+        .created
+        .contains(&"views/open-issues.yaml".to_string()));
+    let views_path = temp_dir.path().join(".centy").join("views");
+    assert!(views_path.join("high-priority.yaml").is_file());
+
+    // A default view the user deleted stays deleted.
+    fs::remove_file(views_path.join("high-priority.yaml"))
+        .await
+        .expect("Should delete view");
+    execute_reconciliation(temp_dir.path(), ReconciliationDecisions::default(), false)
+        .await
+        .expect("Should execute second time");
+    assert!(!views_path.join("high-priority.yaml").exists());
+}
@@ -4,8 +4,8 @@ use super::*;
 fn test_get_managed_files_count() {
     let files = get_managed_files();
 
-    // 8 directories + 6 files = 14 total
-    assert_eq!(files.len(), 14);
+    // 9 directories + 7 files = 16 total
+    assert_eq!(files.len(), 16);
 }
 
 #[test]
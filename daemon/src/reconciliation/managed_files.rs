@@ -8,7 +8,8 @@ mod managed_files_content_alt;
 mod managed_files_merge;
 use managed_files_content::{ISSUES_README_CONTENT, README_CONTENT};
 use managed_files_content_alt::{
-    CSPELL_JSON_CONTENT, GITIGNORE_CONTENT, HOOKS_YAML_CONTENT, TEMPLATES_README_CONTENT,
+    CSPELL_JSON_CONTENT, GITATTRIBUTES_CONTENT, GITIGNORE_CONTENT, HOOKS_YAML_CONTENT,
+    TEMPLATES_README_CONTENT,
 };
 pub use managed_files_merge::{merge_json_content, merge_lines_content};
 /// Strategy for how a managed file should be updated when it already exists
@@ -61,6 +62,7 @@ pub fn get_managed_files() -> HashMap<String, ManagedFileTemplate> {
     dir(&mut files, "templates/");
     dir(&mut files, "templates/issues/");
     dir(&mut files, "templates/docs/");
+    dir(&mut files, "views/");
     file(&mut files, "README.md", README_CONTENT, None);
     file(&mut files, "issues/README.md", ISSUES_README_CONTENT, None);
     file(
@@ -82,6 +84,12 @@ pub fn get_managed_files() -> HashMap<String, ManagedFileTemplate> {
         GITIGNORE_CONTENT,
         Some(MergeStrategy::LineEnsureMerge),
     );
+    file(
+        &mut files,
+        ".gitattributes",
+        GITATTRIBUTES_CONTENT,
+        Some(MergeStrategy::LineEnsureMerge),
+    );
     files
 }
 #[cfg(test)]
@@ -13,6 +13,7 @@ fn test_get_managed_files_returns_expected_files() {
     assert!(files.contains_key("templates/issues/"));
     assert!(files.contains_key("templates/docs/"));
     assert!(files.contains_key("comments/"));
+    assert!(files.contains_key("views/"));
 
     // Should have expected files
     assert!(files.contains_key("README.md"));
@@ -21,6 +22,7 @@ fn test_get_managed_files_returns_expected_files() {
     assert!(files.contains_key("cspell.json"));
     assert!(files.contains_key("hooks.yaml"));
     assert!(files.contains_key(".gitignore"));
+    assert!(files.contains_key(".gitattributes"));
 }
 
 #[test]
@@ -102,6 +104,18 @@ fn test_cspell_json_has_json_array_merge_strategy() {
     assert!(content.contains("centy"));
 }
 
+#[test]
+fn test_gitattributes_routes_items_to_merge_driver() {
+    let files = get_managed_files();
+    let attributes = &files[".gitattributes"];
+    assert_eq!(
+        attributes.merge_strategy,
+        Some(MergeStrategy::LineEnsureMerge)
+    );
+    let content = attributes.content.as_ref().unwrap();
+    assert!(content.lines().any(|l| l == "*.md merge=centy"));
+}
+
 #[test]
 fn test_hooks_yaml_no_merge_strategy() {
     let files = get_managed_files();
@@ -135,8 +149,8 @@ fn test_comments_directory_has_no_content() {
 #[test]
 fn test_managed_file_template_count() {
     let files = get_managed_files();
-    // Should have at least 8 directories + 6 files = 14 entries
-    assert!(files.len() >= 14);
+    // Should have at least 9 directories + 7 files = 16 entries
+    assert!(files.len() >= 16);
 }
 
 #[test]
@@ -23,6 +23,7 @@ Use the CLI commands below to interact with the centy system.
 - `assets/` - Shared assets
 - `archived/` - Archived items from any type
 - `templates/` - Custom templates for issues and docs
+- `views/` - Saved views (named filters and sorts for listing items)
 
 ## Getting Started
 
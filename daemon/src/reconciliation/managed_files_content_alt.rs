@@ -3,7 +3,7 @@ pub const HOOKS_YAML_CONTENT: &str = "\
 # This file is managed by Centy. Use the Centy CLI to modify it.\n\
 # Centy Hooks \u{2014} https://docs.centy.io/hooks\n\
 #\n\
-# Hooks run bash commands before or after item lifecycle events.\n\
+# Hooks run bash commands, or POST JSON to a url, before or after item lifecycle events.\n\
 # Pattern format: {item_type}.{event}  \u{2014} wildcards: *.created, issue.*, *.*\n\
 #\n\
 # Events (before): creating, updating, deleting, soft-deleting, restoring, moving, duplicating\n\
@@ -14,12 +14,22 @@ pub const HOOKS_YAML_CONTENT: &str = "\
 #     command: \"echo Issue created: $CENTY_ITEM_TITLE\"\n\
 #   - pattern: \"issue.creating\"\n\
 #     command: \"echo pre-create hook: $CENTY_ITEM_TYPE\"\n\
+#   - pattern: \"issue.updated\"\n\
+#     command: \"notify-closed.sh\"\n\
+#     when:\n\
+#       status: { from: open, to: closed }\n\
 #   - pattern: \"*.deleted\"\n\
 #     command: \"notify.sh\"\n\
 #     async: true\n\
 #     timeout: 10\n\
+#     retries: 5\n\
+#     backoff: 30\n\
 #   - pattern: \"*.*\"\n\
 #     command: \"audit-log.sh\"\n\
+#   - pattern: \"issue.created\"\n\
+#     url: \"https://example.com/centy\"\n\
+#     secret: \"${CENTY_WEBHOOK_SECRET}\"\n\
+#     retries: 3\n\
 ";
 
 /// `CSpell` configuration content
@@ -91,3 +101,12 @@ pub const TEMPLATES_README_CONTENT: &str = concat!(
 
 /// `.gitignore` content for the `.centy` folder
 pub const GITIGNORE_CONTENT: &str = "*.local.*\n";
+
+/// `.gitattributes` content for the `.centy` folder: item files go through
+/// the `centy-daemon merge-driver` and the journal keeps both sides' lines
+pub const GITATTRIBUTES_CONTENT: &str = "\
+# Register the driver with: git config merge.centy.driver \"centy-daemon merge-driver %O %A %B %P\"
+# Duplicate issue numbers are fixed by the post-merge hook centy installs (centy-daemon post-merge)
+*.md merge=centy
+journal.jsonl merge=union
+";
@@ -0,0 +1,69 @@
+#![allow(clippy::unwrap_used)]
+use super::post_merge_hook::ensure_post_merge_hook;
+use git2::Repository;
+
+fn hook_content(repo: &Repository) -> String {
+    std::fs::read_to_string(repo.path().join("hooks").join("post-merge")).unwrap()
+}
+
+#[test]
+fn test_installs_hook_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+
+    assert!(ensure_post_merge_hook(dir.path()).unwrap().is_some());
+    assert!(ensure_post_merge_hook(dir.path()).unwrap().is_none());
+
+    let content = hook_content(&repo);
+    assert!(content.starts_with("#!/bin/sh\n"));
+    assert_eq!(
+        content
+            .lines()
+            .filter(|l| *l == "centy-daemon post-merge '.'")
+            .count(),
+        1
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+        let hook = repo.path().join("hooks").join("post-merge");
+        let mode = std::fs::metadata(hook).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn test_adds_nested_projects_to_the_same_hook() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    let nested = dir.path().join("apps").join("web");
+    std::fs::create_dir_all(&nested).unwrap();
+
+    ensure_post_merge_hook(dir.path()).unwrap();
+    ensure_post_merge_hook(&nested).unwrap();
+
+    let content = hook_content(&repo);
+    assert!(content.lines().any(|l| l == "centy-daemon post-merge '.'"));
+    assert!(content
+        .lines()
+        .any(|l| l == "centy-daemon post-merge 'apps/web'"));
+}
+
+#[test]
+fn test_leaves_foreign_hook_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    let hook = repo.path().join("hooks").join("post-merge");
+    std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    std::fs::write(&hook, "#!/bin/sh\nmake deps\n").unwrap();
+
+    assert!(ensure_post_merge_hook(dir.path()).unwrap().is_none());
+    assert_eq!(hook_content(&repo), "#!/bin/sh\nmake deps\n");
+}
+
+#[test]
+fn test_does_nothing_outside_a_repository() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert!(ensure_post_merge_hook(dir.path()).unwrap().is_none());
+}
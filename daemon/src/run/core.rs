@@ -39,6 +39,14 @@ pub fn setup_logging(
     Ok(log_file)
 }
 
+/// Restart async hook deliveries the previous run left in the queue.
+pub async fn drain_hook_queue() {
+    let resumed = crate::hooks::queue::resume_pending().await;
+    if resumed > 0 {
+        info!("Resuming {} queued hook deliveries", resumed);
+    }
+}
+
 pub fn build_cors(origins: &[String]) -> CorsLayer {
     let cors_origins: Vec<String> = origins
         .iter()
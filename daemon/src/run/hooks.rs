@@ -0,0 +1,108 @@
+use crate::app::{HookTestArgs, HooksArgs, HooksCommand};
+use crate::hooks::{dry_run_hooks, DryRunHook, DryRunInput, DryRunReport, HookRunOutcome};
+use color_eyre::eyre::{eyre, Result};
+use std::io::Write;
+use std::process::ExitCode;
+
+/// Run a `centy-daemon hooks` subcommand.
+pub async fn hooks(args: HooksArgs) -> Result<ExitCode> {
+    match args.command {
+        HooksCommand::Test(test) => test_hooks(test).await,
+    }
+}
+
+/// Print the hooks an event would run and, with `--run`, how they did.
+///
+/// Exits with 1 when a hook fails or a pre-hook would abort the operation.
+async fn test_hooks(args: HookTestArgs) -> Result<ExitCode> {
+    let (item_type, event) = args
+        .event
+        .split_once('.')
+        .ok_or_else(|| eyre!("Expected <item_type>.<event>, e.g. issue.creating"))?;
+    let request_data = args.data.as_deref().map(serde_json::from_str).transpose()?;
+    let input = DryRunInput {
+        item_type: item_type.to_string(),
+        event: event.to_string(),
+        item_id: args.item_id,
+        request_data,
+        execute: args.run,
+    };
+    let report = dry_run_hooks(&args.project, input).await?;
+    write_report(&mut std::io::stdout().lock(), &args.event, &report)?;
+    let failed = report.blocked_by.is_some()
+        || report.hooks.iter().any(|tested| {
+            tested.run.as_ref().is_some_and(|run| {
+                !matches!(
+                    run.outcome,
+                    HookRunOutcome::Succeeded | HookRunOutcome::Skipped
+                )
+            })
+        });
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn write_report<W: Write>(out: &mut W, event: &str, report: &DryRunReport) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "{event} ({} {}): {} matching hook(s)",
+        report.context.phase,
+        report.context.operation,
+        report.hooks.len()
+    )?;
+    for (index, tested) in report.hooks.iter().enumerate() {
+        write_hook(out, index.saturating_add(1), tested)?;
+    }
+    if let Some(pattern) = &report.blocked_by {
+        writeln!(
+            out,
+            "Blocked by '{pattern}': the operation would be aborted"
+        )?;
+    }
+    if !report.modifications.is_empty() {
+        if let Some(request) = &report.request_data {
+            writeln!(out, "Request after pre-hooks: {request}")?;
+        }
+    }
+    Ok(())
+}
+
+fn write_hook<W: Write>(out: &mut W, position: usize, tested: &DryRunHook) -> std::io::Result<()> {
+    let hook = &tested.hook;
+    let target = hook.url.as_deref().unwrap_or(&hook.command);
+    let mode = if hook.is_async { ", async" } else { "" };
+    writeln!(
+        out,
+        "{position}. {} (specificity {}{mode}): {}",
+        hook.pattern,
+        tested.specificity,
+        target.trim()
+    )?;
+    if !tested.when_matches {
+        writeln!(out, "   `when` does not match the sample")?;
+    }
+    let Some(run) = &tested.run else {
+        return Ok(());
+    };
+    let exit = run
+        .exit_code
+        .map_or_else(String::new, |code| format!(", exit code {code}"));
+    writeln!(
+        out,
+        "   {}{exit} in {}ms",
+        run.outcome.as_str(),
+        run.duration_ms
+    )?;
+    for (label, output) in [("stdout", &run.stdout), ("stderr", &run.stderr)] {
+        if !output.trim().is_empty() {
+            writeln!(out, "   {label}: {}", output.trim())?;
+        }
+    }
+    if let Some(error) = &run.error {
+        writeln!(out, "   error: {error}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::app::{MergeDriverArgs, PostMergeArgs};
+use crate::item::entities::issue::reconcile::reconcile_display_numbers;
+use crate::sync::merge_for_git;
+use crate::utils::get_centy_path;
+use color_eyre::eyre::{eyre, Result};
+use git2::Repository;
+use std::process::ExitCode;
+use tokio::fs;
+
+/// Merge one `.centy` file for git and write the result over `%A`.
+///
+/// Only `%A` is written; git may not have written the other merged files
+/// yet. Exits with 1 when fields are left in conflict, so git reports the
+/// file as conflicted. A merge that would delete the file leaves `%A` as
+/// it is and exits with 1 too, since a driver cannot delete.
+pub async fn merge_driver(args: MergeDriverArgs) -> Result<ExitCode> {
+    let base = fs::read_to_string(&args.base).await?;
+    let ours = fs::read_to_string(&args.ours).await?;
+    let theirs = fs::read_to_string(&args.theirs).await?;
+    let merged = merge_for_git(&args.path, &base, &ours, &theirs);
+    let Some(content) = merged.content else {
+        eprintln!(
+            "{}: deleted on one side and changed on the other",
+            args.path
+        );
+        return Ok(ExitCode::FAILURE);
+    };
+    fs::write(&args.ours, content).await?;
+    for conflict in &merged.conflicts {
+        eprintln!("{}: {} changed on both sides", args.path, conflict.field);
+    }
+    if merged.conflicts.is_empty() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Renumber issues whose display numbers collide once a merge is done.
+///
+/// Git runs post-merge hooks after the merge commit, so every merged file
+/// is in place, including issues both branches added, which never reach
+/// the merge driver. The project is found relative to the work tree root.
+/// Renumbered issues are left as working tree changes to commit.
+pub async fn post_merge(args: PostMergeArgs) -> Result<ExitCode> {
+    let repo = Repository::discover(".")?;
+    let root = repo
+        .workdir()
+        .ok_or_else(|| eyre!("post-merge needs a repository with a work tree"))?;
+    let issues = get_centy_path(&root.join(&args.project)).join("issues");
+    let renumbered = reconcile_display_numbers(&issues).await?;
+    if renumbered > 0 {
+        eprintln!("Renumbered {renumbered} issue(s) with duplicate display numbers");
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -10,6 +10,11 @@ use tower_http::trace::{DefaultMakeSpan, TraceLayer};
 use tracing::{info, warn, Level};
 
 mod core;
+mod hooks;
+mod merge_driver;
+
+pub use hooks::hooks;
+pub use merge_driver::{merge_driver, post_merge};
 
 pub async fn run(args: app::Args) -> Result<()> {
     let log_file = core::setup_logging(args.log_dir, args.log_json, &args.log_rotation)?;
@@ -24,6 +29,8 @@ pub async fn run(args: app::Args) -> Result<()> {
     let shutdown_tx = Arc::new(tx_raw);
     let exe_path = std::env::current_exe().ok();
     crate::cleanup::spawn_cleanup_task();
+    crate::sync::spawn_auto_commit_task();
+    core::drain_hook_queue().await;
     let service = CentyDaemonService::new(Arc::clone(&shutdown_tx), exe_path, user_cfg);
     let reflection_service = tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(app::FILE_DESCRIPTOR_SET)
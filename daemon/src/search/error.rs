@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Errors raised while reading, writing or rebuilding a search index.
+#[derive(Error, Debug)]
+pub enum SearchError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Store error: {0}")]
+    Store(#[from] mdstore::StoreError),
+    #[error("Item type config error: {0}")]
+    Config(#[from] mdstore::ConfigError),
+}
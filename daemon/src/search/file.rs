@@ -0,0 +1,138 @@
+use super::error::SearchError;
+use super::index::{IndexedDocument, SearchIndex, INDEX_VERSION};
+use crate::utils::get_centy_path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncBufReadExt as _, AsyncWriteExt as _, BufReader};
+
+/// Index file name; `*.local.*` keeps it out of git.
+const INDEX_FILE: &str = "search-index.local.jsonl";
+
+/// The single JSON document used before the index became a change log.
+const LEGACY_INDEX_FILE: &str = "search-index.local.json";
+
+/// Superseded lines tolerated before a read compacts the file.
+const COMPACT_SLACK: usize = 64;
+
+/// First line of the index file.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    version: u32,
+}
+
+/// One line after the header: the new entry for an item, or its removal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct IndexChange {
+    pub item_type: String,
+    pub id: String,
+    /// `None` removes the entry.
+    #[serde(default)]
+    pub document: Option<IndexedDocument>,
+}
+
+fn index_path(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join(INDEX_FILE)
+}
+
+fn is_header(line: &str) -> bool {
+    serde_json::from_str::<Header>(line).is_ok_and(|h| h.version == INDEX_VERSION)
+}
+
+/// Whether the index file exists in the current layout, from its header alone.
+pub(super) async fn is_current(project_path: &Path) -> bool {
+    let Ok(file) = fs::File::open(index_path(project_path)).await else {
+        return false;
+    };
+    match BufReader::new(file).lines().next_line().await {
+        Ok(Some(line)) => is_header(&line),
+        Ok(None) | Err(_) => false,
+    }
+}
+
+/// Replay the index file, returning the index and how many change lines it
+/// took. `None` when the file is missing, outdated or has a line that does
+/// not parse, e.g. one cut short by a crash.
+pub(super) async fn read_index(project_path: &Path) -> Option<(SearchIndex, usize)> {
+    let content = fs::read_to_string(index_path(project_path)).await.ok()?;
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    if !is_header(lines.next()?) {
+        return None;
+    }
+    let mut index = SearchIndex::new();
+    let mut count: usize = 0;
+    for line in lines {
+        let change: IndexChange = serde_json::from_str(line).ok()?;
+        match change.document {
+            Some(document) => index.insert(document),
+            None => {
+                index.remove(&change.item_type, &change.id);
+            }
+        }
+        count = count.saturating_add(1);
+    }
+    Some((index, count))
+}
+
+/// Append one change to the index file (caller must hold the lock).
+pub(super) async fn append_change(
+    project_path: &Path,
+    change: &IndexChange,
+) -> Result<(), SearchError> {
+    let mut line = serde_json::to_string(change)?;
+    line.push('\n');
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(index_path(project_path))
+        .await?;
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await?;
+    Ok(())
+}
+
+/// Write the index with one line per entry, atomically (caller must hold
+/// the lock). Removes a leftover index in the old layout.
+pub(super) async fn write_index(
+    project_path: &Path,
+    index: &SearchIndex,
+) -> Result<(), SearchError> {
+    let mut content = serde_json::to_string(&Header {
+        version: INDEX_VERSION,
+    })?;
+    content.push('\n');
+    for document in index.documents.values() {
+        let change = IndexChange {
+            item_type: document.item_type.clone(),
+            id: document.id.clone(),
+            document: Some(document.clone()),
+        };
+        content.push_str(&serde_json::to_string(&change)?);
+        content.push('\n');
+    }
+    let path = index_path(project_path);
+    let temp_path = path.with_extension("jsonl.tmp");
+    fs::write(&temp_path, content).await?;
+    fs::rename(&temp_path, &path).await?;
+    let legacy = get_centy_path(project_path).join(LEGACY_INDEX_FILE);
+    if legacy.exists() {
+        fs::remove_file(legacy).await?;
+    }
+    Ok(())
+}
+
+/// Remove the index file, so the next read or change rebuilds it (caller
+/// must hold the lock).
+pub(super) async fn discard_index(project_path: &Path) -> Result<(), SearchError> {
+    match fs::remove_file(index_path(project_path)).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Whether a file of `lines` changes holding `documents` entries carries
+/// enough superseded lines to be worth rewriting.
+pub(super) const fn needs_compaction(lines: usize, documents: usize) -> bool {
+    lines > documents.saturating_mul(2).saturating_add(COMPACT_SLACK)
+}
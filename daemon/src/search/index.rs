@@ -0,0 +1,115 @@
+use super::tokenize::tokenize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Bumped whenever the on-disk layout changes; older indexes are rebuilt.
+pub const INDEX_VERSION: u32 = 2;
+
+/// Indexed fields, in the order they are weighted by the ranker.
+pub const FIELD_TITLE: &str = "title";
+pub const FIELD_TAGS: &str = "tags";
+pub const FIELD_CUSTOM: &str = "customFields";
+pub const FIELD_BODY: &str = "body";
+
+/// Per-project inverted index, keyed by `<folder>/<id>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIndex {
+    pub version: u32,
+    pub documents: BTreeMap<String, IndexedDocument>,
+}
+
+/// The indexed form of one item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedDocument {
+    /// Folder name of the item type (e.g. `issues`).
+    pub item_type: String,
+    pub id: String,
+    #[serde(default)]
+    pub deleted: bool,
+    pub updated_at: String,
+    /// Field name → term → number of occurrences.
+    pub terms: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl SearchIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            documents: BTreeMap::new(),
+        }
+    }
+
+    /// Add or replace the entry for an item.
+    pub fn upsert(&mut self, folder: &str, item: &mdstore::Item) {
+        self.insert(document_for(folder, item));
+    }
+
+    /// Add or replace an already indexed document.
+    pub fn insert(&mut self, document: IndexedDocument) {
+        self.documents
+            .insert(document_key(&document.item_type, &document.id), document);
+    }
+
+    /// Drop the entry for an item. Returns whether it was present.
+    pub fn remove(&mut self, folder: &str, id: &str) -> bool {
+        self.documents.remove(&document_key(folder, id)).is_some()
+    }
+}
+
+impl Default for SearchIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn document_key(folder: &str, id: &str) -> String {
+    format!("{folder}/{id}")
+}
+
+pub(super) fn document_for(folder: &str, item: &mdstore::Item) -> IndexedDocument {
+    let mut terms = BTreeMap::new();
+    add_field(&mut terms, FIELD_TITLE, &item.title);
+    add_field(&mut terms, FIELD_BODY, &item.body);
+    for tag in item.frontmatter.tags.iter().flatten() {
+        add_field(&mut terms, FIELD_TAGS, tag);
+    }
+    for value in item.frontmatter.custom_fields.values() {
+        for text in string_values(value) {
+            add_field(&mut terms, FIELD_CUSTOM, text);
+        }
+    }
+    IndexedDocument {
+        item_type: folder.to_string(),
+        id: item.id.clone(),
+        deleted: item.frontmatter.deleted_at.is_some(),
+        updated_at: item.frontmatter.updated_at.clone(),
+        terms,
+    }
+}
+
+fn add_field(terms: &mut BTreeMap<String, BTreeMap<String, u32>>, field: &str, text: &str) {
+    let tokens = tokenize(text);
+    if tokens.is_empty() {
+        return;
+    }
+    let counts = terms.entry(field.to_string()).or_default();
+    for token in tokens {
+        let count = counts.entry(token).or_insert(0);
+        *count = count.saturating_add(1);
+    }
+}
+
+/// Searchable text inside a custom field value: strings and string arrays.
+fn string_values(value: &serde_json::Value) -> Vec<&str> {
+    match value {
+        serde_json::Value::String(s) => vec![s.as_str()],
+        serde_json::Value::Array(values) => values.iter().filter_map(|v| v.as_str()).collect(),
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::Object(_) => vec![],
+    }
+}
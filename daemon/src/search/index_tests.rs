@@ -0,0 +1,57 @@
+use super::*;
+use std::collections::HashMap;
+
+fn item(id: &str, title: &str, body: &str) -> mdstore::Item {
+    let mut custom_fields = HashMap::new();
+    custom_fields.insert("team".to_string(), serde_json::json!("Platform"));
+    custom_fields.insert("estimate".to_string(), serde_json::json!(3u32));
+    mdstore::Item {
+        id: id.to_string(),
+        title: title.to_string(),
+        body: body.to_string(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: Some(1),
+            status: Some("open".to_string()),
+            priority: Some(1),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-02T00:00:00Z".to_string(),
+            deleted_at: None,
+            tags: Some(vec!["backend".to_string()]),
+            custom_fields,
+        },
+        comment: None,
+    }
+}
+
+#[test]
+fn test_upsert_indexes_all_fields() {
+    let mut index = SearchIndex::new();
+    index.upsert("issues", &item("a", "Login bug", "login fails twice"));
+    let doc = &index.documents["issues/a"];
+    assert_eq!(doc.terms["title"]["login"], 1);
+    assert_eq!(doc.terms["body"]["login"], 1);
+    assert_eq!(doc.terms["tags"]["backend"], 1);
+    assert_eq!(doc.terms["customFields"]["platform"], 1);
+    assert!(!doc.terms["customFields"].contains_key("3"));
+}
+
+#[test]
+fn test_upsert_replaces_and_remove_drops() {
+    let mut index = SearchIndex::new();
+    index.upsert("issues", &item("a", "Old title", ""));
+    index.upsert("issues", &item("a", "New title", ""));
+    assert_eq!(index.documents.len(), 1);
+    assert!(!index.documents["issues/a"].terms["title"].contains_key("old"));
+    assert!(index.remove("issues", "a"));
+    assert!(!index.remove("issues", "a"));
+}
+
+#[test]
+fn test_index_round_trips_through_json() {
+    let mut index = SearchIndex::new();
+    index.upsert("docs", &item("guide", "Setup guide", "install it"));
+    let json = serde_json::to_string(&index).unwrap();
+    let parsed: SearchIndex = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.version, INDEX_VERSION);
+    assert_eq!(parsed.documents, index.documents);
+}
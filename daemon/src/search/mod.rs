@@ -0,0 +1,36 @@
+//! Full-text search over item titles, bodies, tags and custom fields.
+//!
+//! Each project keeps an inverted index in `.centy/search-index.local.jsonl`
+//! (git-ignored through the `*.local.*` rule). The `generic_*` storage
+//! functions append each change to it; a missing or outdated index is
+//! rebuilt from disk on first use.
+mod error;
+mod file;
+mod index;
+mod rank;
+mod snippet;
+mod store;
+mod tokenize;
+pub use error::SearchError;
+pub use index::{IndexedDocument, SearchIndex, INDEX_VERSION};
+pub use rank::{rank, RankedDocument};
+pub use snippet::{build_snippet, Highlight, Snippet};
+pub use store::{
+    index_item, rebuild_index, remove_item, search_project, SearchMatch, SearchOptions,
+};
+pub use tokenize::{term_spans, tokenize, TermSpan};
+#[cfg(test)]
+#[path = "index_tests.rs"]
+mod index_tests;
+#[cfg(test)]
+#[path = "rank_tests.rs"]
+mod rank_tests;
+#[cfg(test)]
+#[path = "snippet_tests.rs"]
+mod snippet_tests;
+#[cfg(test)]
+#[path = "store_tests.rs"]
+mod store_tests;
+#[cfg(test)]
+#[path = "tokenize_tests.rs"]
+mod tokenize_tests;
@@ -0,0 +1,128 @@
+use super::index::{IndexedDocument, SearchIndex, FIELD_CUSTOM, FIELD_TAGS, FIELD_TITLE};
+use std::collections::BTreeMap;
+
+/// A document that matched every query term, with its relevance score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedDocument<'idx> {
+    pub document: &'idx IndexedDocument,
+    pub score: u64,
+}
+
+/// Rank the documents accepted by `include` against the query terms.
+///
+/// A document matches when it contains every term; the last term also
+/// matches as a prefix so partially typed words still find results. The
+/// score is an integer TF-IDF: term frequency saturates, rarer terms weigh
+/// more, and hits in the title outweigh tags, custom fields and the body.
+/// Results are ordered by score, then most recently updated.
+#[must_use]
+pub fn rank<'idx, F>(
+    index: &'idx SearchIndex,
+    terms: &[String],
+    include: F,
+) -> Vec<RankedDocument<'idx>>
+where
+    F: Fn(&IndexedDocument) -> bool,
+{
+    if terms.is_empty() {
+        return vec![];
+    }
+    let candidates: Vec<&IndexedDocument> = index
+        .documents
+        .values()
+        .filter(|doc| include(doc))
+        .collect();
+    let total = u64::try_from(candidates.len()).unwrap_or(u64::MAX);
+    let last = terms.len().saturating_sub(1);
+    let mut scores: BTreeMap<(&str, &str), (u64, &IndexedDocument)> = BTreeMap::new();
+    for (pos, term) in terms.iter().enumerate() {
+        let prefix = pos == last;
+        let hits: Vec<(&IndexedDocument, u64)> = candidates
+            .iter()
+            .filter_map(|doc| {
+                let tf = weighted_frequency(doc, term, prefix);
+                (tf > 0).then_some((*doc, tf))
+            })
+            .collect();
+        let doc_freq = u64::try_from(hits.len()).unwrap_or(u64::MAX);
+        let weight = idf(total, doc_freq);
+        let mut next: BTreeMap<(&str, &str), (u64, &IndexedDocument)> = BTreeMap::new();
+        for (doc, tf) in hits {
+            let key = (doc.item_type.as_str(), doc.id.as_str());
+            let previous = if pos == 0 {
+                Some(0)
+            } else {
+                scores.get(&key).map(|(score, _)| *score)
+            };
+            if let Some(score) = previous {
+                next.insert(key, (score.saturating_add(tf.saturating_mul(weight)), doc));
+            }
+        }
+        scores = next;
+    }
+    let mut ranked: Vec<RankedDocument<'idx>> = scores
+        .into_values()
+        .map(|(score, document)| RankedDocument { document, score })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| b.document.updated_at.cmp(&a.document.updated_at))
+            .then_with(|| a.document.id.cmp(&b.document.id))
+    });
+    ranked
+}
+
+fn field_weight(field: &str) -> u64 {
+    match field {
+        FIELD_TITLE => 4,
+        FIELD_TAGS => 3,
+        FIELD_CUSTOM => 2,
+        // body and anything unknown
+        _ => 1,
+    }
+}
+
+/// Saturating term frequency: 1 → 2, 2 → 4, 5 → 5, approaching 8.
+fn saturate(count: u64) -> u64 {
+    count
+        .saturating_mul(8)
+        .checked_div(count.saturating_add(2))
+        .unwrap_or(0)
+}
+
+/// Field-weighted, saturated frequency of a term in a document.
+/// Prefix-only matches count half.
+fn weighted_frequency(doc: &IndexedDocument, term: &str, prefix: bool) -> u64 {
+    let mut total: u64 = 0;
+    for (field, counts) in &doc.terms {
+        let exact = counts.get(term).copied().unwrap_or(0);
+        let partial: u32 = if prefix {
+            counts
+                .iter()
+                .filter(|(t, _)| t.len() > term.len() && t.starts_with(term))
+                .map(|(_, n)| *n)
+                .fold(0, u32::saturating_add)
+        } else {
+            0
+        };
+        let count = u64::from(exact)
+            .saturating_mul(2)
+            .saturating_add(u64::from(partial));
+        if count > 0 {
+            let score = saturate(count).saturating_mul(field_weight(field));
+            total = total.saturating_add(score);
+        }
+    }
+    total
+}
+
+/// Inverse document frequency on a log2 scale, always at least 1.
+fn idf(total: u64, doc_freq: u64) -> u64 {
+    let ratio = total
+        .saturating_mul(8)
+        .checked_div(doc_freq)
+        .unwrap_or(1)
+        .max(1);
+    u64::from(ratio.ilog2()).saturating_add(1)
+}
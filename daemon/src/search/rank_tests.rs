@@ -0,0 +1,82 @@
+use super::*;
+use std::collections::BTreeMap;
+
+fn doc(id: &str, fields: &[(&str, &[&str])]) -> IndexedDocument {
+    let mut terms: BTreeMap<String, BTreeMap<String, u32>> = BTreeMap::new();
+    for (field, words) in fields {
+        let counts = terms.entry((*field).to_string()).or_default();
+        for word in *words {
+            *counts.entry((*word).to_string()).or_insert(0) += 1;
+        }
+    }
+    IndexedDocument {
+        item_type: "issues".to_string(),
+        id: id.to_string(),
+        deleted: false,
+        updated_at: "2024-01-01T00:00:00Z".to_string(),
+        terms,
+    }
+}
+
+fn index(docs: Vec<IndexedDocument>) -> SearchIndex {
+    let mut index = SearchIndex::new();
+    for d in docs {
+        index.documents.insert(format!("issues/{}", d.id), d);
+    }
+    index
+}
+
+fn terms(words: &[&str]) -> Vec<String> {
+    words.iter().map(|w| (*w).to_string()).collect()
+}
+
+fn ids(ranked: &[RankedDocument<'_>]) -> Vec<String> {
+    ranked.iter().map(|r| r.document.id.clone()).collect()
+}
+
+#[test]
+fn test_title_hit_outranks_body_hit() {
+    let idx = index(vec![
+        doc("body", &[("body", &["login", "broken"])]),
+        doc("title", &[("title", &["login"])]),
+    ]);
+    let ranked = rank(&idx, &terms(&["login"]), |_| true);
+    assert_eq!(ids(&ranked), vec!["title", "body"]);
+}
+
+#[test]
+fn test_all_terms_required() {
+    let idx = index(vec![
+        doc("both", &[("body", &["login", "timeout"])]),
+        doc("one", &[("body", &["login"])]),
+    ]);
+    let ranked = rank(&idx, &terms(&["login", "timeout"]), |_| true);
+    assert_eq!(ids(&ranked), vec!["both"]);
+}
+
+#[test]
+fn test_last_term_matches_prefix() {
+    let idx = index(vec![doc("a", &[("title", &["authentication"])])]);
+    assert_eq!(ids(&rank(&idx, &terms(&["auth"]), |_| true)), vec!["a"]);
+    assert!(rank(&idx, &terms(&["auth", "x"]), |_| true).is_empty());
+}
+
+#[test]
+fn test_rare_terms_weigh_more() {
+    let idx = index(vec![
+        doc("common", &[("body", &["bug", "bug"])]),
+        doc("rare", &[("body", &["bug", "crash"])]),
+        doc("other", &[("body", &["bug"])]),
+    ]);
+    let ranked = rank(&idx, &terms(&["bug"]), |_| true);
+    assert_eq!(ranked.len(), 3);
+    let crash = rank(&idx, &terms(&["crash"]), |_| true);
+    assert!(crash[0].score > ranked[2].score);
+}
+
+#[test]
+fn test_include_filter_and_empty_query() {
+    let idx = index(vec![doc("a", &[("title", &["login"])])]);
+    assert!(rank(&idx, &terms(&["login"]), |d| d.id != "a").is_empty());
+    assert!(rank(&idx, &[], |_| true).is_empty());
+}
@@ -0,0 +1,92 @@
+use super::tokenize::{term_spans, TermSpan};
+
+/// Maximum number of body characters in a snippet.
+const SNIPPET_CHARS: usize = 160;
+/// Characters of context kept before the first body hit.
+const LEADING_CONTEXT: usize = 40;
+const ELLIPSIS: char = '\u{2026}';
+
+/// A highlighted range, in characters, within `title` or `snippet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+    pub field: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// A short excerpt of the body around the first hit, plus highlight ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snippet {
+    pub text: String,
+    pub highlights: Vec<Highlight>,
+}
+
+/// Build the snippet and highlights shown for a search result.
+///
+/// Every title word and every body word inside the excerpt that starts with
+/// a query term is highlighted. Whitespace in the excerpt is flattened to
+/// single spaces so it fits on one line; offsets are in characters.
+#[must_use]
+pub fn build_snippet(title: &str, body: &str, terms: &[String]) -> Snippet {
+    let mut highlights: Vec<Highlight> = term_spans(title)
+        .into_iter()
+        .filter(|span| is_hit(span, terms))
+        .map(|span| highlight("title", span.start, span.end))
+        .collect();
+
+    let body_hits: Vec<TermSpan> = term_spans(body)
+        .into_iter()
+        .filter(|span| is_hit(span, terms))
+        .collect();
+    let window_start = body_hits
+        .first()
+        .map_or(0, |span| span.start.saturating_sub(LEADING_CONTEXT));
+    let window_end = window_start.saturating_add(SNIPPET_CHARS);
+
+    let mut text = String::new();
+    let shift = if window_start > 0 {
+        text.push(ELLIPSIS);
+        1
+    } else {
+        0
+    };
+    text.extend(
+        body.chars()
+            .skip(window_start)
+            .take(SNIPPET_CHARS)
+            .map(|c| if c.is_whitespace() { ' ' } else { c }),
+    );
+    if body.chars().count() > window_end {
+        text.push(ELLIPSIS);
+    }
+
+    highlights.extend(
+        body_hits
+            .iter()
+            .filter(|span| span.start >= window_start && span.end <= window_end)
+            .map(|span| {
+                highlight(
+                    "snippet",
+                    span.start
+                        .saturating_sub(window_start)
+                        .saturating_add(shift),
+                    span.end.saturating_sub(window_start).saturating_add(shift),
+                )
+            }),
+    );
+    Snippet { text, highlights }
+}
+
+fn is_hit(span: &TermSpan, terms: &[String]) -> bool {
+    terms
+        .iter()
+        .any(|term| span.term.starts_with(term.as_str()))
+}
+
+fn highlight(field: &str, start: usize, end: usize) -> Highlight {
+    Highlight {
+        field: field.to_string(),
+        start: u32::try_from(start).unwrap_or(u32::MAX),
+        end: u32::try_from(end).unwrap_or(u32::MAX),
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+fn terms(words: &[&str]) -> Vec<String> {
+    words.iter().map(|w| (*w).to_string()).collect()
+}
+
+#[test]
+fn test_title_and_body_highlights() {
+    let s = build_snippet(
+        "Login timeout",
+        "The login page\nhangs.",
+        &terms(&["login"]),
+    );
+    assert_eq!(s.text, "The login page hangs.");
+    assert_eq!(
+        s.highlights,
+        vec![
+            Highlight {
+                field: "title".to_string(),
+                start: 0,
+                end: 5
+            },
+            Highlight {
+                field: "snippet".to_string(),
+                start: 4,
+                end: 9
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_snippet_centers_on_first_hit() {
+    let body = format!("{} needle {}", "a ".repeat(100), "b ".repeat(100));
+    let s = build_snippet("", &body, &terms(&["needle"]));
+    assert!(s.text.starts_with('\u{2026}'));
+    assert!(s.text.ends_with('\u{2026}'));
+    let hit = &s.highlights[0];
+    let highlighted: String = s
+        .text
+        .chars()
+        .skip(hit.start as usize)
+        .take((hit.end - hit.start) as usize)
+        .collect();
+    assert_eq!(highlighted, "needle");
+}
+
+#[test]
+fn test_snippet_without_body_hit_uses_start() {
+    let s = build_snippet("Needle", "plain body", &terms(&["needle"]));
+    assert_eq!(s.text, "plain body");
+    assert_eq!(s.highlights.len(), 1);
+}
@@ -0,0 +1,163 @@
+use super::error::SearchError;
+use super::file::{
+    append_change, discard_index, is_current, needs_compaction, read_index, write_index,
+    IndexChange,
+};
+use super::index::{document_for, SearchIndex};
+use super::rank::rank;
+use super::tokenize::tokenize;
+use crate::config::item_type_config::ItemTypeRegistry;
+use crate::utils::get_centy_path;
+use mdstore::Filters;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, PoisonError};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// One lock per project, serializing changes to its index file. Projects
+/// never wait on each other.
+static INDEX_LOCKS: OnceLock<std::sync::Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
+fn project_lock(project_path: &Path) -> Arc<Mutex<()>> {
+    let mut locks = INDEX_LOCKS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    Arc::clone(locks.entry(project_path.to_path_buf()).or_default())
+}
+
+/// Options for [`search_project`].
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    /// Restrict results to one item type folder.
+    pub item_type: Option<String>,
+    pub include_deleted: bool,
+}
+
+/// One ranked hit in a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Folder name of the item type.
+    pub item_type: String,
+    pub id: String,
+    pub score: u64,
+}
+
+/// Build a fresh index from every item type folder (caller must hold the lock).
+async fn rebuild_unlocked(project_path: &Path) -> Result<SearchIndex, SearchError> {
+    let registry = ItemTypeRegistry::build(project_path).await?;
+    let mut index = SearchIndex::new();
+    for folder in registry.folders() {
+        let dir = get_centy_path(project_path).join(folder);
+        if !dir.exists() {
+            continue;
+        }
+        for item in mdstore::list(&dir, Filters::new().include_deleted()).await? {
+            index.upsert(folder, &item);
+        }
+    }
+    write_index(project_path, &index).await?;
+    Ok(index)
+}
+
+/// Read the index, compacting it once superseded changes outnumber the
+/// entries (caller must hold the lock).
+async fn load_unlocked(project_path: &Path) -> Result<SearchIndex, SearchError> {
+    let Some((index, lines)) = read_index(project_path).await else {
+        return rebuild_unlocked(project_path).await;
+    };
+    if needs_compaction(lines, index.documents.len()) {
+        write_index(project_path, &index).await?;
+    }
+    Ok(index)
+}
+
+/// Append a change, rebuilding the index first when it is missing or
+/// outdated (caller must hold the lock).
+///
+/// When the change cannot be written the index file is removed, so the
+/// next read rebuilds it instead of missing the change.
+async fn apply_unlocked(project_path: &Path, change: &IndexChange) -> Result<(), SearchError> {
+    let result = async {
+        if !is_current(project_path).await {
+            rebuild_unlocked(project_path).await?;
+        }
+        append_change(project_path, change).await
+    }
+    .await;
+    if result.is_err() {
+        if let Err(e) = discard_index(project_path).await {
+            warn!(error = %e, "Failed to remove stale search index");
+        }
+    }
+    result
+}
+
+/// Rebuild a project's index from the files on disk.
+pub async fn rebuild_index(project_path: &Path) -> Result<SearchIndex, SearchError> {
+    let lock = project_lock(project_path);
+    let _guard = lock.lock().await;
+    rebuild_unlocked(project_path).await
+}
+
+/// Add or refresh one item in a project's index.
+///
+/// Only the item's entry is appended to the index file; the rest of the
+/// index is not read or rewritten.
+pub async fn index_item(
+    project_path: &Path,
+    folder: &str,
+    item: &mdstore::Item,
+) -> Result<(), SearchError> {
+    let change = IndexChange {
+        item_type: folder.to_string(),
+        id: item.id.clone(),
+        document: Some(document_for(folder, item)),
+    };
+    let lock = project_lock(project_path);
+    let _guard = lock.lock().await;
+    apply_unlocked(project_path, &change).await
+}
+
+/// Remove one item from a project's index.
+pub async fn remove_item(project_path: &Path, folder: &str, id: &str) -> Result<(), SearchError> {
+    let change = IndexChange {
+        item_type: folder.to_string(),
+        id: id.to_string(),
+        document: None,
+    };
+    let lock = project_lock(project_path);
+    let _guard = lock.lock().await;
+    apply_unlocked(project_path, &change).await
+}
+
+/// Run a text query against one project's index.
+pub async fn search_project(
+    project_path: &Path,
+    query: &str,
+    options: &SearchOptions,
+) -> Result<Vec<SearchMatch>, SearchError> {
+    let mut terms = tokenize(query);
+    terms.dedup();
+    let index = {
+        let lock = project_lock(project_path);
+        let _guard = lock.lock().await;
+        load_unlocked(project_path).await?
+    };
+    let matches = rank(&index, &terms, |doc| {
+        (options.include_deleted || !doc.deleted)
+            && options
+                .item_type
+                .as_ref()
+                .is_none_or(|folder| *folder == doc.item_type)
+    })
+    .into_iter()
+    .map(|ranked| SearchMatch {
+        item_type: ranked.document.item_type.clone(),
+        id: ranked.document.id.clone(),
+        score: ranked.score,
+    })
+    .collect();
+    Ok(matches)
+}
@@ -0,0 +1,152 @@
+use super::*;
+use std::collections::HashMap;
+
+fn item(id: &str, title: &str) -> mdstore::Item {
+    mdstore::Item {
+        id: id.to_string(),
+        title: title.to_string(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: None,
+            priority: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            deleted_at: None,
+            tags: None,
+            custom_fields: HashMap::new(),
+        },
+        comment: None,
+    }
+}
+
+async fn project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    tokio::fs::create_dir_all(temp.path().join(".centy"))
+        .await
+        .unwrap();
+    temp
+}
+
+#[tokio::test]
+async fn test_index_and_search_round_trip() {
+    let temp = project().await;
+    index_item(temp.path(), "issues", &item("a", "Login timeout"))
+        .await
+        .unwrap();
+    index_item(temp.path(), "docs", &item("b", "Login guide"))
+        .await
+        .unwrap();
+    assert!(temp.path().join(".centy/search-index.local.jsonl").exists());
+
+    let all = search_project(temp.path(), "login", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 2);
+
+    let options = SearchOptions {
+        item_type: Some("docs".to_string()),
+        include_deleted: false,
+    };
+    let docs = search_project(temp.path(), "login", &options)
+        .await
+        .unwrap();
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0].id, "b");
+
+    remove_item(temp.path(), "docs", "b").await.unwrap();
+    let after = search_project(temp.path(), "guide", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert!(after.is_empty());
+}
+
+#[tokio::test]
+async fn test_deleted_items_hidden_by_default() {
+    let temp = project().await;
+    let mut deleted = item("a", "Old login flow");
+    deleted.frontmatter.deleted_at = Some("2024-02-01T00:00:00Z".to_string());
+    index_item(temp.path(), "issues", &deleted).await.unwrap();
+    let hidden = search_project(temp.path(), "login", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert!(hidden.is_empty());
+    let options = SearchOptions {
+        item_type: None,
+        include_deleted: true,
+    };
+    let shown = search_project(temp.path(), "login", &options)
+        .await
+        .unwrap();
+    assert_eq!(shown.len(), 1);
+}
+
+#[tokio::test]
+async fn test_corrupt_index_is_rebuilt() {
+    let temp = project().await;
+    tokio::fs::write(temp.path().join(".centy/search-index.local.jsonl"), "{bad")
+        .await
+        .unwrap();
+    let results = search_project(temp.path(), "anything", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+}
+
+async fn index_lines(project_path: &std::path::Path) -> usize {
+    tokio::fs::read_to_string(project_path.join(".centy/search-index.local.jsonl"))
+        .await
+        .unwrap()
+        .lines()
+        .count()
+}
+
+#[tokio::test]
+async fn test_changes_are_appended_and_compacted_on_read() {
+    let temp = project().await;
+    index_item(temp.path(), "issues", &item("a", "Login timeout"))
+        .await
+        .unwrap();
+    index_item(temp.path(), "issues", &item("b", "Signup form"))
+        .await
+        .unwrap();
+    remove_item(temp.path(), "issues", "b").await.unwrap();
+    // Header, two entries and a removal
+    assert_eq!(index_lines(temp.path()).await, 4);
+
+    for n in 0..100u32 {
+        let title = format!("Login timeout {n}");
+        index_item(temp.path(), "issues", &item("a", &title))
+            .await
+            .unwrap();
+    }
+    let hits = search_project(temp.path(), "99", &SearchOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(hits.len(), 1);
+    assert_eq!(index_lines(temp.path()).await, 2);
+    let signup = search_project(temp.path(), "signup", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert!(signup.is_empty());
+}
+
+#[tokio::test]
+async fn test_old_index_layout_is_replaced() {
+    let temp = project().await;
+    let legacy = temp.path().join(".centy/search-index.local.json");
+    tokio::fs::write(&legacy, r#"{"version":1,"documents":{}}"#)
+        .await
+        .unwrap();
+
+    index_item(temp.path(), "issues", &item("a", "Login timeout"))
+        .await
+        .unwrap();
+
+    assert!(!legacy.exists());
+    let hits = search_project(temp.path(), "login", &SearchOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+}
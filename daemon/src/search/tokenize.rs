@@ -0,0 +1,50 @@
+/// One term found in a piece of text, with its position in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermSpan {
+    /// The lowercased term.
+    pub term: String,
+    /// Offset of the first character, counted in Unicode scalar values.
+    pub start: usize,
+    /// Offset one past the last character.
+    pub end: usize,
+}
+
+/// Split text into lowercase alphanumeric terms.
+#[must_use]
+pub fn tokenize(text: &str) -> Vec<String> {
+    term_spans(text).into_iter().map(|span| span.term).collect()
+}
+
+/// Split text into lowercase alphanumeric terms, keeping their positions.
+#[must_use]
+pub fn term_spans(text: &str) -> Vec<TermSpan> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    let mut end = 0;
+    for (pos, ch) in text.chars().enumerate() {
+        end = pos;
+        if ch.is_alphanumeric() {
+            if current.is_empty() {
+                start = pos;
+            }
+            current.extend(ch.to_lowercase());
+        } else if !current.is_empty() {
+            spans.push(TermSpan {
+                term: std::mem::take(&mut current),
+                start,
+                end: pos,
+            });
+        } else {
+            // consecutive separators
+        }
+    }
+    if !current.is_empty() {
+        spans.push(TermSpan {
+            term: current,
+            start,
+            end: end.saturating_add(1),
+        });
+    }
+    spans
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_tokenize_lowercases_and_splits() {
+    assert_eq!(
+        tokenize("Fix Login-timeout (v2)!"),
+        vec!["fix", "login", "timeout", "v2"]
+    );
+}
+
+#[test]
+fn test_tokenize_empty() {
+    assert!(tokenize("  -- ").is_empty());
+}
+
+#[test]
+fn test_term_spans_use_char_offsets() {
+    let spans = term_spans("h\u{e9}llo w\u{f6}rld");
+    assert_eq!(spans.len(), 2);
+    assert_eq!((spans[0].start, spans[0].end), (0, 5));
+    assert_eq!((spans[1].start, spans[1].end), (6, 11));
+    assert_eq!(spans[1].term, "w\u{f6}rld");
+}
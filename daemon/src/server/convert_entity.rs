@@ -1,7 +1,8 @@
 use crate::config::item_type_config::ItemTypeConfig;
 
 use super::proto::{
-    GenericItem as ProtoGenericItem, GenericItemMetadata, ItemTypeConfigProto, User as ProtoUser,
+    GenericItem as ProtoGenericItem, GenericItemMetadata, ItemTypeConfigProto,
+    SavedView as ProtoSavedView, User as ProtoUser,
 };
 
 pub fn generic_item_to_proto(item: &mdstore::Item, item_type: &str) -> ProtoGenericItem {
@@ -51,6 +52,18 @@ pub fn user_to_proto(user: &crate::user::User) -> ProtoUser {
     }
 }
 
+pub fn view_to_proto(view: &crate::view::SavedView) -> ProtoSavedView {
+    ProtoSavedView {
+        id: view.id.clone(),
+        name: view.name.clone(),
+        description: view.description.clone().unwrap_or_default(),
+        item_type: view.item_type.clone(),
+        filter: view.filter_json(),
+        sort: view.sort.clone(),
+        fields: view.fields.clone(),
+    }
+}
+
 pub fn user_to_generic_item_proto(user: &crate::user::User) -> ProtoGenericItem {
     let status = if user.deleted_at.is_some() {
         "deleted".to_string()
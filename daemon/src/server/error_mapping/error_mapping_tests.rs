@@ -463,6 +463,21 @@ fn test_hook_error_all_variants() {
                 stderr: "fail".into(),
             },
         ),
+        (
+            "HOOK_INVALID_PATCH",
+            HookError::InvalidPatch {
+                pattern: "test".into(),
+                reason: "bad".into(),
+            },
+        ),
+        (
+            "HOOK_WEBHOOK_FAILED",
+            HookError::WebhookFailed {
+                pattern: "test".into(),
+                status: 403,
+                body: "denied".into(),
+            },
+        ),
         (
             "HOOK_TIMEOUT",
             HookError::Timeout {
@@ -478,6 +493,10 @@ fn test_hook_error_all_variants() {
             "HOOK_INVALID_PATTERN",
             HookError::InvalidPattern("test".into()),
         ),
+        (
+            "HOOK_DELIVERY_NOT_FOUND",
+            HookError::DeliveryNotFound("test".into()),
+        ),
         ("IO_ERROR", HookError::IoError(std::io::Error::other("x"))),
         (
             "JSON_ERROR",
@@ -621,6 +640,10 @@ fn test_execute_error_all_variants() {
             "CONFIG_ERROR",
             ExecuteError::ConfigError(mdstore::ConfigError::IoError(std::io::Error::other("x"))),
         ),
+        (
+            "VIEW_ERROR",
+            ExecuteError::ViewError(crate::view::ViewError::NotFound("v".into())),
+        ),
     ];
     for (expected_code, err) in cases {
         let (code, _) = err.error_code_and_tip();
@@ -630,3 +653,192 @@ fn test_execute_error_all_variants() {
         );
     }
 }
+
+// ── views.rs: ViewError ──────────────────────────────────────────────────────
+
+#[test]
+fn test_view_error_all_variants() {
+    use crate::view::ViewError;
+    let cases: &[(&str, ViewError)] = &[
+        ("IO_ERROR", ViewError::IoError(std::io::Error::other("x"))),
+        (
+            "YAML_ERROR",
+            ViewError::YamlError(serde_yaml::from_str::<u32>("[").unwrap_err()),
+        ),
+        ("VIEW_NOT_FOUND", ViewError::NotFound("v".into())),
+        ("VIEW_ALREADY_EXISTS", ViewError::AlreadyExists("v".into())),
+        ("INVALID_VIEW_ID", ViewError::InvalidId("x".into())),
+        ("INVALID_VIEW", ViewError::Invalid("x".into())),
+        (
+            "INVALID_FILTER",
+            ViewError::InvalidFilter(crate::query::QueryError::new("", "x")),
+        ),
+    ];
+    for (expected_code, err) in cases {
+        let (code, _) = err.error_code_and_tip();
+        assert_eq!(code, *expected_code, "Unexpected code for ViewError: {err}");
+    }
+}
+
+#[test]
+fn test_view_error_invalid_filter_has_path() {
+    use crate::view::ViewError;
+    let err = ViewError::InvalidFilter(crate::query::QueryError::new("status.$in", "x"));
+    assert_eq!(err.error_path(), Some("status.$in"));
+    assert_eq!(ViewError::NotFound("v".into()).error_path(), None);
+}
+
+// ── query.rs: QueryError ─────────────────────────────────────────────────────
+
+#[test]
+fn test_query_error_code_tip_and_path() {
+    let err = crate::query::QueryError::new("priority.$lte", "expected a number");
+    let (code, tip) = err.error_code_and_tip();
+    assert_eq!(code, "INVALID_FILTER");
+    assert!(tip.unwrap().contains("$regex"));
+    assert_eq!(err.error_path(), Some("priority.$lte"));
+}
+
+// ── infra.rs: HistoryError ───────────────────────────────────────────────────
+
+#[test]
+fn test_history_error_all_variants() {
+    use crate::history::HistoryError;
+    let cases: &[(&str, HistoryError)] = &[
+        ("NOT_GIT_REPOSITORY", HistoryError::NotGitRepository),
+        (
+            "OUTSIDE_WORK_TREE",
+            HistoryError::OutsideWorkTree("x".to_string()),
+        ),
+        (
+            "REVISION_NOT_FOUND",
+            HistoryError::RevisionNotFound("x".to_string()),
+        ),
+        (
+            "ITEM_NOT_IN_REVISION",
+            HistoryError::NotInRevision {
+                item_id: "x".to_string(),
+                revision: "HEAD".to_string(),
+            },
+        ),
+        ("IO_ERROR", HistoryError::Io(std::io::Error::other("x"))),
+        ("GIT_ERROR", HistoryError::Git(git2::Error::from_str("x"))),
+    ];
+    for (expected_code, err) in cases {
+        let (code, _) = err.error_code_and_tip();
+        assert_eq!(
+            code, *expected_code,
+            "Unexpected code for HistoryError: {err}"
+        );
+    }
+}
+
+// ── infra.rs: JournalError ───────────────────────────────────────────────────
+
+#[test]
+fn test_journal_error_all_variants() {
+    use crate::journal::JournalError;
+    let cases: &[(&str, JournalError)] = &[
+        ("IO_ERROR", JournalError::Io(std::io::Error::other("x"))),
+        (
+            "JSON_ERROR",
+            JournalError::Json(serde_json::from_str::<u32>("[").unwrap_err()),
+        ),
+    ];
+    for (expected_code, err) in cases {
+        let (code, _) = err.error_code_and_tip();
+        assert_eq!(
+            code, *expected_code,
+            "Unexpected code for JournalError: {err}"
+        );
+    }
+}
+
+// ── infra.rs: SyncError ──────────────────────────────────────────────────────
+
+#[test]
+fn test_sync_error_all_variants() {
+    use crate::sync::SyncError;
+    let cases: &[(&str, SyncError)] = &[
+        ("NOT_GIT_REPOSITORY", SyncError::NotGitRepository),
+        (
+            "OUTSIDE_WORK_TREE",
+            SyncError::OutsideWorkTree("x".to_string()),
+        ),
+        (
+            "REMOTE_NOT_FOUND",
+            SyncError::RemoteNotFound("origin".to_string()),
+        ),
+        ("SYNC_DIVERGED", SyncError::Diverged),
+        (
+            "PUSH_REJECTED",
+            SyncError::PushRejected {
+                reference: "refs/heads/centy".to_string(),
+                message: "x".to_string(),
+            },
+        ),
+        (
+            "CONFLICT_NOT_FOUND",
+            SyncError::ConflictNotFound("x".to_string()),
+        ),
+        (
+            "INVALID_RESOLUTION",
+            SyncError::InvalidResolution("x".to_string()),
+        ),
+        (
+            "INVALID_SYNC_CONFIG",
+            SyncError::InvalidSettings("x".to_string()),
+        ),
+        (
+            "CONFIG_ERROR",
+            SyncError::Config(mdstore::ConfigError::IoError(std::io::Error::other("x"))),
+        ),
+        ("IO_ERROR", SyncError::Io(std::io::Error::other("x"))),
+        (
+            "JSON_ERROR",
+            SyncError::Json(serde_json::from_str::<u32>("[").unwrap_err()),
+        ),
+        ("GIT_ERROR", SyncError::Git(git2::Error::from_str("x"))),
+    ];
+    for (expected_code, err) in cases {
+        let (code, _) = err.error_code_and_tip();
+        assert_eq!(code, *expected_code, "Unexpected code for SyncError: {err}");
+    }
+}
+
+// ── infra.rs: UndoError ──────────────────────────────────────────────────────
+
+#[test]
+fn test_undo_error_all_variants() {
+    use crate::undo::UndoError;
+    let cases: &[(&str, UndoError)] = &[
+        ("NOTHING_TO_UNDO", UndoError::NothingToUndo),
+        (
+            "UNDO_CONFLICT",
+            UndoError::Conflict {
+                operation: "update".to_string(),
+                path: "x".to_string(),
+            },
+        ),
+        ("IO_ERROR", UndoError::Io(std::io::Error::other("x"))),
+        (
+            "JSON_ERROR",
+            UndoError::Json(serde_json::from_str::<u32>("[").unwrap_err()),
+        ),
+    ];
+    for (expected_code, err) in cases {
+        let (code, _) = err.error_code_and_tip();
+        assert_eq!(code, *expected_code, "Unexpected code for UndoError: {err}");
+    }
+}
+
+// ── infra.rs: WatchError ─────────────────────────────────────────────────────
+
+#[test]
+fn test_watch_error_all_variants() {
+    use crate::watch::WatchError;
+    let err = WatchError::NotifyError(notify::Error::generic("x"));
+    let (code, tip) = err.error_code_and_tip();
+    assert_eq!(code, "WATCH_ERROR");
+    assert!(tip.is_some());
+}
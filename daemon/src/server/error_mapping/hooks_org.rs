@@ -4,9 +4,12 @@ impl ToStructuredError for crate::hooks::HookError {
         use crate::hooks::HookError;
         match self {
             HookError::PreHookFailed { .. } => ("HOOK_PRE_FAILED", None),
+            HookError::InvalidPatch { .. } => ("HOOK_INVALID_PATCH", None),
+            HookError::WebhookFailed { .. } => ("HOOK_WEBHOOK_FAILED", None),
             HookError::Timeout { .. } => ("HOOK_TIMEOUT", None),
             HookError::ExecutionError(_) => ("HOOK_EXECUTION_ERROR", None),
             HookError::InvalidPattern(_) => ("HOOK_INVALID_PATTERN", None),
+            HookError::DeliveryNotFound(_) => ("HOOK_DELIVERY_NOT_FOUND", None),
             HookError::IoError(_) => ("IO_ERROR", None),
             HookError::JsonError(_) => ("JSON_ERROR", None),
         }
@@ -74,3 +74,94 @@ impl ToStructuredError for crate::link::LinkError {
         }
     }
 }
+// ── HistoryError ───────────────────────────────────────────────────────────────
+impl ToStructuredError for crate::history::HistoryError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::history::HistoryError;
+        match self {
+            HistoryError::NotGitRepository => (
+                "NOT_GIT_REPOSITORY",
+                Some("Item history is read from git; commit the project to a git repository"),
+            ),
+            HistoryError::OutsideWorkTree(_) => ("OUTSIDE_WORK_TREE", None),
+            HistoryError::RevisionNotFound(_) => ("REVISION_NOT_FOUND", None),
+            HistoryError::NotInRevision { .. } => ("ITEM_NOT_IN_REVISION", None),
+            HistoryError::Io(_) => ("IO_ERROR", None),
+            HistoryError::Git(_) => ("GIT_ERROR", None),
+        }
+    }
+}
+// ── JournalError ───────────────────────────────────────────────────────────────
+impl ToStructuredError for crate::journal::JournalError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::journal::JournalError;
+        match self {
+            JournalError::Io(_) => ("IO_ERROR", None),
+            JournalError::Json(_) => ("JSON_ERROR", None),
+        }
+    }
+}
+// ── SyncError ──────────────────────────────────────────────────────────────────
+impl ToStructuredError for crate::sync::SyncError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::sync::SyncError;
+        match self {
+            SyncError::NotGitRepository => (
+                "NOT_GIT_REPOSITORY",
+                Some("Sync goes through git; put the project in a git repository"),
+            ),
+            SyncError::OutsideWorkTree(_) => ("OUTSIDE_WORK_TREE", None),
+            SyncError::RemoteNotFound(_) => (
+                "REMOTE_NOT_FOUND",
+                Some(
+                    "Add one with: git remote add <name> <url>, or set sync.remote in config.json",
+                ),
+            ),
+            SyncError::Diverged => (
+                "SYNC_DIVERGED",
+                Some("Pull and resolve any conflicts before pushing"),
+            ),
+            SyncError::PushRejected { .. } => ("PUSH_REJECTED", None),
+            SyncError::ConflictNotFound(_) => (
+                "CONFLICT_NOT_FOUND",
+                Some("List open conflicts with ListSyncConflicts"),
+            ),
+            SyncError::InvalidResolution(_) => ("INVALID_RESOLUTION", None),
+            SyncError::InvalidSettings(_) => (
+                "INVALID_SYNC_CONFIG",
+                Some("Check the sync.remote and sync.branch keys in config.json"),
+            ),
+            SyncError::Config(_) => ("CONFIG_ERROR", None),
+            SyncError::Io(_) => ("IO_ERROR", None),
+            SyncError::Json(_) => ("JSON_ERROR", None),
+            SyncError::Git(_) => ("GIT_ERROR", None),
+        }
+    }
+}
+// ── UndoError ──────────────────────────────────────────────────────────────────
+impl ToStructuredError for crate::undo::UndoError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::undo::UndoError;
+        match self {
+            UndoError::NothingToUndo => ("NOTHING_TO_UNDO", None),
+            UndoError::Conflict { .. } => (
+                "UNDO_CONFLICT",
+                Some("The file was edited outside the daemon; use git to restore an older version"),
+            ),
+            UndoError::Io(_) => ("IO_ERROR", None),
+            UndoError::Json(_) => ("JSON_ERROR", None),
+        }
+    }
+}
+// ── WatchError ─────────────────────────────────────────────────────────────────
+impl ToStructuredError for crate::watch::WatchError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::watch::WatchError;
+        match self {
+            WatchError::NotifyError(_) => (
+                "WATCH_ERROR",
+                Some("Check the inotify watch limit (fs.inotify.max_user_watches)"),
+            ),
+        }
+    }
+}
@@ -1,12 +1,19 @@
 /// Trait for mapping domain errors to structured error codes and optional tips.
 pub trait ToStructuredError {
     fn error_code_and_tip(&self) -> (&str, Option<&str>);
+
+    /// Where in the request the error was found, if it points at one.
+    fn error_path(&self) -> Option<&str> {
+        None
+    }
 }
 
 mod hooks_org;
 mod infra;
 mod issue_crud;
+mod query;
 mod user_assets;
+mod views;
 
 impl ToStructuredError for crate::server::assert_service::AssertError {
     fn error_code_and_tip(&self) -> (&str, Option<&str>) {
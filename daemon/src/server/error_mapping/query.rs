@@ -0,0 +1,13 @@
+use super::ToStructuredError;
+impl ToStructuredError for crate::query::QueryError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        (
+            "INVALID_FILTER",
+            Some("Supported operators: $eq, $ne, $in, $nin, $all, $gt, $gte, $lt, $lte, $exists, $regex, $contains, $and, $or, $not"),
+        )
+    }
+
+    fn error_path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+}
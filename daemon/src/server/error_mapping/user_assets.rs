@@ -61,6 +61,7 @@ impl ToStructuredError for crate::reconciliation::ExecuteError {
             ExecuteError::ManifestError(_) => ("MANIFEST_ERROR", None),
             ExecuteError::PlanError(_) => ("RECONCILE_PLAN_ERROR", None),
             ExecuteError::ConfigError(_) => ("CONFIG_ERROR", None),
+            ExecuteError::ViewError(_) => ("VIEW_ERROR", None),
         }
     }
 }
@@ -0,0 +1,28 @@
+use super::ToStructuredError;
+impl ToStructuredError for crate::view::ViewError {
+    fn error_code_and_tip(&self) -> (&str, Option<&str>) {
+        use crate::view::ViewError;
+        match self {
+            ViewError::IoError(_) => ("IO_ERROR", None),
+            ViewError::YamlError(_) => ("YAML_ERROR", None),
+            ViewError::NotFound(_) => ("VIEW_NOT_FOUND", None),
+            ViewError::AlreadyExists(_) => ("VIEW_ALREADY_EXISTS", None),
+            ViewError::InvalidId(_) => ("INVALID_VIEW_ID", None),
+            ViewError::Invalid(_) => ("INVALID_VIEW", None),
+            ViewError::InvalidFilter(_) => ("INVALID_FILTER", None),
+        }
+    }
+
+    fn error_path(&self) -> Option<&str> {
+        use crate::view::ViewError;
+        match self {
+            ViewError::InvalidFilter(e) => Some(&e.path),
+            ViewError::IoError(_)
+            | ViewError::YamlError(_)
+            | ViewError::NotFound(_)
+            | ViewError::AlreadyExists(_)
+            | ViewError::InvalidId(_)
+            | ViewError::Invalid(_) => None,
+        }
+    }
+}
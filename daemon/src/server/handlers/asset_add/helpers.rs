@@ -27,7 +27,7 @@ pub(super) async fn prepare_add_asset_hooks(
     if let Err(e) = maybe_run_pre_hooks(
         project_path,
         "asset",
-        HookOperation::Create,
+        HookOperation::Add,
         &path_str,
         Some(&item_id),
         Some(request_data.clone()),
@@ -57,7 +57,7 @@ pub(super) async fn finish_add_asset(
     maybe_run_post_hooks(
         project_path,
         "asset",
-        HookOperation::Create,
+        HookOperation::Add,
         &ctx.project_path,
         Some(&ctx.item_id),
         Some(ctx.request_data),
@@ -1,9 +1,11 @@
 use std::path::Path;
 
-use crate::config::write_config;
+use crate::config::{read_config, write_config};
+use crate::hooks::HookOperation;
 use crate::registry::track_project_async;
 use crate::server::assert_service::assert_initialized;
 use crate::server::config_to_proto::config_to_proto;
+use crate::server::hooks_helper::{maybe_run_post_hooks, maybe_run_pre_hooks};
 use crate::server::proto::{UpdateConfigRequest, UpdateConfigResponse};
 use crate::server::proto_to_config::proto_to_config;
 use crate::server::structured_error::{to_error_json, StructuredError};
@@ -36,7 +38,12 @@ pub async fn update_config(
             config: None,
         }));
     };
-    let config = proto_to_config(&proto_config);
+    let mut config = proto_to_config(&proto_config);
+    // The proto does not carry these sections; keep what config.json has.
+    if let Ok(Some(current)) = read_config(project_path).await {
+        config.auto_commit = current.auto_commit;
+        config.sync = current.sync;
+    }
 
     // Validate config
     if let Err(e) = validate_config(&config) {
@@ -47,8 +54,37 @@ pub async fn update_config(
         }));
     }
 
+    let hook_data = serde_json::json!({ "config": &config });
+    if let Err(e) = maybe_run_pre_hooks(
+        project_path,
+        "config",
+        HookOperation::Update,
+        &req.project_path,
+        None,
+        Some(hook_data.clone()),
+    )
+    .await
+    {
+        return Ok(Response::new(UpdateConfigResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            config: None,
+        }));
+    }
+
     // Write config
-    match write_config(project_path, &config).await {
+    let result = write_config(project_path, &config).await;
+    maybe_run_post_hooks(
+        project_path,
+        "config",
+        HookOperation::Update,
+        &req.project_path,
+        None,
+        Some(hook_data),
+        result.is_ok(),
+    )
+    .await;
+    match result {
         Ok(()) => Ok(Response::new(UpdateConfigResponse {
             success: true,
             error: String::new(),
@@ -0,0 +1,31 @@
+use crate::query::explain;
+use crate::server::proto::{ExplainQueryRequest, ExplainQueryResponse};
+use tonic::{Response, Status};
+
+use super::item_list::parse_request_filter;
+
+/// Show how a `ListItems` filter is executed: its normalized MQL form, the
+/// part pushed down to the store, and the part evaluated in memory.
+///
+/// `project_path` is only used as the `cwd` of structured errors.
+pub fn explain_query(req: &ExplainQueryRequest) -> Result<Response<ExplainQueryResponse>, Status> {
+    let query = match parse_request_filter(&req.project_path, &req.filter) {
+        Ok(q) => q,
+        Err(error) => {
+            return Ok(Response::new(ExplainQueryResponse {
+                success: false,
+                error,
+                ..Default::default()
+            }))
+        }
+    };
+    let explanation = explain(&query);
+    Ok(Response::new(ExplainQueryResponse {
+        success: true,
+        error: String::new(),
+        normalized: explanation.normalized.to_string(),
+        pushed_down: explanation.pushed_down.to_string(),
+        residual: explanation.residual.to_string(),
+        complete: explanation.complete,
+    }))
+}
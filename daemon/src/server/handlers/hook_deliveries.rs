@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use crate::hooks::queue::{discard_delivery, project_deliveries, retry_delivery};
+use crate::hooks::HookDelivery;
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::proto::{
+    DiscardHookDeliveryRequest, DiscardHookDeliveryResponse, HookDelivery as ProtoHookDelivery,
+    ListHookDeliveriesRequest, ListHookDeliveriesResponse, RetryHookDeliveryRequest,
+    RetryHookDeliveryResponse,
+};
+use crate::server::structured_error::to_error_json;
+use tonic::{Response, Status};
+
+/// List a project's queued async hook deliveries, oldest first.
+///
+/// `status` narrows the list to `pending` or `dead` deliveries.
+pub async fn list_hook_deliveries(
+    req: ListHookDeliveriesRequest,
+) -> Result<Response<ListHookDeliveriesResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(ListHookDeliveriesResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+    match project_deliveries(project_path).await {
+        Ok(deliveries) => Ok(Response::new(ListHookDeliveriesResponse {
+            success: true,
+            error: String::new(),
+            deliveries: deliveries
+                .into_iter()
+                .filter(|d| req.status.is_empty() || d.status.as_str() == req.status)
+                .map(delivery_to_proto)
+                .collect(),
+        })),
+        Err(e) => Ok(Response::new(ListHookDeliveriesResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        })),
+    }
+}
+
+/// Put a dead delivery back in the queue with its retries restored.
+pub async fn retry_hook_delivery(
+    req: RetryHookDeliveryRequest,
+) -> Result<Response<RetryHookDeliveryResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(RetryHookDeliveryResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+    match retry_delivery(project_path, &req.delivery_id).await {
+        Ok(delivery) => Ok(Response::new(RetryHookDeliveryResponse {
+            success: true,
+            error: String::new(),
+            delivery: Some(delivery_to_proto(delivery)),
+        })),
+        Err(e) => Ok(Response::new(RetryHookDeliveryResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        })),
+    }
+}
+
+/// Drop a delivery from the queue without running it again.
+pub async fn discard_hook_delivery(
+    req: DiscardHookDeliveryRequest,
+) -> Result<Response<DiscardHookDeliveryResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(DiscardHookDeliveryResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+        }));
+    }
+    match discard_delivery(project_path, &req.delivery_id).await {
+        Ok(_delivery) => Ok(Response::new(DiscardHookDeliveryResponse {
+            success: true,
+            error: String::new(),
+        })),
+        Err(e) => Ok(Response::new(DiscardHookDeliveryResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+        })),
+    }
+}
+
+fn delivery_to_proto(delivery: HookDelivery) -> ProtoHookDelivery {
+    ProtoHookDelivery {
+        target: delivery.hook.url.unwrap_or(delivery.hook.command),
+        max_attempts: delivery.hook.retries.saturating_add(1),
+        id: delivery.id,
+        pattern: delivery.hook.pattern,
+        item_type: delivery.context.item_type,
+        operation: delivery.context.operation,
+        item_id: delivery.context.item_id.unwrap_or_default(),
+        status: delivery.status.as_str().to_string(),
+        attempts: delivery.attempts,
+        created_at: delivery.created_at,
+        next_attempt_at: delivery.next_attempt_at.to_rfc3339(),
+        last_error: delivery.last_error.unwrap_or_default(),
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use crate::hooks::{dry_run_hooks, DryRunHook, DryRunInput, HookError};
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::hooks_helper::hook_item_type;
+use crate::server::proto::{TestHookRequest, TestHookResponse, TestedHook};
+use crate::server::structured_error::to_error_json;
+use tonic::{Response, Status};
+
+use super::hook_runs::run_to_proto;
+
+/// Show which hooks an event would run, most specific first, and
+/// optionally run them with the sample `request_data` (a JSON object).
+///
+/// No item is created or changed and no run is recorded.
+pub async fn test_hook(req: TestHookRequest) -> Result<Response<TestHookResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(error_response(to_error_json(&req.project_path, &e)));
+    }
+    let request_data = if req.request_data.trim().is_empty() {
+        None
+    } else {
+        match serde_json::from_str(&req.request_data) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                let err = HookError::from(e);
+                return Ok(error_response(to_error_json(&req.project_path, &err)));
+            }
+        }
+    };
+    let input = DryRunInput {
+        item_type: hook_item_type(project_path, &req.item_type).await,
+        event: req.event.clone(),
+        item_id: (!req.item_id.is_empty()).then(|| req.item_id.clone()),
+        request_data,
+        execute: req.execute,
+    };
+    let report = match dry_run_hooks(project_path, input).await {
+        Ok(report) => report,
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+    };
+    Ok(Response::new(TestHookResponse {
+        success: true,
+        error: String::new(),
+        phase: report.context.phase,
+        operation: report.context.operation,
+        item_type: report.context.item_type,
+        hooks: report.hooks.into_iter().map(tested_hook_to_proto).collect(),
+        request_data: report
+            .request_data
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+        blocked_by: report.blocked_by.unwrap_or_default(),
+    }))
+}
+
+fn tested_hook_to_proto(tested: DryRunHook) -> TestedHook {
+    TestedHook {
+        target: tested.hook.url.unwrap_or(tested.hook.command),
+        pattern: tested.hook.pattern,
+        specificity: u32::from(tested.specificity),
+        is_async: tested.hook.is_async,
+        when_matches: tested.when_matches,
+        run: tested.run.map(run_to_proto),
+    }
+}
+
+fn error_response(error: String) -> Response<TestHookResponse> {
+    Response::new(TestHookResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
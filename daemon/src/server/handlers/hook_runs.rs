@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use crate::hooks::runs::{read_runs, select_runs};
+use crate::hooks::{HookRun, HookRunFilter};
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::hooks_helper::hook_item_type;
+use crate::server::proto::{HookRun as ProtoHookRun, ListHookRunsRequest, ListHookRunsResponse};
+use crate::server::structured_error::to_error_json;
+use tonic::{Response, Status};
+
+/// List a project's recorded hook runs, newest first.
+///
+/// Runs can be narrowed to one item (`item_type`, `item_id`), to a hook
+/// `pattern`, to a `phase` (`pre` or `post`) and to an `outcome`
+/// (`succeeded`, `failed`, `timed_out`, `errored` or `skipped`).
+pub async fn list_hook_runs(
+    req: ListHookRunsRequest,
+) -> Result<Response<ListHookRunsResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(error_response(to_error_json(&req.project_path, &e)));
+    }
+    let filter = HookRunFilter {
+        item_type: hook_item_type(project_path, &req.item_type).await,
+        item_id: req.item_id.clone(),
+        pattern: req.pattern.clone(),
+        phase: req.phase.clone(),
+        outcome: req.outcome.clone(),
+    };
+    let runs = match read_runs(project_path).await {
+        Ok(runs) => runs,
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+    };
+    let limit = usize::try_from(req.limit).unwrap_or(usize::MAX);
+    let (selected, total) = select_runs(runs, &filter, limit);
+    Ok(Response::new(ListHookRunsResponse {
+        success: true,
+        error: String::new(),
+        runs: selected.into_iter().map(run_to_proto).collect(),
+        total_count: total.try_into().unwrap_or(i32::MAX),
+    }))
+}
+
+pub(super) fn run_to_proto(run: HookRun) -> ProtoHookRun {
+    ProtoHookRun {
+        timed_out: run.timed_out(),
+        timestamp: run.timestamp,
+        pattern: run.pattern,
+        target: run.target,
+        phase: run.phase,
+        item_type: run.item_type,
+        operation: run.operation,
+        item_id: run.item_id.unwrap_or_default(),
+        is_async: run.is_async,
+        outcome: run.outcome.as_str().to_string(),
+        exit_code: run.exit_code,
+        duration_ms: run.duration_ms,
+        stdout: run.stdout,
+        stderr: run.stderr,
+        error: run.error.unwrap_or_default(),
+    }
+}
+
+fn error_response(error: String) -> Response<ListHookRunsResponse> {
+    Response::new(ListHookRunsResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
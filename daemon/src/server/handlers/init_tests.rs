@@ -20,6 +20,8 @@ fn test_all_config_fields_acknowledged_in_init() {
         default_editor: _,    // ✓ exposed via InitRequest.init_config (Config.default_editor)
         workspace,            // ✓ exposed via InitRequest.init_config (Config.workspace)
         cleanup: _,           // not exposed at init time; configured directly in config.json
+        auto_commit: _,       // not exposed at init time; configured directly in config.json
+        sync: _,              // not exposed at init time; configured directly in config.json
         extra: _,             // ✓ exposed via InitRequest.init_config (Config.user_values)
     } = CentyConfig::default();
 
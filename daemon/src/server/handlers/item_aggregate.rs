@@ -0,0 +1,163 @@
+use std::path::Path;
+
+use crate::item::core::error::ItemError;
+use crate::item::generic::storage::generic_list;
+use crate::link::resolve_link_filters;
+use crate::query::{parse_group_by, pushdown, GroupBy, GroupCounts, Query};
+use crate::registry::{list_projects, track_project_async, ListProjectsOptions, RegistryError};
+use crate::server::assert_service::assert_initialized;
+use crate::server::proto::{AggregateItemsRequest, AggregateItemsResponse, ItemGroup};
+use crate::server::structured_error::{to_error_json, StructuredError};
+use tonic::{Response, Status};
+
+use super::item_list::parse_request_filter;
+use super::item_type_resolve::resolve_item_type_config;
+
+/// Count the items of one type that match `filter`, grouped by `group_by`.
+///
+/// With a `project_path` the counts cover that project; otherwise they cover
+/// every initialized project of `organization_slug`. In the organization case
+/// projects that fail to list are reported in `errors` and skipped.
+pub async fn aggregate_items(
+    req: AggregateItemsRequest,
+) -> Result<Response<AggregateItemsResponse>, Status> {
+    let group_by = match parse_group_by(&req.group_by) {
+        Ok(g) => g,
+        Err(e) => {
+            return Ok(error_response(
+                StructuredError::new(&req.project_path, "INVALID_GROUP_BY", e.to_string())
+                    .to_json(),
+            ))
+        }
+    };
+    let query = match parse_request_filter(&req.project_path, &req.filter) {
+        Ok(q) => q,
+        Err(error) => return Ok(error_response(error)),
+    };
+    let mut counts = GroupCounts::new();
+    let mut errors: Vec<String> = Vec::new();
+
+    if !req.project_path.is_empty() {
+        track_project_async(req.project_path.clone());
+        let project_path = Path::new(&req.project_path);
+        if let Err(e) = assert_initialized(project_path) {
+            return Ok(error_response(to_error_json(&req.project_path, &e)));
+        }
+        let project_query = match resolve_link_filters(project_path, query).await {
+            Ok(q) => q,
+            Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+        };
+        let counted = match resolve_item_type_config(project_path, &req.item_type).await {
+            Ok((item_type, _config)) => {
+                count_project(
+                    project_path,
+                    &item_type,
+                    &project_query,
+                    &group_by,
+                    &mut counts,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = counted {
+            return Ok(error_response(to_error_json(&req.project_path, &e)));
+        }
+    } else if !req.organization_slug.is_empty() {
+        match count_organization(&req, &query, &group_by, &mut counts).await {
+            Ok(project_errors) => errors = project_errors,
+            Err(e) => {
+                return Ok(error_response(
+                    StructuredError::new(
+                        "",
+                        "REGISTRY_ERROR",
+                        format!("Failed to list projects: {e}"),
+                    )
+                    .to_json(),
+                ))
+            }
+        }
+    } else {
+        return Ok(error_response(
+            StructuredError::new(
+                "",
+                "INVALID_REQUEST",
+                "Either project_path or organization_slug is required".to_string(),
+            )
+            .to_json(),
+        ));
+    }
+
+    let total_count = counts.total().try_into().unwrap_or(i32::MAX);
+    Ok(Response::new(AggregateItemsResponse {
+        success: true,
+        error: String::new(),
+        groups: counts
+            .into_groups()
+            .into_iter()
+            .map(|g| ItemGroup {
+                key: g.key,
+                count: g.count.try_into().unwrap_or(i32::MAX),
+            })
+            .collect(),
+        total_count,
+        errors,
+    }))
+}
+
+/// Add the matching items of every initialized project in the request's
+/// organization to `counts`, returning the per-project failures.
+async fn count_organization(
+    req: &AggregateItemsRequest,
+    query: &Query,
+    group_by: &GroupBy,
+    counts: &mut GroupCounts,
+) -> Result<Vec<String>, RegistryError> {
+    let projects = list_projects(ListProjectsOptions {
+        organization_slug: Some(&req.organization_slug),
+        ..Default::default()
+    })
+    .await?;
+    let mut errors = Vec::new();
+    for project in projects.iter().filter(|p| p.initialized) {
+        let path = Path::new(&project.path);
+        // A project without the requested item type contributes nothing.
+        let Ok((item_type, _config)) = resolve_item_type_config(path, &req.item_type).await else {
+            continue;
+        };
+        let project_query = match resolve_link_filters(path, query.clone()).await {
+            Ok(q) => q,
+            Err(e) => {
+                errors.push(format!("{}: {e}", project.path));
+                continue;
+            }
+        };
+        if let Err(e) = count_project(path, &item_type, &project_query, group_by, counts).await {
+            errors.push(format!("{}: {e}", project.path));
+        }
+    }
+    Ok(errors)
+}
+
+/// Add the matching items of one project to `counts`.
+async fn count_project(
+    project_path: &Path,
+    item_type: &str,
+    query: &Query,
+    group_by: &GroupBy,
+    counts: &mut GroupCounts,
+) -> Result<(), ItemError> {
+    let items = generic_list(project_path, item_type, pushdown(query).filters).await?;
+    for item in items.iter().filter(|item| query.matches(item)) {
+        counts.add(item, group_by);
+    }
+    Ok(())
+}
+
+fn error_response(error: String) -> Response<AggregateItemsResponse> {
+    Response::new(AggregateItemsResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
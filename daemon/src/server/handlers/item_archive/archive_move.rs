@@ -20,7 +20,7 @@ pub(super) async fn run_archive_hooks_and_move(
     if let Err(e) = maybe_run_pre_hooks(
         project_path,
         hook_type,
-        HookOperation::Move,
+        HookOperation::Archive,
         project_path_str,
         Some(item_id),
         Some(hook_request_data.clone()),
@@ -42,7 +42,7 @@ pub(super) async fn run_archive_hooks_and_move(
     maybe_run_post_hooks(
         project_path,
         hook_type,
-        HookOperation::Move,
+        HookOperation::Archive,
         project_path_str,
         Some(item_id),
         Some(hook_request_data),
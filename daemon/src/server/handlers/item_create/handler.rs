@@ -1,10 +1,11 @@
 use super::super::item_type_resolve::resolve_item_type_config;
 use super::operation::{build_options, do_create};
-use crate::hooks::HookOperation;
+use crate::hooks::{HookError, HookOperation};
 use crate::registry::track_project_async;
 use crate::server::assert_service::assert_initialized;
 use crate::server::helpers::{nonempty, nonzero_u32};
-use crate::server::hooks_helper::maybe_run_pre_hooks;
+use crate::server::hook_patch::{custom_fields_json, PatchedRequest};
+use crate::server::hooks_helper::maybe_run_patching_pre_hooks;
 use crate::server::proto::{CreateItemRequest, CreateItemResponse};
 use crate::server::structured_error::to_error_json;
 use std::path::Path;
@@ -19,9 +20,26 @@ fn err_resp(
         ..Default::default()
     })
 }
-pub async fn create_item(req: CreateItemRequest) -> Result<Response<CreateItemResponse>, Status> {
+/// Take the fields pre-hooks may have rewritten from the patched request.
+fn apply_hook_patch(
+    req: &mut CreateItemRequest,
+    patched: &PatchedRequest<'_>,
+) -> Result<(), HookError> {
+    req.title = patched.string("title")?;
+    req.body = patched.string("body")?;
+    req.status = patched.string("status")?;
+    req.priority = patched.priority()?;
+    req.tags = patched.tags()?;
+    req.custom_fields = patched.custom_fields()?;
+    Ok(())
+}
+pub async fn create_item(
+    mut req: CreateItemRequest,
+) -> Result<Response<CreateItemResponse>, Status> {
     track_project_async(req.project_path.clone());
-    let project_path = Path::new(&req.project_path);
+    // Owned, since pre-hook patches rewrite `req`.
+    let project_path_str = req.project_path.clone();
+    let project_path = Path::new(&project_path_str);
     if let Err(e) = assert_initialized(project_path) {
         return Ok(err_resp(&req.project_path, &e));
     }
@@ -31,22 +49,31 @@ pub async fn create_item(req: CreateItemRequest) -> Result<Response<CreateItemRe
     };
     let hook_type = config.name.to_lowercase();
     let hook_project_path = req.project_path.clone();
-    let hook_data = serde_json::json!({
+    let request_data = serde_json::json!({
         "item_type": &item_type, "title": &req.title,
         "body": &req.body, "priority": req.priority, "status": &req.status,
+        "tags": &req.tags, "custom_fields": custom_fields_json(&req.custom_fields),
     });
-    if let Err(e) = maybe_run_pre_hooks(
+    let outcome = match maybe_run_patching_pre_hooks(
         project_path,
         &hook_type,
         HookOperation::Create,
         &hook_project_path,
         None,
-        Some(hook_data.clone()),
+        Some(request_data.clone()),
+        None,
     )
     .await
     {
-        return Ok(err_resp(&req.project_path, &e));
+        Ok(outcome) => outcome,
+        Err(e) => return Ok(err_resp(&req.project_path, &e)),
+    };
+    if let Some(patched) = PatchedRequest::new(&outcome) {
+        if let Err(e) = apply_hook_patch(&mut req, &patched) {
+            return Ok(err_resp(&req.project_path, &e));
+        }
     }
+    let hook_data = outcome.request_data.unwrap_or(request_data);
     let options = build_options(
         req.title,
         req.body,
@@ -64,6 +91,7 @@ pub async fn create_item(req: CreateItemRequest) -> Result<Response<CreateItemRe
             &hook_type,
             &hook_project_path,
             hook_data,
+            outcome.modifications,
             &req.project_path,
             options,
             req.projects.len() > 1,
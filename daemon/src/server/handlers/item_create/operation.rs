@@ -1,8 +1,8 @@
-use crate::hooks::HookOperation;
+use crate::hooks::{HookModification, HookOperation};
 use crate::item::core::error::ItemError;
 use crate::registry::find_org_repo;
 use crate::server::convert_entity::generic_item_to_proto;
-use crate::server::hooks_helper::maybe_run_post_hooks;
+use crate::server::hooks_helper::{item_snapshot, maybe_run_patched_post_hooks};
 use crate::server::proto::CreateItemResponse;
 use crate::server::structured_error::to_error_json;
 use crate::utils::get_centy_path;
@@ -18,6 +18,7 @@ pub(super) async fn do_create(
     hook_type: &str,
     hook_project_path: &str,
     hook_data: serde_json::Value,
+    modifications: Vec<HookModification>,
     project_path_str: &str,
     mut options: CreateOptions,
     org_wide: bool,
@@ -70,7 +71,7 @@ pub(super) async fn do_create(
 
     match mdstore::create(&write_path, config, options).await {
         Ok(item) => {
-            maybe_run_post_hooks(
+            maybe_run_patched_post_hooks(
                 project_path,
                 hook_type,
                 HookOperation::Create,
@@ -78,6 +79,9 @@ pub(super) async fn do_create(
                 Some(&item.id),
                 Some(hook_data),
                 true,
+                modifications,
+                None,
+                Some(item_snapshot(&item)),
             )
             .await;
             CreateItemResponse {
@@ -87,7 +91,7 @@ pub(super) async fn do_create(
             }
         }
         Err(e) => {
-            maybe_run_post_hooks(
+            maybe_run_patched_post_hooks(
                 project_path,
                 hook_type,
                 HookOperation::Create,
@@ -95,6 +99,9 @@ pub(super) async fn do_create(
                 None,
                 Some(hook_data),
                 false,
+                modifications,
+                None,
+                None,
             )
             .await;
             CreateItemResponse {
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use crate::history::{item_at_revision, item_history, Revision};
+use crate::item::core::error::ItemError;
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::convert_entity::generic_item_to_proto;
+use crate::server::proto::{
+    GetItemAtRevisionRequest, GetItemAtRevisionResponse, GetItemHistoryRequest,
+    GetItemHistoryResponse, ItemRevision,
+};
+use crate::server::structured_error::to_error_json;
+use tonic::{Response, Status};
+
+use super::item_type_resolve::{resolve_item_id, resolve_item_type_config};
+use super::journal::change_to_proto;
+
+/// List the commits that touched an item's file, newest first.
+///
+/// Each revision carries the author, date and message, the frontmatter
+/// fields that changed and a diff of the body. Moves between item types and
+/// slug renames are followed. `limit` of `0` returns the whole history.
+pub async fn get_item_history(
+    req: GetItemHistoryRequest,
+) -> Result<Response<GetItemHistoryResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(history_error(to_error_json(&req.project_path, &e)));
+    }
+    let (folder, id) = match resolve_item(project_path, &req.item_type, &req.item_id).await {
+        Ok(pair) => pair,
+        Err(e) => return Ok(history_error(to_error_json(&req.project_path, &e))),
+    };
+    let limit = usize::try_from(req.limit).unwrap_or(usize::MAX);
+    let path = project_path.to_path_buf();
+    let history = tokio::task::spawn_blocking(move || item_history(&path, &folder, &id, limit))
+        .await
+        .map_err(|e| Status::internal(format!("History task failed: {e}")))?;
+    match history {
+        Ok(revisions) => Ok(Response::new(GetItemHistoryResponse {
+            success: true,
+            error: String::new(),
+            revisions: revisions.into_iter().map(revision_to_proto).collect(),
+        })),
+        Err(e) => Ok(history_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// Read an item as it was at a revision (a commit id, branch, tag or any
+/// other `git rev-parse` expression). Restoring is an `UpdateItem` with the
+/// returned fields.
+pub async fn get_item_at_revision(
+    req: GetItemAtRevisionRequest,
+) -> Result<Response<GetItemAtRevisionResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(revision_error(to_error_json(&req.project_path, &e)));
+    }
+    let (folder, id) = match resolve_item(project_path, &req.item_type, &req.item_id).await {
+        Ok(pair) => pair,
+        Err(e) => return Ok(revision_error(to_error_json(&req.project_path, &e))),
+    };
+    let path = project_path.to_path_buf();
+    let revision = req.revision.clone();
+    let result =
+        tokio::task::spawn_blocking(move || item_at_revision(&path, &folder, &id, &revision))
+            .await
+            .map_err(|e| Status::internal(format!("History task failed: {e}")))?;
+    match result {
+        Ok(found) => Ok(Response::new(GetItemAtRevisionResponse {
+            success: true,
+            error: String::new(),
+            item: Some(generic_item_to_proto(&found.item, &found.item_type)),
+            commit: found.commit,
+            path: found.path,
+            content: found.content,
+        })),
+        Err(e) => Ok(revision_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// The folder and id of the requested item. Folders without a type config
+/// (the archive, removed types) are used as given so their history stays
+/// reachable.
+async fn resolve_item(
+    project_path: &Path,
+    item_type: &str,
+    item_id: &str,
+) -> Result<(String, String), ItemError> {
+    match resolve_item_type_config(project_path, item_type).await {
+        Ok((folder, config)) => {
+            let id = resolve_item_id(project_path, &folder, &config, item_id).await?;
+            Ok((folder, id))
+        }
+        Err(_e) => Ok((item_type.to_string(), item_id.to_string())),
+    }
+}
+
+fn revision_to_proto(revision: Revision) -> ItemRevision {
+    ItemRevision {
+        commit: revision.commit,
+        author_name: revision.author_name,
+        author_email: revision.author_email,
+        date: revision.date,
+        message: revision.message,
+        path: revision.path,
+        previous_path: revision.previous_path.unwrap_or_default(),
+        change_kind: revision.kind.as_str().to_string(),
+        changes: revision.changes.into_iter().map(change_to_proto).collect(),
+        body_diff: revision.body_diff,
+    }
+}
+
+fn history_error(error: String) -> Response<GetItemHistoryResponse> {
+    Response::new(GetItemHistoryResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn revision_error(error: String) -> Response<GetItemAtRevisionResponse> {
+    Response::new(GetItemAtRevisionResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
@@ -1,101 +1,147 @@
 use super::super::item_type_resolve::resolve_item_type_config;
-use super::filters::{build_filters_from_mql, parse_custom_field_filters};
+use super::filters::parse_request_filter;
+use super::page::select_page;
 use crate::item::generic::storage::generic_list;
+use crate::link::resolve_link_filters;
+use crate::query::{parse_sort, pushdown, sort_items, Cursor, Query, SortKey};
 use crate::registry::{get_org_projects, get_project_info, track_project_async};
 use crate::server::assert_service::assert_initialized;
 use crate::server::convert_entity::generic_item_to_proto;
-use crate::server::proto::{GenericItem as ProtoGenericItem, ListItemsRequest, ListItemsResponse};
-use crate::server::structured_error::to_error_json;
-use mdstore::Filters;
-use std::collections::HashMap;
+use crate::server::proto::{ListItemsRequest, ListItemsResponse};
+use crate::server::structured_error::{to_error_json, StructuredError};
+use crate::view::{find_view, ViewError};
 use std::path::Path;
 use tonic::{Response, Status};
 
+/// Order used when the request does not ask for one.
+const DEFAULT_SORT: &str = "createdAt";
+
 pub async fn list_items(req: ListItemsRequest) -> Result<Response<ListItemsResponse>, Status> {
     track_project_async(req.project_path.clone());
     let project_path = Path::new(&req.project_path);
     if let Err(e) = assert_initialized(project_path) {
-        return Ok(Response::new(ListItemsResponse {
-            success: false,
-            error: to_error_json(&req.project_path, &e),
-            ..Default::default()
-        }));
+        return Ok(error_response(to_error_json(&req.project_path, &e)));
     }
-    let (item_type, _config) = match resolve_item_type_config(project_path, &req.item_type).await {
+    let spec = match list_spec(project_path, &req).await {
+        Ok(spec) => spec,
+        Err(error) => return Ok(error_response(error)),
+    };
+    let (item_type, config) = match resolve_item_type_config(project_path, &spec.item_type).await {
         Ok(pair) => pair,
-        Err(e) => {
-            return Ok(Response::new(ListItemsResponse {
-                success: false,
-                error: to_error_json(&req.project_path, &e),
-                items: vec![],
-                total_count: 0,
-            }))
-        }
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
     };
-    let filters = build_filters_from_mql(&req.filter, req.limit, req.offset);
-    let custom_field_filters = parse_custom_field_filters(&req.filter);
-    match generic_list(project_path, &item_type, filters).await {
-        Ok(mut project_items) => {
-            apply_custom_field_filters(&mut project_items, &custom_field_filters);
-            let mut proto_items: Vec<ProtoGenericItem> = project_items
-                .iter()
-                .map(|item| generic_item_to_proto(item, &item_type))
-                .collect();
-
-            let include_org = req.include_organization_items.unwrap_or(true);
-            if include_org {
-                let org_filters = build_filters_from_mql(&req.filter, 0, 0);
-                let org_proto_items = fetch_org_items(
-                    &req.project_path,
-                    &item_type,
-                    org_filters,
-                    &custom_field_filters,
-                )
-                .await;
-                proto_items.extend(org_proto_items);
+    let query = match resolve_link_filters(project_path, spec.query).await {
+        Ok(q) => q,
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+    };
+    let sort_keys = effective_sort(&spec.sort);
+    let cursor = if req.cursor.is_empty() {
+        None
+    } else {
+        match Cursor::decode(&req.cursor, &sort_keys) {
+            Ok(c) => Some(c),
+            Err(e) => {
+                return Ok(error_response(
+                    StructuredError::new(&req.project_path, "INVALID_CURSOR", e.to_string())
+                        .to_json(),
+                ))
             }
-
-            let total_count = proto_items.len().try_into().unwrap_or(i32::MAX);
-            Ok(Response::new(ListItemsResponse {
-                success: true,
-                error: String::new(),
-                items: proto_items,
-                total_count,
-            }))
         }
-        Err(e) => Ok(Response::new(ListItemsResponse {
-            success: false,
-            error: to_error_json(&req.project_path, &e),
-            items: vec![],
-            total_count: 0,
-        })),
+    };
+    let mut items = match generic_list(project_path, &item_type, pushdown(&query).filters).await {
+        Ok(items) => items,
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+    };
+    items.retain(|item| query.matches(item));
+    if req.include_organization_items.unwrap_or(true) {
+        items.extend(fetch_org_items(&req.project_path, &item_type, &query).await);
     }
+    sort_items(&mut items, &sort_keys, &config.statuses);
+    let page = select_page(
+        items,
+        req.limit,
+        req.offset,
+        cursor.as_ref(),
+        &sort_keys,
+        &config.statuses,
+    );
+    Ok(Response::new(ListItemsResponse {
+        success: true,
+        error: String::new(),
+        items: page
+            .items
+            .iter()
+            .map(|item| generic_item_to_proto(item, &item_type))
+            .collect(),
+        total_count: page.total_count,
+        next_cursor: page.next_cursor,
+    }))
 }
 
-fn apply_custom_field_filters(
-    items: &mut Vec<mdstore::Item>,
-    custom_field_filters: &HashMap<String, String>,
-) {
-    if !custom_field_filters.is_empty() {
-        items.retain(|item| {
-            custom_field_filters.iter().all(|(field, value)| {
-                item.frontmatter
-                    .custom_fields
-                    .get(field)
-                    .and_then(|v| v.as_str())
-                    == Some(value.as_str())
-            })
+/// What to list once the request's saved view, if any, is applied.
+struct ListSpec {
+    item_type: String,
+    query: Query,
+    sort: String,
+}
+
+/// Combine the request with the saved view it names.
+///
+/// The view supplies the item type and sort unless the request sets them;
+/// a request filter narrows the view's filter rather than replacing it.
+/// Errors are returned as structured error JSON.
+async fn list_spec(project_path: &Path, req: &ListItemsRequest) -> Result<ListSpec, String> {
+    let request_query = parse_request_filter(&req.project_path, &req.filter)?;
+    if req.view.is_empty() {
+        return Ok(ListSpec {
+            item_type: req.item_type.clone(),
+            query: request_query,
+            sort: req.sort.clone(),
         });
     }
+    let view_error = |e: ViewError| to_error_json(&req.project_path, &e);
+    let view = find_view(project_path, &req.view)
+        .await
+        .map_err(view_error)?;
+    let query = Query::And(vec![view.query().map_err(view_error)?, request_query]);
+    Ok(ListSpec {
+        item_type: if req.item_type.is_empty() {
+            view.item_type
+        } else {
+            req.item_type.clone()
+        },
+        query,
+        sort: if req.sort.is_empty() {
+            view.sort
+        } else {
+            req.sort.clone()
+        },
+    })
+}
+
+/// The request's sort keys, or creation order when none are given.
+///
+/// Every page is cut from one fully ordered list (ties broken by id), which
+/// is what keeps offsets and cursors consistent across pages.
+fn effective_sort(spec: &str) -> Vec<SortKey> {
+    let keys = parse_sort(spec);
+    if keys.is_empty() {
+        parse_sort(DEFAULT_SORT)
+    } else {
+        keys
+    }
+}
+
+fn error_response(error: String) -> Response<ListItemsResponse> {
+    Response::new(ListItemsResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
 }
 
 /// Fetch org-wide items for the given project and item type, filtered by the project's slug.
-async fn fetch_org_items(
-    project_path: &str,
-    item_type: &str,
-    filters: Filters,
-    custom_field_filters: &HashMap<String, String>,
-) -> Vec<ProtoGenericItem> {
+async fn fetch_org_items(project_path: &str, item_type: &str, query: &Query) -> Vec<mdstore::Item> {
     let Some(org_repo_path) = resolve_org_repo_path(project_path).await else {
         return vec![];
     };
@@ -105,7 +151,7 @@ async fn fetch_org_items(
     if !org_type_dir.exists() {
         return vec![];
     }
-    let Ok(mut org_items) = mdstore::list(&org_type_dir, filters).await else {
+    let Ok(mut org_items) = mdstore::list(&org_type_dir, pushdown(query).filters).await else {
         return vec![];
     };
     // Keep only items whose `projects` field contains the current project's slug
@@ -120,11 +166,8 @@ async fn fetch_org_items(
     } else {
         return vec![];
     }
-    apply_custom_field_filters(&mut org_items, custom_field_filters);
+    org_items.retain(|item| query.matches(item));
     org_items
-        .iter()
-        .map(|item| generic_item_to_proto(item, item_type))
-        .collect()
 }
 
 /// Resolve the org repo path for a project.
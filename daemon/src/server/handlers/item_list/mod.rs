@@ -1,3 +1,5 @@
 mod filters;
 mod handler;
+mod page;
+pub use filters::{paginate, parse_request_filter};
 pub use handler::list_items;
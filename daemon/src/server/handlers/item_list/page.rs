@@ -0,0 +1,48 @@
+use super::filters::paginate;
+use crate::query::{Cursor, SortKey};
+
+/// One page cut from the merged, sorted result set.
+pub struct Page {
+    pub items: Vec<mdstore::Item>,
+    /// Number of items matching the filter across all pages.
+    pub total_count: i32,
+    /// Token for the following page, empty on the last page.
+    pub next_cursor: String,
+}
+
+/// Cut a page out of the full sorted list.
+///
+/// With a cursor the page starts right after it and `offset` is ignored.
+/// A `next_cursor` is issued whenever `limit` is set and items remain.
+#[must_use]
+pub fn select_page(
+    items: Vec<mdstore::Item>,
+    limit: u32,
+    offset: u32,
+    cursor: Option<&Cursor>,
+    keys: &[SortKey],
+    statuses: &[String],
+) -> Page {
+    let total_count = items.len().try_into().unwrap_or(i32::MAX);
+    let remaining: Vec<mdstore::Item> = match cursor {
+        Some(position) => items
+            .into_iter()
+            .filter(|item| position.precedes(item, keys, statuses))
+            .collect(),
+        None => items.into_iter().skip(offset as usize).collect(),
+    };
+    let has_more = limit > 0 && remaining.len() > limit as usize;
+    let page = paginate(remaining, limit, 0);
+    let next_cursor = if has_more {
+        page.last()
+            .map(|last| Cursor::after(last, keys, statuses).encode())
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+    Page {
+        items: page,
+        total_count,
+        next_cursor,
+    }
+}
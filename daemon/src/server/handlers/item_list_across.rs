@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use crate::item::core::error::ItemError;
+use crate::item::generic::storage::generic_list;
+use crate::link::resolve_link_filters;
+use crate::query::{pushdown, Query};
+use crate::registry::{list_projects, ListProjectsOptions, ProjectInfo};
+use crate::server::convert_entity::generic_item_to_proto;
+use crate::server::proto::{
+    ItemWithProject as ProtoItemWithProject, ListItemsAcrossProjectsRequest,
+    ListItemsAcrossProjectsResponse,
+};
+use crate::server::structured_error::StructuredError;
+use crate::utils::format_display_path;
+use tonic::{Response, Status};
+
+use super::item_list::{paginate, parse_request_filter};
+use super::item_type_resolve::resolve_item_type_config;
+
+/// List items of one type across every registered project, or across the
+/// projects of one organization when `organization_slug` is set.
+///
+/// Items are ordered newest first. Projects that fail to list are reported
+/// in `errors` and do not fail the whole request.
+pub async fn list_items_across_projects(
+    req: ListItemsAcrossProjectsRequest,
+) -> Result<Response<ListItemsAcrossProjectsResponse>, Status> {
+    let org_slug = Some(req.organization_slug.as_str()).filter(|s| !s.is_empty());
+    let projects = match list_projects(ListProjectsOptions {
+        organization_slug: org_slug,
+        ..Default::default()
+    })
+    .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            return Ok(Response::new(ListItemsAcrossProjectsResponse {
+                success: false,
+                error: StructuredError::new(
+                    "",
+                    "REGISTRY_ERROR",
+                    format!("Failed to list projects: {e}"),
+                )
+                .to_json(),
+                ..Default::default()
+            }))
+        }
+    };
+
+    let query = match parse_request_filter("", &req.filter) {
+        Ok(q) => q,
+        Err(error) => {
+            return Ok(Response::new(ListItemsAcrossProjectsResponse {
+                success: false,
+                error,
+                ..Default::default()
+            }))
+        }
+    };
+    let mut found: Vec<(String, ProtoItemWithProject)> = Vec::new();
+    let mut errors: Vec<String> = Vec::new();
+    for project in projects.iter().filter(|p| p.initialized) {
+        // Link conditions refer to each project's own links.
+        let project_query =
+            match resolve_link_filters(Path::new(&project.path), query.clone()).await {
+                Ok(q) => q,
+                Err(e) => {
+                    errors.push(format!("{}: {e}", project.path));
+                    continue;
+                }
+            };
+        match list_project_items(project, &req.item_type, &project_query).await {
+            Ok(items) => found.extend(items),
+            Err(e) => errors.push(format!("{}: {e}", project.path)),
+        }
+    }
+
+    found.sort_by(|(a_created, a), (b_created, b)| {
+        b_created
+            .cmp(a_created)
+            .then_with(|| a.project_path.cmp(&b.project_path))
+    });
+    let total_count = found.len().try_into().unwrap_or(i32::MAX);
+    let items = paginate(found, req.limit, req.offset)
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect();
+
+    Ok(Response::new(ListItemsAcrossProjectsResponse {
+        success: true,
+        error: String::new(),
+        items,
+        total_count,
+        errors,
+    }))
+}
+
+/// Matching items of one project, each paired with its `createdAt` for ordering.
+///
+/// A project without the requested item type contributes nothing; any other
+/// failure to resolve the type is reported for the project.
+async fn list_project_items(
+    project: &ProjectInfo,
+    item_type_name: &str,
+    query: &Query,
+) -> Result<Vec<(String, ProtoItemWithProject)>, ItemError> {
+    let project_path = Path::new(&project.path);
+    let item_type = match resolve_item_type_config(project_path, item_type_name).await {
+        Ok((item_type, _config)) => item_type,
+        Err(ItemError::ItemTypeNotFound(_)) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let items = generic_list(project_path, &item_type, pushdown(query).filters).await?;
+    let project_name = project.name.as_deref().unwrap_or(&project.path);
+    Ok(items
+        .iter()
+        .filter(|item| query.matches(item))
+        .map(|item| {
+            let entry = ProtoItemWithProject {
+                item: Some(generic_item_to_proto(item, &item_type)),
+                project_path: project.path.clone(),
+                project_name: project_name.to_owned(),
+                display_path: format_display_path(&project.path),
+            };
+            (item.frontmatter.created_at.clone(), entry)
+        })
+        .collect())
+}
@@ -1,3 +1,5 @@
+use super::super::page::Page;
+use super::super::paginate;
 use super::*;
 
 // ── extract_project_slug ──────────────────────────────────────────────────────
@@ -23,9 +25,13 @@ fn test_extract_project_slug_empty() {
 
 // ── filter tests ─────────────────────────────────────────────────────────────
 
+fn build_filters_from_mql(filter_json: &str) -> mdstore::Filters {
+    pushdown(&parse_request_filter("", filter_json).unwrap()).filters
+}
+
 #[test]
 fn test_empty_filter_returns_defaults() {
-    let f = build_filters_from_mql("", 0, 0);
+    let f = build_filters_from_mql("");
     assert!(f.statuses.is_none());
     assert!(f.priority.is_none());
     assert!(!f.include_deleted);
@@ -33,22 +39,15 @@ fn test_empty_filter_returns_defaults() {
     assert!(f.offset.is_none());
 }
 
-#[test]
-fn test_pagination_applied() {
-    let f = build_filters_from_mql("", 10, 5);
-    assert_eq!(f.limit, Some(10));
-    assert_eq!(f.offset, Some(5));
-}
-
 #[test]
 fn test_status_exact_match() {
-    let f = build_filters_from_mql(r#"{"status":"open"}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"status":"open"}"#);
     assert_eq!(f.statuses, Some(vec!["open".to_string()]));
 }
 
 #[test]
 fn test_status_in_operator() {
-    let f = build_filters_from_mql(r#"{"status":{"$in":["open","in-progress"]}}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"status":{"$in":["open","in-progress"]}}"#);
     assert_eq!(
         f.statuses,
         Some(vec!["open".to_string(), "in-progress".to_string()])
@@ -57,46 +56,120 @@ fn test_status_in_operator() {
 
 #[test]
 fn test_priority_exact() {
-    let f = build_filters_from_mql(r#"{"priority":1}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"priority":1}"#);
     assert_eq!(f.priority, Some(1));
 }
 
 #[test]
 fn test_priority_lte() {
-    let f = build_filters_from_mql(r#"{"priority":{"$lte":2}}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"priority":{"$lte":2}}"#);
     assert_eq!(f.priority_lte, Some(2));
 }
 
 #[test]
 fn test_priority_gte() {
-    let f = build_filters_from_mql(r#"{"priority":{"$gte":1}}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"priority":{"$gte":1}}"#);
     assert_eq!(f.priority_gte, Some(1));
 }
 
 #[test]
 fn test_deleted_at_exists() {
-    let f = build_filters_from_mql(r#"{"deletedAt":{"$exists":true}}"#, 0, 0);
+    let f = build_filters_from_mql(r#"{"deletedAt":{"$exists":true}}"#);
     assert!(f.include_deleted);
 }
 
 #[test]
-fn test_invalid_json_returns_defaults() {
-    let f = build_filters_from_mql("not-json", 0, 0);
-    assert!(f.statuses.is_none());
-    assert!(f.priority.is_none());
+fn test_invalid_filter_is_rejected() {
+    for bad in ["not-json", r#"{"priority":{"$bogus":1}}"#, r#"{"$or":[]}"#] {
+        let error = parse_request_filter("/p", bad).unwrap_err();
+        let parsed: serde_json::Value = serde_json::from_str(&error).unwrap();
+        assert_eq!(parsed["messages"][0]["code"], "INVALID_FILTER", "{bad}");
+        assert!(parsed["messages"][0]["path"].is_string(), "{bad}");
+    }
 }
 
 #[test]
 fn test_combined_filter() {
     let f = build_filters_from_mql(
         r#"{"status":{"$in":["open","in-progress"]},"priority":{"$lte":2}}"#,
-        20,
-        0,
     );
     assert_eq!(
         f.statuses,
         Some(vec!["open".to_string(), "in-progress".to_string()])
     );
     assert_eq!(f.priority_lte, Some(2));
-    assert_eq!(f.limit, Some(20));
+    assert!(f.limit.is_none());
+}
+
+#[test]
+fn test_paginate_in_memory() {
+    assert_eq!(paginate(vec![1, 2, 3, 4, 5], 2, 1), vec![2, 3]);
+    assert_eq!(paginate(vec![1, 2, 3], 0, 2), vec![3]);
+}
+
+// ── page selection ───────────────────────────────────────────────────────────
+
+fn page_item(id: &str, created_at: &str) -> mdstore::Item {
+    mdstore::Item {
+        id: id.to_string(),
+        title: id.to_uppercase(),
+        body: String::new(),
+        frontmatter: mdstore::Frontmatter {
+            display_number: None,
+            status: None,
+            priority: None,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            deleted_at: None,
+            tags: None,
+            custom_fields: std::collections::HashMap::new(),
+        },
+        comment: None,
+    }
+}
+
+fn page_ids(page: &Page) -> Vec<&str> {
+    page.items.iter().map(|i| i.id.as_str()).collect()
+}
+
+fn sorted_items() -> Vec<mdstore::Item> {
+    vec![
+        page_item("a", "2024-01-01T00:00:00Z"),
+        page_item("b", "2024-01-02T00:00:00Z"),
+        page_item("c", "2024-01-03T00:00:00Z"),
+        page_item("d", "2024-01-04T00:00:00Z"),
+    ]
+}
+
+#[test]
+fn test_page_offset_keeps_true_total() {
+    let keys = effective_sort("");
+    let page = select_page(sorted_items(), 2, 1, None, &keys, &[]);
+    assert_eq!(page_ids(&page), vec!["b", "c"]);
+    assert_eq!(page.total_count, 4);
+    assert!(!page.next_cursor.is_empty());
+}
+
+#[test]
+fn test_cursor_pages_are_stable_across_inserts_and_deletes() {
+    let keys = effective_sort("");
+    let first = select_page(sorted_items(), 2, 0, None, &keys, &[]);
+    assert_eq!(page_ids(&first), vec!["a", "b"]);
+    let cursor = Cursor::decode(&first.next_cursor, &keys).unwrap();
+
+    // "a" is deleted and an older item "z" is inserted before the cursor.
+    let mut changed = sorted_items();
+    changed.remove(0);
+    changed.insert(0, page_item("z", "2023-12-31T00:00:00Z"));
+    let second = select_page(changed, 2, 0, Some(&cursor), &keys, &[]);
+    assert_eq!(page_ids(&second), vec!["c", "d"]);
+    assert!(second.next_cursor.is_empty());
+}
+
+#[test]
+fn test_cursor_rejected_for_other_sort() {
+    let keys = effective_sort("");
+    let first = select_page(sorted_items(), 1, 0, None, &keys, &[]);
+    assert!(Cursor::decode(&first.next_cursor, &parse_sort("-priority")).is_err());
+    assert!(Cursor::decode("zz-not-hex", &keys).is_err());
 }
@@ -0,0 +1,55 @@
+use crate::server::proto::{
+    ItemWithProject as ProtoItemWithProject, SearchHit as ProtoSearchHit, SearchItemsRequest,
+    SearchItemsResponse,
+};
+use crate::server::structured_error::StructuredError;
+use tonic::{Response, Status};
+
+use super::id_lookup::lookup_by_id;
+use super::scope::scoped_projects;
+use super::text::search_text;
+
+/// Results gathered across the scoped projects.
+#[derive(Default)]
+pub(super) struct SearchOutcome {
+    pub items: Vec<ProtoItemWithProject>,
+    pub hits: Vec<ProtoSearchHit>,
+    pub total_count: i32,
+    pub errors: Vec<String>,
+}
+
+/// Search items by text (`query`) or, when no query is given, by exact `item_id`.
+pub async fn search_items(
+    req: SearchItemsRequest,
+) -> Result<Response<SearchItemsResponse>, Status> {
+    let projects = match scoped_projects(&req.project_path, &req.organization_slug).await {
+        Ok(p) => p,
+        Err(e) => {
+            return Ok(Response::new(SearchItemsResponse {
+                success: false,
+                error: StructuredError::new(
+                    "",
+                    "REGISTRY_ERROR",
+                    format!("Failed to list projects: {e}"),
+                )
+                .to_json(),
+                ..Default::default()
+            }))
+        }
+    };
+
+    let outcome = if req.query.trim().is_empty() {
+        lookup_by_id(&projects, &req.item_type, &req.item_id).await
+    } else {
+        search_text(&projects, &req).await
+    };
+
+    Ok(Response::new(SearchItemsResponse {
+        items: outcome.items,
+        hits: outcome.hits,
+        total_count: outcome.total_count,
+        errors: outcome.errors,
+        success: true,
+        error: String::new(),
+    }))
+}
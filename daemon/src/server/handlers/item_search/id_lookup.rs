@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use crate::item::core::error::ItemError;
+use crate::item::generic::storage::generic_get;
+use crate::server::convert_entity::generic_item_to_proto;
+use crate::server::proto::ItemWithProject as ProtoItemWithProject;
+use crate::utils::format_display_path;
+
+use super::super::item_type_resolve::resolve_item_type_config;
+use super::handler::SearchOutcome;
+use super::scope::ScopedProject;
+
+/// Look up one exact item ID in every scoped project.
+pub(super) async fn lookup_by_id(
+    projects: &[ScopedProject],
+    item_type_name: &str,
+    item_id: &str,
+) -> SearchOutcome {
+    let mut outcome = SearchOutcome::default();
+    for project in projects {
+        process_project(
+            project,
+            item_type_name,
+            item_id,
+            &mut outcome.items,
+            &mut outcome.errors,
+        )
+        .await;
+    }
+    outcome.total_count = outcome.items.len().try_into().unwrap_or(i32::MAX);
+    outcome
+}
+
+async fn process_project(
+    project: &ScopedProject,
+    item_type_name: &str,
+    item_id: &str,
+    found_items: &mut Vec<ProtoItemWithProject>,
+    errors: &mut Vec<String>,
+) {
+    let project_path = Path::new(&project.path);
+    let Ok((item_type, _config)) = resolve_item_type_config(project_path, item_type_name).await
+    else {
+        return;
+    };
+    match generic_get(project_path, &item_type, item_id).await {
+        Ok(item) => {
+            found_items.push(ProtoItemWithProject {
+                item: Some(generic_item_to_proto(&item, &item_type)),
+                project_path: project.path.clone(),
+                project_name: project.name.clone(),
+                display_path: format_display_path(&project.path),
+            });
+        }
+        Err(ItemError::NotFound(_) | ItemError::NotInitialized) => {}
+        Err(e) => {
+            errors.push(format!("{}: {e}", project.path));
+        }
+    }
+}
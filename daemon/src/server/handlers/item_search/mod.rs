@@ -0,0 +1,5 @@
+mod handler;
+mod id_lookup;
+mod scope;
+mod text;
+pub use handler::search_items;
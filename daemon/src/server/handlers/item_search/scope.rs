@@ -0,0 +1,43 @@
+use crate::registry::{get_project_info, list_projects, ListProjectsOptions, RegistryError};
+
+/// A project included in a search, with the name shown in results.
+pub(super) struct ScopedProject {
+    pub path: String,
+    pub name: String,
+}
+
+/// Resolve which projects a search covers.
+///
+/// A non-empty `project_path` limits the search to that project, a non-empty
+/// `organization_slug` to that organization's projects; otherwise every
+/// initialized registered project is searched.
+pub(super) async fn scoped_projects(
+    project_path: &str,
+    organization_slug: &str,
+) -> Result<Vec<ScopedProject>, RegistryError> {
+    if !project_path.is_empty() {
+        let name = get_project_info(project_path)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|info| info.name)
+            .unwrap_or_else(|| project_path.to_string());
+        return Ok(vec![ScopedProject {
+            path: project_path.to_string(),
+            name,
+        }]);
+    }
+    let projects = list_projects(ListProjectsOptions {
+        organization_slug: Some(organization_slug).filter(|s| !s.is_empty()),
+        ..Default::default()
+    })
+    .await?;
+    Ok(projects
+        .into_iter()
+        .filter(|p| p.initialized)
+        .map(|p| ScopedProject {
+            name: p.name.unwrap_or_else(|| p.path.clone()),
+            path: p.path,
+        })
+        .collect())
+}
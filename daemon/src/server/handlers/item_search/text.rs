@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use crate::item::core::error::ItemError;
+use crate::item::generic::storage::generic_get;
+use crate::search::{build_snippet, search_project, tokenize, SearchMatch, SearchOptions};
+use crate::server::convert_entity::generic_item_to_proto;
+use crate::server::proto::{
+    ItemWithProject as ProtoItemWithProject, SearchHighlight as ProtoSearchHighlight,
+    SearchHit as ProtoSearchHit, SearchItemsRequest,
+};
+use crate::utils::format_display_path;
+
+use super::super::item_type_resolve::resolve_item_type_config;
+use super::handler::SearchOutcome;
+use super::scope::ScopedProject;
+
+/// Number of hits returned when the request sets no limit.
+const DEFAULT_LIMIT: usize = 50;
+
+struct Candidate<'proj> {
+    project: &'proj ScopedProject,
+    found: SearchMatch,
+}
+
+/// Full-text search over the scoped projects' indexes.
+///
+/// Hits from all projects are merged by score; only the returned page is
+/// loaded from disk. Index entries whose item has vanished are skipped.
+pub(super) async fn search_text(
+    projects: &[ScopedProject],
+    req: &SearchItemsRequest,
+) -> SearchOutcome {
+    let mut outcome = SearchOutcome::default();
+    let mut candidates: Vec<Candidate<'_>> = Vec::new();
+    for project in projects {
+        let project_path = Path::new(&project.path);
+        let item_type = if req.item_type.is_empty() {
+            None
+        } else {
+            match resolve_item_type_config(project_path, &req.item_type).await {
+                Ok((folder, _config)) => Some(folder),
+                Err(_) => continue,
+            }
+        };
+        let options = SearchOptions {
+            item_type,
+            include_deleted: false,
+        };
+        match search_project(project_path, &req.query, &options).await {
+            Ok(found) => {
+                candidates.extend(found.into_iter().map(|m| Candidate { project, found: m }))
+            }
+            Err(e) => outcome.errors.push(format!("{}: {e}", project.path)),
+        }
+    }
+    candidates.sort_by(|a, b| b.found.score.cmp(&a.found.score));
+    outcome.total_count = candidates.len().try_into().unwrap_or(i32::MAX);
+
+    let limit = usize::try_from(req.limit)
+        .ok()
+        .filter(|l| *l > 0)
+        .unwrap_or(DEFAULT_LIMIT);
+    let terms = tokenize(&req.query);
+    for candidate in candidates.into_iter().take(limit) {
+        let project_path = Path::new(&candidate.project.path);
+        match generic_get(
+            project_path,
+            &candidate.found.item_type,
+            &candidate.found.id,
+        )
+        .await
+        {
+            Ok(item) => outcome.hits.push(to_hit(&candidate, &item, &terms)),
+            Err(ItemError::NotFound(_)) => {}
+            Err(e) => outcome
+                .errors
+                .push(format!("{}: {e}", candidate.project.path)),
+        }
+    }
+    outcome.items = outcome
+        .hits
+        .iter()
+        .filter_map(|hit| hit.item.clone())
+        .collect();
+    outcome
+}
+
+fn to_hit(candidate: &Candidate<'_>, item: &mdstore::Item, terms: &[String]) -> ProtoSearchHit {
+    let snippet = build_snippet(&item.title, &item.body, terms);
+    ProtoSearchHit {
+        item: Some(ProtoItemWithProject {
+            item: Some(generic_item_to_proto(item, &candidate.found.item_type)),
+            project_path: candidate.project.path.clone(),
+            project_name: candidate.project.name.clone(),
+            display_path: format_display_path(&candidate.project.path),
+        }),
+        score: candidate.found.score,
+        snippet: snippet.text,
+        highlights: snippet
+            .highlights
+            .into_iter()
+            .map(|h| ProtoSearchHighlight {
+                field: h.field,
+                start: h.start,
+                end: h.end,
+            })
+            .collect(),
+    }
+}
@@ -48,7 +48,7 @@ pub(super) async fn move_and_respond(
     if let Err(e) = maybe_run_pre_hooks(
         project_path,
         &hook_type,
-        HookOperation::Move,
+        HookOperation::Unarchive,
         project_path_str,
         Some(item_id),
         Some(hook_request_data.clone()),
@@ -72,7 +72,7 @@ pub(super) async fn move_and_respond(
     maybe_run_post_hooks(
         project_path,
         &hook_type,
-        HookOperation::Move,
+        HookOperation::Unarchive,
         project_path_str,
         Some(item_id),
         Some(hook_request_data),
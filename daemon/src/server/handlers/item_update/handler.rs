@@ -1,10 +1,12 @@
 use super::super::item_type_resolve::{resolve_item_id, resolve_item_type_config};
 use super::operation::{build_update_options, do_update};
-use crate::hooks::HookOperation;
+use crate::hooks::{HookError, HookOperation};
 use crate::item::core::error::ItemError;
+use crate::item::generic::storage::generic_get;
 use crate::registry::track_project_async;
 use crate::server::assert_service::assert_initialized;
-use crate::server::hooks_helper::maybe_run_pre_hooks;
+use crate::server::hook_patch::{custom_fields_json, PatchedRequest};
+use crate::server::hooks_helper::{item_snapshot, maybe_run_patching_pre_hooks};
 use crate::server::proto::{UpdateItemRequest, UpdateItemResponse};
 use crate::server::structured_error::to_error_json;
 use std::path::Path;
@@ -19,9 +21,28 @@ fn err_response(
         ..Default::default()
     })
 }
-pub async fn update_item(req: UpdateItemRequest) -> Result<Response<UpdateItemResponse>, Status> {
+/// Take the fields pre-hooks may have rewritten from the patched request.
+/// Empty values leave the item's field unchanged, as in the request.
+fn apply_hook_patch(
+    req: &mut UpdateItemRequest,
+    patched: &PatchedRequest<'_>,
+) -> Result<(), HookError> {
+    req.title = patched.string("title")?;
+    req.body = patched.string("body")?;
+    req.status = patched.string("status")?;
+    req.priority = patched.priority()?;
+    req.tags = patched.tags()?;
+    req.clear_tags = patched.flag("clear_tags")?;
+    req.custom_fields = patched.custom_fields()?;
+    Ok(())
+}
+pub async fn update_item(
+    mut req: UpdateItemRequest,
+) -> Result<Response<UpdateItemResponse>, Status> {
     track_project_async(req.project_path.clone());
-    let project_path = Path::new(&req.project_path);
+    // Owned, since pre-hook patches rewrite `req`.
+    let project_path_str = req.project_path.clone();
+    let project_path = Path::new(&project_path_str);
     if let Err(e) = assert_initialized(project_path) {
         return Ok(err_response(&req.project_path, &e));
     }
@@ -40,23 +61,37 @@ pub async fn update_item(req: UpdateItemRequest) -> Result<Response<UpdateItemRe
     };
     let hook_project_path = req.project_path.clone();
     let hook_item_id = item_id.clone();
-    let hook_data = serde_json::json!({
+    let before = generic_get(project_path, &item_type, &item_id)
+        .await
+        .ok()
+        .map(|item| item_snapshot(&item));
+    let request_data = serde_json::json!({
         "item_type": &item_type, "item_id": &req.item_id,
         "title": &req.title, "body": &req.body,
         "priority": req.priority, "status": &req.status,
+        "tags": &req.tags, "clear_tags": req.clear_tags,
+        "custom_fields": custom_fields_json(&req.custom_fields),
     });
-    if let Err(e) = maybe_run_pre_hooks(
+    let outcome = match maybe_run_patching_pre_hooks(
         project_path,
         &hook_type,
         HookOperation::Update,
         &hook_project_path,
         Some(&hook_item_id),
-        Some(hook_data.clone()),
+        Some(request_data.clone()),
+        before.clone(),
     )
     .await
     {
-        return Ok(err_response(&req.project_path, &e));
+        Ok(outcome) => outcome,
+        Err(e) => return Ok(err_response(&req.project_path, &e)),
+    };
+    if let Some(patched) = PatchedRequest::new(&outcome) {
+        if let Err(e) = apply_hook_patch(&mut req, &patched) {
+            return Ok(err_response(&req.project_path, &e));
+        }
     }
+    let hook_data = outcome.request_data.unwrap_or(request_data);
     let options = build_update_options(
         req.title,
         req.body,
@@ -77,6 +112,8 @@ pub async fn update_item(req: UpdateItemRequest) -> Result<Response<UpdateItemRe
             &hook_project_path,
             &hook_item_id,
             hook_data,
+            outcome.modifications,
+            before,
             &req.project_path,
             options,
         )
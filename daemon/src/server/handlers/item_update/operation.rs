@@ -1,10 +1,10 @@
-use crate::hooks::HookOperation;
+use crate::hooks::{HookModification, HookOperation};
 use crate::item::core::error::ItemError;
 use crate::item::generic::storage::generic_update;
 use crate::registry::find_org_repo;
 use crate::server::convert_entity::generic_item_to_proto;
 use crate::server::helpers::{nonempty, nonzero_u32};
-use crate::server::hooks_helper::maybe_run_post_hooks;
+use crate::server::hooks_helper::{item_snapshot, maybe_run_patched_post_hooks};
 use crate::server::proto::UpdateItemResponse;
 use crate::server::structured_error::to_error_json;
 use mdstore::{Filters, TypeConfig, UpdateOptions};
@@ -53,6 +53,8 @@ pub(super) async fn do_update(
     hook_project_path: &str,
     hook_item_id: &str,
     hook_data: serde_json::Value,
+    modifications: Vec<HookModification>,
+    before: Option<serde_json::Value>,
     project_path_str: &str,
     options: UpdateOptions,
 ) -> UpdateItemResponse {
@@ -67,7 +69,7 @@ pub(super) async fn do_update(
         };
     match result {
         Ok(item) => {
-            maybe_run_post_hooks(
+            maybe_run_patched_post_hooks(
                 project_path,
                 hook_type,
                 HookOperation::Update,
@@ -75,6 +77,9 @@ pub(super) async fn do_update(
                 Some(hook_item_id),
                 Some(hook_data),
                 true,
+                modifications,
+                before,
+                Some(item_snapshot(&item)),
             )
             .await;
             UpdateItemResponse {
@@ -84,7 +89,7 @@ pub(super) async fn do_update(
             }
         }
         Err(e) => {
-            maybe_run_post_hooks(
+            maybe_run_patched_post_hooks(
                 project_path,
                 hook_type,
                 HookOperation::Update,
@@ -92,6 +97,9 @@ pub(super) async fn do_update(
                 Some(hook_item_id),
                 Some(hook_data),
                 false,
+                modifications,
+                before,
+                None,
             )
             .await;
             UpdateItemResponse {
@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use crate::journal::{read_entries, select_entries, FieldChange, JournalEntry, JournalFilter};
+use crate::query::resolve_relative_date;
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::proto::{
+    JournalEntry as ProtoJournalEntry, JournalFieldChange, ListJournalEntriesRequest,
+    ListJournalEntriesResponse,
+};
+use crate::server::structured_error::{to_error_json, StructuredError};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use tonic::{Response, Status};
+
+use super::item_type_resolve::resolve_item_type_config;
+
+/// List a project's journal entries, newest first.
+///
+/// Entries can be narrowed to one item (`item_type`, `item_id`), to an
+/// actor (a case-insensitive substring of `Name <email>`), and to a time
+/// range `[since, until)`. The bounds accept RFC 3339 timestamps, plain
+/// dates and the relative expressions filters use (`-7d`, `startOfWeek`).
+pub async fn list_journal_entries(
+    req: ListJournalEntriesRequest,
+) -> Result<Response<ListJournalEntriesResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(error_response(to_error_json(&req.project_path, &e)));
+    }
+    let (since, until) = match (parse_bound(&req.since), parse_bound(&req.until)) {
+        (Ok(since), Ok(until)) => (since, until),
+        (Err(message), _) | (_, Err(message)) => {
+            return Ok(error_response(
+                StructuredError::new(&req.project_path, "INVALID_TIME_RANGE", message).to_json(),
+            ))
+        }
+    };
+    let filter = JournalFilter {
+        item_type: journal_item_type(project_path, &req.item_type).await,
+        item_id: req.item_id.clone(),
+        actor: req.actor.clone(),
+        since,
+        until,
+    };
+    let entries = match read_entries(project_path).await {
+        Ok(entries) => entries,
+        Err(e) => return Ok(error_response(to_error_json(&req.project_path, &e))),
+    };
+    let limit = usize::try_from(req.limit).unwrap_or(usize::MAX);
+    let (selected, total) = select_entries(entries, &filter, limit);
+    Ok(Response::new(ListJournalEntriesResponse {
+        success: true,
+        error: String::new(),
+        entries: selected.into_iter().map(entry_to_proto).collect(),
+        total_count: total.try_into().unwrap_or(i32::MAX),
+    }))
+}
+
+/// The folder an item type is journaled under. Types that no longer exist
+/// are matched by the name given.
+async fn journal_item_type(project_path: &Path, item_type: &str) -> String {
+    if item_type.is_empty() {
+        return String::new();
+    }
+    resolve_item_type_config(project_path, item_type)
+        .await
+        .map_or_else(|_e| item_type.to_string(), |(folder, _config)| folder)
+}
+
+/// Parse a time range bound; an empty bound is open.
+fn parse_bound(expr: &str) -> Result<Option<DateTime<Utc>>, String> {
+    let trimmed = expr.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    if let Some(relative) = resolve_relative_date(trimmed, &Local::now())? {
+        return Ok(Some(relative));
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(Some(at.with_timezone(&Utc)));
+    }
+    NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|midnight| Some(midnight.and_utc()))
+        .ok_or_else(|| {
+            format!("invalid time bound '{trimmed}' (expected RFC 3339, YYYY-MM-DD or e.g. -7d)")
+        })
+}
+
+fn entry_to_proto(entry: JournalEntry) -> ProtoJournalEntry {
+    ProtoJournalEntry {
+        timestamp: entry.timestamp,
+        actor: entry.actor,
+        operation: entry.operation.as_str().to_string(),
+        entity: entry.entity.as_str().to_string(),
+        item_type: entry.item_type,
+        item_id: entry.item_id,
+        changes: entry.changes.into_iter().map(change_to_proto).collect(),
+    }
+}
+
+/// Values are sent as JSON; an absent value is an empty string.
+#[must_use]
+pub fn change_to_proto(change: FieldChange) -> JournalFieldChange {
+    let json = |value: Option<serde_json::Value>| value.map(|v| v.to_string()).unwrap_or_default();
+    JournalFieldChange {
+        field: change.field,
+        before: json(change.before),
+        after: json(change.after),
+    }
+}
+
+fn error_response(error: String) -> Response<ListJournalEntriesResponse> {
+    Response::new(ListJournalEntriesResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
@@ -6,12 +6,19 @@ pub mod config_update;
 pub mod daemon;
 pub mod daemon_restart;
 pub mod entity_actions;
+pub mod explain_query;
+pub mod hook_deliveries;
+pub mod hook_dry_run;
+pub mod hook_runs;
 pub mod init;
+pub mod item_aggregate;
 pub mod item_archive;
 pub mod item_create;
 pub mod item_delete;
 pub mod item_duplicate;
+pub mod item_history;
 pub mod item_list;
+pub mod item_list_across;
 pub mod item_move;
 pub mod item_read;
 pub mod item_restore;
@@ -22,6 +29,7 @@ pub mod item_type_list;
 pub mod item_type_resolve;
 pub mod item_unarchive;
 pub mod item_update;
+pub mod journal;
 pub mod link_create;
 pub mod link_delete;
 pub mod link_read;
@@ -34,6 +42,7 @@ pub mod project_register;
 pub mod project_settings;
 pub mod project_title;
 pub mod sync;
+pub mod undo;
 pub mod user_create;
 pub mod user_delete;
 pub mod user_read;
@@ -41,6 +50,9 @@ pub mod user_restore;
 pub mod user_soft_delete;
 pub mod user_sync;
 pub mod user_update;
+pub mod view;
+pub mod view_write;
+pub mod watch;
 pub mod workspace_cleanup;
 pub mod workspace_manage;
 pub mod workspace_standalone;
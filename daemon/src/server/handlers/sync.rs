@@ -1,82 +1,274 @@
+use std::path::Path;
+
+use crate::item::entities::issue::reconcile::reconcile_display_numbers;
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
 use crate::server::proto;
-use crate::server::structured_error::StructuredError;
+use crate::server::structured_error::to_error_json;
+use crate::sync::{
+    get_conflict, list_conflicts, pull, push, resolve_conflict, sync_status, FieldConflict,
+    Resolution, SyncConflict, SyncError, SyncMode,
+};
+use crate::utils::get_centy_path;
 use tonic::{Response, Status};
+use tracing::warn;
 
-pub fn list_sync_conflicts(
-    _req: proto::ListSyncConflictsRequest,
+/// List the unresolved conflicts left by pulls, oldest first.
+pub async fn list_sync_conflicts(
+    req: &proto::ListSyncConflictsRequest,
 ) -> Result<Response<proto::ListSyncConflictsResponse>, Status> {
-    // Sync feature removed - return empty list
-    Ok(Response::new(proto::ListSyncConflictsResponse {
-        conflicts: vec![],
-        success: true,
-        error: String::new(),
-    }))
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(list_error(to_error_json(&req.project_path, &e)));
+    }
+    match run_blocking(project_path, list_conflicts).await? {
+        Ok(conflicts) => Ok(Response::new(proto::ListSyncConflictsResponse {
+            conflicts: conflicts.into_iter().map(conflict_to_proto).collect(),
+            success: true,
+            error: String::new(),
+        })),
+        Err(e) => Ok(list_error(to_error_json(&req.project_path, &e))),
+    }
 }
 
-pub fn get_sync_conflict(
+/// Get one conflict with the base, local and remote value of each field.
+pub async fn get_sync_conflict(
     req: &proto::GetSyncConflictRequest,
 ) -> Result<Response<proto::GetSyncConflictResponse>, Status> {
-    // Sync feature removed - conflict not found
-    Ok(Response::new(proto::GetSyncConflictResponse {
-        conflict: None,
-        success: false,
-        error: StructuredError::new(
-            "",
-            "SYNC_DISABLED",
-            format!(
-                "Sync feature disabled. Conflict not found: {}",
-                req.conflict_id
-            ),
-        )
-        .to_json(),
-    }))
-}
-
-pub fn resolve_sync_conflict(
-    _req: proto::ResolveSyncConflictRequest,
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(get_error(to_error_json(&req.project_path, &e)));
+    }
+    let conflict_id = req.conflict_id.clone();
+    match run_blocking(project_path, move |path| get_conflict(path, &conflict_id)).await? {
+        Ok(conflict) => Ok(Response::new(proto::GetSyncConflictResponse {
+            conflict: Some(conflict_to_proto(conflict)),
+            success: true,
+            error: String::new(),
+        })),
+        Err(e) => Ok(get_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// Resolve fields of a conflict by taking `ours`, `theirs` or a `custom`
+/// JSON value (empty removes the field).
+///
+/// The chosen values are written to the file. `conflict` holds the fields
+/// still unresolved, and is unset once the conflict is gone.
+pub async fn resolve_sync_conflict(
+    req: proto::ResolveSyncConflictRequest,
 ) -> Result<Response<proto::ResolveSyncConflictResponse>, Status> {
-    // Sync feature removed - cannot resolve conflicts
-    Ok(Response::new(proto::ResolveSyncConflictResponse {
-        success: false,
-        error: StructuredError::new("", "SYNC_DISABLED", "Sync feature is disabled".to_string())
-            .to_json(),
-    }))
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(resolve_error(to_error_json(&req.project_path, &e)));
+    }
+    let resolutions = match req
+        .resolutions
+        .into_iter()
+        .map(|r| Ok((r.field, parse_resolution(&r.take, &r.value)?)))
+        .collect::<Result<Vec<_>, SyncError>>()
+    {
+        Ok(resolutions) => resolutions,
+        Err(e) => return Ok(resolve_error(to_error_json(&req.project_path, &e))),
+    };
+    let conflict_id = req.conflict_id;
+    let resolve = move |path: &Path| resolve_conflict(path, &conflict_id, resolutions);
+    match run_blocking(project_path, resolve).await? {
+        Ok(remaining) => Ok(Response::new(proto::ResolveSyncConflictResponse {
+            success: true,
+            error: String::new(),
+            conflict: remaining.map(conflict_to_proto),
+        })),
+        Err(e) => Ok(resolve_error(to_error_json(&req.project_path, &e))),
+    }
 }
 
-pub fn get_sync_status(
-    _req: proto::GetSyncStatusRequest,
+fn parse_resolution(take: &str, value: &str) -> Result<Resolution, SyncError> {
+    match take {
+        "ours" => Ok(Resolution::Ours),
+        "theirs" => Ok(Resolution::Theirs),
+        "custom" if value.is_empty() => Ok(Resolution::Custom(None)),
+        "custom" => serde_json::from_str(value)
+            .map(|v| Resolution::Custom(Some(v)))
+            .map_err(|e| SyncError::InvalidResolution(format!("custom value is not JSON: {e}"))),
+        other => Err(SyncError::InvalidResolution(format!(
+            "unknown choice '{other}', expected ours, theirs or custom"
+        ))),
+    }
+}
+
+/// Values are sent as JSON; an absent value is an empty string.
+fn conflict_to_proto(conflict: SyncConflict) -> proto::SyncConflict {
+    proto::SyncConflict {
+        id: conflict.id,
+        path: conflict.path,
+        item_type: conflict.item_type,
+        item_id: conflict.item_id,
+        created_at: conflict.created_at,
+        fields: conflict.fields.into_iter().map(field_to_proto).collect(),
+    }
+}
+
+fn field_to_proto(field: FieldConflict) -> proto::SyncConflictField {
+    let json = |value: Option<serde_json::Value>| value.map(|v| v.to_string()).unwrap_or_default();
+    proto::SyncConflictField {
+        field: field.field,
+        base: json(field.base),
+        ours: json(field.ours),
+        theirs: json(field.theirs),
+    }
+}
+
+/// Report how the project's `.centy` directory stands against the sync
+/// branch and its remote, as of the last pull or push.
+pub async fn get_sync_status(
+    req: &proto::GetSyncStatusRequest,
 ) -> Result<Response<proto::GetSyncStatusResponse>, Status> {
-    // Sync feature removed - return disabled status
-    Ok(Response::new(proto::GetSyncStatusResponse {
-        mode: proto::SyncMode::Disabled as i32,
-        has_pending_changes: false,
-        has_pending_push: false,
-        conflict_count: 0,
-        last_sync_time: String::new(),
-        success: true,
-        error: String::new(),
-    }))
-}
-
-pub fn sync_pull(
-    _req: proto::SyncPullRequest,
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(status_error(to_error_json(&req.project_path, &e)));
+    }
+    match run_blocking(project_path, sync_status).await? {
+        Ok(status) => Ok(Response::new(proto::GetSyncStatusResponse {
+            mode: mode_to_proto(status.mode) as i32,
+            has_pending_changes: status.has_pending_changes,
+            has_pending_push: status.has_pending_push,
+            conflict_count: status.conflict_files.len().try_into().unwrap_or(i32::MAX),
+            last_sync_time: status.last_sync_time.unwrap_or_default(),
+            success: true,
+            error: String::new(),
+        })),
+        Err(e) => Ok(status_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// Fetch the sync branch from its remote and merge it into `.centy`.
+///
+/// Local changes are committed first. Files changed on both sides are
+/// returned in `conflict_files` and left as they are. Issues both sides
+/// numbered the same are renumbered afterwards.
+pub async fn sync_pull(
+    req: proto::SyncPullRequest,
 ) -> Result<Response<proto::SyncPullResponse>, Status> {
-    // Sync feature removed - no-op success
-    Ok(Response::new(proto::SyncPullResponse {
-        success: true,
-        error: String::new(),
-        had_changes: false,
-        conflict_files: vec![],
-    }))
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(pull_error(to_error_json(&req.project_path, &e)));
+    }
+    match run_blocking(project_path, pull).await? {
+        Ok(outcome) => {
+            if outcome.had_changes {
+                renumber_issues(project_path).await;
+            }
+            Ok(Response::new(proto::SyncPullResponse {
+                success: true,
+                error: String::new(),
+                had_changes: outcome.had_changes,
+                conflict_files: outcome.conflict_files,
+            }))
+        }
+        Err(e) => Ok(pull_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// Give issues created on both sides with the same display number unique
+/// numbers. The next push shares the result.
+async fn renumber_issues(project_path: &Path) {
+    let issues = get_centy_path(project_path).join("issues");
+    if let Err(e) = reconcile_display_numbers(&issues).await {
+        warn!(error = %e, "Failed to renumber issues after a sync pull");
+    }
 }
 
-pub fn sync_push(
-    _req: proto::SyncPushRequest,
+/// Commit `.centy` to the sync branch and push it to its remote.
+pub async fn sync_push(
+    req: proto::SyncPushRequest,
 ) -> Result<Response<proto::SyncPushResponse>, Status> {
-    // Sync feature removed - no-op success
-    Ok(Response::new(proto::SyncPushResponse {
-        success: true,
-        error: String::new(),
-        had_changes: false,
-    }))
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(push_error(to_error_json(&req.project_path, &e)));
+    }
+    match run_blocking(project_path, push).await? {
+        Ok(outcome) => Ok(Response::new(proto::SyncPushResponse {
+            success: true,
+            error: String::new(),
+            had_changes: outcome.had_changes,
+        })),
+        Err(e) => Ok(push_error(to_error_json(&req.project_path, &e))),
+    }
+}
+
+/// Git work blocks: fetching and pushing on the network, status and
+/// conflict lookups on repository walks and file hashing. It runs off the
+/// async workers.
+async fn run_blocking<T, F>(project_path: &Path, f: F) -> Result<Result<T, SyncError>, Status>
+where
+    T: Send + 'static,
+    F: FnOnce(&Path) -> Result<T, SyncError> + Send + 'static,
+{
+    let path = project_path.to_path_buf();
+    tokio::task::spawn_blocking(move || f(&path))
+        .await
+        .map_err(|e| Status::internal(format!("Sync task failed: {e}")))
+}
+
+const fn mode_to_proto(mode: SyncMode) -> proto::SyncMode {
+    match mode {
+        SyncMode::Disabled => proto::SyncMode::Disabled,
+        SyncMode::LocalOnly => proto::SyncMode::LocalOnly,
+        SyncMode::Full => proto::SyncMode::Full,
+    }
+}
+
+fn status_error(error: String) -> Response<proto::GetSyncStatusResponse> {
+    Response::new(proto::GetSyncStatusResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn pull_error(error: String) -> Response<proto::SyncPullResponse> {
+    Response::new(proto::SyncPullResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn push_error(error: String) -> Response<proto::SyncPushResponse> {
+    Response::new(proto::SyncPushResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn list_error(error: String) -> Response<proto::ListSyncConflictsResponse> {
+    Response::new(proto::ListSyncConflictsResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn get_error(error: String) -> Response<proto::GetSyncConflictResponse> {
+    Response::new(proto::GetSyncConflictResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
+
+fn resolve_error(error: String) -> Response<proto::ResolveSyncConflictResponse> {
+    Response::new(proto::ResolveSyncConflictResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
 }
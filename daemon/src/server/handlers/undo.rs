@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use crate::item::generic::storage::generic_undo;
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::proto::{UndoRequest, UndoResponse, UndoneOperation};
+use crate::server::structured_error::to_error_json;
+use crate::undo::UndoEntry;
+use tonic::{Response, Status};
+
+/// Undo the last `count` mutations the daemon made in a project (`0` counts
+/// as one).
+///
+/// Nothing is changed when a file touched by one of them was changed by
+/// something else since; the error names the file.
+pub async fn undo(req: UndoRequest) -> Result<Response<UndoResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(error_response(to_error_json(&req.project_path, &e)));
+    }
+    let count = usize::try_from(req.count).unwrap_or(usize::MAX);
+    match generic_undo(project_path, count).await {
+        Ok(undone) => Ok(Response::new(UndoResponse {
+            success: true,
+            error: String::new(),
+            undone: undone.into_iter().map(entry_to_proto).collect(),
+        })),
+        Err(e) => Ok(error_response(to_error_json(&req.project_path, &e))),
+    }
+}
+
+fn entry_to_proto(entry: UndoEntry) -> UndoneOperation {
+    UndoneOperation {
+        id: entry.id,
+        timestamp: entry.timestamp,
+        operation: entry.operation.as_str().to_string(),
+        item_type: entry.item_type,
+        item_id: entry.item_id,
+    }
+}
+
+fn error_response(error: String) -> Response<UndoResponse> {
+    Response::new(UndoResponse {
+        success: false,
+        error,
+        ..Default::default()
+    })
+}
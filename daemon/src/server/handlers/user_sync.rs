@@ -1,8 +1,10 @@
 use std::path::Path;
 
+use crate::hooks::HookOperation;
 use crate::registry::track_project_async;
 use crate::server::assert_service::assert_initialized;
 use crate::server::convert_infra::manifest_to_proto;
+use crate::server::hooks_helper::{maybe_run_post_hooks, maybe_run_pre_hooks};
 use crate::server::proto::{
     GitContributor as ProtoGitContributor, SyncUsersRequest, SyncUsersResponse,
 };
@@ -21,7 +23,46 @@ pub async fn sync_users(req: SyncUsersRequest) -> Result<Response<SyncUsersRespo
         }));
     }
 
-    match internal_sync_users(project_path, req.dry_run).await {
+    // A dry run changes nothing, so it fires no hooks.
+    let hooked = !req.dry_run;
+    if hooked {
+        if let Err(e) = maybe_run_pre_hooks(
+            project_path,
+            "user",
+            HookOperation::Sync,
+            &req.project_path,
+            None,
+            Some(serde_json::json!({ "dry_run": false })),
+        )
+        .await
+        {
+            return Ok(Response::new(SyncUsersResponse {
+                success: false,
+                error: to_error_json(&req.project_path, &e),
+                ..Default::default()
+            }));
+        }
+    }
+    let sync_result = internal_sync_users(project_path, req.dry_run).await;
+    if hooked {
+        let outcome = sync_result.as_ref().ok().map(|full| &full.result);
+        maybe_run_post_hooks(
+            project_path,
+            "user",
+            HookOperation::Sync,
+            &req.project_path,
+            None,
+            Some(serde_json::json!({
+                "dry_run": false,
+                "created": outcome.map(|r| &r.created),
+                "skipped": outcome.map(|r| &r.skipped),
+                "errors": outcome.map(|r| &r.errors),
+            })),
+            sync_result.is_ok(),
+        )
+        .await;
+    }
+    match sync_result {
         Ok(full_result) => {
             let result = full_result.result;
             Ok(Response::new(SyncUsersResponse {
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::convert_entity::view_to_proto;
+use crate::server::proto::{GetViewRequest, GetViewResponse, ListViewsRequest, ListViewsResponse};
+use crate::server::structured_error::to_error_json;
+use crate::view::{find_view, list_views as internal_list_views};
+use tonic::{Response, Status};
+
+pub async fn list_views(req: ListViewsRequest) -> Result<Response<ListViewsResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(ListViewsResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+
+    match internal_list_views(project_path).await {
+        Ok(views) => {
+            let views: Vec<_> = views
+                .iter()
+                .filter(|v| req.item_type.is_empty() || v.item_type == req.item_type)
+                .map(view_to_proto)
+                .collect();
+            let total_count = views.len().try_into().unwrap_or(i32::MAX);
+            Ok(Response::new(ListViewsResponse {
+                success: true,
+                error: String::new(),
+                views,
+                total_count,
+            }))
+        }
+        Err(e) => Ok(Response::new(ListViewsResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        })),
+    }
+}
+
+/// Get a view by ID or, failing that, by name.
+pub async fn get_view(req: GetViewRequest) -> Result<Response<GetViewResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(GetViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+
+    match find_view(project_path, &req.view).await {
+        Ok(view) => Ok(Response::new(GetViewResponse {
+            success: true,
+            error: String::new(),
+            view: Some(view_to_proto(&view)),
+        })),
+        Err(e) => Ok(Response::new(GetViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            view: None,
+        })),
+    }
+}
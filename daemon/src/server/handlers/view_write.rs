@@ -0,0 +1,143 @@
+use std::path::Path;
+
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::convert_entity::view_to_proto;
+use crate::server::proto::{
+    CreateViewRequest, CreateViewResponse, DeleteViewRequest, DeleteViewResponse,
+    UpdateViewRequest, UpdateViewResponse,
+};
+use crate::server::structured_error::to_error_json;
+use crate::user::slugify;
+use crate::view::{
+    create_view as internal_create_view, delete_view as internal_delete_view, parse_view_filter,
+    update_view as internal_update_view, SavedView, ViewError,
+};
+use tonic::{Response, Status};
+
+/// The view described by a create/update request.
+fn build_view(
+    id: String,
+    name: String,
+    description: String,
+    item_type: String,
+    filter: &str,
+    sort: String,
+    fields: Vec<String>,
+) -> Result<SavedView, ViewError> {
+    Ok(SavedView {
+        id,
+        name,
+        description: Some(description).filter(|d| !d.is_empty()),
+        item_type,
+        filter: parse_view_filter(filter)?,
+        sort,
+        fields,
+    })
+}
+
+pub async fn create_view(req: CreateViewRequest) -> Result<Response<CreateViewResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(CreateViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+
+    let id = if req.id.is_empty() {
+        slugify(&req.name)
+    } else {
+        req.id
+    };
+    let result = match build_view(
+        id,
+        req.name,
+        req.description,
+        req.item_type,
+        &req.filter,
+        req.sort,
+        req.fields,
+    ) {
+        Ok(view) => internal_create_view(project_path, &view)
+            .await
+            .map(|()| view),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(view) => Ok(Response::new(CreateViewResponse {
+            success: true,
+            error: String::new(),
+            view: Some(view_to_proto(&view)),
+        })),
+        Err(e) => Ok(Response::new(CreateViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            view: None,
+        })),
+    }
+}
+
+/// Replace every field of an existing view.
+pub async fn update_view(req: UpdateViewRequest) -> Result<Response<UpdateViewResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(UpdateViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            ..Default::default()
+        }));
+    }
+
+    let result = match build_view(
+        req.view_id,
+        req.name,
+        req.description,
+        req.item_type,
+        &req.filter,
+        req.sort,
+        req.fields,
+    ) {
+        Ok(view) => internal_update_view(project_path, &view)
+            .await
+            .map(|()| view),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(view) => Ok(Response::new(UpdateViewResponse {
+            success: true,
+            error: String::new(),
+            view: Some(view_to_proto(&view)),
+        })),
+        Err(e) => Ok(Response::new(UpdateViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+            view: None,
+        })),
+    }
+}
+
+pub async fn delete_view(req: DeleteViewRequest) -> Result<Response<DeleteViewResponse>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Ok(Response::new(DeleteViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+        }));
+    }
+
+    match internal_delete_view(project_path, &req.view_id).await {
+        Ok(()) => Ok(Response::new(DeleteViewResponse {
+            success: true,
+            error: String::new(),
+        })),
+        Err(e) => Ok(Response::new(DeleteViewResponse {
+            success: false,
+            error: to_error_json(&req.project_path, &e),
+        })),
+    }
+}
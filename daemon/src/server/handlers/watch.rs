@@ -0,0 +1,142 @@
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::Instant;
+
+use crate::registry::track_project_async;
+use crate::server::assert_service::assert_initialized;
+use crate::server::proto::{WatchEvent, WatchRequest};
+use crate::server::structured_error::to_error_json;
+use crate::watch::{
+    subscribe, watch_project, ChangeEvent, Debouncer, EntityKind, WatchGuard, DEBOUNCE_WINDOW,
+};
+use futures::Stream;
+use tokio::sync::{broadcast, mpsc};
+use tonic::{Response, Status};
+use tracing::warn;
+
+use super::item_type_resolve::resolve_item_type_config;
+
+/// Events buffered per stream before the forwarder waits on the client.
+const STREAM_BUFFER: usize = 64;
+
+pub type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchEvent, Status>> + Send>>;
+
+/// Which changes a stream delivers.
+struct Scope {
+    project_path: PathBuf,
+    /// Item type folder; empty to watch the whole project.
+    item_type: String,
+}
+
+impl Scope {
+    fn includes(&self, event: &ChangeEvent) -> bool {
+        // A move to another project reaches the target's streams as a create.
+        if event.project_path != self.project_path {
+            return false;
+        }
+        if self.item_type.is_empty() {
+            return true;
+        }
+        match event.entity {
+            EntityKind::Item | EntityKind::Config => event.item_type == self.item_type,
+            EntityKind::Link | EntityKind::User => false,
+        }
+    }
+}
+
+/// Stream changes to a project's items, links, users and config.
+///
+/// Changes made through the daemon and edits made directly under `.centy/`
+/// are both reported. Bursts of changes to one entity are debounced into a
+/// single event. With an `item_type` only that type's items and its
+/// `config.yaml` are reported.
+pub async fn watch(req: WatchRequest) -> Result<Response<WatchStream>, Status> {
+    track_project_async(req.project_path.clone());
+    let project_path = Path::new(&req.project_path);
+    if let Err(e) = assert_initialized(project_path) {
+        return Err(Status::failed_precondition(to_error_json(
+            &req.project_path,
+            &e,
+        )));
+    }
+    let item_type = if req.item_type.is_empty() {
+        String::new()
+    } else {
+        match resolve_item_type_config(project_path, &req.item_type).await {
+            Ok((folder, _config)) => folder,
+            Err(e) => return Err(Status::not_found(to_error_json(&req.project_path, &e))),
+        }
+    };
+    // Subscribe before starting the watcher so no early change is missed.
+    let changes = subscribe();
+    let guard = watch_project(project_path)
+        .map_err(|e| Status::internal(to_error_json(&req.project_path, &e)))?;
+    let scope = Scope {
+        project_path: project_path.to_path_buf(),
+        item_type,
+    };
+    let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(forward(scope, changes, tx, guard));
+    let stream = futures::stream::unfold(rx, async |mut receiver| {
+        receiver.recv().await.map(|event| (Ok(event), receiver))
+    });
+    Ok(Response::new(Box::pin(stream)))
+}
+
+/// Debounce the hub's changes for one stream until the client goes away.
+///
+/// Holding `_guard` keeps the project's filesystem watcher alive.
+#[allow(clippy::integer_division_remainder_used)] // expanded by `tokio::select!`
+async fn forward(
+    scope: Scope,
+    mut changes: broadcast::Receiver<ChangeEvent>,
+    tx: mpsc::Sender<WatchEvent>,
+    _guard: WatchGuard,
+) {
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+    loop {
+        let next_due = debouncer.next_due();
+        tokio::select! {
+            biased;
+            () = tx.closed() => return,
+            received = changes.recv() => match received {
+                Ok(event) if scope.includes(&event) => debouncer.push(event, Instant::now()),
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(skipped, "Watch stream fell behind; some changes were dropped");
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            () = sleep_until(next_due) => {
+                for event in debouncer.take_due(Instant::now()) {
+                    if tx.send(to_proto(event)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sleep until `due`, or forever when nothing is pending.
+async fn sleep_until(due: Option<Instant>) {
+    match due {
+        Some(instant) => tokio::time::sleep_until(instant.into()).await,
+        None => std::future::pending().await,
+    }
+}
+
+fn to_proto(event: ChangeEvent) -> WatchEvent {
+    WatchEvent {
+        kind: event.kind.as_str().to_string(),
+        entity: event.entity.as_str().to_string(),
+        project_path: event.project_path.to_string_lossy().into_owned(),
+        item_type: event.item_type,
+        id: event.id,
+        previous_id: event.previous_id,
+        moved_to_project_path: event
+            .moved_to
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    }
+}
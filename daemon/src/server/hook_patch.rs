@@ -0,0 +1,127 @@
+use crate::hooks::{HookError, PreHookOutcome};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Typed access to a request that pre-hooks patched. A field of the wrong
+/// type fails with the pattern of the hook that set it.
+pub struct PatchedRequest<'outcome> {
+    outcome: &'outcome PreHookOutcome,
+    data: &'outcome Map<String, Value>,
+}
+
+impl<'outcome> PatchedRequest<'outcome> {
+    /// `None` when no pre-hook changed the request.
+    pub fn new(outcome: &'outcome PreHookOutcome) -> Option<Self> {
+        if let Some(Value::Object(data)) = &outcome.request_data {
+            outcome.is_patched().then_some(Self { outcome, data })
+        } else {
+            None
+        }
+    }
+
+    fn invalid(&self, field: &str, expected: &str) -> HookError {
+        HookError::InvalidPatch {
+            pattern: self
+                .outcome
+                .patched_by(field)
+                .unwrap_or_default()
+                .to_string(),
+            reason: format!("`{field}` must be {expected}"),
+        }
+    }
+
+    /// A text field; missing or `null` is empty.
+    pub fn string(&self, field: &str) -> Result<String, HookError> {
+        match self.data.get(field) {
+            None | Some(Value::Null) => Ok(String::new()),
+            Some(Value::String(text)) => Ok(text.clone()),
+            Some(Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_)) => {
+                Err(self.invalid(field, "a string"))
+            }
+        }
+    }
+
+    /// A flag; missing or `null` is `false`.
+    pub fn flag(&self, field: &str) -> Result<bool, HookError> {
+        match self.data.get(field) {
+            None | Some(Value::Null) => Ok(false),
+            Some(Value::Bool(flag)) => Ok(*flag),
+            Some(Value::String(_) | Value::Number(_) | Value::Array(_) | Value::Object(_)) => {
+                Err(self.invalid(field, "true or false"))
+            }
+        }
+    }
+
+    /// The priority; missing or `null` is 0, meaning not set.
+    pub fn priority(&self) -> Result<i32, HookError> {
+        const FIELD: &str = "priority";
+        match self.data.get(FIELD) {
+            None | Some(Value::Null) => Ok(0),
+            Some(value) => value
+                .as_u64()
+                .and_then(|n| i32::try_from(n).ok())
+                .ok_or_else(|| self.invalid(FIELD, "a non-negative integer")),
+        }
+    }
+
+    /// The tags; missing or `null` is none.
+    pub fn tags(&self) -> Result<Vec<String>, HookError> {
+        const FIELD: &str = "tags";
+        match self.data.get(FIELD) {
+            None | Some(Value::Null) => Ok(Vec::new()),
+            Some(Value::Array(tags)) => tags
+                .iter()
+                .map(|tag| tag.as_str().map(str::to_string))
+                .collect::<Option<Vec<String>>>()
+                .ok_or_else(|| self.invalid(FIELD, "a list of strings")),
+            Some(Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_)) => {
+                Err(self.invalid(FIELD, "a list of strings"))
+            }
+        }
+    }
+
+    /// Custom fields in the request's raw form: JSON text, or plain text
+    /// for strings that are not JSON.
+    pub fn custom_fields(&self) -> Result<HashMap<String, String>, HookError> {
+        const FIELD: &str = "custom_fields";
+        match self.data.get(FIELD) {
+            None | Some(Value::Null) => Ok(HashMap::new()),
+            Some(Value::Object(fields)) => Ok(fields
+                .iter()
+                .map(|(key, value)| (key.clone(), raw_custom_field(value)))
+                .collect()),
+            Some(Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Array(_)) => {
+                Err(self.invalid(FIELD, "an object"))
+            }
+        }
+    }
+}
+
+/// Custom fields as hooks see them, parsed the way storage parses them.
+pub fn custom_fields_json(raw: &HashMap<String, String>) -> Value {
+    Value::Object(
+        raw.iter()
+            .map(|(key, value)| {
+                let parsed =
+                    serde_json::from_str(value).unwrap_or_else(|_e| Value::String(value.clone()));
+                (key.clone(), parsed)
+            })
+            .collect(),
+    )
+}
+
+fn raw_custom_field(value: &Value) -> String {
+    match value {
+        Value::String(text) if serde_json::from_str::<Value>(text).is_err() => text.clone(),
+        Value::Null
+        | Value::Bool(_)
+        | Value::Number(_)
+        | Value::String(_)
+        | Value::Array(_)
+        | Value::Object(_) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "hook_patch_tests.rs"]
+mod hook_patch_tests;
@@ -0,0 +1,70 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::*;
+use crate::hooks::{HookModification, PatchedField};
+use serde_json::json;
+
+fn patched(request: Value, field: &str) -> PreHookOutcome {
+    PreHookOutcome {
+        request_data: Some(request),
+        modifications: vec![HookModification {
+            pattern: "issue.creating".to_string(),
+            changes: vec![PatchedField {
+                field: field.to_string(),
+                before: None,
+                after: Some(json!(1i32)),
+            }],
+        }],
+    }
+}
+
+#[test]
+fn test_unpatched_request_is_none() {
+    let outcome = PreHookOutcome {
+        request_data: Some(json!({"title": "T"})),
+        modifications: Vec::new(),
+    };
+
+    assert!(PatchedRequest::new(&outcome).is_none());
+}
+
+#[test]
+fn test_reads_patched_fields() {
+    let outcome = patched(
+        json!({
+            "title": "Login fails",
+            "status": null,
+            "priority": 2i32,
+            "tags": ["bug"],
+            "clear_tags": true,
+            "custom_fields": {"team": "web", "points": 3i32, "code": "5"},
+        }),
+        "title",
+    );
+    let request = PatchedRequest::new(&outcome).unwrap();
+
+    assert_eq!(request.string("title").unwrap(), "Login fails");
+    assert_eq!(request.string("status").unwrap(), "");
+    assert_eq!(request.priority().unwrap(), 2i32);
+    assert_eq!(request.tags().unwrap(), vec!["bug"]);
+    assert!(request.flag("clear_tags").unwrap());
+    let fields = request.custom_fields().unwrap();
+    assert_eq!(fields["team"], "web");
+    assert_eq!(fields["points"], "3");
+    assert_eq!(fields["code"], "\"5\"");
+    let round_trip = custom_fields_json(&fields);
+    assert_eq!(
+        round_trip,
+        json!({"team": "web", "points": 3i32, "code": "5"})
+    );
+}
+
+#[test]
+fn test_wrong_type_names_the_hook() {
+    let outcome = patched(json!({"priority": "high", "tags": [1i32]}), "priority");
+    let request = PatchedRequest::new(&outcome).unwrap();
+
+    let err = request.priority().unwrap_err();
+    assert!(matches!(&err, HookError::InvalidPatch { pattern, reason }
+            if pattern == "issue.creating" && reason.contains("priority")));
+    assert!(request.tags().is_err());
+}
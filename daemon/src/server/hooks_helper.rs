@@ -1,8 +1,41 @@
 use std::path::Path;
 
-use crate::hooks::{run_post_hooks, run_pre_hooks, HookContext, HookError, HookOperation, Phase};
+use crate::hooks::{
+    run_post_hooks, run_pre_hooks, HookContext, HookError, HookModification, HookOperation, Phase,
+    PreHookOutcome,
+};
+use crate::journal::item_fields;
+use crate::server::handlers::item_type_resolve::resolve_item_type_config;
+use serde_json::Value;
+use tracing::warn;
+
+/// An item as hooks see it in `before` and `after`: its id and journaled
+/// fields, with custom fields as `customFields.<name>`.
+#[must_use]
+pub fn item_snapshot(item: &mdstore::Item) -> Value {
+    let mut fields: serde_json::Map<String, Value> = item_fields(item).into_iter().collect();
+    fields.insert("id".to_string(), Value::String(item.id.clone()));
+    Value::Object(fields)
+}
+
+/// The name hooks use for an item type (`issue` for `issues`). Types that
+/// no longer exist are matched by the name given.
+pub async fn hook_item_type(project_path: &Path, item_type: &str) -> String {
+    if item_type.is_empty() {
+        return String::new();
+    }
+    resolve_item_type_config(project_path, item_type)
+        .await
+        .map_or_else(
+            |_e| item_type.to_lowercase(),
+            |(_folder, config)| config.name.to_lowercase(),
+        )
+}
 
 /// Build pre-hook context and run pre-hooks. Returns `Err(HookError)` if blocked.
+///
+/// For operations whose request cannot be rewritten; patches printed by the
+/// hooks are logged and ignored.
 pub async fn maybe_run_pre_hooks(
     project_path: &Path,
     item_type: &str,
@@ -11,6 +44,38 @@ pub async fn maybe_run_pre_hooks(
     item_id: Option<&str>,
     request_data: Option<serde_json::Value>,
 ) -> Result<(), HookError> {
+    let outcome = maybe_run_patching_pre_hooks(
+        project_path,
+        item_type,
+        operation,
+        project_path_str,
+        item_id,
+        request_data,
+        None,
+    )
+    .await?;
+    for modification in outcome.modifications {
+        warn!(
+            "Pre-hook '{}' patched a {} request, which is not supported; ignoring it",
+            modification.pattern,
+            operation.as_str()
+        );
+    }
+    Ok(())
+}
+
+/// Like [`maybe_run_pre_hooks`], but returns the request as the hooks
+/// patched it, for the caller to re-validate and use. `before` is the
+/// stored item, if any, as [`item_snapshot`] renders it.
+pub async fn maybe_run_patching_pre_hooks(
+    project_path: &Path,
+    item_type: &str,
+    operation: HookOperation,
+    project_path_str: &str,
+    item_id: Option<&str>,
+    request_data: Option<serde_json::Value>,
+    before: Option<Value>,
+) -> Result<PreHookOutcome, HookError> {
     let context = HookContext::new(
         Phase::Pre,
         item_type,
@@ -19,7 +84,8 @@ pub async fn maybe_run_pre_hooks(
         item_id,
         request_data,
         None,
-    );
+    )
+    .with_items(before, None);
     run_pre_hooks(project_path, item_type, operation, &context).await
 }
 
@@ -32,6 +98,35 @@ pub async fn maybe_run_post_hooks(
     item_id: Option<&str>,
     request_data: Option<serde_json::Value>,
     success: bool,
+) {
+    maybe_run_patched_post_hooks(
+        project_path,
+        item_type,
+        operation,
+        project_path_str,
+        item_id,
+        request_data,
+        success,
+        Vec::new(),
+        None,
+        None,
+    )
+    .await;
+}
+
+/// Run post-hooks with the changes pre-hooks made to the request and the
+/// item before and after the operation.
+pub async fn maybe_run_patched_post_hooks(
+    project_path: &Path,
+    item_type: &str,
+    operation: HookOperation,
+    project_path_str: &str,
+    item_id: Option<&str>,
+    request_data: Option<serde_json::Value>,
+    success: bool,
+    modifications: Vec<HookModification>,
+    before: Option<Value>,
+    after: Option<Value>,
 ) {
     let context = HookContext::new(
         Phase::Post,
@@ -41,6 +136,8 @@ pub async fn maybe_run_post_hooks(
         item_id,
         request_data,
         Some(success),
-    );
+    )
+    .with_modifications(modifications)
+    .with_items(before, after);
     run_post_hooks(project_path, item_type, operation, &context).await;
 }
@@ -8,6 +8,7 @@ mod convert_link;
 pub mod error_mapping;
 pub mod handlers;
 mod helpers;
+mod hook_patch;
 mod hooks_helper;
 mod proto_to_config;
 mod resolve;
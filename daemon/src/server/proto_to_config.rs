@@ -39,6 +39,8 @@ pub fn proto_to_config(proto: &Config) -> CentyConfig {
             })
             .unwrap_or_default(),
         cleanup: crate::config::CleanupConfig::default(),
+        auto_commit: crate::config::AutoCommitConfig::default(),
+        sync: crate::config::SyncConfig::default(),
         extra: proto
             .user_values
             .iter()
@@ -9,6 +9,9 @@ pub struct ErrorMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tip: Option<String>,
     pub code: String,
+    /// Location of the problem inside the request, e.g. a filter path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -28,6 +31,7 @@ impl StructuredError {
                 message,
                 tip: None,
                 code: code.to_string(),
+                path: None,
             }],
         }
     }
@@ -39,6 +43,13 @@ impl StructuredError {
         self
     }
     #[must_use]
+    pub fn with_path(mut self, path: &str) -> Self {
+        if let Some(msg) = self.messages.first_mut() {
+            msg.path = Some(path.to_string());
+        }
+        self
+    }
+    #[must_use]
     pub fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or_else(|_| {
             r#"{"cwd":"","logs":"","messages":[{"message":"serialization error","code":"INTERNAL_ERROR"}]}"#.to_string()
@@ -53,6 +64,9 @@ pub fn to_error_json<E: ToStructuredError + Display>(cwd: &str, err: &E) -> Stri
     if let Some(tip) = maybe_tip {
         se = se.with_tip(tip);
     }
+    if let Some(path) = err.error_path() {
+        se = se.with_path(path);
+    }
     se.to_json()
 }
 
@@ -44,6 +44,24 @@ fn test_to_error_json_with_tip() {
         .contains("centy init"));
 }
 
+#[test]
+fn test_to_error_json_with_path() {
+    use crate::query::parse_filter_json;
+    let err = parse_filter_json(r#"{"priority":{"$lte":true}}"#).unwrap_err();
+    let json = to_error_json("/tmp/proj", &err);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["messages"][0]["code"], "INVALID_FILTER");
+    assert_eq!(parsed["messages"][0]["path"], "priority.$lte");
+}
+
+#[test]
+fn test_to_error_json_omits_missing_path() {
+    use crate::registry::RegistryError;
+    let json = to_error_json("/tmp/proj", &RegistryError::HomeDirNotFound);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(parsed["messages"][0].get("path").is_none());
+}
+
 // ── StructuredError::new / with_tip / to_json ────────────────────────────────
 
 #[test]
@@ -297,37 +297,37 @@ impl CentyDaemon for CentyDaemonService {
         &self,
         request: Request<ListSyncConflictsRequest>,
     ) -> Result<Response<ListSyncConflictsResponse>, Status> {
-        handlers::sync::list_sync_conflicts(request.into_inner())
+        handlers::sync::list_sync_conflicts(&request.into_inner()).await
     }
     async fn get_sync_conflict(
         &self,
         request: Request<GetSyncConflictRequest>,
     ) -> Result<Response<GetSyncConflictResponse>, Status> {
-        handlers::sync::get_sync_conflict(&request.into_inner())
+        handlers::sync::get_sync_conflict(&request.into_inner()).await
     }
     async fn resolve_sync_conflict(
         &self,
         request: Request<ResolveSyncConflictRequest>,
     ) -> Result<Response<ResolveSyncConflictResponse>, Status> {
-        handlers::sync::resolve_sync_conflict(request.into_inner())
+        handlers::sync::resolve_sync_conflict(request.into_inner()).await
     }
     async fn get_sync_status(
         &self,
         request: Request<GetSyncStatusRequest>,
     ) -> Result<Response<GetSyncStatusResponse>, Status> {
-        handlers::sync::get_sync_status(request.into_inner())
+        handlers::sync::get_sync_status(&request.into_inner()).await
     }
     async fn sync_pull(
         &self,
         request: Request<SyncPullRequest>,
     ) -> Result<Response<SyncPullResponse>, Status> {
-        handlers::sync::sync_pull(request.into_inner())
+        handlers::sync::sync_pull(request.into_inner()).await
     }
     async fn sync_push(
         &self,
         request: Request<SyncPushRequest>,
     ) -> Result<Response<SyncPushResponse>, Status> {
-        handlers::sync::sync_push(request.into_inner())
+        handlers::sync::sync_push(request.into_inner()).await
     }
     async fn create_item_type(
         &self,
@@ -415,8 +415,108 @@ impl CentyDaemon for CentyDaemonService {
     }
     async fn list_items_across_projects(
         &self,
-        _request: Request<ListItemsAcrossProjectsRequest>,
+        request: Request<ListItemsAcrossProjectsRequest>,
     ) -> Result<Response<ListItemsAcrossProjectsResponse>, Status> {
-        Err(Status::unimplemented("not yet implemented"))
+        handlers::item_list_across::list_items_across_projects(request.into_inner()).await
+    }
+    async fn aggregate_items(
+        &self,
+        request: Request<AggregateItemsRequest>,
+    ) -> Result<Response<AggregateItemsResponse>, Status> {
+        handlers::item_aggregate::aggregate_items(request.into_inner()).await
+    }
+    async fn explain_query(
+        &self,
+        request: Request<ExplainQueryRequest>,
+    ) -> Result<Response<ExplainQueryResponse>, Status> {
+        handlers::explain_query::explain_query(&request.into_inner())
+    }
+    type WatchStream = handlers::watch::WatchStream;
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        handlers::watch::watch(request.into_inner()).await
+    }
+    async fn list_journal_entries(
+        &self,
+        request: Request<ListJournalEntriesRequest>,
+    ) -> Result<Response<ListJournalEntriesResponse>, Status> {
+        handlers::journal::list_journal_entries(request.into_inner()).await
+    }
+    async fn list_hook_runs(
+        &self,
+        request: Request<ListHookRunsRequest>,
+    ) -> Result<Response<ListHookRunsResponse>, Status> {
+        handlers::hook_runs::list_hook_runs(request.into_inner()).await
+    }
+    async fn list_hook_deliveries(
+        &self,
+        request: Request<ListHookDeliveriesRequest>,
+    ) -> Result<Response<ListHookDeliveriesResponse>, Status> {
+        handlers::hook_deliveries::list_hook_deliveries(request.into_inner()).await
+    }
+    async fn retry_hook_delivery(
+        &self,
+        request: Request<RetryHookDeliveryRequest>,
+    ) -> Result<Response<RetryHookDeliveryResponse>, Status> {
+        handlers::hook_deliveries::retry_hook_delivery(request.into_inner()).await
+    }
+    async fn discard_hook_delivery(
+        &self,
+        request: Request<DiscardHookDeliveryRequest>,
+    ) -> Result<Response<DiscardHookDeliveryResponse>, Status> {
+        handlers::hook_deliveries::discard_hook_delivery(request.into_inner()).await
+    }
+    async fn test_hook(
+        &self,
+        request: Request<TestHookRequest>,
+    ) -> Result<Response<TestHookResponse>, Status> {
+        handlers::hook_dry_run::test_hook(request.into_inner()).await
+    }
+    async fn get_item_history(
+        &self,
+        request: Request<GetItemHistoryRequest>,
+    ) -> Result<Response<GetItemHistoryResponse>, Status> {
+        handlers::item_history::get_item_history(request.into_inner()).await
+    }
+    async fn get_item_at_revision(
+        &self,
+        request: Request<GetItemAtRevisionRequest>,
+    ) -> Result<Response<GetItemAtRevisionResponse>, Status> {
+        handlers::item_history::get_item_at_revision(request.into_inner()).await
+    }
+    async fn undo(&self, request: Request<UndoRequest>) -> Result<Response<UndoResponse>, Status> {
+        handlers::undo::undo(request.into_inner()).await
+    }
+    async fn list_views(
+        &self,
+        request: Request<ListViewsRequest>,
+    ) -> Result<Response<ListViewsResponse>, Status> {
+        handlers::view::list_views(request.into_inner()).await
+    }
+    async fn get_view(
+        &self,
+        request: Request<GetViewRequest>,
+    ) -> Result<Response<GetViewResponse>, Status> {
+        handlers::view::get_view(request.into_inner()).await
+    }
+    async fn create_view(
+        &self,
+        request: Request<CreateViewRequest>,
+    ) -> Result<Response<CreateViewResponse>, Status> {
+        handlers::view_write::create_view(request.into_inner()).await
+    }
+    async fn update_view(
+        &self,
+        request: Request<UpdateViewRequest>,
+    ) -> Result<Response<UpdateViewResponse>, Status> {
+        handlers::view_write::update_view(request.into_inner()).await
+    }
+    async fn delete_view(
+        &self,
+        request: Request<DeleteViewRequest>,
+    ) -> Result<Response<DeleteViewResponse>, Status> {
+        handlers::view_write::delete_view(request.into_inner()).await
     }
 }
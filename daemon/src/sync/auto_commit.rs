@@ -0,0 +1,271 @@
+use super::auto_commit_watch::{set_watched, watch_registered_projects};
+use super::commit_message::commit_message;
+use super::error::SyncError;
+use super::files::with_work_tree;
+use super::repo::{lock, SyncRepo};
+use crate::config::read_config;
+use crate::journal::{self, resolve_actor, JournalEntry};
+use crate::user::{find_user_by_email, read_users};
+use crate::watch::{self, ChangeEvent};
+use git2::{ErrorCode, Signature};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, warn};
+
+/// How long to wait for further changes when `debounceMs` is not set.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Changes to one project waiting to be committed. A commit holds the
+/// whole `.centy` directory, so a batch covers every actor's changes.
+#[derive(Default)]
+struct Batch {
+    entries: Vec<JournalEntry>,
+    events: Vec<ChangeEvent>,
+    last_change: Option<Instant>,
+}
+
+static BATCHES: OnceLock<Mutex<HashMap<PathBuf, Batch>>> = OnceLock::new();
+
+fn batches() -> &'static Mutex<HashMap<PathBuf, Batch>> {
+    BATCHES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Start auto-committing `.centy` changes for projects with
+/// `autoCommit.enabled` set.
+///
+/// Journaled changes arrive through [`journal::on_record`]; everything else,
+/// such as config and edits made outside the daemon, arrives through the
+/// watch hub. Those projects' `.centy/` folders are watched from startup,
+/// and from their next change once auto-commit is turned on, whether or not
+/// a `Watch` stream is open. A project's changes are committed once none
+/// has arrived for its debounce window. Failures are logged; they never
+/// fail the change itself.
+pub fn spawn_auto_commit_task() {
+    journal::on_record(|project_path, entry| {
+        add_to_batch(project_path, |batch| batch.entries.push(entry.clone()));
+    });
+    let mut events = watch::subscribe();
+    tokio::spawn(watch_registered_projects());
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let project_path = event.project_path.clone();
+                    add_to_batch(&project_path, |batch| batch.events.push(event));
+                }
+                Err(RecvError::Lagged(missed)) => {
+                    debug!(missed, "Auto-commit fell behind the watch hub");
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Add a change to the project's batch, starting a commit task for a new one.
+fn add_to_batch(project_path: &Path, add: impl FnOnce(&mut Batch)) {
+    let mut pending = batches().lock().unwrap_or_else(PoisonError::into_inner);
+    let batch = pending.entry(project_path.to_path_buf()).or_default();
+    let first = batch.last_change.is_none();
+    add(batch);
+    batch.last_change = Some(Instant::now());
+    drop(pending);
+    if first {
+        tokio::spawn(commit_when_quiet(project_path.to_path_buf()));
+    }
+}
+
+/// Take the project's batch off the queue.
+fn take_batch(project_path: &Path) -> Option<Batch> {
+    batches()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(project_path)
+}
+
+/// Wait until the project's batch has been quiet for its debounce window,
+/// then commit it. The batch is dropped when auto-commit is off.
+async fn commit_when_quiet(project_path: PathBuf) {
+    let settings = match read_config(&project_path).await {
+        Ok(Some(config)) if config.auto_commit.enabled => config.auto_commit,
+        Ok(_) => {
+            set_watched(&project_path, false);
+            take_batch(&project_path);
+            return;
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to read config for auto-commit");
+            take_batch(&project_path);
+            return;
+        }
+    };
+    set_watched(&project_path, true);
+    let window = settings
+        .debounce_ms
+        .map_or(DEFAULT_DEBOUNCE, Duration::from_millis);
+    let mut wait = window;
+    let batch = loop {
+        tokio::time::sleep(wait).await;
+        let mut pending = batches().lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(quiet) = pending
+            .get(&project_path)
+            .and_then(|b| b.last_change)
+            .map(|at| at.elapsed())
+        else {
+            return;
+        };
+        if quiet >= window {
+            match pending.remove(&project_path) {
+                Some(batch) => break batch,
+                None => return,
+            }
+        }
+        drop(pending);
+        wait = window.saturating_sub(quiet);
+    };
+    let (author, co_authors) = batch_authors(&project_path, &batch.entries).await;
+    let count = batch.entries.len().saturating_add(batch.events.len());
+    let result = tokio::task::spawn_blocking(move || {
+        let message = commit_message(&project_path, &batch.entries, &batch.events, &co_authors);
+        commit_centy(&project_path, &author, &message)
+    })
+    .await;
+    match result {
+        Ok(Ok(true)) => debug!(changes = count, "Auto-committed .centy changes"),
+        Ok(Ok(false)) => {}
+        Ok(Err(e)) => warn!(error = %e, "Auto-commit failed"),
+        Err(e) => warn!(error = %e, "Auto-commit task failed"),
+    }
+}
+
+/// The first actor in the batch, and everyone else whose changes are in
+/// it. Changes that were not journaled are credited to the local identity.
+async fn batch_authors(project_path: &Path, entries: &[JournalEntry]) -> (Author, Vec<Author>) {
+    let mut actors: Vec<&str> = Vec::new();
+    for entry in entries {
+        if !actors.contains(&entry.actor.as_str()) {
+            actors.push(&entry.actor);
+        }
+    }
+    let author = match actors.first() {
+        Some(first) => project_author(project_path, first).await,
+        None => project_author(project_path, &resolve_actor(project_path).await).await,
+    };
+    let mut co_authors: Vec<Author> = Vec::new();
+    for actor in actors.iter().skip(1) {
+        let other = project_author(project_path, actor).await;
+        if other != author && !co_authors.contains(&other) {
+            co_authors.push(other);
+        }
+    }
+    (author, co_authors)
+}
+
+/// A commit author.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Author {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+impl Author {
+    /// `Name <email>`, or the name alone.
+    pub fn identity(&self) -> String {
+        self.email.as_ref().map_or_else(
+            || self.name.clone(),
+            |email| format!("{} <{email}>", self.name),
+        )
+    }
+}
+
+/// The author for changes made by `actor`, a `Name <email>` identity or a
+/// bare name. A project user with that email, git username or name is
+/// preferred, so commits carry the name the team knows them by.
+async fn project_author(project_path: &Path, actor: &str) -> Author {
+    let identity = parse_actor(actor);
+    let users = read_users(project_path).await.unwrap_or_default();
+    let user = identity
+        .email
+        .as_deref()
+        .and_then(|email| find_user_by_email(&users, email))
+        .or_else(|| {
+            users
+                .iter()
+                .find(|u| u.name == identity.name || u.git_usernames.contains(&identity.name))
+        });
+    match user {
+        Some(found) => Author {
+            name: found.name.clone(),
+            email: found.email.clone().or(identity.email),
+        },
+        None => identity,
+    }
+}
+
+fn parse_actor(actor: &str) -> Author {
+    match actor
+        .strip_suffix('>')
+        .and_then(|rest| rest.split_once(" <"))
+    {
+        Some((name, email)) => Author {
+            name: name.to_string(),
+            email: Some(email.to_string()),
+        },
+        None => Author {
+            name: actor.to_string(),
+            email: None,
+        },
+    }
+}
+
+/// Commit the work tree's `.centy` directory to the checked-out branch.
+/// Returns whether there was anything to commit.
+///
+/// Only `.centy` paths change, in the commit and in the index; other
+/// staged or unstaged changes are left as they are.
+pub(super) fn commit_centy(
+    project_path: &Path,
+    author: &Author,
+    message: &str,
+) -> Result<bool, SyncError> {
+    let _guard = lock();
+    let sync = SyncRepo::open(project_path)?;
+    let head = match sync.repo.head() {
+        Ok(reference) => Some(reference.peel_to_commit()?),
+        Err(e) if matches!(e.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) => None,
+        Err(e) => return Err(e.into()),
+    };
+    let head_tree = head.as_ref().map(git2::Commit::tree).transpose()?;
+    let Some((tree, changed)) = with_work_tree(&sync, head_tree)? else {
+        return Ok(false);
+    };
+    let committer = sync.signature()?;
+    let email = author
+        .email
+        .clone()
+        .or_else(|| committer.email().map(str::to_string))
+        .unwrap_or_default();
+    let signature = Signature::now(&author.name, &email)?;
+    let parents: Vec<&git2::Commit<'_>> = head.iter().collect();
+    sync.repo.commit(
+        Some("HEAD"),
+        &signature,
+        &committer,
+        message,
+        &tree,
+        &parents,
+    )?;
+    let mut index = sync.repo.index()?;
+    for path in &changed {
+        if sync.workdir.join(path).exists() {
+            index.add_path(path)?;
+        } else {
+            index.remove_path(path)?;
+        }
+    }
+    index.write()?;
+    Ok(true)
+}
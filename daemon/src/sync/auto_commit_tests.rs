@@ -0,0 +1,255 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::auto_commit::{commit_centy, spawn_auto_commit_task, Author};
+use super::auto_commit_watch::set_watched;
+use super::commit_message::commit_message;
+use crate::journal::{FieldChange, JournalEntity, JournalEntry, Operation};
+use crate::watch::{ChangeEvent, ChangeKind, EntityKind};
+use git2::{Repository, Signature};
+use serde_json::json;
+use std::path::Path;
+use std::time::Duration;
+
+fn write(project: &Path, name: &str, content: &str) {
+    let path = project.join(name);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+/// A repository with one commit holding `README.md`.
+fn repo_with_readme() -> (tempfile::TempDir, Repository) {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    write(dir.path(), "README.md", "Hello\n");
+    {
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("Dev", "dev@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .unwrap();
+    }
+    (dir, repo)
+}
+
+fn alice() -> Author {
+    Author {
+        name: "Alice".to_string(),
+        email: Some("alice@example.com".to_string()),
+    }
+}
+
+fn entry(operation: Operation, item_id: &str, changes: Vec<FieldChange>) -> JournalEntry {
+    JournalEntry {
+        timestamp: "2024-01-01T00:00:00Z".to_string(),
+        actor: "Alice <alice@example.com>".to_string(),
+        operation,
+        entity: JournalEntity::Item,
+        item_type: "issues".to_string(),
+        item_id: item_id.to_string(),
+        changes,
+    }
+}
+
+fn change(field: &str, before: serde_json::Value, after: serde_json::Value) -> FieldChange {
+    FieldChange {
+        field: field.to_string(),
+        before: Some(before),
+        after: Some(after),
+    }
+}
+
+#[test]
+fn test_commit_centy_leaves_other_changes_alone() {
+    let (dir, repo) = repo_with_readme();
+    write(dir.path(), "README.md", "Edited\n");
+    write(dir.path(), "notes.txt", "Staged\n");
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("notes.txt")).unwrap();
+    index.write().unwrap();
+    write(dir.path(), ".centy/issues/login.md", "# Login\n");
+
+    let committed = commit_centy(dir.path(), &alice(), "centy: create issue #1").unwrap();
+
+    assert!(committed);
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("centy: create issue #1"));
+    assert_eq!(head.author().name(), Some("Alice"));
+    assert_eq!(head.author().email(), Some("alice@example.com"));
+    let tree = head.tree().unwrap();
+    assert!(tree.get_path(Path::new(".centy/issues/login.md")).is_ok());
+    assert!(tree.get_path(Path::new("notes.txt")).is_err());
+    let readme = tree.get_path(Path::new("README.md")).unwrap();
+    let blob = repo.find_blob(readme.id()).unwrap();
+    assert_eq!(blob.content(), b"Hello\n");
+    let statuses = repo.statuses(None).unwrap();
+    let status_of = |path: &str| {
+        statuses
+            .iter()
+            .find(|s| s.path() == Some(path))
+            .map(|s| s.status())
+    };
+    assert_eq!(status_of(".centy/issues/login.md"), None);
+    assert_eq!(status_of("notes.txt"), Some(git2::Status::INDEX_NEW));
+    assert_eq!(status_of("README.md"), Some(git2::Status::WT_MODIFIED));
+}
+
+#[test]
+fn test_commit_centy_records_deletes_and_skips_when_clean() {
+    let (dir, repo) = repo_with_readme();
+    write(dir.path(), ".centy/issues/login.md", "# Login\n");
+    commit_centy(dir.path(), &alice(), "centy: create").unwrap();
+    std::fs::remove_file(dir.path().join(".centy/issues/login.md")).unwrap();
+
+    assert!(commit_centy(dir.path(), &alice(), "centy: delete").unwrap());
+    assert!(!commit_centy(dir.path(), &alice(), "centy: nothing").unwrap());
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("centy: delete"));
+    assert!(head
+        .tree()
+        .unwrap()
+        .get_path(Path::new(".centy/issues/login.md"))
+        .is_err());
+    assert!(repo.statuses(None).unwrap().is_empty());
+}
+
+#[test]
+fn test_commit_centy_on_a_new_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    write(dir.path(), ".centy/issues/login.md", "# Login\n");
+
+    assert!(commit_centy(dir.path(), &alice(), "centy: create").unwrap());
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.parent_count(), 0);
+}
+
+#[test]
+fn test_commit_message_describes_an_update() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), ".centy/issues/config.yaml", "name: Issue\n");
+    write(
+        dir.path(),
+        ".centy/issues/abc.md",
+        "---\ndisplayNumber: 42\nstatus: closed\n---\n# Login fails\n\nSteps\n",
+    );
+    let update = entry(
+        Operation::Update,
+        "abc",
+        vec![
+            change("body", json!("Old"), json!("Steps")),
+            change("status", json!("open"), json!("closed")),
+        ],
+    );
+
+    let message = commit_message(dir.path(), &[update], &[], &[]);
+
+    assert_eq!(
+        message,
+        "centy: update issue #42 \"Login fails\" (body, status: open \u{2192} closed)"
+    );
+}
+
+#[test]
+fn test_commit_message_lists_a_batch() {
+    let dir = tempfile::tempdir().unwrap();
+    let deleted = entry(
+        Operation::Delete,
+        "gone",
+        vec![
+            FieldChange {
+                field: "displayNumber".to_string(),
+                before: Some(json!(7u32)),
+                after: None,
+            },
+            FieldChange {
+                field: "title".to_string(),
+                before: Some(json!("Old issue")),
+                after: None,
+            },
+        ],
+    );
+    let soft_deleted = entry(Operation::SoftDelete, "slug-id", Vec::new());
+
+    let message = commit_message(dir.path(), &[deleted, soft_deleted], &[], &[]);
+
+    assert_eq!(
+        message,
+        "centy: 2 changes\n\n- delete issues #7 \"Old issue\"\n- soft delete issues slug-id"
+    );
+}
+
+#[test]
+fn test_commit_message_describes_unjournaled_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), ".centy/tasks/config.yaml", "name: Task\n");
+    let config = ChangeEvent::new(ChangeKind::Updated, EntityKind::Config, dir.path(), "", "");
+    let task_config = ChangeEvent::new(
+        ChangeKind::Created,
+        EntityKind::Config,
+        dir.path(),
+        "tasks",
+        "",
+    );
+
+    assert_eq!(
+        commit_message(dir.path(), &[], std::slice::from_ref(&config), &[]),
+        "centy: update config"
+    );
+    assert_eq!(
+        commit_message(dir.path(), &[], &[config.clone(), task_config, config], &[]),
+        "centy: 2 changes\n\n- update config\n- create task config"
+    );
+}
+
+#[test]
+fn test_commit_message_skips_journaled_events_and_credits_co_authors() {
+    let dir = tempfile::tempdir().unwrap();
+    let soft_deleted = entry(Operation::SoftDelete, "slug-id", Vec::new());
+    let same_item = ChangeEvent::item(ChangeKind::Updated, dir.path(), "issues", "slug-id");
+    let users = ChangeEvent::new(ChangeKind::Updated, EntityKind::User, dir.path(), "", "");
+    let bob = Author {
+        name: "Bob".to_string(),
+        email: Some("bob@example.com".to_string()),
+    };
+
+    let message = commit_message(dir.path(), &[soft_deleted], &[same_item, users], &[bob]);
+
+    assert_eq!(
+        message,
+        "centy: 2 changes\n\n- soft delete issues slug-id\n- update users\n\n\
+         Co-authored-by: Bob <bob@example.com>"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_external_edit_is_committed_without_a_watch_stream() {
+    let (dir, repo) = repo_with_readme();
+    write(
+        dir.path(),
+        ".centy/config.json",
+        r#"{"autoCommit": {"enabled": true, "debounceMs": 50}}"#,
+    );
+    std::fs::create_dir_all(dir.path().join(".centy/issues")).unwrap();
+    spawn_auto_commit_task();
+    set_watched(dir.path(), true);
+
+    write(dir.path(), ".centy/issues/login.md", "# Login\n");
+
+    let committed = async {
+        while repo
+            .head()
+            .and_then(|head| head.peel_to_tree())
+            .and_then(|tree| tree.get_path(Path::new(".centy/issues/login.md")))
+            .is_err()
+        {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(10), committed)
+        .await
+        .unwrap();
+    set_watched(dir.path(), false);
+}
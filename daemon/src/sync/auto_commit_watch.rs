@@ -0,0 +1,61 @@
+use crate::config::read_config;
+use crate::registry::{list_projects, ListProjectsOptions};
+use crate::watch::{watch_project, WatchGuard};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tracing::warn;
+
+/// Watchers held for projects with auto-commit on, so edits made outside
+/// the daemon are committed whether or not a `Watch` stream is open.
+static WATCHED: OnceLock<Mutex<HashMap<PathBuf, WatchGuard>>> = OnceLock::new();
+
+fn watched() -> &'static Mutex<HashMap<PathBuf, WatchGuard>> {
+    WATCHED.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Watch every registered project that has auto-commit on.
+pub(super) async fn watch_registered_projects() {
+    let projects = match list_projects(ListProjectsOptions {
+        include_archived: true,
+        include_stale: false,
+        include_uninitialized: false,
+        include_temp: false,
+        ungrouped_only: false,
+        organization_slug: None,
+    })
+    .await
+    {
+        Ok(p) => p,
+        Err(e) => {
+            warn!(error = %e, "Failed to list projects for auto-commit");
+            return;
+        }
+    };
+    for project in &projects {
+        let project_path = Path::new(&project.path);
+        if let Ok(Some(config)) = read_config(project_path).await {
+            set_watched(project_path, config.auto_commit.enabled);
+        }
+    }
+}
+
+/// Start or stop watching the project's `.centy/` folder for auto-commit.
+pub(super) fn set_watched(project_path: &Path, enabled: bool) {
+    let mut watched = watched().lock().unwrap_or_else(PoisonError::into_inner);
+    if !enabled {
+        let stopped = watched.remove(project_path);
+        drop(watched);
+        drop(stopped);
+        return;
+    }
+    if let Entry::Vacant(slot) = watched.entry(project_path.to_path_buf()) {
+        match watch_project(project_path) {
+            Ok(guard) => {
+                slot.insert(guard);
+            }
+            Err(e) => warn!(error = %e, "Failed to watch .centy for auto-commit"),
+        }
+    }
+}
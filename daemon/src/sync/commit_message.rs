@@ -0,0 +1,190 @@
+use super::auto_commit::Author;
+use super::document::{Document, BODY_FIELD, TITLE_FIELD};
+use crate::journal::{FieldChange, JournalEntity, JournalEntry, Operation};
+use crate::utils::get_centy_path;
+use crate::watch::{ChangeEvent, ChangeKind, EntityKind};
+use serde_json::Value;
+use std::path::Path;
+
+const DISPLAY_NUMBER_FIELD: &str = "displayNumber";
+
+/// A structured commit message: the change itself for one change,
+/// otherwise a count with one line per change, then a `Co-authored-by`
+/// trailer per other author.
+///
+/// Journaled changes are described from their entries; `events` only add
+/// the changes no entry covers, such as config edits.
+pub(super) fn commit_message(
+    project_path: &Path,
+    entries: &[JournalEntry],
+    events: &[ChangeEvent],
+    co_authors: &[Author],
+) -> String {
+    let mut lines: Vec<String> = entries
+        .iter()
+        .map(|entry| describe(project_path, entry))
+        .collect();
+    let mut described: Vec<&ChangeEvent> = Vec::new();
+    for event in events {
+        let covered = entries.iter().any(|entry| is_journaled(event, entry))
+            || described.iter().any(|seen| seen.same_entity(event));
+        if !covered {
+            lines.push(describe_event(project_path, event));
+            described.push(event);
+        }
+    }
+    let mut message = if let [only] = lines.as_slice() {
+        format!("centy: {only}")
+    } else {
+        let list: Vec<String> = lines.iter().map(|line| format!("- {line}")).collect();
+        format!("centy: {} changes\n\n{}", lines.len(), list.join("\n"))
+    };
+    if !co_authors.is_empty() {
+        let trailers: Vec<String> = co_authors
+            .iter()
+            .map(|author| format!("Co-authored-by: {}", author.identity()))
+            .collect();
+        message.push_str("\n\n");
+        message.push_str(&trailers.join("\n"));
+    }
+    message
+}
+
+/// Whether `entry` records the change `event` announces. User events
+/// carry no id; a moved item may be journaled under its old one.
+fn is_journaled(event: &ChangeEvent, entry: &JournalEntry) -> bool {
+    let same_entity = matches!(
+        (event.entity, entry.entity),
+        (EntityKind::Item, JournalEntity::Item)
+            | (EntityKind::Link, JournalEntity::Link)
+            | (EntityKind::User, JournalEntity::User)
+    );
+    same_entity
+        && (event.entity == EntityKind::User
+            || event.id == entry.item_id
+            || event.previous_id == entry.item_id)
+}
+
+/// e.g. `update issue #42 "Login fails" (status: open → closed)`.
+fn describe(project_path: &Path, entry: &JournalEntry) -> String {
+    let mut parts = vec![entry.operation.as_str().replace('_', " ")];
+    match entry.entity {
+        JournalEntity::Item => parts.push(item_label(
+            project_path,
+            &entry.item_type,
+            &entry.item_id,
+            &entry.changes,
+        )),
+        JournalEntity::Link | JournalEntity::User => {
+            parts.push(entry.entity.as_str().to_string());
+            parts.push(entry.item_id.clone());
+        }
+    }
+    if entry.operation == Operation::Update && !entry.changes.is_empty() {
+        let changes: Vec<String> = entry.changes.iter().map(describe_change).collect();
+        parts.push(format!("({})", changes.join(", ")));
+    }
+    parts.join(" ")
+}
+
+/// e.g. `update config`, `create task config` or `update users`.
+fn describe_event(project_path: &Path, event: &ChangeEvent) -> String {
+    let verb = match event.kind {
+        ChangeKind::Created => "create",
+        ChangeKind::Updated => "update",
+        ChangeKind::Deleted => "delete",
+        ChangeKind::Moved => "move",
+    };
+    let subject = match event.entity {
+        EntityKind::Config if event.item_type.is_empty() => "config".to_string(),
+        EntityKind::Config => format!("{} config", type_name(project_path, &event.item_type)),
+        EntityKind::Item => item_label(project_path, &event.item_type, &event.id, &[]),
+        EntityKind::Link => format!("link {}", event.id),
+        EntityKind::User => "users".to_string(),
+    };
+    format!("{verb} {subject}")
+}
+
+/// e.g. `issue #42 "Login fails"`; the id when the item has no number.
+/// Deleted items are named from the values in `changes`.
+fn item_label(project_path: &Path, folder: &str, id: &str, changes: &[FieldChange]) -> String {
+    let document = read_item(project_path, folder, id);
+    let field = |name: &str| {
+        document
+            .as_ref()
+            .and_then(|d| d.get(name))
+            .or_else(|| before_value(changes, name))
+    };
+    let number = field(DISPLAY_NUMBER_FIELD)
+        .and_then(|v| v.as_u64())
+        .filter(|n| *n > 0);
+    let mut parts = vec![
+        type_name(project_path, folder),
+        number.map_or_else(|| id.to_string(), |n| format!("#{n}")),
+    ];
+    if let Some(Value::String(title)) = field(TITLE_FIELD) {
+        if !title.is_empty() {
+            parts.push(format!("\"{title}\""));
+        }
+    }
+    parts.join(" ")
+}
+
+/// `field: before → after` for plain values, otherwise the field name.
+fn describe_change(change: &FieldChange) -> String {
+    if change.field == BODY_FIELD {
+        return change.field.clone();
+    }
+    match (plain(change.before.as_ref()), plain(change.after.as_ref())) {
+        (Some(before), Some(after)) => {
+            format!("{}: {before} \u{2192} {after}", change.field)
+        }
+        (Some(_) | None, _) => change.field.clone(),
+    }
+}
+
+/// A short value as text; absent is `none`. Lists, objects and multi-line
+/// text are too long for a subject line.
+fn plain(value: Option<&Value>) -> Option<String> {
+    match value {
+        None | Some(Value::Null) => Some("none".to_string()),
+        Some(Value::String(text)) if !text.contains('\n') => Some(text.clone()),
+        Some(Value::Number(number)) => Some(number.to_string()),
+        Some(Value::Bool(flag)) => Some(flag.to_string()),
+        Some(Value::String(_) | Value::Array(_) | Value::Object(_)) => None,
+    }
+}
+
+fn before_value(changes: &[FieldChange], field: &str) -> Option<Value> {
+    changes
+        .iter()
+        .find(|c| c.field == field)
+        .and_then(|c| c.before.clone())
+}
+
+/// The item as it is now; `None` once deleted.
+fn read_item(project_path: &Path, folder: &str, id: &str) -> Option<Document> {
+    let path = get_centy_path(project_path)
+        .join(folder)
+        .join(format!("{id}.md"));
+    let content = std::fs::read_to_string(&path).ok()?;
+    Some(Document::parse(&path.to_string_lossy(), &content))
+}
+
+/// The item type's singular name from its `config.yaml`, lowercased;
+/// the folder name when it has none.
+fn type_name(project_path: &Path, folder: &str) -> String {
+    let path = get_centy_path(project_path)
+        .join(folder)
+        .join("config.yaml");
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Mapping>(&content).ok())
+        .and_then(|config| {
+            config
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(str::to_lowercase)
+        })
+        .unwrap_or_else(|| folder.to_string())
+}
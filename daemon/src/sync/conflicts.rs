@@ -0,0 +1,146 @@
+use super::document::{Document, CONTENT_FIELD};
+use super::error::SyncError;
+use super::repo::{lock, SyncRepo};
+use super::state::SyncState;
+use super::types::{FieldConflict, Resolution, SyncConflict};
+use crate::utils::now_iso;
+use serde_json::Value;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// A conflict record for the file at `path`, naming the item when the
+/// file is one.
+pub(super) fn new_conflict(
+    sync: &SyncRepo,
+    path: &str,
+    fields: Vec<FieldConflict>,
+) -> SyncConflict {
+    let (item_type, item_id) = item_of(sync, path).unwrap_or_default();
+    SyncConflict {
+        id: uuid::Uuid::new_v4().to_string(),
+        path: path.to_string(),
+        item_type,
+        item_id,
+        created_at: now_iso(),
+        fields,
+    }
+}
+
+/// The item type folder and id of `.centy/<folder>/<id>.md`.
+fn item_of(sync: &SyncRepo, path: &str) -> Option<(String, String)> {
+    let relative = Path::new(path).strip_prefix(&sync.prefix).ok()?;
+    let mut parts = relative.iter();
+    let folder = parts.next()?.to_str()?;
+    let file = Path::new(parts.next()?);
+    if parts.next().is_some() || file.extension()? != "md" {
+        return None;
+    }
+    Some((folder.to_string(), file.file_stem()?.to_str()?.to_string()))
+}
+
+/// Unresolved conflicts, oldest first.
+pub fn list_conflicts(project_path: &Path) -> Result<Vec<SyncConflict>, SyncError> {
+    Ok(SyncState::load(project_path)?.conflicts)
+}
+
+pub fn get_conflict(project_path: &Path, id: &str) -> Result<SyncConflict, SyncError> {
+    SyncState::load(project_path)?
+        .conflicts
+        .into_iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| SyncError::ConflictNotFound(id.to_string()))
+}
+
+/// Resolve some or all fields of a conflict.
+///
+/// The chosen values are written to the file; the next push shares them.
+/// The record is dropped once every field is resolved. Returns what is
+/// left of it, if anything.
+pub fn resolve_conflict(
+    project_path: &Path,
+    id: &str,
+    resolutions: Vec<(String, Resolution)>,
+) -> Result<Option<SyncConflict>, SyncError> {
+    let _guard = lock();
+    let sync = SyncRepo::open(project_path)?;
+    let mut state = SyncState::load(project_path)?;
+    let position = state
+        .conflicts
+        .iter()
+        .position(|c| c.id == id)
+        .ok_or_else(|| SyncError::ConflictNotFound(id.to_string()))?;
+    let mut conflict = state.conflicts.remove(position);
+    let mut chosen = Vec::new();
+    for (field, resolution) in resolutions {
+        let Some(index) = conflict.fields.iter().position(|f| f.field == field) else {
+            return Err(SyncError::InvalidResolution(format!(
+                "{field} is not in conflict"
+            )));
+        };
+        let values = conflict.fields.remove(index);
+        let value = match resolution {
+            Resolution::Ours => values.ours,
+            Resolution::Theirs => values.theirs,
+            Resolution::Custom(value) => value,
+        };
+        chosen.push((field, value));
+    }
+    write_resolution(&sync, &conflict.path, chosen)?;
+    let remaining = if conflict.fields.is_empty() {
+        None
+    } else {
+        state.conflicts.insert(position, conflict.clone());
+        Some(conflict)
+    };
+    state.save(project_path)?;
+    Ok(remaining)
+}
+
+/// Apply chosen field values to the file. Nothing is written when one of
+/// them does not fit the file.
+fn write_resolution(
+    sync: &SyncRepo,
+    path: &str,
+    chosen: Vec<(String, Option<Value>)>,
+) -> Result<(), SyncError> {
+    let file = sync.workdir.join(path);
+    let mut document = match fs::read_to_string(&file) {
+        Ok(content) => Some(Document::parse(path, &content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+    for (field, value) in chosen {
+        if field == CONTENT_FIELD {
+            document = value.map(text_document).transpose()?;
+            continue;
+        }
+        let Some(target) = document.as_mut() else {
+            return Err(SyncError::InvalidResolution(format!(
+                "{path} no longer exists"
+            )));
+        };
+        target.set(&field, value)?;
+    }
+    match document {
+        Some(doc) => {
+            if let Some(parent) = file.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&file, doc.render())?;
+        }
+        None => match fs::remove_file(&file) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        },
+    }
+    Ok(())
+}
+
+/// A whole-file value as a document.
+fn text_document(value: Value) -> Result<Document, SyncError> {
+    let mut document = Document::Text(String::new());
+    document.set(CONTENT_FIELD, Some(value))?;
+    Ok(document)
+}
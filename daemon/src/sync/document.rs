@@ -0,0 +1,118 @@
+use super::error::SyncError;
+use crate::utils::{strip_centy_md_header, CENTY_HEADER_YAML};
+use serde_json::Value;
+use serde_yaml::Mapping;
+use std::path::Path;
+
+/// Pseudo-field holding the whole content of a file that is not an item.
+pub const CONTENT_FIELD: &str = "content";
+pub const TITLE_FIELD: &str = "title";
+pub const BODY_FIELD: &str = "body";
+
+/// A synced file, split into the fields conflicts are tracked by.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Document {
+    /// A markdown item: its frontmatter keys, title and body.
+    Item {
+        frontmatter: Mapping,
+        title: String,
+        body: String,
+    },
+    /// Anything else, as a whole.
+    Text(String),
+}
+
+impl Document {
+    /// Markdown files with frontmatter are items; everything else is text.
+    pub fn parse(path: &str, content: &str) -> Self {
+        let markdown = Path::new(path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+        if !markdown {
+            return Self::Text(content.to_string());
+        }
+        match mdstore::parse_frontmatter::<Mapping>(strip_centy_md_header(content)) {
+            Ok((frontmatter, title, body)) => Self::Item {
+                frontmatter,
+                title,
+                body,
+            },
+            Err(_e) => Self::Text(content.to_string()),
+        }
+    }
+
+    /// The file content, written the way the item store writes items.
+    pub fn render(&self) -> String {
+        match self {
+            Self::Item {
+                frontmatter,
+                title,
+                body,
+            } => mdstore::generate_frontmatter(frontmatter, title, body, Some(CENTY_HEADER_YAML)),
+            Self::Text(content) => content.clone(),
+        }
+    }
+
+    /// Field names, in file order.
+    pub fn field_names(&self) -> Vec<String> {
+        match self {
+            Self::Item { frontmatter, .. } => [TITLE_FIELD, BODY_FIELD]
+                .into_iter()
+                .map(str::to_string)
+                .chain(
+                    frontmatter
+                        .keys()
+                        .filter_map(|k| k.as_str().map(str::to_string)),
+                )
+                .collect(),
+            Self::Text(_) => vec![CONTENT_FIELD.to_string()],
+        }
+    }
+
+    pub fn get(&self, field: &str) -> Option<Value> {
+        match (self, field) {
+            (Self::Item { title, .. }, TITLE_FIELD) => Some(Value::String(title.clone())),
+            (Self::Item { body, .. }, BODY_FIELD) => Some(Value::String(body.clone())),
+            (Self::Item { frontmatter, .. }, _) => {
+                serde_json::to_value(frontmatter.get(field)?).ok()
+            }
+            (Self::Text(content), CONTENT_FIELD) => Some(Value::String(content.clone())),
+            (Self::Text(_), _) => None,
+        }
+    }
+
+    /// Set a field; `None` removes a frontmatter key. The title, body and
+    /// content must be strings.
+    pub fn set(&mut self, field: &str, value: Option<Value>) -> Result<(), SyncError> {
+        match (self, field) {
+            (Self::Item { title, .. }, TITLE_FIELD) => *title = text_value(field, value)?,
+            (Self::Item { body, .. }, BODY_FIELD) => *body = text_value(field, value)?,
+            (Self::Item { frontmatter, .. }, _) => match value {
+                Some(v) => {
+                    let yaml = serde_yaml::to_value(v)
+                        .map_err(|e| SyncError::InvalidResolution(e.to_string()))?;
+                    frontmatter.insert(field.into(), yaml);
+                }
+                None => {
+                    frontmatter.remove(field);
+                }
+            },
+            (Self::Text(content), CONTENT_FIELD) => *content = text_value(field, value)?,
+            (Self::Text(_), _) => {
+                return Err(SyncError::InvalidResolution(format!(
+                    "{field} is not a field of this file"
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+fn text_value(field: &str, value: Option<Value>) -> Result<String, SyncError> {
+    match value {
+        Some(Value::String(text)) => Ok(text),
+        Some(_) | None => Err(SyncError::InvalidResolution(format!(
+            "{field} must be a string"
+        ))),
+    }
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+/// Errors raised while syncing the `.centy` directory with a git remote.
+#[derive(Error, Debug)]
+pub enum SyncError {
+    #[error("Project is not inside a git repository")]
+    NotGitRepository,
+
+    #[error("Project directory {0} is outside the repository work tree")]
+    OutsideWorkTree(String),
+
+    #[error("Remote '{0}' not found")]
+    RemoteNotFound(String),
+
+    #[error("The local and remote sync branches have diverged")]
+    Diverged,
+
+    #[error("Remote rejected {reference}: {message}")]
+    PushRejected { reference: String, message: String },
+
+    #[error("Sync conflict not found: {0}")]
+    ConflictNotFound(String),
+
+    #[error("Invalid resolution: {0}")]
+    InvalidResolution(String),
+
+    #[error("Invalid sync config: {0}")]
+    InvalidSettings(String),
+
+    #[error("Config error: {0}")]
+    Config(#[from] mdstore::ConfigError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Git error: {0}")]
+    Git(#[from] git2::Error),
+}
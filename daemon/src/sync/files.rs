@@ -0,0 +1,156 @@
+use super::error::SyncError;
+use super::repo::SyncRepo;
+use git2::build::TreeUpdateBuilder;
+use git2::{FileMode, ObjectType, Oid, Tree, TreeWalkMode, TreeWalkResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Repository-relative paths of files, with the ids of their contents.
+pub(super) type Files = BTreeMap<PathBuf, Oid>;
+
+/// The files under the `.centy` directory in the work tree. Paths git
+/// ignores, such as the `*.local.*` state, are left out.
+pub(super) fn work_tree_files(sync: &SyncRepo) -> Result<Files, SyncError> {
+    let mut files = Files::new();
+    let walk = WalkDir::new(sync.workdir.join(&sync.prefix))
+        .into_iter()
+        .filter_entry(|entry| !is_ignored(sync, entry.path()));
+    for result in walk {
+        let entry = result.map_err(std::io::Error::from)?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let Some(relative) = relative_path(sync, entry.path()) else {
+            continue;
+        };
+        files.insert(relative, Oid::hash_file(ObjectType::Blob, entry.path())?);
+    }
+    Ok(files)
+}
+
+fn relative_path(sync: &SyncRepo, path: &Path) -> Option<PathBuf> {
+    path.strip_prefix(&sync.workdir).ok().map(Path::to_path_buf)
+}
+
+fn is_ignored(sync: &SyncRepo, path: &Path) -> bool {
+    relative_path(sync, path)
+        .is_some_and(|relative| sync.repo.is_path_ignored(&relative).unwrap_or(false))
+}
+
+/// The files under the `.centy` directory in `tree`.
+pub(super) fn tree_files(sync: &SyncRepo, tree: Option<&Tree<'_>>) -> Result<Files, SyncError> {
+    let mut files = Files::new();
+    let Some(entry) = tree.and_then(|t| t.get_path(&sync.prefix).ok()) else {
+        return Ok(files);
+    };
+    let Ok(subtree) = entry.to_object(&sync.repo)?.into_tree() else {
+        return Ok(files);
+    };
+    subtree.walk(TreeWalkMode::PreOrder, |root, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            if let Some(name) = entry.name() {
+                files.insert(sync.prefix.join(root).join(name), entry.id());
+            }
+        }
+        TreeWalkResult::Ok
+    })?;
+    Ok(files)
+}
+
+/// Commit the work tree's `.centy` directory to the local sync branch.
+/// Returns whether there was anything to commit.
+///
+/// Only the project's own directory is replaced, so several projects in
+/// one repository share the branch.
+pub(super) fn commit_work_tree(sync: &SyncRepo, message: &str) -> Result<bool, SyncError> {
+    let parent = sync.local_tip()?;
+    let parent_tree = parent.as_ref().map(git2::Commit::tree).transpose()?;
+    let Some((tree, _changed)) = with_work_tree(sync, parent_tree)? else {
+        return Ok(false);
+    };
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    let commit = sync.commit(message, &tree, &parents)?;
+    sync.set_local_tip(&commit, "centy sync: commit")?;
+    Ok(true)
+}
+
+/// `base` with its `.centy` directory replaced by the work tree's, and the
+/// paths that changed. `None` when nothing did.
+pub(super) fn with_work_tree<'repo>(
+    sync: &'repo SyncRepo,
+    base: Option<Tree<'repo>>,
+) -> Result<Option<(Tree<'repo>, Vec<PathBuf>)>, SyncError> {
+    let committed = tree_files(sync, base.as_ref())?;
+    let current = work_tree_files(sync)?;
+    if committed == current {
+        return Ok(None);
+    }
+    let mut update = TreeUpdateBuilder::new();
+    let mut changed = Vec::new();
+    for path in committed.keys() {
+        if !current.contains_key(path) {
+            update.remove(path);
+            changed.push(path.clone());
+        }
+    }
+    for (path, id) in &current {
+        if committed.get(path) != Some(id) {
+            let blob = sync.repo.blob(&fs::read(sync.workdir.join(path))?)?;
+            update.upsert(path, blob, FileMode::Blob);
+            changed.push(path.clone());
+        }
+    }
+    let base_tree = match base {
+        Some(tree) => tree,
+        None => empty_tree(sync)?,
+    };
+    let tree = sync
+        .repo
+        .find_tree(update.create_updated(&sync.repo, &base_tree)?)?;
+    Ok(Some((tree, changed)))
+}
+
+pub(super) fn empty_tree(sync: &SyncRepo) -> Result<Tree<'_>, SyncError> {
+    let id = sync.repo.treebuilder(None)?.write()?;
+    Ok(sync.repo.find_tree(id)?)
+}
+
+/// Bring the work tree's `.centy` directory from `old` to `new`. Returns
+/// whether any file changed.
+pub(super) fn write_changes(
+    sync: &SyncRepo,
+    old: Option<&Tree<'_>>,
+    new: &Tree<'_>,
+) -> Result<bool, SyncError> {
+    let before = tree_files(sync, old)?;
+    let after = tree_files(sync, Some(new))?;
+    let mut changed = false;
+    for path in before.keys() {
+        if !after.contains_key(path) {
+            remove_file(&sync.workdir.join(path))?;
+            changed = true;
+        }
+    }
+    for (path, id) in &after {
+        if before.get(path) == Some(id) {
+            continue;
+        }
+        let target = sync.workdir.join(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, sync.repo.find_blob(*id)?.content())?;
+        changed = true;
+    }
+    Ok(changed)
+}
+
+fn remove_file(path: &Path) -> Result<(), SyncError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
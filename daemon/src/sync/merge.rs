@@ -0,0 +1,287 @@
+use super::document::{Document, BODY_FIELD, CONTENT_FIELD};
+use super::types::FieldConflict;
+use git2::{IndexEntry, IndexTime, MergeFileOptions, Odb, Repository};
+use serde_json::Value;
+use tracing::warn;
+
+/// Fields both sides rewrite on every edit; the later value wins instead
+/// of conflicting.
+const LATEST_WINS: &[&str] = &["updatedAt"];
+
+/// List fields merged as sets: additions from both sides are kept and
+/// removals from either side apply.
+const SET_FIELDS: &[&str] = &["tags"];
+
+/// Text fields merged line by line, like git merges files.
+const TEXT_FIELDS: &[&str] = &[BODY_FIELD, CONTENT_FIELD];
+
+/// The three-way merge of one value.
+enum Merged {
+    Value(Option<Value>),
+    /// Both sides changed it to different values. Text fields carry both
+    /// sides between conflict markers.
+    Conflict {
+        marked: Option<String>,
+    },
+}
+
+/// What a conflicted text field holds in the merged file. Other fields
+/// always keep our value, since markers would break the frontmatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ConflictStyle {
+    /// Our value, as if only we had changed it.
+    KeepOurs,
+    /// Both sides between git conflict markers.
+    Markers,
+}
+
+/// A file merged from its common ancestor and both sides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMerge {
+    /// The merged content; `None` when the file is deleted.
+    pub content: Option<String>,
+    /// Fields changed differently on both sides.
+    pub conflicts: Vec<FieldConflict>,
+}
+
+/// Merge a file field by field. `None` is a side without the file.
+///
+/// Item files are merged per frontmatter key, title and body, so changes
+/// to different fields combine. Other files, and files one side deleted,
+/// are merged as a whole.
+pub(super) fn merge_file(
+    path: &str,
+    base: Option<&str>,
+    ours: Option<&str>,
+    theirs: Option<&str>,
+    style: ConflictStyle,
+) -> FileMerge {
+    let parse = |content: Option<&str>| content.map(|c| Document::parse(path, c));
+    match (parse(base), parse(ours), parse(theirs)) {
+        (
+            base_doc @ (None | Some(Document::Item { .. })),
+            Some(ours_doc @ Document::Item { .. }),
+            Some(theirs_doc @ Document::Item { .. }),
+        ) => merge_fields(base_doc.as_ref(), &ours_doc, &theirs_doc, style),
+        (_, _, _) => merge_content(base, ours, theirs, style),
+    }
+}
+
+/// Merge a `.centy` file for `git merge`, which passes all three versions
+/// and an empty base when both sides added the file.
+///
+/// Conflicted text gets conflict markers; conflicted frontmatter keys keep
+/// our value.
+#[must_use]
+pub fn merge_for_git(path: &str, base: &str, ours: &str, theirs: &str) -> FileMerge {
+    let added = base.is_empty();
+    merge_file(
+        path,
+        (!added).then_some(base),
+        Some(ours),
+        Some(theirs),
+        ConflictStyle::Markers,
+    )
+}
+
+fn merge_fields(
+    base: Option<&Document>,
+    ours: &Document,
+    theirs: &Document,
+    style: ConflictStyle,
+) -> FileMerge {
+    let mut names = ours.field_names();
+    for name in theirs
+        .field_names()
+        .into_iter()
+        .chain(base.map(Document::field_names).unwrap_or_default())
+    {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut merged = ours.clone();
+    let mut conflicts = Vec::new();
+    for field in names {
+        let base_value = base.and_then(|b| b.get(&field));
+        let ours_value = ours.get(&field);
+        let theirs_value = theirs.get(&field);
+        let outcome = merge_value(
+            &field,
+            base_value.as_ref(),
+            ours_value.as_ref(),
+            theirs_value.as_ref(),
+        );
+        let (value, conflicted) = match outcome {
+            Merged::Value(value) => (value, false),
+            Merged::Conflict { marked } => match (style, marked) {
+                (ConflictStyle::Markers, Some(text)) => (Some(Value::String(text)), true),
+                (ConflictStyle::Markers | ConflictStyle::KeepOurs, _) => (ours_value.clone(), true),
+            },
+        };
+        let rejected = value != ours_value && merged.set(&field, value).is_err();
+        if conflicted || rejected {
+            conflicts.push(conflict(field, base_value, ours_value, theirs_value));
+        }
+    }
+    FileMerge {
+        content: Some(merged.render()),
+        conflicts,
+    }
+}
+
+fn merge_content(
+    base: Option<&str>,
+    ours: Option<&str>,
+    theirs: Option<&str>,
+    style: ConflictStyle,
+) -> FileMerge {
+    let value = |content: Option<&str>| content.map(|c| Value::String(c.to_string()));
+    let (base_value, ours_value, theirs_value) = (value(base), value(ours), value(theirs));
+    let outcome = merge_value(
+        CONTENT_FIELD,
+        base_value.as_ref(),
+        ours_value.as_ref(),
+        theirs_value.as_ref(),
+    );
+    match outcome {
+        Merged::Value(merged) => FileMerge {
+            content: merged.and_then(|v| v.as_str().map(str::to_string)),
+            conflicts: Vec::new(),
+        },
+        Merged::Conflict { marked } => FileMerge {
+            content: match (style, marked) {
+                (ConflictStyle::Markers, Some(text)) => Some(text),
+                (ConflictStyle::Markers | ConflictStyle::KeepOurs, _) => ours.map(str::to_string),
+            },
+            conflicts: vec![conflict(
+                CONTENT_FIELD.to_string(),
+                base_value,
+                ours_value,
+                theirs_value,
+            )],
+        },
+    }
+}
+
+/// Three-way merge of one value.
+fn merge_value(
+    field: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+) -> Merged {
+    if ours == theirs || theirs == base {
+        return Merged::Value(ours.cloned());
+    }
+    if ours == base {
+        return Merged::Value(theirs.cloned());
+    }
+    if LATEST_WINS.contains(&field) {
+        let text = |value: Option<&Value>| value.and_then(Value::as_str).map(str::to_string);
+        return Merged::Value(text(ours).max(text(theirs)).map(Value::String));
+    }
+    if SET_FIELDS.contains(&field) {
+        if let Some(merged) = merge_sets(base, ours, theirs) {
+            return Merged::Value(Some(Value::Array(merged)));
+        }
+    }
+    if TEXT_FIELDS.contains(&field) {
+        if let (Some(Value::String(o)), Some(Value::String(t))) = (ours, theirs) {
+            let b = base.and_then(Value::as_str).unwrap_or_default();
+            return merge_text(b, o, t);
+        }
+    }
+    Merged::Conflict { marked: None }
+}
+
+/// Merge lists as sets, keeping our order. `None` when a side is not a
+/// list.
+fn merge_sets(
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+) -> Option<Vec<Value>> {
+    let items = |value: Option<&Value>| match value {
+        Some(Value::Array(items)) => Some(items.clone()),
+        Some(_) => None,
+        None => Some(Vec::new()),
+    };
+    let (base_items, ours_items, theirs_items) = (items(base)?, items(ours)?, items(theirs)?);
+    let mut merged: Vec<Value> = ours_items
+        .iter()
+        .filter(|v| !base_items.contains(v) || theirs_items.contains(v))
+        .cloned()
+        .collect();
+    for value in theirs_items {
+        if !base_items.contains(&value) && !merged.contains(&value) {
+            merged.push(value);
+        }
+    }
+    Some(merged)
+}
+
+fn merge_text(base: &str, ours: &str, theirs: &str) -> Merged {
+    match merge_lines(base, ours, theirs) {
+        Ok((true, text)) => Merged::Value(Some(Value::String(text))),
+        Ok((false, text)) => Merged::Conflict { marked: Some(text) },
+        Err(e) => {
+            warn!("Line merge failed: {e}");
+            Merged::Conflict { marked: None }
+        }
+    }
+}
+
+/// Merge text line by line with libgit2, in an in-memory repository so
+/// nothing is written to disk. Returns whether it merged cleanly, and the
+/// text, with conflict markers if not.
+fn merge_lines(base: &str, ours: &str, theirs: &str) -> Result<(bool, String), git2::Error> {
+    let odb = Odb::new()?;
+    odb.add_new_mempack_backend(1)?;
+    let repo = Repository::from_odb(odb)?;
+    let entry = |text: &str| -> Result<IndexEntry, git2::Error> {
+        Ok(IndexEntry {
+            ctime: IndexTime::new(0, 0),
+            mtime: IndexTime::new(0, 0),
+            dev: 0,
+            ino: 0,
+            mode: 0o100_644,
+            uid: 0,
+            gid: 0,
+            file_size: u32::try_from(text.len()).unwrap_or(u32::MAX),
+            id: repo.blob(text.as_bytes())?,
+            flags: 0,
+            flags_extended: 0,
+            path: b"file".to_vec(),
+        })
+    };
+    let mut options = MergeFileOptions::new();
+    options
+        .ancestor_label("base")
+        .our_label("ours")
+        .their_label("theirs");
+    let result = repo.merge_file_from_index(
+        &entry(base)?,
+        &entry(ours)?,
+        &entry(theirs)?,
+        Some(&mut options),
+    )?;
+    Ok((
+        result.is_automergeable(),
+        String::from_utf8_lossy(result.content()).into_owned(),
+    ))
+}
+
+const fn conflict(
+    field: String,
+    base: Option<Value>,
+    ours: Option<Value>,
+    theirs: Option<Value>,
+) -> FieldConflict {
+    FieldConflict {
+        field,
+        base,
+        ours,
+        theirs,
+    }
+}
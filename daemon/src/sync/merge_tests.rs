@@ -0,0 +1,208 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::document::Document;
+use super::merge::{merge_file, merge_for_git, ConflictStyle};
+use serde_json::json;
+
+const PATH: &str = ".centy/issues/login.md";
+
+fn item(status: &str, priority: u32, updated: &str, body: &str) -> String {
+    format!(
+        "---\nstatus: {status}\npriority: {priority}\nupdatedAt: {updated}\n---\n# Login\n\n{body}\n"
+    )
+}
+
+fn field(content: &str, name: &str) -> Option<serde_json::Value> {
+    Document::parse(PATH, content).get(name)
+}
+
+#[test]
+fn test_changes_to_different_fields_merge() {
+    let base = item("open", 2, "2024-01-01", "Text");
+    let ours = item("closed", 2, "2024-01-02", "Text");
+    let theirs = item("open", 1, "2024-01-03", "Text");
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        Some(&ours),
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert!(merged.conflicts.is_empty());
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "status"), Some(json!("closed")));
+    assert_eq!(field(&content, "priority"), Some(json!(1u32)));
+    assert_eq!(field(&content, "updatedAt"), Some(json!("2024-01-03")));
+    assert_eq!(field(&content, "body"), Some(json!("Text")));
+}
+
+#[test]
+fn test_same_field_changed_differently_conflicts_and_keeps_ours() {
+    let base = item("open", 2, "2024-01-01", "Text");
+    let ours = item("closed", 2, "2024-01-02", "Ours");
+    let theirs = item("in-progress", 2, "2024-01-03", "Text");
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        Some(&ours),
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert_eq!(merged.conflicts.len(), 1);
+    let conflict = &merged.conflicts[0];
+    assert_eq!(conflict.field, "status");
+    assert_eq!(conflict.base, Some(json!("open")));
+    assert_eq!(conflict.ours, Some(json!("closed")));
+    assert_eq!(conflict.theirs, Some(json!("in-progress")));
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "status"), Some(json!("closed")));
+    assert_eq!(field(&content, "body"), Some(json!("Ours")));
+}
+
+#[test]
+fn test_added_and_removed_keys_merge() {
+    let base = item("open", 2, "2024-01-01", "Text");
+    let ours = "---\nstatus: open\npriority: 2\nupdatedAt: 2024-01-01\nowner: alice\n---\n# Login\n\nText\n";
+    let theirs = "---\nstatus: open\nupdatedAt: 2024-01-01\n---\n# Login\n\nText\n";
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        Some(ours),
+        Some(theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert!(merged.conflicts.is_empty());
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "owner"), Some(json!("alice")));
+    assert_eq!(field(&content, "priority"), None);
+}
+
+#[test]
+fn test_files_added_on_both_sides_merge_from_nothing() {
+    let ours = item("open", 2, "2024-01-01", "Text");
+    let theirs = item("open", 3, "2024-01-01", "Text");
+
+    let merged = merge_file(
+        PATH,
+        None,
+        Some(&ours),
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert_eq!(merged.conflicts.len(), 1);
+    assert_eq!(merged.conflicts[0].field, "priority");
+    assert_eq!(merged.conflicts[0].base, None);
+}
+
+#[test]
+fn test_delete_against_edit_conflicts_on_content() {
+    let base = item("open", 2, "2024-01-01", "Text");
+    let theirs = item("closed", 2, "2024-01-02", "Text");
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        None,
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert_eq!(merged.content, None);
+    assert_eq!(merged.conflicts.len(), 1);
+    assert_eq!(merged.conflicts[0].field, "content");
+    assert_eq!(merged.conflicts[0].ours, None);
+    assert_eq!(merged.conflicts[0].theirs, Some(json!(theirs)));
+}
+
+#[test]
+fn test_other_files_merge_as_a_whole() {
+    let merged = merge_file(
+        ".centy/config.json",
+        Some("{\"a\": 1}\n"),
+        Some("{\"a\": 2}\n"),
+        Some("{\"a\": 3}\n"),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert_eq!(merged.content.as_deref(), Some("{\"a\": 2}\n"));
+    assert_eq!(merged.conflicts[0].field, "content");
+    assert_eq!(merged.conflicts[0].theirs, Some(json!("{\"a\": 3}\n")));
+}
+
+#[test]
+fn test_tags_merge_as_sets() {
+    let with_tags =
+        |tags: &str| format!("---\nstatus: open\ntags: [{tags}]\n---\n# Login\n\nText\n");
+    let base = with_tags("ui, auth");
+    let ours = with_tags("ui, auth, bug");
+    let theirs = with_tags("auth, urgent");
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        Some(&ours),
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert!(merged.conflicts.is_empty());
+    let content = merged.content.unwrap();
+    assert_eq!(
+        field(&content, "tags"),
+        Some(json!(["auth", "bug", "urgent"]))
+    );
+}
+
+#[test]
+fn test_body_edits_to_different_lines_merge() {
+    let base = item("open", 2, "2024-01-01", "One\nTwo\nThree");
+    let ours = item("open", 2, "2024-01-01", "One!\nTwo\nThree");
+    let theirs = item("open", 2, "2024-01-01", "One\nTwo\nThree!");
+
+    let merged = merge_file(
+        PATH,
+        Some(&base),
+        Some(&ours),
+        Some(&theirs),
+        ConflictStyle::KeepOurs,
+    );
+
+    assert!(merged.conflicts.is_empty());
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "body"), Some(json!("One!\nTwo\nThree!")));
+}
+
+#[test]
+fn test_merge_for_git_marks_body_conflicts_and_keeps_our_fields() {
+    let base = item("open", 2, "2024-01-01", "Text");
+    let ours = item("closed", 2, "2024-01-02", "Ours");
+    let theirs = item("in-progress", 2, "2024-01-03", "Theirs");
+
+    let merged = merge_for_git(PATH, &base, &ours, &theirs);
+
+    let fields: Vec<&str> = merged.conflicts.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(fields, vec!["body", "status"]);
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "status"), Some(json!("closed")));
+    assert_eq!(field(&content, "updatedAt"), Some(json!("2024-01-03")));
+    assert!(content.contains("<<<<<<< ours\nOurs"));
+    assert!(content.contains(">>>>>>> theirs"));
+}
+
+#[test]
+fn test_merge_for_git_treats_an_empty_base_as_added_on_both_sides() {
+    let ours = item("open", 2, "2024-01-01", "Text");
+    let theirs = item("open", 2, "2024-01-02", "Text");
+
+    let merged = merge_for_git(PATH, "", &ours, &theirs);
+
+    assert!(merged.conflicts.is_empty());
+    let content = merged.content.unwrap();
+    assert_eq!(field(&content, "updatedAt"), Some(json!("2024-01-02")));
+}
@@ -0,0 +1,45 @@
+//! Sync of the `.centy` directory through git.
+//!
+//! Items live on their own branch (`centy` unless the `sync` config names
+//! another), apart from the code, so syncing never touches the checked-out
+//! branch or the index. A push commits the work tree's `.centy` directory
+//! to the branch and pushes it to the configured remote (`origin` by
+//! default); a pull fetches it, merges it into the local branch and writes
+//! the result back to `.centy`. Files changed on both sides are merged
+//! field by field; fields changed differently are kept as conflict records
+//! until resolved. It backs the sync RPCs, and auto-commits `.centy`
+//! changes to the checked-out branch when a project enables it.
+mod auto_commit;
+mod auto_commit_watch;
+mod commit_message;
+mod conflicts;
+mod document;
+mod error;
+mod files;
+mod merge;
+mod pull;
+mod push;
+mod repo;
+mod state;
+mod status;
+mod types;
+pub use auto_commit::spawn_auto_commit_task;
+pub use conflicts::{get_conflict, list_conflicts, resolve_conflict};
+pub use error::SyncError;
+pub use merge::{merge_for_git, FileMerge};
+pub use pull::pull;
+pub use push::push;
+pub use state::SyncState;
+pub use status::sync_status;
+pub use types::{
+    FieldConflict, PullOutcome, PushOutcome, Resolution, SyncConflict, SyncMode, SyncStatus,
+};
+#[cfg(test)]
+#[path = "auto_commit_tests.rs"]
+mod auto_commit_tests;
+#[cfg(test)]
+#[path = "merge_tests.rs"]
+mod merge_tests;
+#[cfg(test)]
+#[path = "sync_tests.rs"]
+mod sync_tests;
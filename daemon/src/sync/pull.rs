@@ -0,0 +1,157 @@
+use super::conflicts::new_conflict;
+use super::error::SyncError;
+use super::files::{commit_work_tree, empty_tree, write_changes};
+use super::merge::{merge_file, ConflictStyle};
+use super::repo::{lock, SyncRepo};
+use super::state::SyncState;
+use super::types::{PullOutcome, SyncConflict};
+use crate::utils::now_iso;
+use git2::{Commit, ErrorCode, Index, IndexConflict, IndexEntry, Tree};
+use std::path::Path;
+use tracing::warn;
+
+/// Commit message for local changes picked up by a pull or push.
+pub(super) const LOCAL_CHANGES_MESSAGE: &str = "Sync local changes";
+
+const MERGE_MESSAGE: &str = "Merge remote changes";
+
+/// Fetch the sync branch and merge it into the local one, then write the
+/// result to the `.centy` directory.
+///
+/// Uncommitted local changes are committed first so the merge sees them.
+/// Fields both sides changed differently keep the local value and are
+/// recorded as [`SyncConflict`]s for `resolve_conflict`.
+pub fn pull(project_path: &Path) -> Result<PullOutcome, SyncError> {
+    let _guard = lock();
+    let sync = SyncRepo::open(project_path)?;
+    commit_work_tree(&sync, LOCAL_CHANGES_MESSAGE)?;
+    sync.fetch()?;
+    let (had_changes, conflicts) = merge_remote(&sync)?;
+    let conflict_files = conflicts.iter().map(|c| c.path.clone()).collect();
+    let mut state = SyncState::load(project_path)?;
+    state.add_conflicts(conflicts);
+    state.last_sync_time = Some(now_iso());
+    state.save(project_path)?;
+    Ok(PullOutcome {
+        had_changes,
+        conflict_files,
+    })
+}
+
+/// Bring the local branch and `.centy` up to the remote. Returns whether
+/// files changed, and the conflicts the merge recorded.
+fn merge_remote(sync: &SyncRepo) -> Result<(bool, Vec<SyncConflict>), SyncError> {
+    let Some(theirs) = sync.remote_tip()? else {
+        return Ok((false, Vec::new()));
+    };
+    let ours = sync.local_tip()?;
+    if let Some(local) = &ours {
+        if local.id() == theirs.id() || sync.repo.graph_descendant_of(local.id(), theirs.id())? {
+            return Ok((false, Vec::new()));
+        }
+    }
+    let (new_tip, conflicts) = match &ours {
+        Some(local) if !sync.repo.graph_descendant_of(theirs.id(), local.id())? => {
+            merge(sync, local, &theirs)?
+        }
+        Some(_) | None => (theirs, Vec::new()),
+    };
+    let old_tree = ours.as_ref().map(Commit::tree).transpose()?;
+    let had_changes = write_changes(sync, old_tree.as_ref(), &new_tip.tree()?)?;
+    sync.set_local_tip(&new_tip, "centy sync: pull")?;
+    Ok((had_changes, conflicts))
+}
+
+/// Three-way merge of the two tips.
+///
+/// Files changed on both sides are merged field by field. Fields changed
+/// differently on both sides keep our value and are returned as conflict
+/// records.
+fn merge<'repo>(
+    sync: &'repo SyncRepo,
+    ours: &Commit<'repo>,
+    theirs: &Commit<'repo>,
+) -> Result<(Commit<'repo>, Vec<SyncConflict>), SyncError> {
+    let base = merge_base_tree(sync, ours, theirs)?;
+    let mut index = sync
+        .repo
+        .merge_trees(&base, &ours.tree()?, &theirs.tree()?, None)?;
+    let mut records = Vec::new();
+    if index.has_conflicts() {
+        let conflicts = index.conflicts()?.collect::<Result<Vec<_>, _>>()?;
+        for conflict in conflicts {
+            if let Some(record) = merge_conflict(sync, &mut index, conflict)? {
+                records.push(record);
+            }
+        }
+    }
+    let tree = sync.repo.find_tree(index.write_tree_to(&sync.repo)?)?;
+    let commit = sync.commit(MERGE_MESSAGE, &tree, &[ours, theirs])?;
+    Ok((commit, records))
+}
+
+/// Replace a conflicted index entry with the field-level merge of its
+/// sides. Binary files keep our version.
+fn merge_conflict(
+    sync: &SyncRepo,
+    index: &mut Index,
+    conflict: IndexConflict,
+) -> Result<Option<SyncConflict>, SyncError> {
+    let base = blob_content(sync, conflict.ancestor.as_ref())?;
+    let ours = blob_content(sync, conflict.our.as_ref())?;
+    let theirs = blob_content(sync, conflict.their.as_ref())?;
+    let Some(mut entry) = conflict.our.or(conflict.their).or(conflict.ancestor) else {
+        return Ok(None);
+    };
+    let path = String::from_utf8_lossy(&entry.path).into_owned();
+    index.conflict_remove(Path::new(&path))?;
+    let sides = (
+        side_text(base.as_deref()),
+        side_text(ours.as_deref()),
+        side_text(theirs.as_deref()),
+    );
+    let (merged, record) = match sides {
+        (Ok(b), Ok(o), Ok(t)) => {
+            let file = merge_file(&path, b, o, t, ConflictStyle::KeepOurs);
+            let record =
+                (!file.conflicts.is_empty()).then(|| new_conflict(sync, &path, file.conflicts));
+            (file.content.map(String::into_bytes), record)
+        }
+        (Err(_), _, _) | (_, Err(_), _) | (_, _, Err(_)) => {
+            warn!(path = %path, "Binary file changed on both sides; keeping the local version");
+            (ours.clone(), None)
+        }
+    };
+    if let Some(content) = merged {
+        entry.id = sync.repo.blob(&content)?;
+        // Clears the conflict stage; the path length is filled in on add.
+        entry.flags = 0;
+        entry.file_size = u32::try_from(content.len()).unwrap_or(u32::MAX);
+        index.add(&entry)?;
+    }
+    Ok(record)
+}
+
+fn side_text(content: Option<&[u8]>) -> Result<Option<&str>, std::str::Utf8Error> {
+    content.map(std::str::from_utf8).transpose()
+}
+
+fn blob_content(sync: &SyncRepo, entry: Option<&IndexEntry>) -> Result<Option<Vec<u8>>, SyncError> {
+    entry
+        .map(|e| Ok(sync.repo.find_blob(e.id)?.content().to_vec()))
+        .transpose()
+}
+
+/// The tree both tips started from. Branches started independently, such
+/// as two clones syncing for the first time, merge from an empty tree.
+fn merge_base_tree<'repo>(
+    sync: &'repo SyncRepo,
+    ours: &Commit<'repo>,
+    theirs: &Commit<'repo>,
+) -> Result<Tree<'repo>, SyncError> {
+    match sync.repo.merge_base(ours.id(), theirs.id()) {
+        Ok(id) => Ok(sync.repo.find_commit(id)?.tree()?),
+        Err(e) if e.code() == ErrorCode::NotFound => empty_tree(sync),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,45 @@
+use super::error::SyncError;
+use super::files::commit_work_tree;
+use super::pull::LOCAL_CHANGES_MESSAGE;
+use super::repo::{lock, SyncRepo};
+use super::state::SyncState;
+use super::types::PushOutcome;
+use crate::utils::now_iso;
+use std::path::Path;
+
+/// Commit the `.centy` directory to the local sync branch and push it.
+///
+/// Fails with [`SyncError::Diverged`] when the remote has commits the
+/// local branch does not; pull first. Without a remote the changes are
+/// only committed.
+pub fn push(project_path: &Path) -> Result<PushOutcome, SyncError> {
+    let _guard = lock();
+    let sync = SyncRepo::open(project_path)?;
+    let committed = commit_work_tree(&sync, LOCAL_CHANGES_MESSAGE)?;
+    if !sync.has_remote() {
+        return Ok(PushOutcome {
+            had_changes: committed,
+        });
+    }
+    sync.fetch()?;
+    let Some(local) = sync.local_tip()? else {
+        return Ok(PushOutcome {
+            had_changes: committed,
+        });
+    };
+    let ahead = match sync.remote_tip()? {
+        None => true,
+        Some(remote) if remote.id() == local.id() => false,
+        Some(remote) if sync.repo.graph_descendant_of(local.id(), remote.id())? => true,
+        Some(_) => return Err(SyncError::Diverged),
+    };
+    if ahead {
+        sync.push()?;
+    }
+    let mut state = SyncState::load(project_path)?;
+    state.last_sync_time = Some(now_iso());
+    state.save(project_path)?;
+    Ok(PushOutcome {
+        had_changes: committed || ahead,
+    })
+}
@@ -0,0 +1,206 @@
+use super::error::SyncError;
+use crate::config::{read_sync_config, SyncConfig};
+use crate::utils::get_centy_path;
+use git2::{
+    Commit, Config, Cred, CredentialType, ErrorCode, FetchOptions, PushOptions, Reference, Remote,
+    RemoteCallbacks, Repository, Signature,
+};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Credential callbacks libgit2 may make before giving up, so a rejected
+/// key does not retry forever.
+const MAX_CREDENTIAL_ATTEMPTS: u8 = 3;
+
+/// Serializes pulls and pushes; they move the same refs and files.
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+pub(super) fn lock() -> MutexGuard<'static, ()> {
+    SYNC_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A project's repository, with where its `.centy` directory lives in it.
+pub(super) struct SyncRepo {
+    pub repo: Repository,
+    /// The canonical work tree root.
+    pub workdir: PathBuf,
+    /// The `.centy` directory relative to `workdir`.
+    pub prefix: PathBuf,
+    /// The remote and branch from the project's `sync` config.
+    pub settings: SyncConfig,
+}
+
+impl SyncRepo {
+    pub fn open(project_path: &Path) -> Result<Self, SyncError> {
+        let repo = Repository::discover(project_path).map_err(|e| {
+            if e.code() == ErrorCode::NotFound {
+                SyncError::NotGitRepository
+            } else {
+                SyncError::Git(e)
+            }
+        })?;
+        let workdir = repo
+            .workdir()
+            .ok_or(SyncError::NotGitRepository)?
+            .canonicalize()?;
+        let centy = get_centy_path(project_path).canonicalize()?;
+        let prefix = centy
+            .strip_prefix(&workdir)
+            .map_err(|_e| SyncError::OutsideWorkTree(project_path.display().to_string()))?
+            .to_path_buf();
+        let settings = read_sync_config(project_path)?;
+        if !Remote::is_valid_name(&settings.remote) {
+            return Err(SyncError::InvalidSettings(format!(
+                "'{}' is not a valid remote name",
+                settings.remote
+            )));
+        }
+        if !Reference::is_valid_name(&format!("refs/heads/{}", settings.branch)) {
+            return Err(SyncError::InvalidSettings(format!(
+                "'{}' is not a valid branch name",
+                settings.branch
+            )));
+        }
+        Ok(Self {
+            repo,
+            workdir,
+            prefix,
+            settings,
+        })
+    }
+
+    pub fn has_remote(&self) -> bool {
+        self.repo.find_remote(&self.settings.remote).is_ok()
+    }
+
+    fn local_ref(&self) -> String {
+        format!("refs/heads/{}", self.settings.branch)
+    }
+
+    fn remote_ref(&self) -> String {
+        format!(
+            "refs/remotes/{}/{}",
+            self.settings.remote, self.settings.branch
+        )
+    }
+
+    /// The tip of the local sync branch, if it exists yet.
+    pub fn local_tip(&self) -> Result<Option<Commit<'_>>, SyncError> {
+        self.tip(&self.local_ref())
+    }
+
+    /// The tip of the sync branch as of the last fetch.
+    pub fn remote_tip(&self) -> Result<Option<Commit<'_>>, SyncError> {
+        self.tip(&self.remote_ref())
+    }
+
+    fn tip(&self, name: &str) -> Result<Option<Commit<'_>>, SyncError> {
+        match self.repo.find_reference(name) {
+            Ok(reference) => Ok(Some(reference.peel_to_commit()?)),
+            Err(e) if e.code() == ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Point the local sync branch at `commit`.
+    pub fn set_local_tip(&self, commit: &Commit<'_>, message: &str) -> Result<(), SyncError> {
+        self.repo
+            .reference(&self.local_ref(), commit.id(), true, message)?;
+        Ok(())
+    }
+
+    /// Commit `tree` on top of `parents` without moving any branch.
+    pub fn commit(
+        &self,
+        message: &str,
+        tree: &git2::Tree<'_>,
+        parents: &[&Commit<'_>],
+    ) -> Result<Commit<'_>, SyncError> {
+        let signature = self.signature()?;
+        let id = self
+            .repo
+            .commit(None, &signature, &signature, message, tree, parents)?;
+        Ok(self.repo.find_commit(id)?)
+    }
+
+    /// The configured git identity, or a generic one on machines without
+    /// `user.name`.
+    pub fn signature(&self) -> Result<Signature<'static>, SyncError> {
+        match self.repo.signature() {
+            Ok(signature) => Ok(signature.to_owned()),
+            Err(_e) => Ok(Signature::now("Centy", "centy@localhost")?),
+        }
+    }
+
+    /// Update the remote-tracking sync branch. A remote without the branch
+    /// leaves it unset.
+    pub fn fetch(&self) -> Result<(), SyncError> {
+        let mut remote = self.find_remote()?;
+        let config = self.repo.config()?;
+        let mut options = FetchOptions::new();
+        options.remote_callbacks(callbacks(&config));
+        let refspec = format!("+{}:{}", self.local_ref(), self.remote_ref());
+        remote.fetch(&[refspec], Some(&mut options), None)?;
+        Ok(())
+    }
+
+    /// Push the local sync branch. Fails when the remote refuses it.
+    pub fn push(&self) -> Result<(), SyncError> {
+        let mut remote = self.find_remote()?;
+        let config = self.repo.config()?;
+        let mut rejected = None;
+        {
+            let mut remote_callbacks = callbacks(&config);
+            remote_callbacks.push_update_reference(|reference, status| {
+                if let Some(message) = status {
+                    rejected = Some(SyncError::PushRejected {
+                        reference: reference.to_string(),
+                        message: message.to_string(),
+                    });
+                }
+                Ok(())
+            });
+            let mut options = PushOptions::new();
+            options.remote_callbacks(remote_callbacks);
+            let refspec = format!("{0}:{0}", self.local_ref());
+            remote.push(&[refspec], Some(&mut options))?;
+        }
+        if let Some(e) = rejected {
+            return Err(e);
+        }
+        // Keep the tracking branch in step so status does not report the
+        // pushed commits as pending.
+        if let Some(local) = self.local_tip()? {
+            self.repo
+                .reference(&self.remote_ref(), local.id(), true, "centy sync: push")?;
+        }
+        Ok(())
+    }
+
+    fn find_remote(&self) -> Result<Remote<'_>, SyncError> {
+        self.repo
+            .find_remote(&self.settings.remote)
+            .map_err(|_e| SyncError::RemoteNotFound(self.settings.remote.clone()))
+    }
+}
+
+/// Credentials from the SSH agent or git's credential helpers, the same
+/// sources the `git` command uses.
+fn callbacks(config: &Config) -> RemoteCallbacks<'_> {
+    let mut attempts = 0u8;
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |url, username, allowed| {
+        attempts = attempts.saturating_add(1);
+        if attempts > MAX_CREDENTIAL_ATTEMPTS {
+            return Err(git2::Error::from_str("authentication failed"));
+        }
+        if allowed.contains(CredentialType::SSH_KEY) {
+            return Cred::ssh_key_from_agent(username.unwrap_or("git"));
+        }
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            return Cred::credential_helper(config, url, username);
+        }
+        Cred::default()
+    });
+    callbacks
+}
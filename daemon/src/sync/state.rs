@@ -0,0 +1,51 @@
+use super::error::SyncError;
+use super::types::SyncConflict;
+use crate::utils::get_centy_path;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Sync bookkeeping; `*.local.*` keeps it out of git and off the sync
+/// branch.
+const STATE_FILE: &str = "sync.local.json";
+
+/// What the last sync left behind, per clone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_time: Option<String>,
+    /// Unresolved conflicts, oldest first.
+    #[serde(default)]
+    pub conflicts: Vec<SyncConflict>,
+}
+
+impl SyncState {
+    pub fn load(project_path: &Path) -> Result<Self, SyncError> {
+        match fs::read(state_path(project_path)) {
+            Ok(content) => Ok(serde_json::from_slice(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Record conflicts from a pull. They replace earlier ones for the same
+    /// file, whose values are out of date.
+    pub fn add_conflicts(&mut self, conflicts: Vec<SyncConflict>) {
+        for conflict in conflicts {
+            self.conflicts.retain(|c| c.path != conflict.path);
+            self.conflicts.push(conflict);
+        }
+    }
+
+    pub fn save(&self, project_path: &Path) -> Result<(), SyncError> {
+        let json = serde_json::to_vec_pretty(self)?;
+        fs::write(state_path(project_path), json)?;
+        Ok(())
+    }
+}
+
+fn state_path(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join(STATE_FILE)
+}
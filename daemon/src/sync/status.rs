@@ -0,0 +1,47 @@
+use super::error::SyncError;
+use super::files::{tree_files, work_tree_files};
+use super::repo::SyncRepo;
+use super::state::SyncState;
+use super::types::{SyncMode, SyncStatus};
+use git2::Commit;
+use std::path::Path;
+
+/// Where the project stands against the sync branch. The remote side is
+/// as of the last pull or push; nothing is fetched.
+pub fn sync_status(project_path: &Path) -> Result<SyncStatus, SyncError> {
+    let state = SyncState::load(project_path)?;
+    let sync = match SyncRepo::open(project_path) {
+        Ok(sync) => sync,
+        Err(SyncError::NotGitRepository) => {
+            return Ok(SyncStatus {
+                mode: SyncMode::Disabled,
+                has_pending_changes: false,
+                has_pending_push: false,
+                conflict_files: Vec::new(),
+                last_sync_time: None,
+            })
+        }
+        Err(e) => return Err(e),
+    };
+    let mode = if sync.has_remote() {
+        SyncMode::Full
+    } else {
+        SyncMode::LocalOnly
+    };
+    let local = sync.local_tip()?;
+    let committed_tree = local.as_ref().map(Commit::tree).transpose()?;
+    let has_pending_changes =
+        work_tree_files(&sync)? != tree_files(&sync, committed_tree.as_ref())?;
+    let has_pending_push = match (&local, sync.remote_tip()?) {
+        (None, _) => false,
+        (Some(_), None) => mode == SyncMode::Full,
+        (Some(l), Some(r)) => sync.repo.graph_ahead_behind(l.id(), r.id())?.0 > 0,
+    };
+    Ok(SyncStatus {
+        mode,
+        has_pending_changes,
+        has_pending_push,
+        conflict_files: state.conflicts.into_iter().map(|c| c.path).collect(),
+        last_sync_time: state.last_sync_time,
+    })
+}
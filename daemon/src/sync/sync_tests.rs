@@ -0,0 +1,336 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+use super::*;
+use git2::Repository;
+use std::path::{Path, PathBuf};
+
+/// A bare repository standing in for `origin`.
+struct Remote {
+    _dir: tempfile::TempDir,
+    path: PathBuf,
+}
+
+fn remote() -> Remote {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("remote.git");
+    Repository::init_bare(&path).unwrap();
+    Remote { _dir: dir, path }
+}
+
+fn clone(remote: &Remote) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    repo.remote("origin", &remote.path.to_string_lossy())
+        .unwrap();
+    write(dir.path(), ".gitignore", "*.local.*\n");
+    dir
+}
+
+fn write(project: &Path, name: &str, content: &str) {
+    let path = project.join(".centy").join(name);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn read(project: &Path, name: &str) -> Option<String> {
+    std::fs::read_to_string(project.join(".centy").join(name)).ok()
+}
+
+#[test]
+fn test_push_then_pull_copies_items() {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "issues/login.md", "# Login\n");
+
+    assert!(push(alice.path()).unwrap().had_changes);
+    let outcome = pull(bob.path()).unwrap();
+
+    assert!(outcome.had_changes);
+    assert!(outcome.conflict_files.is_empty());
+    assert_eq!(
+        read(bob.path(), "issues/login.md").as_deref(),
+        Some("# Login\n")
+    );
+}
+
+#[test]
+fn test_pull_merges_changes_to_different_files() {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "issues/login.md", "# Login\n");
+    push(alice.path()).unwrap();
+    pull(bob.path()).unwrap();
+    write(alice.path(), "issues/login.md", "# Login page\n");
+    push(alice.path()).unwrap();
+    write(bob.path(), "issues/signup.md", "# Signup\n");
+
+    let outcome = pull(bob.path()).unwrap();
+    push(bob.path()).unwrap();
+    pull(alice.path()).unwrap();
+
+    assert!(outcome.had_changes);
+    assert_eq!(
+        read(bob.path(), "issues/login.md").as_deref(),
+        Some("# Login page\n")
+    );
+    assert_eq!(
+        read(alice.path(), "issues/signup.md").as_deref(),
+        Some("# Signup\n")
+    );
+}
+
+#[test]
+fn test_pull_applies_remote_deletes() {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "issues/login.md", "# Login\n");
+    push(alice.path()).unwrap();
+    pull(bob.path()).unwrap();
+    std::fs::remove_file(alice.path().join(".centy/issues/login.md")).unwrap();
+    push(alice.path()).unwrap();
+
+    pull(bob.path()).unwrap();
+
+    assert_eq!(read(bob.path(), "issues/login.md"), None);
+}
+
+#[test]
+fn test_pull_keeps_local_file_on_conflict() {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "notes.txt", "Login\n");
+    push(alice.path()).unwrap();
+    pull(bob.path()).unwrap();
+    write(alice.path(), "notes.txt", "Login page\n");
+    push(alice.path()).unwrap();
+    write(bob.path(), "notes.txt", "Login form\n");
+
+    let outcome = pull(bob.path()).unwrap();
+
+    assert_eq!(outcome.conflict_files, vec![".centy/notes.txt"]);
+    assert_eq!(
+        read(bob.path(), "notes.txt").as_deref(),
+        Some("Login form\n")
+    );
+    let status = sync_status(bob.path()).unwrap();
+    assert_eq!(status.conflict_files, outcome.conflict_files);
+    let conflicts = list_conflicts(bob.path()).unwrap();
+    assert_eq!(conflicts[0].fields[0].field, "content");
+    assert_eq!(
+        conflicts[0].fields[0].theirs,
+        Some(serde_json::json!("Login page\n"))
+    );
+    assert!(push(bob.path()).unwrap().had_changes);
+}
+
+fn issue(title: &str, status: &str, priority: u32) -> String {
+    format!("---\nstatus: {status}\npriority: {priority}\n---\n# {title}\n\nSteps\n")
+}
+
+/// Alice and Bob edit the same issue: different fields, and both the
+/// status.
+fn conflicting_edits() -> (Remote, tempfile::TempDir, tempfile::TempDir) {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "issues/login.md", &issue("Login", "open", 2));
+    push(alice.path()).unwrap();
+    pull(bob.path()).unwrap();
+    write(
+        alice.path(),
+        "issues/login.md",
+        &issue("Login", "closed", 1),
+    );
+    push(alice.path()).unwrap();
+    write(
+        bob.path(),
+        "issues/login.md",
+        &issue("Login page", "in-progress", 2),
+    );
+    (remote, alice, bob)
+}
+
+#[test]
+fn test_pull_merges_item_fields_and_records_conflicts() {
+    let (_remote, _alice, bob) = conflicting_edits();
+
+    let outcome = pull(bob.path()).unwrap();
+
+    assert_eq!(outcome.conflict_files, vec![".centy/issues/login.md"]);
+    let content = read(bob.path(), "issues/login.md").unwrap();
+    assert!(content.contains("# Login page"));
+    assert!(content.contains("priority: 1"));
+    assert!(content.contains("status: in-progress"));
+    let conflict = &list_conflicts(bob.path()).unwrap()[0];
+    assert_eq!(conflict.item_type, "issues");
+    assert_eq!(conflict.item_id, "login");
+    assert_eq!(conflict.fields.len(), 1);
+    assert_eq!(conflict.fields[0].field, "status");
+    assert_eq!(conflict.fields[0].base, Some(serde_json::json!("open")));
+    assert_eq!(conflict.fields[0].theirs, Some(serde_json::json!("closed")));
+}
+
+#[test]
+fn test_resolve_conflict_writes_the_chosen_value() {
+    let (_remote, alice, bob) = conflicting_edits();
+    pull(bob.path()).unwrap();
+    let id = list_conflicts(bob.path()).unwrap()[0].id.clone();
+
+    let remaining = resolve_conflict(
+        bob.path(),
+        &id,
+        vec![("status".to_string(), Resolution::Theirs)],
+    )
+    .unwrap();
+    push(bob.path()).unwrap();
+    pull(alice.path()).unwrap();
+
+    assert_eq!(remaining, None);
+    assert!(list_conflicts(bob.path()).unwrap().is_empty());
+    let content = read(alice.path(), "issues/login.md").unwrap();
+    assert!(content.contains("status: closed"));
+    assert!(content.contains("# Login page"));
+}
+
+#[test]
+fn test_resolve_conflict_with_custom_value_and_errors() {
+    let (_remote, _alice, bob) = conflicting_edits();
+    pull(bob.path()).unwrap();
+    let id = list_conflicts(bob.path()).unwrap()[0].id.clone();
+
+    assert!(matches!(
+        resolve_conflict(bob.path(), "missing", Vec::new()),
+        Err(SyncError::ConflictNotFound(_))
+    ));
+    assert!(matches!(
+        resolve_conflict(
+            bob.path(),
+            &id,
+            vec![("priority".to_string(), Resolution::Ours)]
+        ),
+        Err(SyncError::InvalidResolution(_))
+    ));
+    resolve_conflict(
+        bob.path(),
+        &id,
+        vec![(
+            "status".to_string(),
+            Resolution::Custom(Some(serde_json::json!("blocked"))),
+        )],
+    )
+    .unwrap();
+
+    let content = read(bob.path(), "issues/login.md").unwrap();
+    assert!(content.contains("status: blocked"));
+    assert!(matches!(
+        get_conflict(bob.path(), &id),
+        Err(SyncError::ConflictNotFound(_))
+    ));
+}
+
+#[test]
+fn test_local_state_is_not_synced() {
+    let remote = remote();
+    let alice = clone(&remote);
+    let bob = clone(&remote);
+    write(alice.path(), "issues/login.md", "# Login\n");
+    write(alice.path(), "search-index.local.json", "{}");
+    push(alice.path()).unwrap();
+
+    pull(bob.path()).unwrap();
+
+    assert!(read(bob.path(), "issues/login.md").is_some());
+    assert_eq!(read(bob.path(), "search-index.local.json"), None);
+}
+
+#[test]
+fn test_status_reports_pending_changes_and_push() {
+    let remote = remote();
+    let alice = clone(&remote);
+    write(alice.path(), "issues/login.md", "# Login\n");
+
+    let before = sync_status(alice.path()).unwrap();
+    push(alice.path()).unwrap();
+    let after = sync_status(alice.path()).unwrap();
+
+    assert_eq!(before.mode, SyncMode::Full);
+    assert!(before.has_pending_changes);
+    assert!(!before.has_pending_push);
+    assert_eq!(before.last_sync_time, None);
+    assert!(!after.has_pending_changes);
+    assert!(!after.has_pending_push);
+    assert!(after.last_sync_time.is_some());
+}
+
+#[test]
+fn test_push_without_remote_only_commits() {
+    let dir = tempfile::tempdir().unwrap();
+    Repository::init(dir.path()).unwrap();
+    write(dir.path(), "issues/login.md", "# Login\n");
+
+    assert!(push(dir.path()).unwrap().had_changes);
+    let status = sync_status(dir.path()).unwrap();
+
+    assert_eq!(status.mode, SyncMode::LocalOnly);
+    assert!(!status.has_pending_changes);
+    assert!(!status.has_pending_push);
+}
+
+#[test]
+fn test_status_outside_git_is_disabled() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join(".centy")).unwrap();
+
+    assert_eq!(sync_status(dir.path()).unwrap().mode, SyncMode::Disabled);
+    assert!(matches!(pull(dir.path()), Err(SyncError::NotGitRepository)));
+}
+
+#[test]
+fn test_sync_uses_configured_remote_and_branch() {
+    let remote = remote();
+    let clone_with_upstream = || {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        repo.remote("upstream", &remote.path.to_string_lossy())
+            .unwrap();
+        write(
+            dir.path(),
+            "config.json",
+            r#"{"sync": {"remote": "upstream", "branch": "items"}}"#,
+        );
+        dir
+    };
+    let alice = clone_with_upstream();
+    let bob = clone_with_upstream();
+    write(alice.path(), "issues/login.md", "# Login\n");
+
+    push(alice.path()).unwrap();
+    pull(bob.path()).unwrap();
+
+    let bare = Repository::open_bare(&remote.path).unwrap();
+    assert!(bare.find_reference("refs/heads/items").is_ok());
+    assert!(bare.find_reference("refs/heads/centy").is_err());
+    assert_eq!(
+        read(bob.path(), "issues/login.md").as_deref(),
+        Some("# Login\n")
+    );
+    assert_eq!(sync_status(bob.path()).unwrap().mode, SyncMode::Full);
+}
+
+#[test]
+fn test_invalid_sync_branch_is_rejected() {
+    let remote = remote();
+    let project = clone(&remote);
+    write(
+        project.path(),
+        "config.json",
+        r#"{"sync": {"branch": "bad..name"}}"#,
+    );
+
+    let err = push(project.path()).unwrap_err();
+
+    assert!(matches!(err, SyncError::InvalidSettings(_)), "{err}");
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// How a project can sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMode {
+    /// The project is not in a git repository.
+    Disabled,
+    /// Changes are committed to the sync branch but there is no remote.
+    LocalOnly,
+    /// Changes are committed and exchanged with the remote.
+    Full,
+}
+
+/// The result of a pull.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullOutcome {
+    /// Whether files under `.centy/` were changed.
+    pub had_changes: bool,
+    /// Repository-relative paths of files with fields changed on both
+    /// sides. Each has a [`SyncConflict`] record.
+    pub conflict_files: Vec<String>,
+}
+
+/// The result of a push.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PushOutcome {
+    /// Whether local changes were committed or commits were pushed.
+    pub had_changes: bool,
+}
+
+/// Where a project stands against its remote, as of the last fetch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncStatus {
+    pub mode: SyncMode,
+    /// Files under `.centy/` differ from the local sync branch.
+    pub has_pending_changes: bool,
+    /// The local sync branch has commits the remote does not.
+    pub has_pending_push: bool,
+    /// Files with unresolved conflicts.
+    pub conflict_files: Vec<String>,
+    /// When the last pull or push completed, RFC 3339.
+    pub last_sync_time: Option<String>,
+}
+
+/// A file whose fields were changed differently on both sides of a pull.
+///
+/// The merge went ahead with the local value of every conflicting field;
+/// the record keeps the three versions until the conflict is resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflict {
+    pub id: String,
+    /// Repository-relative path of the file.
+    pub path: String,
+    /// The item type folder and id, for item files.
+    #[serde(default)]
+    pub item_type: String,
+    #[serde(default)]
+    pub item_id: String,
+    pub created_at: String,
+    pub fields: Vec<FieldConflict>,
+}
+
+/// One field's value in the common ancestor and on each side; `None` when
+/// absent.
+///
+/// Item files have a field per frontmatter key plus `title` and `body`.
+/// Other files, and files one side deleted, have a single `content` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldConflict {
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ours: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theirs: Option<Value>,
+}
+
+/// Which value a conflicting field ends up with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    Ours,
+    Theirs,
+    /// Any value; `None` removes the field.
+    Custom(Option<Value>),
+}
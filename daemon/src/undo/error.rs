@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Errors raised while recording or undoing operations.
+#[derive(Error, Debug)]
+pub enum UndoError {
+    #[error("Nothing to undo")]
+    NothingToUndo,
+
+    #[error("Cannot undo {operation}: {path} was changed since")]
+    Conflict { operation: String, path: String },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
@@ -0,0 +1,75 @@
+use sha2::{Digest as _, Sha256};
+use std::io::ErrorKind;
+use std::path::Path;
+use tokio::fs;
+
+/// Content fingerprint of a file or directory tree; `None` when nothing is
+/// at `path`.
+pub async fn fingerprint(path: &Path) -> std::io::Result<Option<String>> {
+    let metadata = match fs::metadata(path).await {
+        Ok(m) => m,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut hasher = Sha256::new();
+    if metadata.is_dir() {
+        hash_dir(path, Path::new(""), &mut hasher).await?;
+    } else {
+        hasher.update(fs::read(path).await?);
+    }
+    Ok(Some(hex::encode(hasher.finalize())))
+}
+
+/// Hash the names and contents under `root/relative`, in name order.
+async fn hash_dir(root: &Path, relative: &Path, hasher: &mut Sha256) -> std::io::Result<()> {
+    let mut names = Vec::new();
+    let mut entries = fs::read_dir(root.join(relative)).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        names.push(entry.file_name());
+    }
+    names.sort();
+    for name in names {
+        let child = relative.join(&name);
+        let full = root.join(&child);
+        hasher.update(child.to_string_lossy().as_bytes());
+        if fs::metadata(&full).await?.is_dir() {
+            hasher.update(b"/\0");
+            Box::pin(hash_dir(root, &child, hasher)).await?;
+        } else {
+            hasher.update(b"\0");
+            hasher.update(fs::read(&full).await?);
+            hasher.update(b"\0");
+        }
+    }
+    Ok(())
+}
+
+/// Copy a file or directory tree, creating missing parent directories.
+pub async fn copy_path(src: &Path, dst: &Path) -> std::io::Result<()> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    if !fs::metadata(src).await?.is_dir() {
+        fs::copy(src, dst).await?;
+        return Ok(());
+    }
+    fs::create_dir_all(dst).await?;
+    let mut entries = fs::read_dir(src).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        Box::pin(copy_path(&entry.path(), &dst.join(entry.file_name()))).await?;
+    }
+    Ok(())
+}
+
+/// Remove a file or directory tree; a missing path is fine.
+pub async fn remove_path(path: &Path) -> std::io::Result<()> {
+    let result = match fs::metadata(path).await {
+        Ok(m) if m.is_dir() => fs::remove_dir_all(path).await,
+        Ok(_) => fs::remove_file(path).await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
@@ -0,0 +1,58 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+use super::files::{copy_path, remove_path};
+use super::*;
+
+#[tokio::test]
+async fn test_fingerprint_missing_path_is_none() {
+    let temp = tempfile::tempdir().unwrap();
+
+    assert_eq!(fingerprint(&temp.path().join("nope")).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_fingerprint_tracks_file_content() {
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("a.md");
+    std::fs::write(&file, "one").unwrap();
+    let first = fingerprint(&file).await.unwrap();
+    std::fs::write(&file, "two").unwrap();
+
+    assert!(first.is_some());
+    assert_ne!(fingerprint(&file).await.unwrap(), first);
+}
+
+#[tokio::test]
+async fn test_fingerprint_tracks_directory_names_and_content() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path().join("assets");
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    std::fs::write(dir.join("nested/a.png"), "png").unwrap();
+    let first = fingerprint(&dir).await.unwrap();
+
+    std::fs::rename(dir.join("nested/a.png"), dir.join("nested/b.png")).unwrap();
+    let renamed = fingerprint(&dir).await.unwrap();
+    std::fs::write(dir.join("nested/b.png"), "gif").unwrap();
+    let edited = fingerprint(&dir).await.unwrap();
+
+    assert_ne!(first, renamed);
+    assert_ne!(renamed, edited);
+}
+
+#[tokio::test]
+async fn test_copy_and_remove_directory_tree() {
+    let temp = tempfile::tempdir().unwrap();
+    let src = temp.path().join("src");
+    std::fs::create_dir_all(src.join("nested")).unwrap();
+    std::fs::write(src.join("nested/a.txt"), "a").unwrap();
+    let dst = temp.path().join("deep/dst");
+
+    copy_path(&src, &dst).await.unwrap();
+    assert_eq!(
+        fingerprint(&src).await.unwrap(),
+        fingerprint(&dst).await.unwrap()
+    );
+
+    remove_path(&dst).await.unwrap();
+    assert!(!dst.exists());
+    remove_path(&dst).await.unwrap();
+}
@@ -0,0 +1,24 @@
+//! Undo for the last mutating operations.
+//!
+//! Before each write, `item/generic/storage` snapshots the item files, link
+//! files and asset directories it is about to change into
+//! `.centy/undo.local.d/`. Once the write succeeds, the fingerprints it left
+//! are recorded with the snapshot. The `Undo` RPC puts the last N
+//! operations back, refusing when a path has since been changed by
+//! something else.
+mod error;
+mod files;
+mod revert;
+mod store;
+mod types;
+pub use error::UndoError;
+pub use files::fingerprint;
+pub use revert::{plan_undo, UndoPlan};
+pub use store::{begin, finish, record_created, undo_dir, PendingUndo, MAX_ENTRIES};
+pub use types::{ItemRef, PathKind, Snapshot, Target, UndoEntry};
+#[cfg(test)]
+#[path = "files_tests.rs"]
+mod files_tests;
+#[cfg(test)]
+#[path = "revert_tests.rs"]
+mod revert_tests;
@@ -0,0 +1,94 @@
+use super::error::UndoError;
+use super::files::{copy_path, fingerprint, remove_path};
+use super::store::{lock_project, read_entries};
+use super::types::{Snapshot, UndoEntry};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::sync::OwnedMutexGuard;
+
+/// The last operations of a project, checked and ready to be reverted. It
+/// holds the project's undo lock, so no operation starts or finishes until
+/// the plan is applied or dropped.
+#[derive(Debug)]
+pub struct UndoPlan {
+    /// Newest first, with their directories.
+    entries: Vec<(PathBuf, UndoEntry)>,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl UndoPlan {
+    /// The operations the plan reverts, newest first.
+    pub fn entries(&self) -> impl Iterator<Item = &UndoEntry> {
+        self.entries.iter().map(|(_, entry)| entry)
+    }
+
+    /// Put every snapshotted path back as it was before its operation and
+    /// drop the operations from the log. Returns them, newest first.
+    ///
+    /// The paths are checked again first, in case they changed since the
+    /// plan was made.
+    pub async fn apply(self) -> Result<Vec<UndoEntry>, UndoError> {
+        check(&self.entries).await?;
+        let mut undone = Vec::new();
+        for (dir, entry) in self.entries {
+            for (index, snapshot) in entry.snapshots.iter().enumerate() {
+                restore(&dir, index, snapshot).await?;
+            }
+            fs::remove_dir_all(&dir).await?;
+            undone.push(entry);
+        }
+        Ok(undone)
+    }
+}
+
+/// Plan undoing the last `count` operations (at least one).
+///
+/// Fails with [`UndoError::Conflict`] when a path was changed by something
+/// else since its operation, and with [`UndoError::NothingToUndo`] when the
+/// log is empty. Asking for more operations than are logged undoes all of
+/// them.
+pub async fn plan_undo(project_path: &Path, count: usize) -> Result<UndoPlan, UndoError> {
+    let guard = lock_project(project_path).await;
+    let mut entries = read_entries(project_path).await?;
+    entries.reverse();
+    entries.truncate(count.max(1));
+    if entries.is_empty() {
+        return Err(UndoError::NothingToUndo);
+    }
+    check(&entries).await?;
+    Ok(UndoPlan {
+        entries,
+        _guard: guard,
+    })
+}
+
+/// Check that every path is as its operation left it. Older operations are
+/// checked against the state that undoing the newer ones restores.
+async fn check(entries: &[(PathBuf, UndoEntry)]) -> Result<(), UndoError> {
+    let mut expected: HashMap<&Path, Option<String>> = HashMap::new();
+    for (_, entry) in entries {
+        for snapshot in &entry.snapshots {
+            let current = match expected.get(snapshot.path.as_path()) {
+                Some(state) => state.clone(),
+                None => fingerprint(&snapshot.path).await?,
+            };
+            if current != snapshot.after {
+                return Err(UndoError::Conflict {
+                    operation: entry.operation.as_str().to_string(),
+                    path: snapshot.path.display().to_string(),
+                });
+            }
+            expected.insert(&snapshot.path, snapshot.before.clone());
+        }
+    }
+    Ok(())
+}
+
+async fn restore(dir: &Path, index: usize, snapshot: &Snapshot) -> Result<(), UndoError> {
+    remove_path(&snapshot.path).await?;
+    if snapshot.before.is_some() {
+        copy_path(&dir.join(index.to_string()), &snapshot.path).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,181 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::indexing_slicing,
+    clippy::significant_drop_tightening
+)]
+use super::*;
+use crate::journal::Operation;
+use std::path::Path;
+
+fn project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp.path().join(".centy/issues")).unwrap();
+    temp
+}
+
+fn item_path(project: &Path, id: &str) -> std::path::PathBuf {
+    project.join(".centy/issues").join(format!("{id}.md"))
+}
+
+/// Write an item file the way a recorded update would.
+async fn recorded_write(project: &Path, id: &str, content: &str) {
+    let target = Target::item_file(project, "issues", id);
+    let pending = begin(project, Operation::Update, "issues", id, vec![target]).await;
+    std::fs::write(item_path(project, id), content).unwrap();
+    finish(pending).await;
+}
+
+#[tokio::test]
+async fn test_undo_restores_previous_content() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "a"), "v1").unwrap();
+    recorded_write(project, "a", "v2").await;
+
+    let undone = plan_undo(project, 1).await.unwrap().apply().await.unwrap();
+
+    assert_eq!(undone.len(), 1);
+    assert_eq!(undone[0].operation, Operation::Update);
+    assert_eq!(undone[0].item_id, "a");
+    assert_eq!(
+        std::fs::read_to_string(item_path(project, "a")).unwrap(),
+        "v1"
+    );
+    assert!(matches!(
+        plan_undo(project, 1).await,
+        Err(UndoError::NothingToUndo)
+    ));
+}
+
+#[tokio::test]
+async fn test_undo_several_operations_on_one_file() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "a"), "v1").unwrap();
+    recorded_write(project, "a", "v2").await;
+    recorded_write(project, "a", "v3").await;
+
+    let undone = plan_undo(project, 5).await.unwrap().apply().await.unwrap();
+
+    assert_eq!(undone.len(), 2);
+    assert_eq!(
+        std::fs::read_to_string(item_path(project, "a")).unwrap(),
+        "v1"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_created_file_removes_it() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "new"), "created").unwrap();
+    record_created(
+        project,
+        Operation::Create,
+        "issues",
+        "new",
+        vec![Target::item_file(project, "issues", "new")],
+    )
+    .await;
+
+    let undone = plan_undo(project, 1).await.unwrap().apply().await.unwrap();
+
+    assert_eq!(undone[0].operation, Operation::Create);
+    assert!(!item_path(project, "new").exists());
+}
+
+#[tokio::test]
+async fn test_undo_restores_deleted_directory() {
+    let temp = project();
+    let project = temp.path();
+    let assets = Target::asset_dir(project, "issues", "a");
+    std::fs::create_dir_all(&assets.path).unwrap();
+    std::fs::write(assets.path.join("shot.png"), "png").unwrap();
+    let pending = begin(
+        project,
+        Operation::Delete,
+        "issues",
+        "a",
+        vec![assets.clone()],
+    )
+    .await;
+    std::fs::remove_dir_all(&assets.path).unwrap();
+    finish(pending).await;
+
+    plan_undo(project, 1).await.unwrap().apply().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(assets.path.join("shot.png")).unwrap(),
+        "png"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_refuses_after_outside_change() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "a"), "v1").unwrap();
+    recorded_write(project, "a", "v2").await;
+    std::fs::write(item_path(project, "a"), "edited by hand").unwrap();
+
+    assert!(matches!(
+        plan_undo(project, 1).await,
+        Err(UndoError::Conflict { .. })
+    ));
+    assert_eq!(
+        std::fs::read_to_string(item_path(project, "a")).unwrap(),
+        "edited by hand"
+    );
+}
+
+#[tokio::test]
+async fn test_operation_without_changes_is_not_recorded() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "a"), "same").unwrap();
+    recorded_write(project, "a", "same").await;
+
+    assert!(matches!(
+        plan_undo(project, 1).await,
+        Err(UndoError::NothingToUndo)
+    ));
+    assert_eq!(std::fs::read_dir(undo_dir(project)).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_log_keeps_the_newest_entries() {
+    let temp = project();
+    let project = temp.path();
+    for n in 0..=MAX_ENTRIES {
+        recorded_write(project, "a", &n.to_string()).await;
+    }
+
+    assert_eq!(
+        std::fs::read_dir(undo_dir(project)).unwrap().count(),
+        MAX_ENTRIES
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_operations_snapshot_in_turn() {
+    let temp = project();
+    let project = temp.path();
+    std::fs::write(item_path(project, "a"), "v1").unwrap();
+    let target = Target::item_file(project, "issues", "a");
+    let first = begin(project, Operation::Update, "issues", "a", vec![target]).await;
+    let path = project.to_path_buf();
+    let second = tokio::spawn(async move { recorded_write(&path, "a", "v3").await });
+    tokio::task::yield_now().await;
+    assert!(!second.is_finished());
+    std::fs::write(item_path(project, "a"), "v2").unwrap();
+    finish(first).await;
+    second.await.unwrap();
+
+    plan_undo(project, 1).await.unwrap().apply().await.unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(item_path(project, "a")).unwrap(),
+        "v2"
+    );
+}
@@ -0,0 +1,272 @@
+use super::error::UndoError;
+use super::files::{copy_path, fingerprint};
+use super::types::{Snapshot, Target, UndoEntry};
+use crate::journal::Operation;
+use crate::utils::{get_centy_path, now_iso};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, PoisonError};
+use tokio::fs;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tracing::warn;
+
+/// Operation log directory; `*.local.*` keeps it out of git.
+const UNDO_DIR: &str = "undo.local.d";
+
+/// Written once the operation has succeeded; directories without it belong
+/// to writes that are still running or failed.
+const ENTRY_FILE: &str = "entry.json";
+
+/// Operations kept per project; older ones can no longer be undone.
+pub const MAX_ENTRIES: usize = 100;
+
+/// How long an unfinished entry directory is left alone before it is
+/// treated as the leftover of a failed write.
+const ABANDONED_AFTER_NANOS: i64 = 3_600_000_000_000;
+
+/// One lock per project, held from [`begin`] to [`finish`] so concurrent
+/// writes snapshot one after the other, and while undoing. Projects never
+/// wait on each other.
+static UNDO_LOCKS: OnceLock<std::sync::Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
+/// Lock the project's undo log, keyed by its canonical path so every
+/// spelling of the path shares the lock.
+pub(super) async fn lock_project(project_path: &Path) -> OwnedMutexGuard<()> {
+    let key = fs::canonicalize(project_path)
+        .await
+        .unwrap_or_else(|_e| project_path.to_path_buf());
+    let lock = {
+        let mut locks = UNDO_LOCKS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(locks.entry(key).or_default())
+    };
+    lock.lock_owned().await
+}
+
+#[must_use]
+pub fn undo_dir(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join(UNDO_DIR)
+}
+
+/// An operation whose targets have been snapshotted but which has not
+/// finished yet. It holds the project's undo lock until then.
+#[derive(Debug)]
+pub struct PendingUndo {
+    project_path: PathBuf,
+    dir: PathBuf,
+    entry: UndoEntry,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl PendingUndo {
+    /// Add paths the operation creates, such as the destination of a move,
+    /// once they are known. They are recorded as not existing before.
+    pub fn add_created(&mut self, targets: Vec<Target>) {
+        for target in targets {
+            if self.entry.snapshots.iter().all(|s| s.path != target.path) {
+                self.entry.snapshots.push(Snapshot {
+                    path: target.path,
+                    kind: target.kind,
+                    item: target.item,
+                    before: None,
+                    after: None,
+                });
+            }
+        }
+    }
+
+    async fn finish(mut self) -> Result<(), UndoError> {
+        for snapshot in &mut self.entry.snapshots {
+            snapshot.after = fingerprint(&snapshot.path).await?;
+        }
+        if self.entry.snapshots.iter().all(|s| s.before == s.after) {
+            fs::remove_dir_all(&self.dir).await?;
+            return Ok(());
+        }
+        let json = serde_json::to_vec_pretty(&self.entry)?;
+        fs::write(self.dir.join(ENTRY_FILE), json).await?;
+        prune(&self.project_path).await
+    }
+}
+
+/// Snapshot `targets` before an operation writes them.
+///
+/// Waits for other operations on the project to finish first, so no two
+/// snapshot the same state. Returns `None`, after logging, when the
+/// snapshot fails; the operation then goes ahead but cannot be undone.
+pub async fn begin(
+    project_path: &Path,
+    operation: Operation,
+    item_type: &str,
+    item_id: &str,
+    targets: Vec<Target>,
+) -> Option<PendingUndo> {
+    let guard = lock_project(project_path).await;
+    match snapshot(project_path, operation, item_type, item_id, targets, guard).await {
+        Ok(pending) => Some(pending),
+        Err(e) => {
+            warn!(id = %item_id, error = %e, "Failed to snapshot for undo");
+            None
+        }
+    }
+}
+
+/// Record an operation that has succeeded. Operations that left every
+/// target as it was are dropped. Failures are logged.
+pub async fn finish(pending: Option<PendingUndo>) {
+    let Some(operation) = pending else {
+        return;
+    };
+    let id = operation.entry.item_id.clone();
+    if let Err(e) = operation.finish().await {
+        warn!(id = %id, error = %e, "Failed to record operation for undo");
+    }
+}
+
+/// Record an operation that only created `targets`, such as a new item.
+#[allow(clippy::significant_drop_tightening)] // the undo lock is released by `undo::finish`
+pub async fn record_created(
+    project_path: &Path,
+    operation: Operation,
+    item_type: &str,
+    item_id: &str,
+    targets: Vec<Target>,
+) {
+    let mut pending = begin(project_path, operation, item_type, item_id, Vec::new()).await;
+    if let Some(p) = pending.as_mut() {
+        p.add_created(targets);
+    }
+    finish(pending).await;
+}
+
+async fn snapshot(
+    project_path: &Path,
+    operation: Operation,
+    item_type: &str,
+    item_id: &str,
+    targets: Vec<Target>,
+    guard: OwnedMutexGuard<()>,
+) -> Result<PendingUndo, UndoError> {
+    let (id, dir) = create_entry_dir(&undo_dir(project_path)).await?;
+    let mut pending = PendingUndo {
+        project_path: project_path.to_path_buf(),
+        dir,
+        entry: UndoEntry {
+            id,
+            timestamp: now_iso(),
+            operation,
+            item_type: item_type.to_string(),
+            item_id: item_id.to_string(),
+            snapshots: Vec::new(),
+        },
+        _guard: guard,
+    };
+    for target in targets {
+        if pending
+            .entry
+            .snapshots
+            .iter()
+            .any(|s| s.path == target.path)
+        {
+            continue;
+        }
+        let before = fingerprint(&target.path).await?;
+        if before.is_some() {
+            let index = pending.entry.snapshots.len();
+            copy_path(&target.path, &pending.dir.join(index.to_string())).await?;
+        }
+        pending.entry.snapshots.push(Snapshot {
+            path: target.path,
+            kind: target.kind,
+            item: target.item,
+            before,
+            after: None,
+        });
+    }
+    Ok(pending)
+}
+
+/// Create a fresh entry directory named by the current time in
+/// nanoseconds, so names sort in the order operations started.
+async fn create_entry_dir(root: &Path) -> Result<(String, PathBuf), UndoError> {
+    fs::create_dir_all(root).await?;
+    let mut nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    loop {
+        let id = format!("{nanos:020}");
+        let dir = root.join(&id);
+        match fs::create_dir(&dir).await {
+            Ok(()) => return Ok((id, dir)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => nanos = nanos.saturating_add(1),
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Finished entries with their directories, oldest first. Entries that do
+/// not parse are skipped with a warning.
+pub(super) async fn read_entries(
+    project_path: &Path,
+) -> Result<Vec<(PathBuf, UndoEntry)>, UndoError> {
+    let mut entries = Vec::new();
+    for dir in entry_dirs(project_path).await? {
+        let content = match fs::read(dir.join(ENTRY_FILE)).await {
+            Ok(c) => c,
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        match serde_json::from_slice(&content) {
+            Ok(entry) => entries.push((dir, entry)),
+            Err(e) => {
+                warn!(path = %dir.display(), error = %e, "Skipping malformed undo entry");
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Every entry directory, finished or not, oldest first.
+async fn entry_dirs(project_path: &Path) -> Result<Vec<PathBuf>, UndoError> {
+    let root = undo_dir(project_path);
+    let mut read = match fs::read_dir(&root).await {
+        Ok(r) => r,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut dirs = Vec::new();
+    while let Some(entry) = read.next_entry().await? {
+        if entry.file_type().await?.is_dir() {
+            dirs.push(entry.path());
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Drop finished entries beyond [`MAX_ENTRIES`] and unfinished ones that
+/// were abandoned.
+async fn prune(project_path: &Path) -> Result<(), UndoError> {
+    let now = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    let mut finished = Vec::new();
+    for dir in entry_dirs(project_path).await? {
+        if dir.join(ENTRY_FILE).exists() {
+            finished.push(dir);
+            continue;
+        }
+        if started_at(&dir).is_some_and(|at| now.saturating_sub(at) > ABANDONED_AFTER_NANOS) {
+            fs::remove_dir_all(&dir).await?;
+        }
+    }
+    let excess = finished.len().saturating_sub(MAX_ENTRIES);
+    for dir in finished.iter().take(excess) {
+        fs::remove_dir_all(dir).await?;
+    }
+    Ok(())
+}
+
+fn started_at(dir: &Path) -> Option<i64> {
+    dir.file_name()?.to_str()?.parse().ok()
+}
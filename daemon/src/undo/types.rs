@@ -0,0 +1,108 @@
+use crate::journal::Operation;
+use crate::utils::get_centy_path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Whether a snapshotted path is a file or a directory tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathKind {
+    File,
+    Dir,
+}
+
+/// The item a snapshotted file stores, so undo can re-index it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemRef {
+    pub project_path: PathBuf,
+    pub folder: String,
+    pub id: String,
+}
+
+/// A path a write is about to change.
+#[derive(Debug, Clone)]
+pub struct Target {
+    pub path: PathBuf,
+    pub kind: PathKind,
+    pub item: Option<ItemRef>,
+}
+
+impl Target {
+    /// An item's markdown file.
+    #[must_use]
+    pub fn item_file(project_path: &Path, folder: &str, id: &str) -> Self {
+        Self {
+            path: get_centy_path(project_path)
+                .join(folder)
+                .join(format!("{id}.md")),
+            kind: PathKind::File,
+            item: Some(ItemRef {
+                project_path: project_path.to_path_buf(),
+                folder: folder.to_string(),
+                id: id.to_string(),
+            }),
+        }
+    }
+
+    /// An item's asset directory, `.centy/assets/<folder>/<id>`.
+    #[must_use]
+    pub fn asset_dir(project_path: &Path, folder: &str, id: &str) -> Self {
+        Self::dir(
+            get_centy_path(project_path)
+                .join("assets")
+                .join(folder)
+                .join(id),
+        )
+    }
+
+    /// A link's markdown file.
+    #[must_use]
+    pub fn link_file(project_path: &Path, link_id: &str) -> Self {
+        Self {
+            path: get_centy_path(project_path)
+                .join("links")
+                .join(format!("{link_id}.md")),
+            kind: PathKind::File,
+            item: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn dir(path: PathBuf) -> Self {
+        Self {
+            path,
+            kind: PathKind::Dir,
+            item: None,
+        }
+    }
+}
+
+/// A path as recorded in the operation log. The previous content, when
+/// there was any, is kept in the entry's directory under the snapshot's
+/// index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub path: PathBuf,
+    pub kind: PathKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<ItemRef>,
+    /// Fingerprint before the write; `None` when the path did not exist.
+    pub before: Option<String>,
+    /// Fingerprint after the write. Undo refuses unless the path still has it.
+    pub after: Option<String>,
+}
+
+/// One recorded operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoEntry {
+    /// Sortable id; also the name of the entry's directory.
+    pub id: String,
+    pub timestamp: String,
+    pub operation: Operation,
+    pub item_type: String,
+    pub item_id: String,
+    pub snapshots: Vec<Snapshot>,
+}
@@ -1,7 +1,12 @@
 //! User storage operations for reading/writing users.json.
 use super::types::{User, UserError, UsersFile};
+use crate::journal::{
+    self, diff_fields, object_fields, operation_for, Change, Fields, JournalEntity,
+};
 use crate::manifest::read_manifest;
 use crate::utils::get_centy_path;
+use crate::watch::{publish, ChangeEvent, ChangeKind, EntityKind};
+use std::collections::BTreeMap;
 use std::path::Path;
 use tokio::fs;
 
@@ -30,10 +35,48 @@ pub async fn write_users(project_path: &Path, users: &[User]) -> Result<(), User
         users: users.to_vec(),
     };
     let content = serde_json::to_string_pretty(&users_file)?;
+    let previous = read_users(project_path).await.unwrap_or_default();
     fs::write(&users_path, content).await?;
+    journal_users(project_path, &previous, users).await;
+    publish(ChangeEvent::new(
+        ChangeKind::Updated,
+        EntityKind::User,
+        project_path,
+        "",
+        "",
+    ));
     Ok(())
 }
 
+/// Journal every user whose record differs between `before` and `after`.
+async fn journal_users(project_path: &Path, before: &[User], after: &[User]) {
+    let by_id = |users: &[User]| -> BTreeMap<String, Fields> {
+        users
+            .iter()
+            .filter_map(|u| Some((u.id.clone(), object_fields(&serde_json::to_value(u).ok()?))))
+            .collect()
+    };
+    let old = by_id(before);
+    let current = by_id(after);
+    let mut ids: Vec<&String> = old.keys().chain(current.keys()).collect();
+    ids.sort();
+    ids.dedup();
+    for id in ids {
+        let (was, now) = (old.get(id), current.get(id));
+        if was == now {
+            continue;
+        }
+        let change = Change {
+            operation: operation_for(was, now),
+            entity: JournalEntity::User,
+            item_type: String::new(),
+            item_id: id.clone(),
+            changes: diff_fields(was, now),
+        };
+        journal::record(project_path, change).await;
+    }
+}
+
 /// Check if a user with the given email already exists.
 #[must_use]
 pub fn find_user_by_email<'user>(users: &'user [User], email: &str) -> Option<&'user User> {
@@ -0,0 +1,52 @@
+use super::storage::write_view;
+use super::{views_dir, SavedView, ViewError};
+use serde_json::json;
+use std::path::Path;
+
+fn default_fields() -> Vec<String> {
+    ["displayNumber", "title", "status", "priority"]
+        .iter()
+        .map(|f| (*f).to_string())
+        .collect()
+}
+
+/// The views `init` seeds into a new `.centy/views/` folder.
+#[must_use]
+pub fn default_views() -> Vec<SavedView> {
+    vec![
+        SavedView {
+            id: "open-issues".to_string(),
+            name: "Open issues".to_string(),
+            description: Some("Issues still open, most urgent first".to_string()),
+            item_type: "issues".to_string(),
+            filter: json!({ "status": "open" }),
+            sort: "priority,-updatedAt".to_string(),
+            fields: default_fields(),
+        },
+        SavedView {
+            id: "high-priority".to_string(),
+            name: "High priority".to_string(),
+            description: Some("Unfinished issues at the highest priority".to_string()),
+            item_type: "issues".to_string(),
+            filter: json!({ "priority": 1u32, "status": { "$ne": "closed" } }),
+            sort: "-updatedAt".to_string(),
+            fields: default_fields(),
+        },
+    ]
+}
+
+/// Write the default views that are not present yet, returning the paths
+/// (relative to `.centy/`) of the files created.
+pub async fn seed_default_views(project_path: &Path) -> Result<Vec<String>, ViewError> {
+    let dir = views_dir(project_path);
+    let mut created = Vec::new();
+    for view in default_views() {
+        let file_name = format!("{}.yaml", view.id);
+        if dir.join(&file_name).exists() {
+            continue;
+        }
+        write_view(project_path, &view).await?;
+        created.push(format!("views/{file_name}"));
+    }
+    Ok(created)
+}
@@ -0,0 +1,21 @@
+use crate::query::QueryError;
+use thiserror::Error;
+
+/// Saved view errors
+#[derive(Error, Debug)]
+pub enum ViewError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("YAML error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+    #[error("View '{0}' not found")]
+    NotFound(String),
+    #[error("View '{0}' already exists")]
+    AlreadyExists(String),
+    #[error("Invalid view ID: {0}")]
+    InvalidId(String),
+    #[error("Invalid view: {0}")]
+    Invalid(String),
+    #[error(transparent)]
+    InvalidFilter(#[from] QueryError),
+}
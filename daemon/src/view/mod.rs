@@ -0,0 +1,23 @@
+//! Saved views module.
+//!
+//! A saved view is a named query — item type, MQL filter, sort and the fields
+//! a client should show — that `ListItems` can run by name.
+//!
+//! Views are stored one per file in `.centy/views/<id>.yaml` so they are
+//! versioned alongside the items they query.
+
+mod defaults;
+mod error;
+mod storage;
+mod types;
+
+pub use defaults::{default_views, seed_default_views};
+pub use error::ViewError;
+pub use storage::{
+    create_view, delete_view, find_view, get_view, list_views, update_view, views_dir,
+};
+pub use types::{parse_view_filter, SavedView};
+
+#[cfg(test)]
+#[path = "storage_tests.rs"]
+mod storage_tests;
@@ -0,0 +1,111 @@
+use super::types::validate_view_id;
+use super::{SavedView, ViewError};
+use crate::utils::get_centy_path;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::warn;
+
+/// The `.centy/views/` folder of a project.
+#[must_use]
+pub fn views_dir(project_path: &Path) -> PathBuf {
+    get_centy_path(project_path).join("views")
+}
+
+/// The file of view `id`. The ID is validated first, so it cannot point
+/// outside `.centy/views/`.
+fn view_path(project_path: &Path, id: &str) -> Result<PathBuf, ViewError> {
+    validate_view_id(id)?;
+    Ok(views_dir(project_path).join(format!("{id}.yaml")))
+}
+
+/// List every saved view, ordered by name.
+///
+/// Malformed view files are logged and skipped.
+pub async fn list_views(project_path: &Path) -> Result<Vec<SavedView>, ViewError> {
+    let dir = views_dir(project_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut views = Vec::new();
+    let mut entries = fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let Some(id) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".yaml"))
+        else {
+            continue;
+        };
+        match read_view(&path, id).await {
+            Ok(view) => views.push(view),
+            Err(e) => warn!(view = %id, error = %e, "Malformed view file, skipping"),
+        }
+    }
+    views.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    Ok(views)
+}
+
+/// Get a saved view by ID.
+pub async fn get_view(project_path: &Path, id: &str) -> Result<SavedView, ViewError> {
+    let path = view_path(project_path, id)?;
+    if !path.exists() {
+        return Err(ViewError::NotFound(id.to_string()));
+    }
+    read_view(&path, id).await
+}
+
+/// Find a saved view by ID, falling back to a case-insensitive name match.
+pub async fn find_view(project_path: &Path, id_or_name: &str) -> Result<SavedView, ViewError> {
+    if view_path(project_path, id_or_name).is_ok_and(|path| path.exists()) {
+        return get_view(project_path, id_or_name).await;
+    }
+    let wanted = id_or_name.to_lowercase();
+    list_views(project_path)
+        .await?
+        .into_iter()
+        .find(|v| v.name.to_lowercase() == wanted)
+        .ok_or_else(|| ViewError::NotFound(id_or_name.to_string()))
+}
+
+/// Store a new view. Fails if a view with the same ID exists.
+pub async fn create_view(project_path: &Path, view: &SavedView) -> Result<(), ViewError> {
+    view.validate()?;
+    if view_path(project_path, &view.id)?.exists() {
+        return Err(ViewError::AlreadyExists(view.id.clone()));
+    }
+    write_view(project_path, view).await
+}
+
+/// Replace an existing view.
+pub async fn update_view(project_path: &Path, view: &SavedView) -> Result<(), ViewError> {
+    view.validate()?;
+    if !view_path(project_path, &view.id)?.exists() {
+        return Err(ViewError::NotFound(view.id.clone()));
+    }
+    write_view(project_path, view).await
+}
+
+/// Delete a view by ID.
+pub async fn delete_view(project_path: &Path, id: &str) -> Result<(), ViewError> {
+    let path = view_path(project_path, id)?;
+    if !path.exists() {
+        return Err(ViewError::NotFound(id.to_string()));
+    }
+    fs::remove_file(&path).await?;
+    Ok(())
+}
+
+async fn read_view(path: &Path, id: &str) -> Result<SavedView, ViewError> {
+    let content = fs::read_to_string(path).await?;
+    let mut view: SavedView = serde_yaml::from_str(&content)?;
+    view.id = id.to_string();
+    Ok(view)
+}
+
+pub(super) async fn write_view(project_path: &Path, view: &SavedView) -> Result<(), ViewError> {
+    fs::create_dir_all(views_dir(project_path)).await?;
+    let content = serde_yaml::to_string(view)?;
+    fs::write(view_path(project_path, &view.id)?, content).await?;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::utils::get_centy_path;
+use serde_json::json;
+
+fn view(id: &str, name: &str) -> SavedView {
+    SavedView {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: None,
+        item_type: "issues".to_string(),
+        filter: json!({ "status": "open" }),
+        sort: "-priority".to_string(),
+        fields: vec!["title".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn test_view_crud_roundtrip() {
+    let temp = tempfile::tempdir().unwrap();
+    let project = temp.path();
+
+    create_view(project, &view("mine", "Mine")).await.unwrap();
+    let fetched = get_view(project, "mine").await.unwrap();
+    assert_eq!(fetched, view("mine", "Mine"));
+    assert_eq!(fetched.filter_json(), r#"{"status":"open"}"#);
+    assert!(matches!(
+        create_view(project, &view("mine", "Mine")).await,
+        Err(ViewError::AlreadyExists(_))
+    ));
+
+    let mut changed = view("mine", "Mine");
+    changed.sort = "createdAt".to_string();
+    update_view(project, &changed).await.unwrap();
+    assert_eq!(get_view(project, "mine").await.unwrap().sort, "createdAt");
+
+    delete_view(project, "mine").await.unwrap();
+    assert!(matches!(
+        get_view(project, "mine").await,
+        Err(ViewError::NotFound(_))
+    ));
+    assert!(matches!(
+        update_view(project, &changed).await,
+        Err(ViewError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_find_view_by_name_and_list_order() {
+    let temp = tempfile::tempdir().unwrap();
+    let project = temp.path();
+    create_view(project, &view("b", "Zeta")).await.unwrap();
+    create_view(project, &view("a", "Alpha")).await.unwrap();
+    tokio::fs::write(views_dir(project).join("broken.yaml"), ": not yaml [")
+        .await
+        .unwrap();
+
+    let names: Vec<String> = list_views(project)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|v| v.name)
+        .collect();
+    assert_eq!(names, vec!["Alpha", "Zeta"]);
+    assert_eq!(find_view(project, "zeta").await.unwrap().id, "b");
+    assert_eq!(find_view(project, "a").await.unwrap().name, "Alpha");
+    assert!(find_view(project, "missing").await.is_err());
+}
+
+#[tokio::test]
+async fn test_create_view_validates() {
+    let temp = tempfile::tempdir().unwrap();
+    let project = temp.path();
+    assert!(matches!(
+        create_view(project, &view("Bad ID", "x")).await,
+        Err(ViewError::InvalidId(_))
+    ));
+    let mut bad_filter = view("bad-filter", "Bad filter");
+    bad_filter.filter = json!({ "priority": { "$bogus": 1u32 } });
+    assert!(matches!(
+        create_view(project, &bad_filter).await,
+        Err(ViewError::InvalidFilter(_))
+    ));
+    let mut no_type = view("no-type", "No type");
+    no_type.item_type = String::new();
+    assert!(matches!(
+        create_view(project, &no_type).await,
+        Err(ViewError::Invalid(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_view_ids_cannot_leave_views_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let project = temp.path();
+    let hooks = get_centy_path(project).join("hooks.yaml");
+    tokio::fs::create_dir_all(views_dir(project)).await.unwrap();
+    tokio::fs::write(&hooks, "hooks: []\n").await.unwrap();
+
+    for id in ["../hooks", "../../x", "a/b"] {
+        assert!(matches!(
+            get_view(project, id).await,
+            Err(ViewError::InvalidId(_))
+        ));
+        assert!(matches!(
+            delete_view(project, id).await,
+            Err(ViewError::InvalidId(_))
+        ));
+        assert!(matches!(
+            find_view(project, id).await,
+            Err(ViewError::NotFound(_))
+        ));
+    }
+    assert!(hooks.exists());
+}
+
+#[tokio::test]
+async fn test_seed_default_views_keeps_existing_files() {
+    let temp = tempfile::tempdir().unwrap();
+    let project = temp.path();
+    let mut edited = default_views().remove(0);
+    edited.sort = "title".to_string();
+    create_view(project, &edited).await.unwrap();
+
+    let created = seed_default_views(project).await.unwrap();
+    assert_eq!(created, vec!["views/high-priority.yaml"]);
+    assert_eq!(get_view(project, &edited.id).await.unwrap().sort, "title");
+    for default in default_views() {
+        assert!(get_view(project, &default.id)
+            .await
+            .unwrap()
+            .query()
+            .is_ok());
+    }
+    assert!(seed_default_views(project).await.unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_view_filter() {
+    assert!(parse_view_filter("").unwrap().is_null());
+    assert_eq!(
+        parse_view_filter(r#"{"status":"open"}"#).unwrap(),
+        json!({ "status": "open" })
+    );
+    assert!(matches!(
+        parse_view_filter("{not json"),
+        Err(ViewError::InvalidFilter(_))
+    ));
+}
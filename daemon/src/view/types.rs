@@ -0,0 +1,91 @@
+use crate::query::{parse_filter, Query, QueryError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::ViewError;
+
+/// A named, reusable item query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedView {
+    /// File stem under `.centy/views/`; not stored in the file itself.
+    #[serde(skip)]
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub item_type: String,
+    /// MQL filter document; `null` matches every item.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub filter: Value,
+    /// Sort spec in `ListItems` syntax, e.g. `-priority,createdAt`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub sort: String,
+    /// Fields a client should display, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+impl SavedView {
+    /// The parsed filter.
+    pub fn query(&self) -> Result<Query, ViewError> {
+        if self.filter.is_null() {
+            return Ok(Query::All);
+        }
+        Ok(parse_filter(&self.filter)?)
+    }
+
+    /// The filter as a JSON string, empty when there is none.
+    #[must_use]
+    pub fn filter_json(&self) -> String {
+        if self.filter.is_null() {
+            String::new()
+        } else {
+            self.filter.to_string()
+        }
+    }
+
+    /// Check the fields a stored view must have.
+    pub fn validate(&self) -> Result<(), ViewError> {
+        validate_view_id(&self.id)?;
+        if self.name.trim().is_empty() {
+            return Err(ViewError::Invalid("name cannot be empty".to_string()));
+        }
+        if self.item_type.trim().is_empty() {
+            return Err(ViewError::Invalid("item type cannot be empty".to_string()));
+        }
+        self.query()?;
+        Ok(())
+    }
+}
+
+/// Parse a JSON filter string into the form stored in a view. An empty
+/// string means no filter.
+pub fn parse_view_filter(filter_json: &str) -> Result<Value, ViewError> {
+    if filter_json.trim().is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_str(filter_json)
+        .map_err(|e| QueryError::new("", format!("malformed JSON: {e}")).into())
+}
+
+/// Validate a view ID (must be non-empty, lowercase alphanumeric with hyphens)
+pub(super) fn validate_view_id(id: &str) -> Result<(), ViewError> {
+    if id.is_empty() {
+        return Err(ViewError::InvalidId("ID cannot be empty".to_string()));
+    }
+    if !id
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ViewError::InvalidId(
+            "ID must contain only lowercase letters, numbers, and hyphens".to_string(),
+        ));
+    }
+    if id.starts_with('-') || id.ends_with('-') {
+        return Err(ViewError::InvalidId(
+            "ID cannot start or end with a hyphen".to_string(),
+        ));
+    }
+    Ok(())
+}
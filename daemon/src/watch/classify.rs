@@ -0,0 +1,52 @@
+use super::types::{ChangeEvent, ChangeKind, EntityKind};
+use std::path::{Component, Path};
+
+/// Folders under `.centy/` that do not hold items.
+const NON_ITEM_FOLDERS: &[&str] = &["links", "views", "assets", "templates"];
+
+/// Map a path that changed under `.centy/` to the entity it stores.
+///
+/// Returns `None` for files that are not entities: temporary and hidden
+/// files, local caches (`*.local.*`), assets, views and templates.
+#[must_use]
+pub fn classify(
+    project_path: &Path,
+    centy_path: &Path,
+    path: &Path,
+    kind: ChangeKind,
+) -> Option<ChangeEvent> {
+    let relative = path.strip_prefix(centy_path).ok()?;
+    let parts: Vec<&str> = relative
+        .components()
+        .map(|c| match c {
+            Component::Normal(name) => name.to_str(),
+            Component::Prefix(_)
+            | Component::RootDir
+            | Component::CurDir
+            | Component::ParentDir => None,
+        })
+        .collect::<Option<_>>()?;
+    let event = |entity, item_type: &str, id: &str| {
+        Some(ChangeEvent::new(kind, entity, project_path, item_type, id))
+    };
+    match parts.as_slice() {
+        ["config.json"] => event(EntityKind::Config, "", ""),
+        ["users.json"] => event(EntityKind::User, "", ""),
+        ["links", file] => event(EntityKind::Link, "", markdown_stem(file)?),
+        [folder, "config.yaml"] if is_item_folder(folder) => event(EntityKind::Config, folder, ""),
+        [folder, file] if is_item_folder(folder) => {
+            event(EntityKind::Item, folder, markdown_stem(file)?)
+        }
+        _ => None,
+    }
+}
+
+fn is_item_folder(folder: &str) -> bool {
+    !folder.starts_with('.') && !NON_ITEM_FOLDERS.contains(&folder)
+}
+
+/// `abc.md` → `abc`; `None` for hidden, temporary and non-markdown files.
+fn markdown_stem(file: &str) -> Option<&str> {
+    let stem = file.strip_suffix(".md")?;
+    (!stem.is_empty() && !stem.starts_with('.') && !stem.contains(".local")).then_some(stem)
+}
@@ -0,0 +1,60 @@
+use super::*;
+use std::path::Path;
+
+fn classified(relative: &str) -> Option<(EntityKind, String, String)> {
+    let project = Path::new("/work/app");
+    let centy = project.join(".centy");
+    classify(project, &centy, &centy.join(relative), ChangeKind::Updated)
+        .map(|e| (e.entity, e.item_type, e.id))
+}
+
+#[test]
+fn test_entities() {
+    assert_eq!(
+        classified("issues/4f1c.md"),
+        Some((EntityKind::Item, "issues".into(), "4f1c".into()))
+    );
+    assert_eq!(
+        classified("links/9a2b.md"),
+        Some((EntityKind::Link, String::new(), "9a2b".into()))
+    );
+    assert_eq!(
+        classified("users.json"),
+        Some((EntityKind::User, String::new(), String::new()))
+    );
+    assert_eq!(
+        classified("config.json"),
+        Some((EntityKind::Config, String::new(), String::new()))
+    );
+    assert_eq!(
+        classified("docs/config.yaml"),
+        Some((EntityKind::Config, "docs".into(), String::new()))
+    );
+}
+
+#[test]
+fn test_ignored_paths() {
+    for path in [
+        "search-index.local.jsonl",
+        "issues/.4f1c.md.swp",
+        "issues/4f1c.md~",
+        "issues/assets/4f1c/screenshot.png",
+        "views/open-issues.yaml",
+        "templates/issues/bug.md",
+        "assets/logo.png",
+        "issues",
+    ] {
+        assert_eq!(classified(path), None, "{path}");
+    }
+}
+
+#[test]
+fn test_paths_outside_centy_are_ignored() {
+    let project = Path::new("/work/app");
+    let centy = project.join(".centy");
+    let outside = project.join("src/main.rs");
+    assert_eq!(
+        classify(project, &centy, &outside, ChangeKind::Created),
+        None
+    );
+}
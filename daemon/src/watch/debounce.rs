@@ -0,0 +1,94 @@
+use super::types::{ChangeEvent, ChangeKind};
+use std::time::{Duration, Instant};
+
+/// How long an entity must stay quiet before its change is emitted.
+pub const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+
+/// Coalesces bursts of changes to the same entity into one event.
+///
+/// A daemon write and the filesystem event it causes, or the several
+/// events an editor's save produces, reach subscribers as a single change
+/// once the entity has been quiet for the window.
+#[derive(Debug)]
+pub struct Debouncer {
+    window: Duration,
+    /// Pending events in arrival order, each with the instant it is due.
+    pending: Vec<(ChangeEvent, Instant)>,
+}
+
+impl Debouncer {
+    #[must_use]
+    pub const fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Add an event observed at `now`, merging it into a pending change to
+    /// the same entity.
+    pub fn push(&mut self, event: ChangeEvent, now: Instant) {
+        let due = now.checked_add(self.window).unwrap_or(now);
+        // A move explains the disappearance of the old id, whichever of the
+        // two is seen first.
+        if event.kind == ChangeKind::Moved {
+            self.pending.retain(|(p, _)| !is_old_id_of(p, &event));
+        }
+        if self.pending.iter().any(|(p, _)| is_old_id_of(&event, p)) {
+            return;
+        }
+        let Some(index) = self.pending.iter().position(|(p, _)| p.same_entity(&event)) else {
+            self.pending.push((event, due));
+            return;
+        };
+        let (earlier, _) = self.pending.remove(index);
+        if let Some(merged) = merge(earlier, event) {
+            self.pending.push((merged, due));
+        }
+    }
+
+    /// When the next pending event becomes due.
+    #[must_use]
+    pub fn next_due(&self) -> Option<Instant> {
+        self.pending.iter().map(|(_, due)| *due).min()
+    }
+
+    /// Remove and return the events due at `now`, oldest first.
+    pub fn take_due(&mut self, now: Instant) -> Vec<ChangeEvent> {
+        let (due, waiting): (Vec<_>, Vec<_>) = self
+            .pending
+            .drain(..)
+            .partition(|(_, due_at)| *due_at <= now);
+        self.pending = waiting;
+        due.into_iter().map(|(event, _)| event).collect()
+    }
+}
+
+/// Whether `deleted` is the removal of the id `moved` was moved from.
+fn is_old_id_of(deleted: &ChangeEvent, moved: &ChangeEvent) -> bool {
+    deleted.kind == ChangeKind::Deleted
+        && moved.kind == ChangeKind::Moved
+        && deleted.project_path == moved.project_path
+        && deleted.entity == moved.entity
+        && deleted.id == moved.previous_id
+}
+
+/// The net effect of two changes to one entity; `None` when they cancel out.
+fn merge(earlier: ChangeEvent, later: ChangeEvent) -> Option<ChangeEvent> {
+    let kind = match (earlier.kind, later.kind) {
+        (ChangeKind::Created, ChangeKind::Deleted) => return None,
+        (ChangeKind::Created, ChangeKind::Created | ChangeKind::Updated) => ChangeKind::Created,
+        (ChangeKind::Updated | ChangeKind::Deleted | ChangeKind::Moved, ChangeKind::Deleted) => {
+            ChangeKind::Deleted
+        }
+        (ChangeKind::Updated | ChangeKind::Deleted, ChangeKind::Created | ChangeKind::Updated) => {
+            ChangeKind::Updated
+        }
+        (
+            ChangeKind::Created | ChangeKind::Updated | ChangeKind::Deleted | ChangeKind::Moved,
+            ChangeKind::Moved,
+        ) => return Some(later),
+        (ChangeKind::Moved, ChangeKind::Created | ChangeKind::Updated) => return Some(earlier),
+    };
+    Some(ChangeEvent { kind, ..later })
+}
@@ -0,0 +1,118 @@
+use super::*;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+fn item(kind: ChangeKind, id: &str) -> ChangeEvent {
+    ChangeEvent::item(kind, Path::new("/work/app"), "issues", id)
+}
+
+fn settle(debouncer: &mut Debouncer, start: Instant) -> Vec<(ChangeKind, String)> {
+    debouncer
+        .take_due(start + Duration::from_secs(1))
+        .into_iter()
+        .map(|e| (e.kind, e.id))
+        .collect()
+}
+
+#[test]
+fn test_events_wait_for_quiet() {
+    let start = Instant::now();
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+    debouncer.push(item(ChangeKind::Updated, "a"), start);
+    assert!(debouncer.take_due(start).is_empty());
+    assert_eq!(debouncer.next_due(), Some(start + DEBOUNCE_WINDOW));
+    // A second change restarts the wait.
+    let later = start + Duration::from_millis(150);
+    debouncer.push(item(ChangeKind::Updated, "a"), later);
+    assert!(debouncer.take_due(start + DEBOUNCE_WINDOW).is_empty());
+    assert_eq!(debouncer.take_due(later + DEBOUNCE_WINDOW).len(), 1);
+    assert_eq!(debouncer.next_due(), None);
+}
+
+#[test]
+fn test_duplicates_are_merged() {
+    let start = Instant::now();
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+    // Daemon write, then the filesystem events it caused.
+    debouncer.push(item(ChangeKind::Created, "a"), start);
+    debouncer.push(item(ChangeKind::Created, "a"), start);
+    debouncer.push(item(ChangeKind::Updated, "a"), start);
+    debouncer.push(item(ChangeKind::Updated, "b"), start);
+    assert_eq!(
+        settle(&mut debouncer, start),
+        vec![
+            (ChangeKind::Created, "a".into()),
+            (ChangeKind::Updated, "b".into())
+        ]
+    );
+}
+
+#[test]
+fn test_merge_rules() {
+    let cases = [
+        (
+            ChangeKind::Updated,
+            ChangeKind::Deleted,
+            Some(ChangeKind::Deleted),
+        ),
+        (
+            ChangeKind::Deleted,
+            ChangeKind::Created,
+            Some(ChangeKind::Updated),
+        ),
+        (
+            ChangeKind::Updated,
+            ChangeKind::Created,
+            Some(ChangeKind::Updated),
+        ),
+        (ChangeKind::Created, ChangeKind::Deleted, None),
+    ];
+    for (first, second, expected) in cases {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+        debouncer.push(item(first, "a"), start);
+        debouncer.push(item(second, "a"), start);
+        let kinds: Vec<ChangeKind> = settle(&mut debouncer, start)
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            expected.into_iter().collect::<Vec<_>>(),
+            "{first:?} then {second:?}"
+        );
+    }
+}
+
+#[test]
+fn test_move_absorbs_delete_of_old_id() {
+    let start = Instant::now();
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+    debouncer.push(item(ChangeKind::Deleted, "old-slug"), start);
+    debouncer.push(item(ChangeKind::Created, "new-slug"), start);
+    debouncer.push(
+        item(ChangeKind::Updated, "new-slug").moved("old-slug", None),
+        start,
+    );
+    let events = debouncer.take_due(start + Duration::from_secs(1));
+    assert_eq!(events.len(), 1);
+    let moved = events.first().unwrap();
+    assert_eq!(moved.kind, ChangeKind::Moved);
+    assert_eq!(moved.id, "new-slug");
+    assert_eq!(moved.previous_id, "old-slug");
+}
+
+#[test]
+fn test_move_absorbs_later_delete_of_old_id() {
+    let start = Instant::now();
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+    debouncer.push(
+        item(ChangeKind::Updated, "new-slug").moved("old-slug", None),
+        start,
+    );
+    debouncer.push(item(ChangeKind::Deleted, "old-slug"), start);
+    debouncer.push(item(ChangeKind::Created, "new-slug"), start);
+    let events = debouncer.take_due(start + Duration::from_secs(1));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.first().unwrap().kind, ChangeKind::Moved);
+}
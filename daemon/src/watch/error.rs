@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WatchError {
+    #[error("Filesystem watcher error: {0}")]
+    NotifyError(#[from] notify::Error),
+}
@@ -0,0 +1,124 @@
+use super::classify::classify;
+use super::error::WatchError;
+use super::hub::publish;
+use super::types::ChangeKind;
+use crate::utils::get_centy_path;
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tracing::warn;
+
+type Watchers = HashMap<PathBuf, (RecommendedWatcher, usize)>;
+
+/// One watcher per project, with the number of guards keeping it alive.
+static WATCHERS: OnceLock<Mutex<Watchers>> = OnceLock::new();
+
+fn watchers() -> &'static Mutex<Watchers> {
+    WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Keeps a project's `.centy/` folder watched while alive. The watcher is
+/// shared by all guards of a project and stops when the last one drops.
+#[derive(Debug)]
+pub struct WatchGuard {
+    project_path: PathBuf,
+}
+
+/// Start publishing filesystem changes under the project's `.centy/`
+/// folder, or join the watcher that is already running.
+pub fn watch_project(project_path: &Path) -> Result<WatchGuard, WatchError> {
+    let mut running = watchers().lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some((_, guards)) = running.get_mut(project_path) {
+        *guards = guards.saturating_add(1);
+    } else {
+        let watcher = start_watcher(project_path)?;
+        running.insert(project_path.to_path_buf(), (watcher, 1));
+    }
+    drop(running);
+    Ok(WatchGuard {
+        project_path: project_path.to_path_buf(),
+    })
+}
+
+impl Drop for WatchGuard {
+    fn drop(&mut self) {
+        let mut running = watchers().lock().unwrap_or_else(PoisonError::into_inner);
+        let remaining = running.get_mut(&self.project_path).map(|(_, guards)| {
+            *guards = guards.saturating_sub(1);
+            *guards
+        });
+        if remaining == Some(0) {
+            running.remove(&self.project_path);
+        }
+    }
+}
+
+fn start_watcher(project_path: &Path) -> Result<RecommendedWatcher, WatchError> {
+    let project = project_path.to_path_buf();
+    let centy_path = get_centy_path(project_path);
+    let root = centy_path.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        Ok(event) => publish_fs_event(&project, &root, &event),
+        Err(e) => warn!(error = %e, "Filesystem watcher error"),
+    })?;
+    watcher.watch(&centy_path, RecursiveMode::Recursive)?;
+    Ok(watcher)
+}
+
+fn publish_fs_event(project_path: &Path, centy_path: &Path, event: &Event) {
+    let kind = match event.kind {
+        EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            ChangeKind::Created
+        }
+        EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            ChangeKind::Deleted
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            publish_rename(project_path, centy_path, &event.paths);
+            return;
+        }
+        // Backends that cannot pair renames report each side on its own.
+        EventKind::Modify(ModifyKind::Name(RenameMode::Any | RenameMode::Other)) => {
+            for path in &event.paths {
+                let side = if path.exists() {
+                    ChangeKind::Created
+                } else {
+                    ChangeKind::Deleted
+                };
+                if let Some(change) = classify(project_path, centy_path, path, side) {
+                    publish(change);
+                }
+            }
+            return;
+        }
+        EventKind::Modify(
+            ModifyKind::Any | ModifyKind::Data(_) | ModifyKind::Metadata(_) | ModifyKind::Other,
+        ) => ChangeKind::Updated,
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => return,
+    };
+    for path in &event.paths {
+        if let Some(change) = classify(project_path, centy_path, path, kind) {
+            publish(change);
+        }
+    }
+}
+
+/// A rename within `.centy/` becomes a move when both sides are the same
+/// kind of entity, and a delete plus a create otherwise.
+fn publish_rename(project_path: &Path, centy_path: &Path, paths: &[PathBuf]) {
+    let [from, to] = paths else {
+        return;
+    };
+    let before = classify(project_path, centy_path, from, ChangeKind::Deleted);
+    let after = classify(project_path, centy_path, to, ChangeKind::Created);
+    match (before, after) {
+        (Some(old), Some(new)) if old.entity == new.entity => {
+            publish(new.moved(&old.id, None));
+        }
+        (old, new) => {
+            old.into_iter().chain(new).for_each(publish);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::types::ChangeEvent;
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before slow subscribers start missing them.
+const CAPACITY: usize = 1024;
+
+/// Every change the daemon writes or observes, fanned out to `Watch` streams.
+static HUB: OnceLock<broadcast::Sender<ChangeEvent>> = OnceLock::new();
+
+fn hub() -> &'static broadcast::Sender<ChangeEvent> {
+    HUB.get_or_init(|| broadcast::channel(CAPACITY).0)
+}
+
+/// Announce a change. Cheap and non-blocking; a no-op without subscribers.
+pub fn publish(event: ChangeEvent) {
+    // Sending only fails when nobody is subscribed.
+    let _res = hub().send(event);
+}
+
+/// Receive every change published from now on.
+#[must_use]
+pub fn subscribe() -> broadcast::Receiver<ChangeEvent> {
+    hub().subscribe()
+}
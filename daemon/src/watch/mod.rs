@@ -0,0 +1,26 @@
+//! Change notifications for the streaming `Watch` RPC.
+//!
+//! Changes reach the [hub](publish) from two sides: the daemon's own write
+//! paths (`generic_*` storage, link files, users, config) publish them as
+//! they happen, and a filesystem watcher on each watched project's `.centy/`
+//! folder picks up edits made outside the daemon, such as an editor save or
+//! a `git pull`. Each `Watch` stream debounces what it receives, so a daemon
+//! write and the filesystem event it causes arrive as one change.
+mod classify;
+mod debounce;
+mod error;
+mod fs_watcher;
+mod hub;
+mod types;
+pub use classify::classify;
+pub use debounce::{Debouncer, DEBOUNCE_WINDOW};
+pub use error::WatchError;
+pub use fs_watcher::{watch_project, WatchGuard};
+pub use hub::{publish, subscribe};
+pub use types::{ChangeEvent, ChangeKind, EntityKind};
+#[cfg(test)]
+#[path = "classify_tests.rs"]
+mod classify_tests;
+#[cfg(test)]
+#[path = "debounce_tests.rs"]
+mod debounce_tests;
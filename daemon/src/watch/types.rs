@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+/// What happened to an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    /// The entity got a new id or moved to another project.
+    Moved,
+}
+
+impl ChangeKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+            Self::Moved => "moved",
+        }
+    }
+}
+
+/// The kind of entity a change is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    Item,
+    Link,
+    /// `users.json`; user changes carry no id.
+    User,
+    /// The project `config.json` or an item type's `config.yaml`.
+    Config,
+}
+
+impl EntityKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Item => "item",
+            Self::Link => "link",
+            Self::User => "user",
+            Self::Config => "config",
+        }
+    }
+}
+
+/// A change to something stored under a project's `.centy/` folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEvent {
+    pub kind: ChangeKind,
+    pub entity: EntityKind,
+    pub project_path: PathBuf,
+    /// Item type folder for items and item type configs; empty otherwise.
+    pub item_type: String,
+    /// Item, link or user id; empty when the change has no single id.
+    pub id: String,
+    /// For [`ChangeKind::Moved`]: the id before the move.
+    pub previous_id: String,
+    /// For [`ChangeKind::Moved`] across projects: the project it moved to.
+    pub moved_to: Option<PathBuf>,
+}
+
+impl ChangeEvent {
+    #[must_use]
+    pub fn new(
+        kind: ChangeKind,
+        entity: EntityKind,
+        project_path: &Path,
+        item_type: &str,
+        id: &str,
+    ) -> Self {
+        Self {
+            kind,
+            entity,
+            project_path: project_path.to_path_buf(),
+            item_type: item_type.to_string(),
+            id: id.to_string(),
+            previous_id: String::new(),
+            moved_to: None,
+        }
+    }
+
+    /// A change to the item `id` of type `folder`.
+    #[must_use]
+    pub fn item(kind: ChangeKind, project_path: &Path, folder: &str, id: &str) -> Self {
+        Self::new(kind, EntityKind::Item, project_path, folder, id)
+    }
+
+    /// An entity that now lives at `id` but was `previous_id`.
+    #[must_use]
+    pub fn moved(mut self, previous_id: &str, moved_to: Option<&Path>) -> Self {
+        self.kind = ChangeKind::Moved;
+        self.previous_id = previous_id.to_string();
+        self.moved_to = moved_to.map(Path::to_path_buf);
+        self
+    }
+
+    /// Whether two events are about the same entity and may be merged.
+    #[must_use]
+    pub fn same_entity(&self, other: &Self) -> bool {
+        self.entity == other.entity
+            && self.project_path == other.project_path
+            && self.item_type == other.item_type
+            && self.id == other.id
+    }
+}
@@ -0,0 +1,123 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::config::item_type_config::default_issue_config;
+use centy_daemon::config::CentyConfig;
+use centy_daemon::item::generic::storage::generic_create;
+use centy_daemon::server::handlers::item_aggregate::aggregate_items;
+use centy_daemon::server::proto::{AggregateItemsRequest, AggregateItemsResponse};
+use common::{create_test_dir, init_centy_project};
+use mdstore::{CreateOptions, TypeConfig};
+use std::collections::HashMap;
+use std::path::Path;
+
+async fn create_issue(project: &Path, status: &str, priority: u32, tags: &[&str]) {
+    let config = TypeConfig::from(&default_issue_config(&CentyConfig::default()));
+    generic_create(
+        project,
+        "issues",
+        &config,
+        CreateOptions {
+            title: format!("{status} P{priority}"),
+            body: String::new(),
+            id: None,
+            status: Some(status.to_string()),
+            priority: Some(priority),
+            tags: Some(tags.iter().map(|t| (*t).to_string()).collect()),
+            custom_fields: HashMap::new(),
+            comment: None,
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn aggregate(project: &Path, filter: &str, group_by: &str) -> AggregateItemsResponse {
+    aggregate_items(AggregateItemsRequest {
+        project_path: project.to_str().unwrap().to_string(),
+        organization_slug: String::new(),
+        item_type: "issues".to_string(),
+        filter: filter.to_string(),
+        group_by: group_by.to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner()
+}
+
+fn pairs(resp: &AggregateItemsResponse) -> Vec<(&str, i32)> {
+    resp.groups
+        .iter()
+        .map(|g| (g.key.as_str(), g.count))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_aggregate_by_status_and_tags() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+    create_issue(project, "open", 1, &["ui", "bug"]).await;
+    create_issue(project, "open", 2, &["bug"]).await;
+    create_issue(project, "closed", 1, &[]).await;
+
+    let resp = aggregate(project, "", "status").await;
+    assert!(resp.success, "aggregate failed: {}", resp.error);
+    assert_eq!(resp.total_count, 3);
+    assert_eq!(pairs(&resp), vec![("open", 2), ("closed", 1)]);
+
+    let resp = aggregate(project, r#"{"status":"open"}"#, "tags").await;
+    assert!(resp.success, "aggregate failed: {}", resp.error);
+    assert_eq!(resp.total_count, 2);
+    assert_eq!(pairs(&resp), vec![("bug", 2), ("ui", 1)]);
+
+    let resp = aggregate(project, "", "priority").await;
+    assert_eq!(pairs(&resp), vec![("1", 2), ("2", 1)]);
+}
+
+#[tokio::test]
+async fn test_aggregate_by_created_week() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+    create_issue(project, "open", 1, &[]).await;
+
+    let resp = aggregate(project, "", "createdAt:week").await;
+    assert!(resp.success, "aggregate failed: {}", resp.error);
+    assert_eq!(resp.groups.len(), 1);
+    assert!(resp.groups[0].key.contains("-W"), "{}", resp.groups[0].key);
+    assert_eq!(resp.groups[0].count, 1);
+}
+
+#[tokio::test]
+async fn test_aggregate_rejects_bad_requests() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+
+    let resp = aggregate(project, "", "createdAt:month").await;
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_GROUP_BY"), "{}", resp.error);
+
+    let resp = aggregate_items(AggregateItemsRequest {
+        project_path: String::new(),
+        organization_slug: String::new(),
+        item_type: "issues".to_string(),
+        filter: String::new(),
+        group_by: "status".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_REQUEST"), "{}", resp.error);
+}
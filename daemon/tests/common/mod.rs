@@ -2,6 +2,11 @@
 
 //! Common test utilities
 
+use centy_daemon::config::item_type_config::default_issue_config;
+use centy_daemon::config::CentyConfig;
+use centy_daemon::item::generic::storage::{generic_create, generic_update};
+use mdstore::{CreateOptions, TypeConfig, UpdateOptions};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::LazyLock;
 use tempfile::TempDir;
@@ -43,6 +48,64 @@ pub async fn init_centy_project(project_path: &Path) {
         .expect("Failed to initialize centy project");
 }
 
+/// Initialize a bare project: just the `.centy` folder and its manifest
+#[allow(dead_code)] // Used across integration test files
+pub async fn init_minimal_project(project_path: &Path) {
+    tokio::fs::create_dir_all(project_path.join(".centy"))
+        .await
+        .unwrap();
+    let manifest = centy_daemon::manifest::create_manifest();
+    centy_daemon::manifest::write_manifest(project_path, &manifest)
+        .await
+        .unwrap();
+}
+
+/// The default issue type config
+#[allow(dead_code)] // Used across integration test files
+pub fn issue_config() -> TypeConfig {
+    TypeConfig::from(&default_issue_config(&CentyConfig::default()))
+}
+
+/// Create an open, priority 2 issue and return its id
+#[allow(dead_code)] // Used across integration test files
+pub async fn create_issue(project_path: &Path, title: &str) -> String {
+    generic_create(
+        project_path,
+        "issues",
+        &issue_config(),
+        CreateOptions {
+            title: title.to_string(),
+            body: String::new(),
+            id: None,
+            status: Some("open".to_string()),
+            priority: Some(2),
+            tags: None,
+            custom_fields: HashMap::new(),
+            comment: None,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+/// Change an issue's priority
+#[allow(dead_code)] // Used across integration test files
+pub async fn set_issue_priority(project_path: &Path, id: &str, priority: u32) {
+    generic_update(
+        project_path,
+        "issues",
+        &issue_config(),
+        id,
+        UpdateOptions {
+            priority: Some(priority),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// Verify that the .centy folder exists with expected structure
 #[allow(dead_code)] // Test utility for integration tests
 pub fn verify_centy_structure(project_path: &Path) {
@@ -0,0 +1,90 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::server::handlers::explain_query::explain_query;
+use centy_daemon::server::handlers::item_list::list_items;
+use centy_daemon::server::proto::{ExplainQueryRequest, ExplainQueryResponse, ListItemsRequest};
+use common::{create_test_dir, init_centy_project};
+use serde_json::{json, Value};
+
+fn explain(filter: &str) -> ExplainQueryResponse {
+    explain_query(&ExplainQueryRequest {
+        project_path: String::new(),
+        filter: filter.to_string(),
+    })
+    .unwrap()
+    .into_inner()
+}
+
+fn parse(json: &str) -> Value {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_explain_splits_filter() {
+    let resp = explain(r#"{"status":"open","title":{"$regex":"crash"}}"#);
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(
+        parse(&resp.normalized),
+        json!({ "$and": [
+            { "status": { "$eq": "open" } },
+            { "title": { "$regex": "crash" } },
+        ]})
+    );
+    assert_eq!(parse(&resp.pushed_down), json!({ "statuses": ["open"] }));
+    assert_eq!(
+        parse(&resp.residual),
+        json!({ "title": { "$regex": "crash" } })
+    );
+    assert!(!resp.complete);
+}
+
+#[test]
+fn test_explain_empty_filter_is_complete() {
+    let resp = explain("");
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(parse(&resp.normalized), json!({}));
+    assert_eq!(parse(&resp.pushed_down), json!({}));
+    assert_eq!(parse(&resp.residual), json!({}));
+    assert!(resp.complete);
+}
+
+#[test]
+fn test_explain_rejects_unknown_operator() {
+    let resp = explain(r#"{"priority":{"$lessThan":2}}"#);
+    assert!(!resp.success);
+    let error = parse(&resp.error);
+    assert_eq!(error["messages"][0]["code"], "INVALID_FILTER");
+    assert_eq!(error["messages"][0]["path"], "priority.$lessThan");
+}
+
+#[tokio::test]
+async fn test_list_items_rejects_invalid_filter() {
+    let temp_dir = create_test_dir();
+    let project_path = temp_dir.path();
+    init_centy_project(project_path).await;
+
+    let resp = list_items(ListItemsRequest {
+        project_path: project_path.to_str().unwrap().to_string(),
+        item_type: "issues".to_string(),
+        filter: r#"{"status":{"$in":"open"}}"#.to_string(),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!resp.success);
+    let error = parse(&resp.error);
+    assert_eq!(error["messages"][0]["code"], "INVALID_FILTER");
+    assert_eq!(error["messages"][0]["path"], "status.$in");
+}
@@ -196,6 +196,9 @@ async fn test_list_with_filters() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -211,6 +214,9 @@ async fn test_list_with_filters() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -225,6 +231,9 @@ async fn test_list_with_filters() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -239,11 +248,112 @@ async fn test_list_with_filters() {
         limit: 1,
         offset: 1,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
     .into_inner();
-    assert_eq!(resp.total_count, 1i32);
+    assert_eq!(resp.items.len(), 1);
+    assert_eq!(resp.total_count, 3i32);
+    assert!(!resp.next_cursor.is_empty());
+
+    // Cursor pagination visits every item exactly once
+    let mut cursor = String::new();
+    let mut seen = Vec::new();
+    loop {
+        let resp = list_items(centy_daemon::server::proto::ListItemsRequest {
+            project_path: pp.to_string(),
+            item_type: "issues".to_string(),
+            filter: String::new(),
+            limit: 2,
+            offset: 0,
+            include_organization_items: None,
+            sort: "title".to_string(),
+            cursor: cursor.clone(),
+            view: String::new(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+        assert!(resp.success, "list failed: {}", resp.error);
+        assert_eq!(resp.total_count, 3i32);
+        seen.extend(resp.items.into_iter().map(|i| i.title));
+        if resp.next_cursor.is_empty() {
+            break;
+        }
+        cursor = resp.next_cursor;
+    }
+    assert_eq!(seen, vec!["Closed P1", "Open P1", "Open P2"]);
+}
+
+// ─── List with sort ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_with_sort() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_project(path).await;
+    let pp = path.to_str().unwrap();
+
+    for (title, status, priority) in [
+        ("Beta", "closed", 2i32),
+        ("Alpha", "open", 3i32),
+        ("Gamma", "in-progress", 1i32),
+    ] {
+        let resp = create_item(create_req(
+            pp,
+            "issues",
+            title,
+            "",
+            status,
+            priority,
+            HashMap::new(),
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+        assert!(resp.success, "create failed: {}", resp.error);
+    }
+
+    let titles = |resp: centy_daemon::server::proto::ListItemsResponse| {
+        resp.items.into_iter().map(|i| i.title).collect::<Vec<_>>()
+    };
+
+    let resp = list_items(centy_daemon::server::proto::ListItemsRequest {
+        project_path: pp.to_string(),
+        item_type: "issues".to_string(),
+        filter: String::new(),
+        limit: 0,
+        offset: 0,
+        include_organization_items: None,
+        sort: "status,title".to_string(),
+        cursor: String::new(),
+        view: String::new(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success);
+    assert_eq!(titles(resp), vec!["Alpha", "Gamma", "Beta"]);
+
+    // Pagination applies after sorting
+    let resp = list_items(centy_daemon::server::proto::ListItemsRequest {
+        project_path: pp.to_string(),
+        item_type: "issues".to_string(),
+        filter: String::new(),
+        limit: 2,
+        offset: 1,
+        include_organization_items: None,
+        sort: "-priority".to_string(),
+        cursor: String::new(),
+        view: String::new(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert_eq!(titles(resp), vec!["Beta", "Gamma"]);
 }
 
 // ─── Update item fields ─────────────────────────────────────────────────────
@@ -390,6 +500,9 @@ async fn test_soft_delete_and_restore() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -404,6 +517,9 @@ async fn test_soft_delete_and_restore() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -431,6 +547,9 @@ async fn test_soft_delete_and_restore() {
         limit: 0,
         offset: 0,
         include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
@@ -694,6 +813,9 @@ async fn test_delete_slug_type_with_soft_delete_enabled_soft_deletes() {
         limit: 0,
         offset: 0,
         include_organization_items: Some(false),
+        sort: String::new(),
+        cursor: String::new(),
+        view: String::new(),
     })
     .await
     .unwrap()
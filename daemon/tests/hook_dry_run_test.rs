@@ -0,0 +1,108 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+mod common;
+
+use centy_daemon::server::handlers::hook_dry_run::test_hook;
+use centy_daemon::server::proto::TestHookRequest;
+use common::{create_test_dir, init_minimal_project};
+use tokio::fs;
+
+fn request(project_path: &str, event: &str, execute: bool) -> TestHookRequest {
+    TestHookRequest {
+        project_path: project_path.to_string(),
+        item_type: "issues".to_string(),
+        event: event.to_string(),
+        request_data: r#"{"title": "", "priority": 1}"#.to_string(),
+        item_id: String::new(),
+        execute,
+    }
+}
+
+const HOOKS: &str = r#"hooks:
+  - pattern: "*.creating"
+    command: |
+      title=$(jq -r .request_data.title)
+      if [ -z "$title" ]; then echo "Title is required" >&2; exit 1; fi
+  - pattern: issue.creating
+    command: echo '{"tags": ["triage"]}'
+  - pattern: issue.created
+    command: echo created
+"#;
+
+#[tokio::test]
+async fn test_test_hook_lists_hooks_in_specificity_order() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    fs::write(path.join(".centy").join("hooks.yaml"), HOOKS)
+        .await
+        .unwrap();
+    let pp = path.to_str().unwrap();
+
+    let resp = test_hook(request(pp, "creating", false))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(resp.success, "test_hook failed: {}", resp.error);
+    assert_eq!(resp.phase, "pre");
+    assert_eq!(resp.operation, "create");
+    assert_eq!(resp.item_type, "issue");
+    let patterns: Vec<&str> = resp.hooks.iter().map(|h| h.pattern.as_str()).collect();
+    assert_eq!(patterns, ["issue.creating", "*.creating"]);
+    assert_eq!(resp.hooks[0].specificity, 2);
+    assert!(resp.hooks.iter().all(|h| h.run.is_none()));
+}
+
+#[tokio::test]
+async fn test_test_hook_runs_hooks_without_touching_data() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    fs::write(path.join(".centy").join("hooks.yaml"), HOOKS)
+        .await
+        .unwrap();
+    let pp = path.to_str().unwrap();
+
+    let resp = test_hook(request(pp, "creating", true))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(resp.success, "test_hook failed: {}", resp.error);
+    let patch = resp.hooks[0].run.as_ref().unwrap();
+    assert_eq!(patch.outcome, "succeeded");
+    let check = resp.hooks[1].run.as_ref().unwrap();
+    assert_eq!(check.outcome, "failed");
+    assert_eq!(check.exit_code, Some(1));
+    assert_eq!(check.stderr.trim(), "Title is required");
+    assert_eq!(resp.blocked_by, "*.creating");
+    assert!(resp.request_data.contains("triage"));
+    assert!(!path.join(".centy").join("issues").exists());
+    assert!(!path.join(".centy").join("hook-runs.local.jsonl").exists());
+}
+
+#[tokio::test]
+async fn test_test_hook_rejects_bad_input() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+
+    let bad_event = test_hook(request(pp, "exploded", false))
+        .await
+        .unwrap()
+        .into_inner();
+    let bad_json = test_hook(TestHookRequest {
+        request_data: "{not json".to_string(),
+        ..request(pp, "creating", false)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!bad_event.success);
+    assert!(bad_event.error.contains("HOOK_INVALID_PATTERN"));
+    assert!(!bad_json.success);
+    assert!(bad_json.error.contains("JSON_ERROR"));
+}
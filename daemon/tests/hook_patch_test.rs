@@ -0,0 +1,186 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+mod common;
+
+use centy_daemon::hooks::config::{HookDefinition, HooksFile};
+use centy_daemon::server::handlers::item_create::create_item;
+use centy_daemon::server::handlers::item_update::update_item;
+use centy_daemon::server::proto::{CreateItemRequest, UpdateItemRequest};
+use common::{create_test_dir, init_minimal_project};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::fs;
+
+fn hook(pattern: &str, command: String) -> HookDefinition {
+    HookDefinition {
+        pattern: pattern.to_string(),
+        command,
+        is_async: false,
+        timeout: 30,
+        enabled: true,
+        ..HookDefinition::default()
+    }
+}
+
+async fn write_hooks(project_path: &Path, hooks: Vec<HookDefinition>) {
+    let content = serde_yaml::to_string(&HooksFile { hooks }).unwrap();
+    fs::write(project_path.join(".centy").join("hooks.yaml"), content)
+        .await
+        .unwrap();
+}
+
+fn create_req(project_path: &str, title: &str) -> CreateItemRequest {
+    CreateItemRequest {
+        project_path: project_path.to_string(),
+        item_type: "issues".to_string(),
+        title: title.to_string(),
+        body: "Steps".to_string(),
+        status: "open".to_string(),
+        priority: 2,
+        tags: vec![],
+        custom_fields: HashMap::new(),
+        projects: vec![],
+    }
+}
+
+async fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_pre_hook_patch_rewrites_create_request() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let seen = path.join("seen.json");
+    let post = path.join("post.json");
+    write_hooks(
+        path,
+        vec![
+            hook(
+                "issue.creating",
+                r#"echo '{"title": "Login fails", "tags": ["triage"], "custom_fields": {"team": "web"}}'"#
+                    .to_string(),
+            ),
+            hook("*.creating", format!("cat > {}", seen.display())),
+            hook("issue.created", format!("cat > {}", post.display())),
+        ],
+    )
+    .await;
+
+    let resp = create_item(create_req(path.to_str().unwrap(), "  login fails"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(resp.success, "create failed: {}", resp.error);
+    let item = resp.item.unwrap();
+    assert_eq!(item.title, "Login fails");
+    let meta = item.metadata.unwrap();
+    assert_eq!(meta.tags, vec!["triage"]);
+    assert_eq!(meta.custom_fields["team"], "\"web\"");
+    assert_eq!(meta.status, "open");
+
+    let next_hook = read_json(&seen).await;
+    assert_eq!(next_hook["request_data"]["title"], "Login fails");
+    assert!(next_hook.get("modifications").is_none());
+
+    let post_hook = read_json(&post).await;
+    assert_eq!(post_hook["request_data"]["title"], "Login fails");
+    let modification = &post_hook["modifications"][0];
+    assert_eq!(modification["pattern"], "issue.creating");
+    let title = modification["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["field"] == "title")
+        .unwrap();
+    assert_eq!(title["before"], "  login fails");
+    assert_eq!(title["after"], "Login fails");
+}
+
+#[tokio::test]
+async fn test_pre_hook_patch_rewrites_update_request() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+    let created = create_item(create_req(pp, "Login fails"))
+        .await
+        .unwrap()
+        .into_inner();
+    let item_id = created.item.unwrap().id;
+    write_hooks(
+        path,
+        vec![hook(
+            "issue.updating",
+            r#"echo '{"status": "in-progress", "priority": 1}'"#.to_string(),
+        )],
+    )
+    .await;
+
+    let resp = update_item(UpdateItemRequest {
+        project_path: pp.to_string(),
+        item_type: "issues".to_string(),
+        item_id,
+        title: String::new(),
+        body: "New steps".to_string(),
+        status: String::new(),
+        priority: 0,
+        tags: vec![],
+        clear_tags: false,
+        custom_fields: HashMap::new(),
+        projects: vec![],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(resp.success, "update failed: {}", resp.error);
+    let item = resp.item.unwrap();
+    assert_eq!(item.title, "Login fails");
+    assert_eq!(item.body, "New steps");
+    let meta = item.metadata.unwrap();
+    assert_eq!(meta.status, "in-progress");
+    assert_eq!(meta.priority, 1);
+}
+
+#[tokio::test]
+async fn test_invalid_pre_hook_patch_is_rejected() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+
+    write_hooks(
+        path,
+        vec![hook(
+            "issue.creating",
+            r#"echo '{"item_type": "docs"}'"#.to_string(),
+        )],
+    )
+    .await;
+    let read_only = create_item(create_req(pp, "Login fails"))
+        .await
+        .unwrap()
+        .into_inner();
+    write_hooks(
+        path,
+        vec![hook(
+            "issue.creating",
+            r#"echo '{"priority": "high"}'"#.to_string(),
+        )],
+    )
+    .await;
+    let wrong_type = create_item(create_req(pp, "Login fails"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(!read_only.success);
+    assert!(read_only.error.contains("HOOK_INVALID_PATCH"));
+    assert!(read_only.error.contains("item_type"));
+    assert!(!wrong_type.success);
+    assert!(wrong_type.error.contains("HOOK_INVALID_PATCH"));
+    assert!(wrong_type.error.contains("priority"));
+}
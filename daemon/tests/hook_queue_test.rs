@@ -0,0 +1,163 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::indexing_slicing,
+    clippy::panic
+)]
+
+mod common;
+
+use centy_daemon::hooks::config::{HookDefinition, HookOperation, HooksFile, Phase};
+use centy_daemon::hooks::context::HookContext;
+use centy_daemon::hooks::queue::{
+    discard_delivery, project_deliveries, resume_pending, retry_delivery, update_deliveries,
+    DeliveryStatus, HookDelivery,
+};
+use centy_daemon::hooks::runner::run_post_hooks;
+use centy_daemon::hooks::HookError;
+use common::{create_test_dir, init_centy_project};
+use std::path::Path;
+use std::time::Duration;
+
+async fn write_hooks(project_path: &Path, hooks: Vec<HookDefinition>) {
+    let content = serde_yaml::to_string(&HooksFile { hooks }).unwrap();
+    tokio::fs::write(project_path.join(".centy").join("hooks.yaml"), content)
+        .await
+        .unwrap();
+}
+
+fn async_hook(command: String, retries: u32) -> HookDefinition {
+    HookDefinition {
+        pattern: "issue.created".to_string(),
+        command,
+        is_async: true,
+        retries,
+        backoff: 0,
+        ..HookDefinition::default()
+    }
+}
+
+fn context(project_path: &Path) -> HookContext {
+    HookContext::new(
+        Phase::Post,
+        "issue",
+        HookOperation::Create,
+        &project_path.to_string_lossy(),
+        Some("abc"),
+        None,
+        Some(true),
+    )
+}
+
+/// Poll the project's queue until `done` holds, or fail after a few seconds.
+async fn wait_for(
+    project_path: &Path,
+    done: impl Fn(&[HookDelivery]) -> bool,
+) -> Vec<HookDelivery> {
+    for _ in 0..100 {
+        let deliveries = project_deliveries(project_path).await.unwrap();
+        if done(&deliveries) {
+            return deliveries;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("hook queue did not settle");
+}
+
+#[tokio::test]
+async fn test_async_hook_is_delivered_and_removed_from_queue() {
+    let temp_dir = create_test_dir();
+    let path = temp_dir.path();
+    init_centy_project(path).await;
+    let marker = path.join("delivered");
+    write_hooks(
+        path,
+        vec![async_hook(format!("touch {}", marker.display()), 3)],
+    )
+    .await;
+
+    run_post_hooks(path, "issue", HookOperation::Create, &context(path)).await;
+
+    wait_for(path, |d| d.is_empty() && marker.exists()).await;
+}
+
+#[tokio::test]
+async fn test_failing_async_hook_dies_then_retry_delivers_it() {
+    let temp_dir = create_test_dir();
+    let path = temp_dir.path();
+    init_centy_project(path).await;
+    let gate = path.join("gate");
+    let attempts = path.join("attempts");
+    let command = format!(
+        "echo x >> {}; test -f {}",
+        attempts.display(),
+        gate.display()
+    );
+    write_hooks(path, vec![async_hook(command, 2)]).await;
+
+    run_post_hooks(path, "issue", HookOperation::Create, &context(path)).await;
+
+    let dead = wait_for(path, |d| {
+        d.first().is_some_and(|d| d.status == DeliveryStatus::Dead)
+    })
+    .await;
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].attempts, 3);
+    assert!(dead[0].last_error.as_deref().unwrap().contains("code 1"));
+    let tries = tokio::fs::read_to_string(&attempts).await.unwrap();
+    assert_eq!(tries.lines().count(), 3);
+
+    tokio::fs::write(&gate, "").await.unwrap();
+    let requeued = retry_delivery(path, &dead[0].id).await.unwrap();
+    assert_eq!(requeued.status, DeliveryStatus::Pending);
+
+    wait_for(path, <[HookDelivery]>::is_empty).await;
+}
+
+#[tokio::test]
+async fn test_retry_and_discard_unknown_delivery_fail() {
+    let temp_dir = create_test_dir();
+    let path = temp_dir.path();
+    init_centy_project(path).await;
+
+    let retry = retry_delivery(path, "missing").await;
+    let discard = discard_delivery(path, "missing").await;
+
+    assert!(matches!(retry, Err(HookError::DeliveryNotFound(_))));
+    assert!(matches!(discard, Err(HookError::DeliveryNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_resume_pending_runs_queued_deliveries() {
+    let temp_dir = create_test_dir();
+    let path = temp_dir.path();
+    init_centy_project(path).await;
+    let marker = path.join("resumed");
+    let hook = async_hook(format!("touch {}", marker.display()), 0);
+    // A delivery left behind by an earlier daemon
+    let delivery = HookDelivery::new(&path.to_string_lossy(), hook, context(path));
+    update_deliveries(|all| all.push(delivery)).await.unwrap();
+
+    assert!(resume_pending().await >= 1);
+
+    wait_for(path, |d| d.is_empty() && marker.exists()).await;
+}
+
+#[tokio::test]
+async fn test_discard_removes_dead_delivery() {
+    let temp_dir = create_test_dir();
+    let path = temp_dir.path();
+    init_centy_project(path).await;
+    write_hooks(path, vec![async_hook("exit 1".to_string(), 0)]).await;
+
+    run_post_hooks(path, "issue", HookOperation::Create, &context(path)).await;
+    let dead = wait_for(path, |d| {
+        d.first().is_some_and(|d| d.status == DeliveryStatus::Dead)
+    })
+    .await;
+
+    let discarded = discard_delivery(path, &dead[0].id).await.unwrap();
+
+    assert_eq!(discarded.id, dead[0].id);
+    assert!(project_deliveries(path).await.unwrap().is_empty());
+}
@@ -0,0 +1,118 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+mod common;
+
+use centy_daemon::server::handlers::hook_runs::list_hook_runs;
+use centy_daemon::server::handlers::item_create::create_item;
+use centy_daemon::server::proto::{CreateItemRequest, ListHookRunsRequest};
+use common::{create_test_dir, init_minimal_project};
+use std::collections::HashMap;
+use tokio::fs;
+
+fn list_req(project_path: &str) -> ListHookRunsRequest {
+    ListHookRunsRequest {
+        project_path: project_path.to_string(),
+        ..Default::default()
+    }
+}
+
+async fn create(project_path: &str) -> String {
+    let resp = create_item(CreateItemRequest {
+        project_path: project_path.to_string(),
+        item_type: "issues".to_string(),
+        title: "Login fails".to_string(),
+        body: String::new(),
+        status: "open".to_string(),
+        priority: 2,
+        tags: vec![],
+        custom_fields: HashMap::new(),
+        projects: vec![],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "create failed: {}", resp.error);
+    resp.item.unwrap().id
+}
+
+#[tokio::test]
+async fn test_hook_runs_are_recorded_and_listed() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+    fs::write(
+        path.join(".centy").join("hooks.yaml"),
+        "hooks:\n  - pattern: issue.creating\n    command: echo checked\n  - pattern: issue.created\n    command: echo oops >&2; exit 3\n  - pattern: issue.created\n    command: echo urgent\n    when:\n      priority: 1\n",
+    )
+    .await
+    .unwrap();
+
+    let item_id = create(pp).await;
+
+    let resp = list_hook_runs(list_req(pp)).await.unwrap().into_inner();
+    assert!(resp.success, "list failed: {}", resp.error);
+    assert_eq!(resp.total_count, 3);
+    // Newest first: the post-hooks, then the pre-hook
+    let pre = &resp.runs[2];
+    assert_eq!(pre.pattern, "issue.creating");
+    assert_eq!(pre.phase, "pre");
+    assert_eq!(pre.outcome, "succeeded");
+    assert_eq!(pre.exit_code, Some(0));
+    assert_eq!(pre.stdout.trim(), "checked");
+    let outcomes: Vec<&str> = resp.runs[..2].iter().map(|r| r.outcome.as_str()).collect();
+    assert!(outcomes.contains(&"failed"));
+    assert!(outcomes.contains(&"skipped"));
+
+    let failed = list_hook_runs(ListHookRunsRequest {
+        outcome: "failed".to_string(),
+        item_type: "issues".to_string(),
+        item_id: item_id.clone(),
+        ..list_req(pp)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert_eq!(failed.total_count, 1);
+    let run = &failed.runs[0];
+    assert_eq!(run.exit_code, Some(3));
+    assert_eq!(run.stderr.trim(), "oops");
+    assert_eq!(run.item_id, item_id);
+    assert_eq!(run.item_type, "issue");
+    assert!(!run.timed_out);
+}
+
+#[tokio::test]
+async fn test_list_hook_runs_filters_and_limits() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+    fs::write(
+        path.join(".centy").join("hooks.yaml"),
+        "hooks:\n  - pattern: \"*.created\"\n    command: \"true\"\n",
+    )
+    .await
+    .unwrap();
+    create(pp).await;
+    create(pp).await;
+
+    let limited = list_hook_runs(ListHookRunsRequest {
+        limit: 1,
+        ..list_req(pp)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    let pre_only = list_hook_runs(ListHookRunsRequest {
+        phase: "pre".to_string(),
+        ..list_req(pp)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert_eq!(limited.runs.len(), 1);
+    assert_eq!(limited.total_count, 2);
+    assert_eq!(pre_only.total_count, 0);
+}
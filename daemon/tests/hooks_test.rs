@@ -32,6 +32,7 @@ async fn test_pre_hook_blocks_on_exit_1() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -69,6 +70,7 @@ async fn test_pre_hook_passes_on_exit_0() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -107,6 +109,7 @@ async fn test_hook_receives_env_vars() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -145,6 +148,7 @@ async fn test_hook_receives_stdin_json() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -212,6 +216,7 @@ async fn test_wildcard_matching_across_item_types() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     }];
 
     // Should match any item type with post-delete event
@@ -257,6 +262,7 @@ async fn test_specificity_ordering_verified_by_file() {
                 is_async: false,
                 timeout: 30,
                 enabled: true,
+                ..HookDefinition::default()
             },
             HookDefinition {
                 pattern: "issue.creating".to_string(),
@@ -264,6 +270,7 @@ async fn test_specificity_ordering_verified_by_file() {
                 is_async: false,
                 timeout: 30,
                 enabled: true,
+                ..HookDefinition::default()
             },
             HookDefinition {
                 pattern: "*.creating".to_string(),
@@ -271,6 +278,7 @@ async fn test_specificity_ordering_verified_by_file() {
                 is_async: false,
                 timeout: 30,
                 enabled: true,
+                ..HookDefinition::default()
             },
         ],
     )
@@ -313,6 +321,7 @@ async fn test_disabled_hooks_are_skipped() {
             is_async: false,
             timeout: 30,
             enabled: false, // Disabled
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -369,6 +378,7 @@ async fn test_post_hooks_run_after_success() {
             is_async: false,
             timeout: 30,
             enabled: true,
+            ..HookDefinition::default()
         }],
     )
     .await;
@@ -399,6 +409,10 @@ fn test_pattern_parse_all_pre_events() {
         "restoring",
         "moving",
         "duplicating",
+        "archiving",
+        "unarchiving",
+        "adding",
+        "syncing",
     ];
     for event in events {
         let pattern = format!("issue.{event}");
@@ -419,6 +433,10 @@ fn test_pattern_parse_all_post_events() {
         "restored",
         "moved",
         "duplicated",
+        "archived",
+        "unarchived",
+        "added",
+        "synced",
     ];
     for event in events {
         let pattern = format!("issue.{event}");
@@ -432,7 +450,7 @@ fn test_pattern_parse_all_post_events() {
 #[test]
 fn test_pattern_parse_all_item_types() {
     // Built-in types
-    let types = ["issue", "doc", "user", "link", "asset"];
+    let types = ["issue", "doc", "user", "link", "asset", "config"];
     for item_type in types {
         let pattern = format!("{item_type}.creating");
         assert!(
@@ -460,6 +478,7 @@ fn test_hook_definition_yaml_roundtrip() {
         is_async: false,
         timeout: 30,
         enabled: true,
+        ..HookDefinition::default()
     };
 
     let yaml = serde_yaml::to_string(&hook).unwrap();
@@ -498,6 +517,7 @@ async fn test_hooks_yaml_roundtrip() {
                 is_async: false,
                 timeout: 10,
                 enabled: true,
+                ..HookDefinition::default()
             },
             HookDefinition {
                 pattern: "*.created".to_string(),
@@ -505,6 +525,7 @@ async fn test_hooks_yaml_roundtrip() {
                 is_async: true,
                 timeout: 60,
                 enabled: false,
+                ..HookDefinition::default()
             },
         ],
     )
@@ -0,0 +1,204 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+mod common;
+
+use centy_daemon::hooks::config::{HookDefinition, HookOperation, HooksFile, Phase};
+use centy_daemon::hooks::context::HookContext;
+use centy_daemon::hooks::runner::{run_post_hooks, run_pre_hooks};
+use centy_daemon::hooks::signature::signature_header;
+use centy_daemon::hooks::HookError;
+use common::{create_test_dir, init_centy_project};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use tokio::net::TcpListener;
+
+/// A request the test server received.
+struct Received {
+    head: String,
+    body: String,
+}
+
+impl Received {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+}
+
+/// Serve one connection per `(status, body)` response, in order, and record
+/// each request.
+async fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<Received>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&received);
+    tokio::spawn(async move {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut stream).await;
+            log.lock().unwrap().push(request);
+            let response = format!(
+                "HTTP/1.1 {status} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    (url, received)
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Received {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        buf.extend_from_slice(&chunk[..n]);
+        let text = String::from_utf8_lossy(&buf).to_string();
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let received = Received {
+                head: head.to_string(),
+                body: body.to_string(),
+            };
+            let length: usize = received
+                .header("content-length")
+                .map_or(0, |v| v.parse().unwrap());
+            if received.body.len() >= length || n == 0 {
+                return received;
+            }
+        }
+    }
+}
+
+fn url_hook(pattern: &str, url: &str) -> HookDefinition {
+    HookDefinition {
+        pattern: pattern.to_string(),
+        url: Some(url.to_string()),
+        timeout: 5,
+        ..HookDefinition::default()
+    }
+}
+
+async fn write_hooks(project_path: &Path, hooks: Vec<HookDefinition>) {
+    let content = serde_yaml::to_string(&HooksFile { hooks }).unwrap();
+    tokio::fs::write(project_path.join(".centy").join("hooks.yaml"), content)
+        .await
+        .unwrap();
+}
+
+fn context(phase: Phase, project_path: &Path) -> HookContext {
+    HookContext::new(
+        phase,
+        "issue",
+        HookOperation::Create,
+        &project_path.to_string_lossy(),
+        Some("abc"),
+        Some(serde_json::json!({"title": "Login fails"})),
+        None,
+    )
+}
+
+#[tokio::test]
+async fn test_webhook_posts_signed_context() {
+    let temp_dir = create_test_dir();
+    init_centy_project(temp_dir.path()).await;
+    let (url, received) = serve(vec![(200, "")]).await;
+    std::env::set_var("CENTY_WEBHOOK_TEST_TOKEN", "t0ken");
+    let mut hook = url_hook("issue.created", &url);
+    hook.secret = Some("s3cret".to_string());
+    hook.headers.insert(
+        "Authorization".to_string(),
+        "Bearer ${CENTY_WEBHOOK_TEST_TOKEN}".to_string(),
+    );
+    write_hooks(temp_dir.path(), vec![hook]).await;
+
+    let ctx = context(Phase::Post, temp_dir.path());
+    run_post_hooks(temp_dir.path(), "issue", HookOperation::Create, &ctx).await;
+
+    let request = {
+        let mut requests = received.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        requests.remove(0)
+    };
+    assert!(request.head.starts_with("POST /hook"));
+    assert_eq!(request.body, ctx.to_json().unwrap());
+    assert_eq!(request.header("content-type"), Some("application/json"));
+    assert_eq!(request.header("authorization"), Some("Bearer t0ken"));
+    assert_eq!(
+        request.header("x-centy-signature"),
+        Some(
+            signature_header("s3cret", request.body.as_bytes())
+                .unwrap()
+                .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_pre_hook_denied_by_status() {
+    let temp_dir = create_test_dir();
+    init_centy_project(temp_dir.path()).await;
+    let (url, received) = serve(vec![(403, "title is required")]).await;
+    write_hooks(temp_dir.path(), vec![url_hook("issue.creating", &url)]).await;
+
+    let ctx = context(Phase::Pre, temp_dir.path());
+    let result = run_pre_hooks(temp_dir.path(), "issue", HookOperation::Create, &ctx).await;
+
+    let err = result.unwrap_err();
+    assert!(
+        matches!(&err, HookError::WebhookFailed { status, body, .. }
+            if *status == 403 && body == "title is required"),
+        "unexpected error: {err}"
+    );
+    // Pre-hooks are never retried
+    assert_eq!(received.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_webhook_pre_hook_response_patches_request() {
+    let temp_dir = create_test_dir();
+    init_centy_project(temp_dir.path()).await;
+    let (url, _received) = serve(vec![(200, r#"{"title": "Login fails on Safari"}"#)]).await;
+    write_hooks(temp_dir.path(), vec![url_hook("issue.creating", &url)]).await;
+
+    let ctx = context(Phase::Pre, temp_dir.path());
+    let outcome = run_pre_hooks(temp_dir.path(), "issue", HookOperation::Create, &ctx)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        outcome.request_data.unwrap()["title"],
+        "Login fails on Safari"
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_post_hook_retries_server_errors() {
+    let temp_dir = create_test_dir();
+    init_centy_project(temp_dir.path()).await;
+    let (url, received) = serve(vec![(500, ""), (503, ""), (200, "")]).await;
+    let mut hook = url_hook("issue.created", &url);
+    hook.retries = 2;
+    hook.backoff = 0;
+    write_hooks(temp_dir.path(), vec![hook]).await;
+
+    let ctx = context(Phase::Post, temp_dir.path());
+    run_post_hooks(temp_dir.path(), "issue", HookOperation::Create, &ctx).await;
+
+    assert_eq!(received.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_webhook_post_hook_does_not_retry_client_errors() {
+    let temp_dir = create_test_dir();
+    init_centy_project(temp_dir.path()).await;
+    let (url, received) = serve(vec![(400, ""), (200, "")]).await;
+    write_hooks(temp_dir.path(), vec![url_hook("issue.created", &url)]).await;
+
+    let ctx = context(Phase::Post, temp_dir.path());
+    run_post_hooks(temp_dir.path(), "issue", HookOperation::Create, &ctx).await;
+
+    assert_eq!(received.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+mod common;
+
+use centy_daemon::server::handlers::item_create::create_item;
+use centy_daemon::server::handlers::item_update::update_item;
+use centy_daemon::server::proto::{CreateItemRequest, UpdateItemRequest};
+use common::{create_test_dir, init_minimal_project};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::fs;
+
+async fn write_hooks_yaml(project_path: &Path, yaml: &str) {
+    fs::write(project_path.join(".centy").join("hooks.yaml"), yaml)
+        .await
+        .unwrap();
+}
+
+async fn create(project_path: &str, priority: i32) -> String {
+    let resp = create_item(CreateItemRequest {
+        project_path: project_path.to_string(),
+        item_type: "issues".to_string(),
+        title: "Login fails".to_string(),
+        body: String::new(),
+        status: "open".to_string(),
+        priority,
+        tags: vec![],
+        custom_fields: HashMap::new(),
+        projects: vec![],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "create failed: {}", resp.error);
+    resp.item.unwrap().id
+}
+
+fn update_req(project_path: &str, item_id: &str, title: &str, status: &str) -> UpdateItemRequest {
+    UpdateItemRequest {
+        project_path: project_path.to_string(),
+        item_type: "issues".to_string(),
+        item_id: item_id.to_string(),
+        title: title.to_string(),
+        body: String::new(),
+        status: status.to_string(),
+        priority: 0,
+        tags: vec![],
+        clear_tags: false,
+        custom_fields: HashMap::new(),
+        projects: vec![],
+    }
+}
+
+#[tokio::test]
+async fn test_post_hook_runs_only_when_status_changes_to_closed() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+    let item_id = create(pp, 2).await;
+    let marker = path.join("closed.json");
+    write_hooks_yaml(
+        path,
+        &format!(
+            "hooks:\n  - pattern: issue.updated\n    command: cat > {}\n    when:\n      status:\n        from: open\n        to: closed\n",
+            marker.display()
+        ),
+    )
+    .await;
+
+    let renamed = update_item(update_req(pp, &item_id, "Login fails on Safari", ""))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(renamed.success, "update failed: {}", renamed.error);
+    assert!(!marker.exists(), "hook ran without a status change");
+
+    let closed = update_item(update_req(pp, &item_id, "", "closed"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(closed.success, "update failed: {}", closed.error);
+    let context: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&marker).await.unwrap()).unwrap();
+    assert_eq!(context["before"]["status"], "open");
+    assert_eq!(context["after"]["status"], "closed");
+    assert_eq!(context["after"]["title"], "Login fails on Safari");
+}
+
+#[tokio::test]
+async fn test_pre_hook_blocks_only_matching_priority() {
+    let temp = create_test_dir();
+    let path = temp.path();
+    init_minimal_project(path).await;
+    let pp = path.to_str().unwrap();
+    let urgent = create(pp, 1).await;
+    let normal = create(pp, 2).await;
+    write_hooks_yaml(
+        path,
+        "hooks:\n  - pattern: issue.updating\n    command: exit 1\n    when:\n      priority: 1\n",
+    )
+    .await;
+
+    let blocked = update_item(update_req(pp, &urgent, "Renamed", ""))
+        .await
+        .unwrap()
+        .into_inner();
+    let allowed = update_item(update_req(pp, &normal, "Renamed", ""))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(!blocked.success);
+    assert!(blocked.error.contains("HOOK_PRE_FAILED"));
+    assert!(allowed.success, "update failed: {}", allowed.error);
+}
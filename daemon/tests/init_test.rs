@@ -900,6 +900,60 @@ async fn test_init_does_not_overwrite_existing_hooks_yaml() {
     );
 }
 
+#[tokio::test]
+async fn test_init_creates_gitattributes_with_merge_driver() {
+    let temp_dir = create_test_dir();
+    let project_path = temp_dir.path();
+
+    let decisions = ReconciliationDecisions::default();
+    let result = execute_reconciliation(project_path, decisions, false)
+        .await
+        .expect("Should execute reconciliation");
+
+    assert!(
+        result.created.contains(&".gitattributes".to_string()),
+        "Should report .gitattributes as created"
+    );
+    let content = fs::read_to_string(project_path.join(".centy").join(".gitattributes"))
+        .await
+        .expect("Should read .gitattributes");
+    assert!(
+        content.lines().any(|l| l == "*.md merge=centy"),
+        ".gitattributes should route item files to the merge driver"
+    );
+}
+
+#[tokio::test]
+async fn test_init_adds_merge_driver_to_existing_gitattributes() {
+    let temp_dir = create_test_dir();
+    let project_path = temp_dir.path();
+
+    let centy_path = project_path.join(".centy");
+    fs::create_dir_all(&centy_path)
+        .await
+        .expect("Should create .centy dir");
+    fs::write(centy_path.join(".gitattributes"), "*.png binary\n")
+        .await
+        .expect("Should write .gitattributes");
+
+    let decisions = ReconciliationDecisions::default();
+    execute_reconciliation(project_path, decisions, false)
+        .await
+        .expect("Should execute reconciliation");
+
+    let content = fs::read_to_string(centy_path.join(".gitattributes"))
+        .await
+        .expect("Should read .gitattributes");
+    assert!(
+        content.starts_with("*.png binary\n"),
+        "User lines should be kept"
+    );
+    assert!(
+        content.lines().any(|l| l == "*.md merge=centy"),
+        "Merge driver line should be added"
+    );
+}
+
 #[tokio::test]
 async fn test_init_config_yaml_idempotent() {
     let temp_dir = create_test_dir();
@@ -0,0 +1,158 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::generic::storage::{generic_create, generic_update};
+use centy_daemon::server::handlers::item_history::{get_item_at_revision, get_item_history};
+use centy_daemon::server::proto::{GetItemAtRevisionRequest, GetItemHistoryRequest};
+use common::{create_test_dir, init_centy_project, issue_config};
+use git2::{Commit, IndexAddOption, Repository, Signature};
+use mdstore::{CreateOptions, UpdateOptions};
+use std::collections::HashMap;
+use std::path::Path;
+
+fn commit_all(repo: &Repository, message: &str) {
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"], IndexAddOption::DEFAULT, None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Alice", "alice@example.com").unwrap();
+    let parents: Vec<Commit<'_>> = repo
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok())
+        .into_iter()
+        .collect();
+    let parent_refs: Vec<&Commit<'_>> = parents.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parent_refs,
+    )
+    .unwrap();
+}
+
+/// A committed issue whose priority was then changed in a second commit.
+async fn committed_issue(project: &Path, repo: &Repository) -> String {
+    let id = generic_create(
+        project,
+        "issues",
+        &issue_config(),
+        CreateOptions {
+            title: "Login fails".to_string(),
+            body: "Steps to reproduce".to_string(),
+            id: None,
+            status: Some("open".to_string()),
+            priority: Some(2),
+            tags: None,
+            custom_fields: HashMap::new(),
+            comment: None,
+        },
+    )
+    .await
+    .unwrap()
+    .id;
+    commit_all(repo, "Add login issue");
+    generic_update(
+        project,
+        "issues",
+        &issue_config(),
+        &id,
+        UpdateOptions {
+            priority: Some(1),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    commit_all(repo, "Raise login priority");
+    id
+}
+
+#[tokio::test]
+async fn test_get_item_history_returns_field_changes() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    let repo = Repository::init(project).unwrap();
+    init_centy_project(project).await;
+    let id = committed_issue(project, &repo).await;
+
+    let resp = get_item_history(GetItemHistoryRequest {
+        project_path: project.to_string_lossy().to_string(),
+        item_type: "issue".to_string(),
+        item_id: id,
+        limit: 0,
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(resp.revisions.len(), 2);
+    let latest = &resp.revisions[0];
+    assert_eq!(latest.message, "Raise login priority");
+    assert_eq!(latest.author_name, "Alice");
+    assert_eq!(latest.change_kind, "modified");
+    assert!(latest
+        .changes
+        .iter()
+        .any(|c| c.field == "priority" && c.before == "2" && c.after == "1"));
+    assert_eq!(resp.revisions[1].change_kind, "added");
+}
+
+#[tokio::test]
+async fn test_get_item_at_revision_returns_older_version() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    let repo = Repository::init(project).unwrap();
+    init_centy_project(project).await;
+    let id = committed_issue(project, &repo).await;
+
+    let resp = get_item_at_revision(GetItemAtRevisionRequest {
+        project_path: project.to_string_lossy().to_string(),
+        item_type: "issue".to_string(),
+        item_id: id.clone(),
+        revision: "HEAD~1".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    let item = resp.item.unwrap();
+    assert_eq!(item.id, id);
+    assert_eq!(item.title, "Login fails");
+    assert_eq!(item.metadata.unwrap().priority, 2);
+    assert!(resp.path.ends_with(&format!("issues/{id}.md")));
+}
+
+#[tokio::test]
+async fn test_get_item_history_outside_git_fails() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let resp = get_item_history(GetItemHistoryRequest {
+        project_path: project.to_string_lossy().to_string(),
+        item_type: "issue".to_string(),
+        item_id: "missing".to_string(),
+        limit: 0,
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.error.contains("NOT_GIT_REPOSITORY"));
+}
@@ -0,0 +1,175 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::registry::{create_organization, set_project_organization};
+use centy_daemon::server::handlers::item_list_across::list_items_across_projects;
+use centy_daemon::server::proto::{
+    ListItemsAcrossProjectsRequest, ListItemsAcrossProjectsResponse,
+};
+use common::{create_issue, create_test_dir, init_centy_project, set_issue_priority};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// A fresh organization, so tests sharing the registry do not see each
+/// other's projects.
+async fn new_org() -> String {
+    let slug = format!("across-{}", uuid::Uuid::new_v4().simple());
+    create_organization(Some(&slug), &slug, None).await.unwrap();
+    slug
+}
+
+/// An initialized project registered under `org_slug`.
+async fn org_project(org_slug: &str) -> (TempDir, PathBuf) {
+    let temp = create_test_dir();
+    let path = temp.path().canonicalize().unwrap();
+    init_centy_project(&path).await;
+    set_project_organization(path.to_str().unwrap(), Some(org_slug))
+        .await
+        .unwrap();
+    (temp, path)
+}
+
+fn request(org_slug: &str) -> ListItemsAcrossProjectsRequest {
+    ListItemsAcrossProjectsRequest {
+        item_type: "issues".to_string(),
+        organization_slug: org_slug.to_string(),
+        ..Default::default()
+    }
+}
+
+async fn list(req: ListItemsAcrossProjectsRequest) -> ListItemsAcrossProjectsResponse {
+    let resp = list_items_across_projects(req).await.unwrap().into_inner();
+    assert!(resp.success, "list failed: {}", resp.error);
+    resp
+}
+
+fn titles(resp: &ListItemsAcrossProjectsResponse) -> Vec<&str> {
+    resp.items
+        .iter()
+        .map(|i| i.item.as_ref().unwrap().title.as_str())
+        .collect()
+}
+
+fn projects(resp: &ListItemsAcrossProjectsResponse) -> Vec<&str> {
+    resp.items.iter().map(|i| i.project_path.as_str()).collect()
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+#[tokio::test]
+async fn test_lists_items_of_org_projects_newest_first() {
+    let org = new_org().await;
+    let other_org = new_org().await;
+    let (_a_dir, a) = org_project(&org).await;
+    let (_b_dir, b) = org_project(&org).await;
+    let (_c_dir, c) = org_project(&other_org).await;
+    create_issue(&a, "A1").await;
+    create_issue(&b, "B1").await;
+    create_issue(&c, "C1").await;
+    create_issue(&a, "A2").await;
+
+    let resp = list(request(&org)).await;
+
+    assert_eq!(titles(&resp), vec!["A2", "B1", "A1"]);
+    assert_eq!(
+        projects(&resp),
+        vec![path_str(&a), path_str(&b), path_str(&a)]
+    );
+    assert_eq!(resp.total_count, 3);
+    assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+
+    let resp = list(request(&other_org)).await;
+    assert_eq!(titles(&resp), vec!["C1"]);
+}
+
+#[tokio::test]
+async fn test_filter_applies_in_every_project() {
+    let org = new_org().await;
+    let (_a_dir, a) = org_project(&org).await;
+    let (_b_dir, b) = org_project(&org).await;
+    let urgent_a = create_issue(&a, "Urgent A").await;
+    create_issue(&a, "Later A").await;
+    let urgent_b = create_issue(&b, "Urgent B").await;
+    set_issue_priority(&a, &urgent_a, 1).await;
+    set_issue_priority(&b, &urgent_b, 1).await;
+
+    let resp = list(ListItemsAcrossProjectsRequest {
+        filter: r#"{"priority":1}"#.to_string(),
+        ..request(&org)
+    })
+    .await;
+
+    assert_eq!(titles(&resp), vec!["Urgent B", "Urgent A"]);
+    assert_eq!(resp.total_count, 2);
+}
+
+#[tokio::test]
+async fn test_paginates_after_merging_projects() {
+    let org = new_org().await;
+    let (_a_dir, a) = org_project(&org).await;
+    let (_b_dir, b) = org_project(&org).await;
+    for (project, title) in [(&a, "1"), (&b, "2"), (&a, "3"), (&b, "4")] {
+        create_issue(project, title).await;
+    }
+
+    let resp = list(ListItemsAcrossProjectsRequest {
+        limit: 2,
+        offset: 1,
+        ..request(&org)
+    })
+    .await;
+
+    assert_eq!(titles(&resp), vec!["3", "2"]);
+    assert_eq!(resp.total_count, 4);
+}
+
+#[tokio::test]
+async fn test_project_that_fails_to_list_is_reported_in_errors() {
+    let org = new_org().await;
+    let (_good_dir, good) = org_project(&org).await;
+    let (_bad_dir, bad) = org_project(&org).await;
+    create_issue(&good, "Listed").await;
+    // A file where the issues folder should be, so listing it fails
+    let issues = bad.join(".centy").join("issues");
+    tokio::fs::remove_dir_all(&issues).await.unwrap();
+    tokio::fs::write(&issues, "not a folder").await.unwrap();
+
+    let resp = list(request(&org)).await;
+
+    assert_eq!(titles(&resp), vec!["Listed"]);
+    assert_eq!(resp.total_count, 1);
+    assert_eq!(resp.errors.len(), 1, "{:?}", resp.errors);
+    assert!(
+        resp.errors[0].starts_with(path_str(&bad)),
+        "{}",
+        resp.errors[0]
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_filter_fails_the_request() {
+    let org = new_org().await;
+    let (_dir, _project) = org_project(&org).await;
+
+    let resp = list_items_across_projects(ListItemsAcrossProjectsRequest {
+        filter: r#"{"priority":{"$bogus":1}}"#.to_string(),
+        ..request(&org)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.items.is_empty());
+}
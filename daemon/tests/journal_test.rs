@@ -0,0 +1,144 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::generic::storage::{generic_delete, generic_restore};
+use centy_daemon::journal::{read_entries, Operation};
+use centy_daemon::server::handlers::journal::list_journal_entries;
+use centy_daemon::server::proto::ListJournalEntriesRequest;
+use common::{create_issue, create_test_dir, init_centy_project, issue_config, set_issue_priority};
+use std::path::Path;
+
+fn request(project: &Path) -> ListJournalEntriesRequest {
+    ListJournalEntriesRequest {
+        project_path: project.to_string_lossy().to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_mutations_are_journaled_with_diffs() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let id = create_issue(project, "Audit me").await;
+    set_issue_priority(project, &id, 1).await;
+    generic_delete(project, "issues", &issue_config(), &id, false)
+        .await
+        .unwrap();
+    generic_restore(project, "issues", &id).await.unwrap();
+
+    let entries = read_entries(project).await.unwrap();
+    let operations: Vec<_> = entries.iter().map(|e| e.operation).collect();
+    assert_eq!(
+        operations,
+        vec![
+            Operation::Create,
+            Operation::Update,
+            Operation::SoftDelete,
+            Operation::Restore
+        ]
+    );
+    let update = &entries[1];
+    assert_eq!(update.item_type, "issues");
+    assert_eq!(update.item_id, id);
+    assert!(!update.actor.is_empty());
+    assert_eq!(update.changes.len(), 1);
+    assert_eq!(update.changes[0].field, "priority");
+    assert_eq!(update.changes[0].before, Some(serde_json::json!(2u32)));
+    assert_eq!(update.changes[0].after, Some(serde_json::json!(1u32)));
+}
+
+#[tokio::test]
+async fn test_update_without_changes_is_not_journaled() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let id = create_issue(project, "Same").await;
+    set_issue_priority(project, &id, 2).await;
+
+    assert_eq!(read_entries(project).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_journal_entries_filters_by_item() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let first = create_issue(project, "First").await;
+    let second = create_issue(project, "Second").await;
+    set_issue_priority(project, &first, 1).await;
+
+    let resp = list_journal_entries(ListJournalEntriesRequest {
+        item_type: "issue".to_string(),
+        item_id: first.clone(),
+        ..request(project)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(resp.total_count, 2);
+    assert_eq!(resp.entries[0].operation, "update");
+    assert_eq!(resp.entries[0].changes[0].before, "2");
+    assert_eq!(resp.entries[1].operation, "create");
+    assert!(resp.entries.iter().all(|e| e.item_id != second));
+}
+
+#[tokio::test]
+async fn test_list_journal_entries_time_range_and_limit() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    create_issue(project, "One").await;
+    create_issue(project, "Two").await;
+
+    let recent = list_journal_entries(ListJournalEntriesRequest {
+        since: "-1h".to_string(),
+        limit: 1,
+        ..request(project)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(recent.success, "{}", recent.error);
+    assert_eq!(recent.total_count, 2);
+    assert_eq!(recent.entries.len(), 1);
+
+    let old = list_journal_entries(ListJournalEntriesRequest {
+        until: "2000-01-01".to_string(),
+        ..request(project)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert_eq!(old.total_count, 0);
+}
+
+#[tokio::test]
+async fn test_list_journal_entries_rejects_bad_bound() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let resp = list_journal_entries(ListJournalEntriesRequest {
+        since: "yesterday-ish".to_string(),
+        ..request(project)
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_TIME_RANGE"));
+}
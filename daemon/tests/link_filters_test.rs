@@ -0,0 +1,113 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::entities::issue::{create_issue, CreateIssueOptions};
+use centy_daemon::link::{create_link, CreateLinkOptions, TargetType};
+use centy_daemon::server::handlers::item_list::list_items;
+use centy_daemon::server::proto::ListItemsRequest;
+use common::{create_test_dir, init_centy_project};
+use std::path::Path;
+
+async fn issue(project: &Path, title: &str, status: &str) -> String {
+    create_issue(
+        project,
+        CreateIssueOptions {
+            title: title.to_string(),
+            status: Some(status.to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+async fn link(project: &Path, source: &str, link_type: &str, target: &str) {
+    create_link(
+        project,
+        CreateLinkOptions {
+            source_id: source.to_string(),
+            source_type: TargetType::issue(),
+            target_id: target.to_string(),
+            target_type: TargetType::issue(),
+            link_type: link_type.to_string(),
+        },
+        &[],
+    )
+    .await
+    .unwrap();
+}
+
+async fn titles(project: &Path, filter: &str) -> Vec<String> {
+    let resp = list_items(ListItemsRequest {
+        project_path: project.to_str().unwrap().to_string(),
+        item_type: "issues".to_string(),
+        filter: filter.to_string(),
+        sort: "title".to_string(),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "{}", resp.error);
+    resp.items.into_iter().map(|i| i.title).collect()
+}
+
+#[tokio::test]
+async fn test_list_items_by_links() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let epic = issue(project, "Epic", "open").await;
+    let api = issue(project, "API", "in-progress").await;
+    let ui = issue(project, "UI", "open").await;
+    let spike = issue(project, "Spike", "closed").await;
+    issue(project, "Orphan", "open").await;
+    link(project, &epic, "parent-of", &api).await;
+    link(project, &ui, "child-of", &epic).await;
+    link(project, &api, "blocks", &ui).await;
+    link(project, &spike, "blocks", &api).await;
+
+    let children = format!(r#"{{"$linked":{{"type":"child-of","to":"{epic}"}}}}"#);
+    assert_eq!(titles(project, &children).await, vec!["API", "UI"]);
+    assert_eq!(
+        titles(project, r#"{"hasLinks":false}"#).await,
+        vec!["Orphan"]
+    );
+    // UI waits on the unfinished API; API's only blocker is closed.
+    assert_eq!(titles(project, r#"{"$blocked":true}"#).await, vec!["UI"]);
+    let unblocked = r#"{"$blocked":false,"status":{"$ne":"closed"}}"#;
+    assert_eq!(
+        titles(project, unblocked).await,
+        vec!["API", "Epic", "Orphan"]
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_link_filter_is_rejected() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let resp = list_items(ListItemsRequest {
+        project_path: project.to_str().unwrap().to_string(),
+        item_type: "issues".to_string(),
+        filter: r#"{"$linked":{"type":"blocks","from":"x"}}"#.to_string(),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_FILTER"));
+}
@@ -0,0 +1,240 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::config::item_type_config::default_issue_config;
+use centy_daemon::config::CentyConfig;
+use centy_daemon::item::generic::storage::generic_create;
+use centy_daemon::server::handlers::item_list::list_items;
+use centy_daemon::server::handlers::view::{get_view, list_views};
+use centy_daemon::server::handlers::view_write::{create_view, delete_view, update_view};
+use centy_daemon::server::proto::{
+    CreateViewRequest, DeleteViewRequest, GetViewRequest, ListItemsRequest, ListViewsRequest,
+    UpdateViewRequest,
+};
+use common::{create_test_dir, init_centy_project};
+use mdstore::{CreateOptions, TypeConfig};
+use std::collections::HashMap;
+use std::path::Path;
+
+async fn create_issue(project: &Path, title: &str, status: &str, priority: u32) {
+    let config = TypeConfig::from(&default_issue_config(&CentyConfig::default()));
+    generic_create(
+        project,
+        "issues",
+        &config,
+        CreateOptions {
+            title: title.to_string(),
+            body: String::new(),
+            id: None,
+            status: Some(status.to_string()),
+            priority: Some(priority),
+            tags: None,
+            custom_fields: HashMap::new(),
+            comment: None,
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn titles_in_view(project: &str, view: &str, filter: &str) -> Vec<String> {
+    let resp = list_items(ListItemsRequest {
+        project_path: project.to_string(),
+        item_type: String::new(),
+        filter: filter.to_string(),
+        limit: 0,
+        offset: 0,
+        include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: view.to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "list failed: {}", resp.error);
+    resp.items.into_iter().map(|i| i.title).collect()
+}
+
+#[tokio::test]
+async fn test_init_seeds_default_views() {
+    let temp = create_test_dir();
+    init_centy_project(temp.path()).await;
+    let pp = temp.path().to_str().unwrap();
+
+    let resp = list_views(ListViewsRequest {
+        project_path: pp.to_string(),
+        item_type: String::new(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "list views failed: {}", resp.error);
+    let ids: Vec<&str> = resp.views.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(ids, vec!["high-priority", "open-issues"]);
+
+    let resp = get_view(GetViewRequest {
+        project_path: pp.to_string(),
+        view: "Open issues".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "get view failed: {}", resp.error);
+    assert_eq!(resp.view.unwrap().filter, r#"{"status":"open"}"#);
+}
+
+#[tokio::test]
+async fn test_list_items_runs_view_by_name() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+    let pp = project.to_str().unwrap();
+    create_issue(project, "Open low", "open", 3).await;
+    create_issue(project, "Open urgent", "open", 1).await;
+    create_issue(project, "Closed urgent", "closed", 1).await;
+    create_issue(project, "Planned urgent", "planning", 1).await;
+
+    // Default view sort is priority first
+    assert_eq!(
+        titles_in_view(pp, "open-issues", "").await,
+        vec!["Open urgent", "Open low"]
+    );
+    let mut high = titles_in_view(pp, "High priority", "").await;
+    high.sort();
+    assert_eq!(high, vec!["Open urgent", "Planned urgent"]);
+    // A request filter narrows the view
+    assert_eq!(
+        titles_in_view(pp, "high-priority", r#"{"status":"planning"}"#).await,
+        vec!["Planned urgent"]
+    );
+
+    let resp = list_items(ListItemsRequest {
+        project_path: pp.to_string(),
+        item_type: String::new(),
+        filter: String::new(),
+        limit: 0,
+        offset: 0,
+        include_organization_items: None,
+        sort: String::new(),
+        cursor: String::new(),
+        view: "no-such-view".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("VIEW_NOT_FOUND"), "{}", resp.error);
+}
+
+#[tokio::test]
+async fn test_view_create_update_delete() {
+    let temp = create_test_dir();
+    init_centy_project(temp.path()).await;
+    let pp = temp.path().to_str().unwrap();
+
+    let resp = create_view(CreateViewRequest {
+        project_path: pp.to_string(),
+        id: String::new(),
+        name: "Closed Work".to_string(),
+        description: String::new(),
+        item_type: "issues".to_string(),
+        filter: r#"{"status":"closed"}"#.to_string(),
+        sort: "-updatedAt".to_string(),
+        fields: vec!["title".to_string()],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "create failed: {}", resp.error);
+    assert_eq!(resp.view.unwrap().id, "closed-work");
+    assert!(temp.path().join(".centy/views/closed-work.yaml").is_file());
+
+    let resp = create_view(CreateViewRequest {
+        project_path: pp.to_string(),
+        id: "broken".to_string(),
+        name: "Broken".to_string(),
+        description: String::new(),
+        item_type: "issues".to_string(),
+        filter: r#"{"status":{"$nope":1}}"#.to_string(),
+        sort: String::new(),
+        fields: vec![],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_FILTER"), "{}", resp.error);
+
+    let resp = update_view(UpdateViewRequest {
+        project_path: pp.to_string(),
+        view_id: "closed-work".to_string(),
+        name: "Closed work".to_string(),
+        description: "Done items".to_string(),
+        item_type: "issues".to_string(),
+        filter: String::new(),
+        sort: String::new(),
+        fields: vec![],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "update failed: {}", resp.error);
+    let view = resp.view.unwrap();
+    assert_eq!(view.description, "Done items");
+    assert!(view.filter.is_empty());
+
+    let resp = delete_view(DeleteViewRequest {
+        project_path: pp.to_string(),
+        view_id: "closed-work".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "delete failed: {}", resp.error);
+    let resp = get_view(GetViewRequest {
+        project_path: pp.to_string(),
+        view: "closed-work".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+}
+
+#[tokio::test]
+async fn test_view_ids_outside_views_dir_are_rejected() {
+    let temp = create_test_dir();
+    init_centy_project(temp.path()).await;
+    let pp = temp.path().to_str().unwrap();
+    let config = temp.path().join(".centy/issues/config.yaml");
+
+    let resp = delete_view(DeleteViewRequest {
+        project_path: pp.to_string(),
+        view_id: "../issues/config".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_VIEW_ID"), "{}", resp.error);
+    assert!(config.is_file());
+
+    let resp = get_view(GetViewRequest {
+        project_path: pp.to_string(),
+        view: "../../x".to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(!resp.success);
+}
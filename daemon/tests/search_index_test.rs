@@ -0,0 +1,136 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::generic::storage::{
+    generic_create, generic_delete, generic_move, generic_restore, generic_soft_delete,
+    generic_update,
+};
+use centy_daemon::search::{rebuild_index, search_project, SearchOptions};
+use common::{create_test_dir, init_centy_project, issue_config};
+use mdstore::{CreateOptions, UpdateOptions};
+use std::collections::HashMap;
+use std::path::Path;
+
+fn create_options(title: &str, body: &str) -> CreateOptions {
+    CreateOptions {
+        title: title.to_string(),
+        body: body.to_string(),
+        id: None,
+        status: Some("open".to_string()),
+        priority: Some(2),
+        tags: Some(vec!["backend".to_string()]),
+        custom_fields: HashMap::new(),
+        comment: None,
+    }
+}
+
+async fn hit_ids(project_path: &Path, query: &str) -> Vec<String> {
+    search_project(project_path, query, &SearchOptions::default())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|m| m.id)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_index_follows_create_update_and_delete() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+    let config = issue_config();
+
+    let item = generic_create(
+        project,
+        "issues",
+        &config,
+        create_options("Login timeout", "Sessions expire after a minute"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(hit_ids(project, "sessions").await, vec![item.id.clone()]);
+
+    let update = UpdateOptions {
+        title: Some("Checkout crash".to_string()),
+        ..Default::default()
+    };
+    generic_update(project, "issues", &config, &item.id, update)
+        .await
+        .unwrap();
+    assert!(hit_ids(project, "login").await.is_empty());
+    assert_eq!(hit_ids(project, "checkout").await, vec![item.id.clone()]);
+
+    generic_soft_delete(project, "issues", &item.id)
+        .await
+        .unwrap();
+    assert!(hit_ids(project, "checkout").await.is_empty());
+    generic_restore(project, "issues", &item.id).await.unwrap();
+    assert_eq!(hit_ids(project, "checkout").await, vec![item.id.clone()]);
+
+    generic_delete(project, "issues", &config, &item.id, true)
+        .await
+        .unwrap();
+    assert!(hit_ids(project, "checkout").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_index_follows_move_between_projects() {
+    let source_dir = create_test_dir();
+    let target_dir = create_test_dir();
+    init_centy_project(source_dir.path()).await;
+    init_centy_project(target_dir.path()).await;
+    let config = issue_config();
+
+    let item = generic_create(
+        source_dir.path(),
+        "issues",
+        &config,
+        create_options("Flaky migration", ""),
+    )
+    .await
+    .unwrap();
+    generic_move(
+        source_dir.path(),
+        target_dir.path(),
+        "issues",
+        "issues",
+        &config,
+        &config,
+        &item.id,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(hit_ids(source_dir.path(), "flaky").await.is_empty());
+    assert_eq!(hit_ids(target_dir.path(), "flaky").await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_rebuild_picks_up_items_written_outside_the_daemon() {
+    let temp = create_test_dir();
+    let project = temp.path();
+    init_centy_project(project).await;
+    let config = issue_config();
+    generic_create(project, "issues", &config, create_options("Indexed", ""))
+        .await
+        .unwrap();
+
+    let type_dir = project.join(".centy").join("issues");
+    mdstore::create(&type_dir, &config, create_options("Handwritten note", ""))
+        .await
+        .unwrap();
+    assert!(hit_ids(project, "handwritten").await.is_empty());
+
+    rebuild_index(project).await.unwrap();
+    assert_eq!(hit_ids(project, "handwritten").await.len(), 1);
+}
@@ -0,0 +1,282 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::generic::storage::{generic_get, generic_update};
+use centy_daemon::server::handlers::sync::{
+    get_sync_status, list_sync_conflicts, resolve_sync_conflict, sync_pull, sync_push,
+};
+use centy_daemon::server::proto::{
+    GetSyncStatusRequest, ListSyncConflictsRequest, ResolveSyncConflictRequest,
+    SyncFieldResolution, SyncMode, SyncPullRequest, SyncPushRequest,
+};
+use common::{create_issue, create_test_dir, init_centy_project, issue_config};
+use git2::Repository;
+use mdstore::UpdateOptions;
+use std::path::Path;
+
+fn path_string(project: &Path) -> String {
+    project.to_string_lossy().to_string()
+}
+
+/// A git repository with `origin` pointing at `remote`.
+fn init_clone(project: &Path, remote: &Path) {
+    let repo = Repository::init(project).unwrap();
+    repo.remote("origin", &remote.to_string_lossy()).unwrap();
+}
+
+/// Copy a `.centy` directory, as if it had been checked out from the main
+/// branch.
+fn copy_centy(from: &Path, to: &Path) {
+    for entry in walkdir::WalkDir::new(from.join(".centy")) {
+        let entry = entry.unwrap();
+        let target = to.join(entry.path().strip_prefix(from).unwrap());
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &target).unwrap();
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sync_push_and_pull_between_clones() {
+    let remote_dir = create_test_dir();
+    Repository::init_bare(remote_dir.path()).unwrap();
+    let alice = create_test_dir();
+    let bob = create_test_dir();
+    init_clone(alice.path(), remote_dir.path());
+    init_clone(bob.path(), remote_dir.path());
+    init_centy_project(alice.path()).await;
+    copy_centy(alice.path(), bob.path());
+    let id = create_issue(alice.path(), "Shared issue").await;
+
+    let pushed = sync_push(SyncPushRequest {
+        project_path: path_string(alice.path()),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    let pulled = sync_pull(SyncPullRequest {
+        project_path: path_string(bob.path()),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(pushed.success, "{}", pushed.error);
+    assert!(pushed.had_changes);
+    assert!(pulled.success, "{}", pulled.error);
+    assert!(pulled.had_changes);
+    assert!(pulled.conflict_files.is_empty());
+    let item = generic_get(bob.path(), "issues", &id).await.unwrap();
+    assert_eq!(item.title, "Shared issue");
+}
+
+async fn update_issue(project: &Path, id: &str, options: UpdateOptions) {
+    generic_update(project, "issues", &issue_config(), id, options)
+        .await
+        .unwrap();
+}
+
+async fn push_project(project: &Path) {
+    let resp = sync_push(SyncPushRequest {
+        project_path: path_string(project),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    assert!(resp.success, "{}", resp.error);
+}
+
+#[tokio::test]
+async fn test_sync_conflict_is_listed_and_resolved() {
+    let remote_dir = create_test_dir();
+    Repository::init_bare(remote_dir.path()).unwrap();
+    let alice = create_test_dir();
+    let bob = create_test_dir();
+    init_clone(alice.path(), remote_dir.path());
+    init_clone(bob.path(), remote_dir.path());
+    init_centy_project(alice.path()).await;
+    copy_centy(alice.path(), bob.path());
+    let id = create_issue(alice.path(), "Login").await;
+    push_project(alice.path()).await;
+    sync_pull(SyncPullRequest {
+        project_path: path_string(bob.path()),
+    })
+    .await
+    .unwrap();
+    update_issue(
+        alice.path(),
+        &id,
+        UpdateOptions {
+            status: Some("closed".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    push_project(alice.path()).await;
+    update_issue(
+        bob.path(),
+        &id,
+        UpdateOptions {
+            title: Some("Login page".to_string()),
+            status: Some("in-progress".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let pulled = sync_pull(SyncPullRequest {
+        project_path: path_string(bob.path()),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    let listed = list_sync_conflicts(&ListSyncConflictsRequest {
+        project_path: path_string(bob.path()),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+    let conflict = &listed.conflicts[0];
+    let resolved = resolve_sync_conflict(ResolveSyncConflictRequest {
+        project_path: path_string(bob.path()),
+        conflict_id: conflict.id.clone(),
+        resolutions: vec![SyncFieldResolution {
+            field: "status".to_string(),
+            take: "theirs".to_string(),
+            value: String::new(),
+        }],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(pulled.success, "{}", pulled.error);
+    assert_eq!(pulled.conflict_files.len(), 1);
+    assert!(listed.success, "{}", listed.error);
+    assert_eq!(conflict.item_type, "issues");
+    assert_eq!(conflict.item_id, id);
+    assert_eq!(conflict.fields.len(), 1);
+    assert_eq!(conflict.fields[0].field, "status");
+    assert_eq!(conflict.fields[0].ours, "\"in-progress\"");
+    assert_eq!(conflict.fields[0].theirs, "\"closed\"");
+    assert!(resolved.success, "{}", resolved.error);
+    assert!(resolved.conflict.is_none());
+    let item = generic_get(bob.path(), "issues", &id).await.unwrap();
+    assert_eq!(item.title, "Login page");
+    assert_eq!(item.frontmatter.status, Some("closed".to_string()));
+}
+
+#[tokio::test]
+async fn test_resolve_sync_conflict_rejects_unknown_choice() {
+    let project_dir = create_test_dir();
+    let project = project_dir.path();
+    Repository::init(project).unwrap();
+    init_centy_project(project).await;
+
+    let resp = resolve_sync_conflict(ResolveSyncConflictRequest {
+        project_path: path_string(project),
+        conflict_id: "missing".to_string(),
+        resolutions: vec![SyncFieldResolution {
+            field: "status".to_string(),
+            take: "mine".to_string(),
+            value: String::new(),
+        }],
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.error.contains("INVALID_RESOLUTION"));
+}
+
+#[tokio::test]
+async fn test_get_sync_status_reports_pending_changes() {
+    let remote_dir = create_test_dir();
+    Repository::init_bare(remote_dir.path()).unwrap();
+    let project_dir = create_test_dir();
+    let project = project_dir.path();
+    init_clone(project, remote_dir.path());
+    init_centy_project(project).await;
+    let request = GetSyncStatusRequest {
+        project_path: path_string(project),
+    };
+
+    let before = get_sync_status(&request).await.unwrap().into_inner();
+    sync_push(SyncPushRequest {
+        project_path: path_string(project),
+    })
+    .await
+    .unwrap();
+    let after = get_sync_status(&request).await.unwrap().into_inner();
+
+    assert!(before.success, "{}", before.error);
+    assert_eq!(before.mode, SyncMode::Full as i32);
+    assert!(before.has_pending_changes);
+    assert!(before.last_sync_time.is_empty());
+    assert!(!after.has_pending_changes);
+    assert!(!after.has_pending_push);
+    assert!(!after.last_sync_time.is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_pull_without_remote_fails() {
+    let project_dir = create_test_dir();
+    let project = project_dir.path();
+    Repository::init(project).unwrap();
+    init_centy_project(project).await;
+
+    let resp = sync_pull(SyncPullRequest {
+        project_path: path_string(project),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.error.contains("REMOTE_NOT_FOUND"));
+}
+
+#[tokio::test]
+async fn test_sync_pull_renumbers_issues_created_on_both_sides() {
+    let remote_dir = create_test_dir();
+    Repository::init_bare(remote_dir.path()).unwrap();
+    let alice = create_test_dir();
+    let bob = create_test_dir();
+    init_clone(alice.path(), remote_dir.path());
+    init_clone(bob.path(), remote_dir.path());
+    init_centy_project(alice.path()).await;
+    copy_centy(alice.path(), bob.path());
+    let from_alice = create_issue(alice.path(), "From Alice").await;
+    let from_bob = create_issue(bob.path(), "From Bob").await;
+    push_project(alice.path()).await;
+
+    let pulled = sync_pull(SyncPullRequest {
+        project_path: path_string(bob.path()),
+    })
+    .await
+    .unwrap()
+    .into_inner();
+
+    assert!(pulled.success, "{}", pulled.error);
+    let alice_issue = generic_get(bob.path(), "issues", &from_alice)
+        .await
+        .unwrap();
+    let bob_issue = generic_get(bob.path(), "issues", &from_bob).await.unwrap();
+    assert!(alice_issue.frontmatter.display_number.is_some());
+    assert_ne!(
+        alice_issue.frontmatter.display_number,
+        bob_issue.frontmatter.display_number
+    );
+}
@@ -0,0 +1,124 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::generic::storage::{generic_delete, generic_get};
+use centy_daemon::link::{create_link, list_all_links, CreateLinkOptions, TargetType};
+use centy_daemon::server::handlers::undo::undo;
+use centy_daemon::server::proto::UndoRequest;
+use common::{create_issue, create_test_dir, init_centy_project, issue_config, set_issue_priority};
+use std::path::Path;
+
+fn request(project: &Path, count: u32) -> UndoRequest {
+    UndoRequest {
+        project_path: project.to_string_lossy().to_string(),
+        count,
+    }
+}
+
+#[tokio::test]
+async fn test_undo_reverts_last_updates() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let id = create_issue(project, "Undo me").await;
+    set_issue_priority(project, &id, 1).await;
+    set_issue_priority(project, &id, 3).await;
+
+    let resp = undo(request(project, 2)).await.unwrap().into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(resp.undone.len(), 2);
+    assert_eq!(resp.undone[0].operation, "update");
+    assert_eq!(resp.undone[0].item_id, id);
+    let item = generic_get(project, "issues", &id).await.unwrap();
+    assert_eq!(item.frontmatter.priority, Some(2));
+}
+
+#[tokio::test]
+async fn test_undo_force_delete_restores_item_and_links() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let parent = create_issue(project, "Parent").await;
+    let child = create_issue(project, "Child").await;
+    create_link(
+        project,
+        CreateLinkOptions {
+            source_id: parent.clone(),
+            source_type: TargetType::issue(),
+            target_id: child.clone(),
+            target_type: TargetType::issue(),
+            link_type: "parent-of".to_string(),
+        },
+        &[],
+    )
+    .await
+    .unwrap();
+    generic_delete(project, "issues", &issue_config(), &child, true)
+        .await
+        .unwrap();
+    assert!(list_all_links(project).await.unwrap().is_empty());
+
+    let resp = undo(request(project, 1)).await.unwrap().into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(resp.undone[0].operation, "delete");
+    assert_eq!(
+        generic_get(project, "issues", &child).await.unwrap().title,
+        "Child"
+    );
+    assert_eq!(list_all_links(project).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_undo_create_removes_item() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let id = create_issue(project, "Oops").await;
+
+    let resp = undo(request(project, 0)).await.unwrap().into_inner();
+
+    assert!(resp.success, "{}", resp.error);
+    assert_eq!(resp.undone[0].operation, "create");
+    assert!(generic_get(project, "issues", &id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_undo_refuses_after_outside_edit() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let id = create_issue(project, "Edited").await;
+    set_issue_priority(project, &id, 1).await;
+    let file = project.join(".centy/issues").join(format!("{id}.md"));
+    let edited = std::fs::read_to_string(&file).unwrap() + "\nEdited by hand\n";
+    std::fs::write(&file, &edited).unwrap();
+
+    let resp = undo(request(project, 1)).await.unwrap().into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.error.contains("UNDO_CONFLICT"));
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), edited);
+}
+
+#[tokio::test]
+async fn test_undo_with_empty_log() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let resp = undo(request(project, 1)).await.unwrap().into_inner();
+
+    assert!(!resp.success);
+    assert!(resp.error.contains("NOTHING_TO_UNDO"));
+}
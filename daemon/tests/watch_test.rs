@@ -0,0 +1,144 @@
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing
+)]
+
+mod common;
+
+use centy_daemon::item::entities::issue::{
+    create_issue, update_issue, CreateIssueOptions, UpdateIssueOptions,
+};
+use centy_daemon::server::handlers::watch::{watch, WatchStream};
+use centy_daemon::server::proto::{WatchEvent, WatchRequest};
+use common::{create_test_dir, init_centy_project};
+use futures::StreamExt as _;
+use std::path::Path;
+use std::time::Duration;
+
+async fn open_stream(project: &Path, item_type: &str) -> WatchStream {
+    watch(WatchRequest {
+        project_path: project.to_string_lossy().to_string(),
+        item_type: item_type.to_string(),
+    })
+    .await
+    .unwrap()
+    .into_inner()
+}
+
+async fn next_event(stream: &mut WatchStream) -> WatchEvent {
+    tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("no watch event within 5s")
+        .unwrap()
+        .unwrap()
+}
+
+async fn new_issue(project: &Path, title: &str) -> String {
+    create_issue(
+        project,
+        CreateIssueOptions {
+            title: title.to_string(),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+#[tokio::test]
+async fn test_watch_reports_created_issue_once() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let mut stream = open_stream(project, "issues").await;
+
+    let id = new_issue(project, "Watched").await;
+
+    // The daemon write and the file it produces arrive as one event.
+    let event = next_event(&mut stream).await;
+    assert_eq!(event.kind, "created");
+    assert_eq!(event.entity, "item");
+    assert_eq!(event.item_type, "issues");
+    assert_eq!(event.id, id);
+    let extra = tokio::time::timeout(Duration::from_millis(600), stream.next()).await;
+    assert!(extra.is_err(), "unexpected second event: {extra:?}");
+}
+
+#[tokio::test]
+async fn test_watch_merges_create_and_update() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let mut stream = open_stream(project, "").await;
+
+    let id = new_issue(project, "Draft").await;
+    update_issue(
+        project,
+        &id,
+        UpdateIssueOptions {
+            title: Some("Final".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let event = next_event(&mut stream).await;
+    assert_eq!(event.kind, "created");
+    assert_eq!(event.id, id);
+}
+
+#[tokio::test]
+async fn test_watch_reports_external_edit() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+    let id = new_issue(project, "Edited by hand").await;
+    let mut stream = open_stream(project, "issues").await;
+
+    let file = project.join(".centy/issues").join(format!("{id}.md"));
+    let content = tokio::fs::read_to_string(&file).await.unwrap();
+    tokio::fs::write(&file, format!("{content}\nMore text.\n"))
+        .await
+        .unwrap();
+
+    let event = next_event(&mut stream).await;
+    assert_eq!(event.kind, "updated");
+    assert_eq!(event.id, id);
+}
+
+#[tokio::test]
+async fn test_watch_unknown_item_type_is_not_found() {
+    let temp_dir = create_test_dir();
+    let project = temp_dir.path();
+    init_centy_project(project).await;
+
+    let status = watch(WatchRequest {
+        project_path: project.to_string_lossy().to_string(),
+        item_type: "nope".to_string(),
+    })
+    .await
+    .err()
+    .unwrap();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_watch_requires_initialized_project() {
+    let temp_dir = create_test_dir();
+
+    let status = watch(WatchRequest {
+        project_path: temp_dir.path().to_string_lossy().to_string(),
+        item_type: String::new(),
+    })
+    .await
+    .err()
+    .unwrap();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}